@@ -0,0 +1,161 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Algorithms over graphs stored in collections.
+//!
+//! A graph is given as any collection of pairs of a node and the nodes it
+//! depends on, such as a `BTreeMap<K, BTreeSet<K>>`, a
+//! `HashMap<K, Vec<K>>`, or a reference to either of them.
+
+#![unstable(feature = "topo_sort", issue = "0")]
+
+use core::fmt;
+use core::mem;
+
+use btree_map::BTreeMap;
+use btree_set::BTreeSet;
+use vec::Vec;
+
+/// Sorts the nodes of a dependency graph so that every node comes after
+/// all of the nodes it depends on.
+///
+/// `graph` yields each node together with the nodes it depends on. A node
+/// that only ever appears as a dependency is sorted too, as a node without
+/// dependencies of its own. Whenever more than one node could come next,
+/// the smallest of them does, so the result only depends on the graph, not
+/// on the order in which `graph` yields it.
+///
+/// This takes `O((n + e) log n)` time for `n` nodes and `e` dependencies.
+///
+/// # Errors
+///
+/// If the dependencies form a cycle, no order exists and a [`CycleError`]
+/// holding the nodes of one of the cycles is returned.
+///
+/// [`CycleError`]: struct.CycleError.html
+///
+/// # Examples
+///
+/// ```
+/// #![feature(topo_sort)]
+/// use std::collections::BTreeMap;
+/// use std::collections::algorithms::topo_sort;
+///
+/// let mut deps = BTreeMap::new();
+/// deps.insert("app", vec!["net", "log"]);
+/// deps.insert("net", vec!["log"]);
+/// assert_eq!(topo_sort(&deps), Ok(vec![&"log", &"net", &"app"]));
+///
+/// deps.insert("log", vec!["app"]);
+/// let err = topo_sort(&deps).unwrap_err();
+/// assert_eq!(err.cycle(), [&"app", &"log"]);
+/// ```
+pub fn topo_sort<G, D, K>(graph: G) -> Result<Vec<K>, CycleError<K>>
+    where G: IntoIterator<Item = (K, D)>,
+          D: IntoIterator<Item = K>,
+          K: Ord + Clone
+{
+    let mut nodes: BTreeMap<K, Node<K>> = BTreeMap::new();
+    for (node, deps) in graph {
+        let mut dependencies = Vec::new();
+        for dep in deps {
+            nodes.entry(dep.clone()).or_insert_with(Node::new).dependents.push(node.clone());
+            dependencies.push(dep);
+        }
+        let entry = nodes.entry(node).or_insert_with(Node::new);
+        entry.pending += dependencies.len();
+        entry.dependencies.extend(dependencies);
+    }
+
+    let mut ready: BTreeSet<K> = nodes.iter()
+        .filter(|&(_, node)| node.pending == 0)
+        .map(|(key, _)| key.clone())
+        .collect();
+    let mut order = Vec::with_capacity(nodes.len());
+    while let Some(key) = ready.iter().next().cloned() {
+        ready.remove(&key);
+        let dependents = mem::replace(&mut nodes.get_mut(&key).unwrap().dependents, Vec::new());
+        for dependent in dependents {
+            let node = nodes.get_mut(&dependent).unwrap();
+            node.pending -= 1;
+            if node.pending == 0 {
+                ready.insert(dependent);
+            }
+        }
+        order.push(key);
+    }
+
+    if order.len() == nodes.len() {
+        return Ok(order);
+    }
+
+    // Every node that is left has a dependency that is left too, so
+    // following those dependencies from any of them runs into a cycle.
+    let mut path = Vec::new();
+    let mut seen = BTreeMap::new();
+    let mut key = nodes.iter().find(|&(_, node)| node.pending > 0).unwrap().0.clone();
+    while !seen.contains_key(&key) {
+        let next = nodes[&key].dependencies.iter()
+            .filter(|&dep| nodes[dep].pending > 0)
+            .min()
+            .unwrap()
+            .clone();
+        seen.insert(key.clone(), path.len());
+        path.push(key);
+        key = next;
+    }
+    let start = seen[&key];
+    path.drain(..start);
+    Err(CycleError { cycle: path })
+}
+
+// A node of the graph being sorted.
+struct Node<K> {
+    // The number of dependencies that are not in the order yet.
+    pending: usize,
+    dependencies: Vec<K>,
+    dependents: Vec<K>,
+}
+
+impl<K> Node<K> {
+    fn new() -> Node<K> {
+        Node { pending: 0, dependencies: Vec::new(), dependents: Vec::new() }
+    }
+}
+
+/// The error returned by [`topo_sort`] for a graph whose dependencies form
+/// a cycle.
+///
+/// [`topo_sort`]: fn.topo_sort.html
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CycleError<K> {
+    cycle: Vec<K>,
+}
+
+impl<K> CycleError<K> {
+    /// Returns the nodes of the cycle. Each of them depends on the one
+    /// after it, and the last one depends on the first.
+    pub fn cycle(&self) -> &[K] {
+        &self.cycle
+    }
+
+    /// Returns the nodes of the cycle, in the same order as [`cycle`].
+    ///
+    /// [`cycle`]: #method.cycle
+    pub fn into_cycle(self) -> Vec<K> {
+        self.cycle
+    }
+}
+
+impl<K> fmt::Display for CycleError<K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("dependency cycle detected")
+    }
+}
@@ -14,6 +14,7 @@
 #![feature(rand)]
 #![feature(repr_simd)]
 #![feature(slice_sort_by_cached_key)]
+#![feature(string_push_num)]
 #![feature(test)]
 
 extern crate rand;
@@ -132,3 +132,56 @@ fn bench_to_string(b: &mut Bencher) {
              Lorem ipsum dolor sit amet, consectetur. ";
     b.iter(|| s.to_string())
 }
+
+#[bench]
+fn bench_add_str(b: &mut Bencher) {
+    b.iter(|| String::from("Hello") + ", " + "the quick brown fox" + " jumped over the lazy dog")
+}
+
+#[bench]
+fn bench_write_fmt(b: &mut Bencher) {
+    use std::fmt::Write;
+
+    b.iter(|| {
+        let mut s = String::new();
+        for i in 0..16 {
+            write!(s, "item {} of {}, ", i, 16).unwrap();
+        }
+        s
+    })
+}
+
+#[bench]
+fn bench_push_num_int(b: &mut Bencher) {
+    b.iter(|| {
+        let mut s = String::with_capacity(256);
+        for i in -16i64..16 {
+            s.push_num(i * 1_000_003);
+        }
+        s
+    })
+}
+
+#[bench]
+fn bench_push_num_float(b: &mut Bencher) {
+    b.iter(|| {
+        let mut s = String::with_capacity(512);
+        for i in 0..16 {
+            s.push_num(i as f64 / 7.0);
+        }
+        s
+    })
+}
+
+#[bench]
+fn bench_write_int(b: &mut Bencher) {
+    use std::fmt::Write;
+
+    b.iter(|| {
+        let mut s = String::with_capacity(256);
+        for i in -16i64..16 {
+            write!(s, "{}", i * 1_000_003).unwrap();
+        }
+        s
+    })
+}
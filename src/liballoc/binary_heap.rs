@@ -155,13 +155,17 @@
 #![allow(missing_docs)]
 #![stable(feature = "rust1", since = "1.0.0")]
 
+use core::cmp::Ordering;
 use core::ops::{Deref, DerefMut};
 use core::iter::{FromIterator, FusedIterator};
-use core::mem::{swap, size_of};
+use core::marker::PhantomData;
+use core::mem::{self, swap, size_of};
 use core::ptr;
 use core::fmt;
 
+use dot;
 use slice;
+use string::String;
 use vec::{self, Vec};
 
 use super::SpecExtend;
@@ -217,9 +221,135 @@ use super::SpecExtend;
 /// // The heap should now be empty.
 /// assert!(heap.is_empty())
 /// ```
+///
+/// ## Min-heap
+///
+/// Either `std::cmp::Reverse` or a custom `Ord` implementation can be used to
+/// make `BinaryHeap` a min-heap. This makes `heap.pop()` return the smallest
+/// value instead of the greatest one.
+///
+/// ```
+/// use std::collections::BinaryHeap;
+/// use std::cmp::Reverse;
+///
+/// let mut heap = BinaryHeap::new();
+///
+/// // Wrap values in `Reverse`
+/// heap.push(Reverse(1));
+/// heap.push(Reverse(5));
+/// heap.push(Reverse(2));
+///
+/// // If we pop these scores now, they should come back in the reverse order.
+/// assert_eq!(heap.pop(), Some(Reverse(1)));
+/// assert_eq!(heap.pop(), Some(Reverse(2)));
+/// assert_eq!(heap.pop(), Some(Reverse(5)));
+/// assert_eq!(heap.pop(), None);
+/// ```
+///
+/// The elements are kept in a `Vec<T>` by default, but any other
+/// [`HeapStorage`] can be used instead, such as a fixed-capacity array for
+/// code that must not allocate.
+///
+/// [`HeapStorage`]: trait.HeapStorage.html
 #[stable(feature = "rust1", since = "1.0.0")]
-pub struct BinaryHeap<T> {
-    data: Vec<T>,
+pub struct BinaryHeap<T, S = Vec<T>> {
+    data: S,
+    marker: PhantomData<T>,
+}
+
+/// The backing store of a [`BinaryHeap`].
+///
+/// A heap only needs to view its elements as a slice and to add and remove
+/// elements at the end, so any contiguous sequence can hold it. `Vec<T>` is
+/// the default; a fixed-capacity vector gives a heap that never allocates.
+///
+/// [`BinaryHeap`]: struct.BinaryHeap.html
+///
+/// # Examples
+///
+/// ```
+/// #![feature(binary_heap_storage)]
+/// use std::collections::BinaryHeap;
+/// use std::collections::binary_heap::HeapStorage;
+///
+/// struct Fixed {
+///     buf: [u32; 4],
+///     len: usize,
+/// }
+///
+/// impl HeapStorage<u32> for Fixed {
+///     fn as_slice(&self) -> &[u32] {
+///         &self.buf[..self.len]
+///     }
+///
+///     fn as_mut_slice(&mut self) -> &mut [u32] {
+///         &mut self.buf[..self.len]
+///     }
+///
+///     fn push(&mut self, item: u32) -> Result<(), u32> {
+///         if self.len == self.buf.len() {
+///             return Err(item);
+///         }
+///         self.buf[self.len] = item;
+///         self.len += 1;
+///         Ok(())
+///     }
+///
+///     fn pop(&mut self) -> Option<u32> {
+///         if self.len == 0 {
+///             return None;
+///         }
+///         self.len -= 1;
+///         Some(self.buf[self.len])
+///     }
+/// }
+///
+/// let mut heap = BinaryHeap::from_storage(Fixed { buf: [0; 4], len: 0 });
+/// for &x in &[3, 1, 4, 1] {
+///     heap.try_push(x).unwrap();
+/// }
+/// assert_eq!(heap.try_push(5), Err(5));
+/// assert_eq!(heap.pop(), Some(4));
+/// assert_eq!(heap.pop(), Some(3));
+/// ```
+#[unstable(feature = "binary_heap_storage", issue = "0")]
+pub trait HeapStorage<T> {
+    /// Returns the elements as a slice.
+    fn as_slice(&self) -> &[T];
+
+    /// Returns the elements as a mutable slice.
+    fn as_mut_slice(&mut self) -> &mut [T];
+
+    /// Appends an element at the end, handing it back if there is no room.
+    fn push(&mut self, item: T) -> Result<(), T>;
+
+    /// Removes the last element and returns it, or `None` if there are no
+    /// elements.
+    fn pop(&mut self) -> Option<T>;
+}
+
+#[unstable(feature = "binary_heap_storage", issue = "0")]
+impl<T> HeapStorage<T> for Vec<T> {
+    #[inline]
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+
+    #[inline]
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+
+    #[inline]
+    fn push(&mut self, item: T) -> Result<(), T> {
+        Vec::push(self, item);
+        Ok(())
+    }
+
+    #[inline]
+    fn pop(&mut self) -> Option<T> {
+        Vec::pop(self)
+    }
 }
 
 /// Structure wrapping a mutable reference to the greatest item on a
@@ -282,7 +412,7 @@ impl<'a, T: Ord> PeekMut<'a, T> {
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T: Clone> Clone for BinaryHeap<T> {
     fn clone(&self) -> Self {
-        BinaryHeap { data: self.data.clone() }
+        BinaryHeap { data: self.data.clone(), marker: PhantomData }
     }
 
     fn clone_from(&mut self, source: &Self) {
@@ -320,7 +450,7 @@ impl<T: Ord> BinaryHeap<T> {
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn new() -> BinaryHeap<T> {
-        BinaryHeap { data: vec![] }
+        BinaryHeap { data: vec![], marker: PhantomData }
     }
 
     /// Creates an empty `BinaryHeap` with a specific capacity.
@@ -339,50 +469,7 @@ impl<T: Ord> BinaryHeap<T> {
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn with_capacity(capacity: usize) -> BinaryHeap<T> {
-        BinaryHeap { data: Vec::with_capacity(capacity) }
-    }
-
-    /// Returns an iterator visiting all values in the underlying vector, in
-    /// arbitrary order.
-    ///
-    /// # Examples
-    ///
-    /// Basic usage:
-    ///
-    /// ```
-    /// use std::collections::BinaryHeap;
-    /// let heap = BinaryHeap::from(vec![1, 2, 3, 4]);
-    ///
-    /// // Print 1, 2, 3, 4 in arbitrary order
-    /// for x in heap.iter() {
-    ///     println!("{}", x);
-    /// }
-    /// ```
-    #[stable(feature = "rust1", since = "1.0.0")]
-    pub fn iter(&self) -> Iter<T> {
-        Iter { iter: self.data.iter() }
-    }
-
-    /// Returns the greatest item in the binary heap, or `None` if it is empty.
-    ///
-    /// # Examples
-    ///
-    /// Basic usage:
-    ///
-    /// ```
-    /// use std::collections::BinaryHeap;
-    /// let mut heap = BinaryHeap::new();
-    /// assert_eq!(heap.peek(), None);
-    ///
-    /// heap.push(1);
-    /// heap.push(5);
-    /// heap.push(2);
-    /// assert_eq!(heap.peek(), Some(&5));
-    ///
-    /// ```
-    #[stable(feature = "rust1", since = "1.0.0")]
-    pub fn peek(&self) -> Option<&T> {
-        self.data.get(0)
+        BinaryHeap { data: Vec::with_capacity(capacity), marker: PhantomData }
     }
 
     /// Returns a mutable reference to the greatest item in the binary heap, or
@@ -534,32 +621,6 @@ impl<T: Ord> BinaryHeap<T> {
         self.data.shrink_to(min_capacity)
     }
 
-    /// Removes the greatest item from the binary heap and returns it, or `None` if it
-    /// is empty.
-    ///
-    /// # Examples
-    ///
-    /// Basic usage:
-    ///
-    /// ```
-    /// use std::collections::BinaryHeap;
-    /// let mut heap = BinaryHeap::from(vec![1, 3]);
-    ///
-    /// assert_eq!(heap.pop(), Some(3));
-    /// assert_eq!(heap.pop(), Some(1));
-    /// assert_eq!(heap.pop(), None);
-    /// ```
-    #[stable(feature = "rust1", since = "1.0.0")]
-    pub fn pop(&mut self) -> Option<T> {
-        self.data.pop().map(|mut item| {
-            if !self.is_empty() {
-                swap(&mut item, &mut self.data[0]);
-                self.sift_down_to_bottom(0);
-            }
-            item
-        })
-    }
-
     /// Pushes an item onto the binary heap.
     ///
     /// # Examples
@@ -633,122 +694,6 @@ impl<T: Ord> BinaryHeap<T> {
         self.into_vec()
     }
 
-    // The implementations of sift_up and sift_down use unsafe blocks in
-    // order to move an element out of the vector (leaving behind a
-    // hole), shift along the others and move the removed element back into the
-    // vector at the final location of the hole.
-    // The `Hole` type is used to represent this, and make sure
-    // the hole is filled back at the end of its scope, even on panic.
-    // Using a hole reduces the constant factor compared to using swaps,
-    // which involves twice as many moves.
-    fn sift_up(&mut self, start: usize, pos: usize) -> usize {
-        unsafe {
-            // Take out the value at `pos` and create a hole.
-            let mut hole = Hole::new(&mut self.data, pos);
-
-            while hole.pos() > start {
-                let parent = (hole.pos() - 1) / 2;
-                if hole.element() <= hole.get(parent) {
-                    break;
-                }
-                hole.move_to(parent);
-            }
-            hole.pos()
-        }
-    }
-
-    /// Take an element at `pos` and move it down the heap,
-    /// while its children are larger.
-    fn sift_down_range(&mut self, pos: usize, end: usize) {
-        unsafe {
-            let mut hole = Hole::new(&mut self.data, pos);
-            let mut child = 2 * pos + 1;
-            while child < end {
-                let right = child + 1;
-                // compare with the greater of the two children
-                if right < end && !(hole.get(child) > hole.get(right)) {
-                    child = right;
-                }
-                // if we are already in order, stop.
-                if hole.element() >= hole.get(child) {
-                    break;
-                }
-                hole.move_to(child);
-                child = 2 * hole.pos() + 1;
-            }
-        }
-    }
-
-    fn sift_down(&mut self, pos: usize) {
-        let len = self.len();
-        self.sift_down_range(pos, len);
-    }
-
-    /// Take an element at `pos` and move it all the way down the heap,
-    /// then sift it up to its position.
-    ///
-    /// Note: This is faster when the element is known to be large / should
-    /// be closer to the bottom.
-    fn sift_down_to_bottom(&mut self, mut pos: usize) {
-        let end = self.len();
-        let start = pos;
-        unsafe {
-            let mut hole = Hole::new(&mut self.data, pos);
-            let mut child = 2 * pos + 1;
-            while child < end {
-                let right = child + 1;
-                // compare with the greater of the two children
-                if right < end && !(hole.get(child) > hole.get(right)) {
-                    child = right;
-                }
-                hole.move_to(child);
-                child = 2 * hole.pos() + 1;
-            }
-            pos = hole.pos;
-        }
-        self.sift_up(start, pos);
-    }
-
-    /// Returns the length of the binary heap.
-    ///
-    /// # Examples
-    ///
-    /// Basic usage:
-    ///
-    /// ```
-    /// use std::collections::BinaryHeap;
-    /// let heap = BinaryHeap::from(vec![1, 3]);
-    ///
-    /// assert_eq!(heap.len(), 2);
-    /// ```
-    #[stable(feature = "rust1", since = "1.0.0")]
-    pub fn len(&self) -> usize {
-        self.data.len()
-    }
-
-    /// Checks if the binary heap is empty.
-    ///
-    /// # Examples
-    ///
-    /// Basic usage:
-    ///
-    /// ```
-    /// use std::collections::BinaryHeap;
-    /// let mut heap = BinaryHeap::new();
-    ///
-    /// assert!(heap.is_empty());
-    ///
-    /// heap.push(3);
-    /// heap.push(5);
-    /// heap.push(1);
-    ///
-    /// assert!(!heap.is_empty());
-    /// ```
-    #[stable(feature = "rust1", since = "1.0.0")]
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
-    }
-
     /// Clears the binary heap, returning an iterator over the removed elements.
     ///
     /// The elements are removed in arbitrary order.
@@ -796,12 +741,42 @@ impl<T: Ord> BinaryHeap<T> {
         self.drain();
     }
 
-    fn rebuild(&mut self) {
-        let mut n = self.len() / 2;
-        while n > 0 {
-            n -= 1;
-            self.sift_down(n);
+    /// Describes the tree structure of the binary heap in the Graphviz DOT
+    /// language.
+    ///
+    /// Every element becomes a node with edges to its (at most two) children
+    /// in the heap. This is meant for debugging and the exact output is not
+    /// specified.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(collections_debug_dot)]
+    /// use std::collections::BinaryHeap;
+    /// let heap = BinaryHeap::from(vec![1, 3, 2]);
+    ///
+    /// let dot = heap.debug_dot();
+    /// assert!(dot.starts_with("digraph BinaryHeap {"));
+    /// assert!(dot.contains("n0 [label=\"3\"];"));
+    /// assert!(dot.contains("n0 -> n1;"));
+    /// ```
+    #[unstable(feature = "collections_debug_dot", issue = "0")]
+    pub fn debug_dot(&self) -> String
+        where T: fmt::Debug
+    {
+        let mut out = String::from("digraph BinaryHeap {\n");
+        for (i, elem) in self.data.iter().enumerate() {
+            out.push_str(&format!("    n{} [label=\"", i));
+            dot::push_label(&mut out, elem, false);
+            out.push_str("\"];\n");
         }
+        for child in 1..self.len() {
+            out.push_str(&format!("    n{} -> n{};\n", (child - 1) / 2, child));
+        }
+        out.push_str("}\n");
+        out
     }
 
     /// Moves all the elements of `other` into `self`, leaving `other` empty.
@@ -858,19 +833,345 @@ impl<T: Ord> BinaryHeap<T> {
     }
 }
 
-/// Hole represents a hole in a slice i.e. an index without valid value
-/// (because it was moved from or duplicated).
-/// In drop, `Hole` will restore the slice by filling the hole
-/// position with the value that was originally removed.
-struct Hole<'a, T: 'a> {
-    data: &'a mut [T],
-    /// `elt` is always `Some` from new until drop.
-    elt: Option<T>,
-    pos: usize,
-}
-
-impl<'a, T> Hole<'a, T> {
-    /// Create a new Hole at index `pos`.
+impl<T: Ord, S: HeapStorage<T>> BinaryHeap<T, S> {
+    /// Creates a binary heap from the elements of `storage`, using it as the
+    /// backing store.
+    ///
+    /// This rearranges the elements into heap order in `O(n)` time.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(binary_heap_storage)]
+    /// use std::collections::BinaryHeap;
+    ///
+    /// let mut heap = BinaryHeap::from_storage(vec![1, 5, 2]);
+    /// assert_eq!(heap.pop(), Some(5));
+    /// assert_eq!(heap.into_storage().len(), 2);
+    /// ```
+    #[unstable(feature = "binary_heap_storage", issue = "0")]
+    pub fn from_storage(storage: S) -> BinaryHeap<T, S> {
+        let mut heap = BinaryHeap { data: storage, marker: PhantomData };
+        heap.rebuild();
+        heap
+    }
+
+    /// Consumes the `BinaryHeap` and returns its backing store, with the
+    /// elements in arbitrary order.
+    #[unstable(feature = "binary_heap_storage", issue = "0")]
+    pub fn into_storage(self) -> S {
+        self.data
+    }
+
+
+    /// Returns an iterator visiting all values in the underlying vector, in
+    /// arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::collections::BinaryHeap;
+    /// let heap = BinaryHeap::from(vec![1, 2, 3, 4]);
+    ///
+    /// // Print 1, 2, 3, 4 in arbitrary order
+    /// for x in heap.iter() {
+    ///     println!("{}", x);
+    /// }
+    /// ```
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub fn iter(&self) -> Iter<T> {
+        Iter { iter: self.data.as_slice().iter() }
+    }
+
+    /// Returns the greatest item in the binary heap, or `None` if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::collections::BinaryHeap;
+    /// let mut heap = BinaryHeap::new();
+    /// assert_eq!(heap.peek(), None);
+    ///
+    /// heap.push(1);
+    /// heap.push(5);
+    /// heap.push(2);
+    /// assert_eq!(heap.peek(), Some(&5));
+    ///
+    /// ```
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub fn peek(&self) -> Option<&T> {
+        self.data.as_slice().get(0)
+    }
+
+    /// Returns the length of the binary heap.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::collections::BinaryHeap;
+    /// let heap = BinaryHeap::from(vec![1, 3]);
+    ///
+    /// assert_eq!(heap.len(), 2);
+    /// ```
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub fn len(&self) -> usize {
+        self.data.as_slice().len()
+    }
+
+    /// Checks if the binary heap is empty.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::collections::BinaryHeap;
+    /// let mut heap = BinaryHeap::new();
+    ///
+    /// assert!(heap.is_empty());
+    ///
+    /// heap.push(3);
+    /// heap.push(5);
+    /// heap.push(1);
+    ///
+    /// assert!(!heap.is_empty());
+    /// ```
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes the greatest item from the binary heap and returns it, or `None` if it
+    /// is empty.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::collections::BinaryHeap;
+    /// let mut heap = BinaryHeap::from(vec![1, 3]);
+    ///
+    /// assert_eq!(heap.pop(), Some(3));
+    /// assert_eq!(heap.pop(), Some(1));
+    /// assert_eq!(heap.pop(), None);
+    /// ```
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub fn pop(&mut self) -> Option<T> {
+        self.data.pop().map(|mut item| {
+            if !self.is_empty() {
+                swap(&mut item, &mut self.data.as_mut_slice()[0]);
+                self.sift_down_to_bottom(0);
+            }
+            item
+        })
+    }
+
+    /// Pushes an item onto the binary heap, or hands it back if the backing
+    /// store is full.
+    ///
+    /// A heap backed by a `Vec` is never full; [`push`] is the more
+    /// convenient method there.
+    ///
+    /// [`push`]: #method.push
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(binary_heap_storage)]
+    /// use std::collections::BinaryHeap;
+    ///
+    /// let mut heap = BinaryHeap::new();
+    /// assert_eq!(heap.try_push(3), Ok(()));
+    /// assert_eq!(heap.peek(), Some(&3));
+    /// ```
+    #[unstable(feature = "binary_heap_storage", issue = "0")]
+    pub fn try_push(&mut self, item: T) -> Result<(), T> {
+        let old_len = self.len();
+        self.data.push(item)?;
+        self.sift_up(0, old_len);
+        Ok(())
+    }
+
+    /// Pushes an item onto the binary heap, then pops the greatest item off
+    /// it and returns it.
+    ///
+    /// This is the same as a [`push`] followed by a [`pop`], but it sifts
+    /// once instead of twice, and not at all if `item` is at least as great
+    /// as every item in the heap, in which case `item` itself is returned.
+    /// Keeping the `k` smallest items of a stream in a heap of size `k` is a
+    /// `push_pop` per item.
+    ///
+    /// [`push`]: #method.push
+    /// [`pop`]: #method.pop
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(binary_heap_push_pop)]
+    /// use std::collections::BinaryHeap;
+    ///
+    /// let mut heap = BinaryHeap::from(vec![1, 5, 3]);
+    /// assert_eq!(heap.push_pop(4), 5);
+    /// assert_eq!(heap.push_pop(6), 6);
+    /// assert_eq!(heap.into_sorted_vec(), [1, 3, 4]);
+    /// ```
+    #[unstable(feature = "binary_heap_push_pop", issue = "0")]
+    pub fn push_pop(&mut self, mut item: T) -> T {
+        match self.data.as_slice().first() {
+            Some(top) if *top > item => {}
+            _ => return item,
+        }
+        swap(&mut item, &mut self.data.as_mut_slice()[0]);
+        self.sift_down(0);
+        item
+    }
+
+    /// Replaces the greatest item in the binary heap with `item`, and
+    /// returns the item that was replaced.
+    ///
+    /// This is the same as a [`pop`] followed by a [`push`], but it sifts
+    /// once instead of twice. If the heap is empty, `item` is pushed and
+    /// `None` is returned; if the backing store has no room for it either,
+    /// it is handed back instead.
+    ///
+    /// [`push`]: #method.push
+    /// [`pop`]: #method.pop
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(binary_heap_push_pop)]
+    /// use std::collections::BinaryHeap;
+    ///
+    /// let mut heap = BinaryHeap::new();
+    /// assert_eq!(heap.replace_top(1), None);
+    /// heap.push(5);
+    /// assert_eq!(heap.replace_top(3), Some(5));
+    /// assert_eq!(heap.into_sorted_vec(), [1, 3]);
+    /// ```
+    #[unstable(feature = "binary_heap_push_pop", issue = "0")]
+    pub fn replace_top(&mut self, mut item: T) -> Option<T> {
+        if self.is_empty() {
+            return self.try_push(item).err();
+        }
+        swap(&mut item, &mut self.data.as_mut_slice()[0]);
+        self.sift_down(0);
+        Some(item)
+    }
+
+    // The implementations of sift_up and sift_down use unsafe blocks in
+    // order to move an element out of the vector (leaving behind a
+    // hole), shift along the others and move the removed element back into the
+    // vector at the final location of the hole.
+    // The `Hole` type is used to represent this, and make sure
+    // the hole is filled back at the end of its scope, even on panic.
+    // Using a hole reduces the constant factor compared to using swaps,
+    // which involves twice as many moves.
+    fn sift_up(&mut self, start: usize, pos: usize) -> usize {
+        unsafe {
+            // Take out the value at `pos` and create a hole.
+            let mut hole = Hole::new(self.data.as_mut_slice(), pos);
+
+            while hole.pos() > start {
+                let parent = (hole.pos() - 1) / 2;
+                if hole.element() <= hole.get(parent) {
+                    break;
+                }
+                hole.move_to(parent);
+            }
+            hole.pos()
+        }
+    }
+
+    /// Take an element at `pos` and move it down the heap,
+    /// while its children are larger.
+    fn sift_down_range(&mut self, pos: usize, end: usize) {
+        unsafe {
+            let mut hole = Hole::new(self.data.as_mut_slice(), pos);
+            let mut child = 2 * pos + 1;
+            while child < end {
+                let right = child + 1;
+                // compare with the greater of the two children
+                if right < end && !(hole.get(child) > hole.get(right)) {
+                    child = right;
+                }
+                // if we are already in order, stop.
+                if hole.element() >= hole.get(child) {
+                    break;
+                }
+                hole.move_to(child);
+                child = 2 * hole.pos() + 1;
+            }
+        }
+    }
+
+    fn sift_down(&mut self, pos: usize) {
+        let len = self.len();
+        self.sift_down_range(pos, len);
+    }
+
+    /// Take an element at `pos` and move it all the way down the heap,
+    /// then sift it up to its position.
+    ///
+    /// Note: This is faster when the element is known to be large / should
+    /// be closer to the bottom.
+    fn sift_down_to_bottom(&mut self, mut pos: usize) {
+        let end = self.len();
+        let start = pos;
+        unsafe {
+            let mut hole = Hole::new(self.data.as_mut_slice(), pos);
+            let mut child = 2 * pos + 1;
+            while child < end {
+                let right = child + 1;
+                // compare with the greater of the two children
+                if right < end && !(hole.get(child) > hole.get(right)) {
+                    child = right;
+                }
+                hole.move_to(child);
+                child = 2 * hole.pos() + 1;
+            }
+            pos = hole.pos;
+        }
+        self.sift_up(start, pos);
+    }
+
+    fn rebuild(&mut self) {
+        let mut n = self.len() / 2;
+        while n > 0 {
+            n -= 1;
+            self.sift_down(n);
+        }
+    }
+}
+
+/// Hole represents a hole in a slice i.e. an index without valid value
+/// (because it was moved from or duplicated).
+/// In drop, `Hole` will restore the slice by filling the hole
+/// position with the value that was originally removed.
+struct Hole<'a, T: 'a> {
+    data: &'a mut [T],
+    /// `elt` is always `Some` from new until drop.
+    elt: Option<T>,
+    pos: usize,
+}
+
+impl<'a, T> Hole<'a, T> {
+    /// Create a new Hole at index `pos`.
     ///
     /// Unsafe because pos must be within the data slice.
     #[inline]
@@ -1096,9 +1397,7 @@ impl<'a, T: 'a> FusedIterator for Drain<'a, T> {}
 #[stable(feature = "binary_heap_extras_15", since = "1.5.0")]
 impl<T: Ord> From<Vec<T>> for BinaryHeap<T> {
     fn from(vec: Vec<T>) -> BinaryHeap<T> {
-        let mut heap = BinaryHeap { data: vec };
-        heap.rebuild();
-        heap
+        BinaryHeap::from_storage(vec)
     }
 }
 
@@ -1195,3 +1494,576 @@ impl<'a, T: 'a + Ord + Copy> Extend<&'a T> for BinaryHeap<T> {
         self.extend(iter.into_iter().cloned());
     }
 }
+
+/// A priority queue whose items can be removed in `O(1)` time, no matter
+/// where they are in the heap.
+///
+/// Removing an arbitrary item from a [`BinaryHeap`] means finding it and
+/// restoring the heap property around the hole it leaves, which takes
+/// `O(n)` time. A `LazyHeap` instead only marks the item as removed: it
+/// stays in the heap as a tombstone, and is thrown away once it reaches the
+/// top. This suits event schedulers and timer wheels, where most of the
+/// queued events are cancelled before they are due.
+///
+/// Items are removed through the [`LazyHandle`] that [`push`] returns for
+/// them, or with a predicate through [`mark_removed_where`]. Whenever the
+/// tombstones outnumber the items that are still live, the heap is
+/// compacted by rebuilding it from the live items, so that heavy
+/// cancellation can't make it grow without bound. This takes `O(n)` time,
+/// but happens at most once for every `n` removals.
+///
+/// [`BinaryHeap`]: struct.BinaryHeap.html
+/// [`LazyHandle`]: struct.LazyHandle.html
+/// [`push`]: #method.push
+/// [`mark_removed_where`]: #method.mark_removed_where
+///
+/// # Examples
+///
+/// ```
+/// #![feature(binary_heap_lazy)]
+/// use std::collections::binary_heap::LazyHeap;
+/// use std::cmp::Reverse;
+///
+/// let mut timers = LazyHeap::new();
+/// let _retry = timers.push(Reverse((30, "retry")));
+/// let timeout = timers.push(Reverse((10, "timeout")));
+/// let _tick = timers.push(Reverse((20, "tick")));
+///
+/// // The request completed, so the timeout will never fire.
+/// assert!(timers.mark_removed(timeout));
+/// assert_eq!(timers.len(), 2);
+///
+/// assert_eq!(timers.pop(), Some(Reverse((20, "tick"))));
+/// assert_eq!(timers.pop(), Some(Reverse((30, "retry"))));
+/// assert_eq!(timers.pop(), None);
+/// ```
+#[unstable(feature = "binary_heap_lazy", issue = "0")]
+pub struct LazyHeap<T> {
+    heap: BinaryHeap<LazyEntry<T>>,
+    /// The state of every entry, indexed by the `slot` of the entry.
+    slots: Vec<Slot>,
+    /// The indices of the slots that are not in use.
+    free: Vec<usize>,
+    /// The number of entries in `heap` that are marked as removed.
+    removed: usize,
+}
+
+/// A handle to an item in a [`LazyHeap`], used to remove the item.
+///
+/// This `struct` is returned by the [`push`] method on [`LazyHeap`]. A
+/// handle stays valid until its item is popped or removed; after that, it
+/// no longer refers to any item, even if the heap reuses its slot.
+///
+/// [`LazyHeap`]: struct.LazyHeap.html
+/// [`push`]: struct.LazyHeap.html#method.push
+#[unstable(feature = "binary_heap_lazy", issue = "0")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LazyHandle {
+    slot: usize,
+    generation: usize,
+}
+
+/// An item in the heap of a `LazyHeap`, ordered by the item alone.
+struct LazyEntry<T> {
+    item: T,
+    slot: usize,
+}
+
+impl<T: Ord> PartialEq for LazyEntry<T> {
+    fn eq(&self, other: &LazyEntry<T>) -> bool {
+        self.item == other.item
+    }
+}
+
+impl<T: Ord> Eq for LazyEntry<T> {}
+
+impl<T: Ord> PartialOrd for LazyEntry<T> {
+    fn partial_cmp(&self, other: &LazyEntry<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord> Ord for LazyEntry<T> {
+    fn cmp(&self, other: &LazyEntry<T>) -> Ordering {
+        self.item.cmp(&other.item)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum SlotState {
+    Free,
+    Live,
+    Removed,
+}
+
+#[derive(Clone, Copy)]
+struct Slot {
+    /// Incremented whenever the slot is freed, so that the handles to its
+    /// previous entries don't match its next one.
+    generation: usize,
+    state: SlotState,
+}
+
+#[unstable(feature = "binary_heap_lazy", issue = "0")]
+impl<T: Ord> LazyHeap<T> {
+    /// Creates an empty `LazyHeap`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(binary_heap_lazy)]
+    /// use std::collections::binary_heap::LazyHeap;
+    ///
+    /// let mut heap = LazyHeap::new();
+    /// heap.push(4);
+    /// ```
+    pub fn new() -> LazyHeap<T> {
+        LazyHeap::with_capacity(0)
+    }
+
+    /// Creates an empty `LazyHeap` with space for at least `capacity`
+    /// items and tombstones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(binary_heap_lazy)]
+    /// use std::collections::binary_heap::LazyHeap;
+    ///
+    /// let mut heap = LazyHeap::with_capacity(10);
+    /// heap.push(4);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> LazyHeap<T> {
+        LazyHeap {
+            heap: BinaryHeap::with_capacity(capacity),
+            slots: Vec::with_capacity(capacity),
+            free: Vec::new(),
+            removed: 0,
+        }
+    }
+
+    /// Returns the number of items in the heap, not counting the ones that
+    /// are marked as removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(binary_heap_lazy)]
+    /// use std::collections::binary_heap::LazyHeap;
+    ///
+    /// let mut heap = LazyHeap::new();
+    /// let one = heap.push(1);
+    /// heap.push(3);
+    /// heap.mark_removed(one);
+    /// assert_eq!(heap.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.heap.len() - self.removed
+    }
+
+    /// Returns `true` if the heap contains no items, not counting the ones
+    /// that are marked as removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(binary_heap_lazy)]
+    /// use std::collections::binary_heap::LazyHeap;
+    ///
+    /// let mut heap = LazyHeap::new();
+    /// let one = heap.push(1);
+    /// heap.mark_removed(one);
+    /// assert!(heap.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of items that are marked as removed but still take
+    /// up space in the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(binary_heap_lazy)]
+    /// use std::collections::binary_heap::LazyHeap;
+    ///
+    /// let mut heap = LazyHeap::new();
+    /// let one = heap.push(1);
+    /// heap.push(2);
+    /// heap.push(3);
+    /// heap.mark_removed(one);
+    /// assert_eq!(heap.tombstones(), 1);
+    /// ```
+    pub fn tombstones(&self) -> usize {
+        self.removed
+    }
+
+    /// Pushes an item onto the heap, returning a handle that can be used to
+    /// remove it again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(binary_heap_lazy)]
+    /// use std::collections::binary_heap::LazyHeap;
+    ///
+    /// let mut heap = LazyHeap::new();
+    /// let five = heap.push(5);
+    /// assert!(heap.contains(five));
+    /// assert_eq!(heap.peek(), Some(&5));
+    /// ```
+    pub fn push(&mut self, item: T) -> LazyHandle {
+        let slot = match self.free.pop() {
+            Some(slot) => slot,
+            None => {
+                self.slots.push(Slot { generation: 0, state: SlotState::Free });
+                self.slots.len() - 1
+            }
+        };
+        self.slots[slot].state = SlotState::Live;
+        self.heap.push(LazyEntry { item, slot });
+        LazyHandle { slot, generation: self.slots[slot].generation }
+    }
+
+    /// Returns the greatest item in the heap that is not marked as removed,
+    /// or `None` if there is none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(binary_heap_lazy)]
+    /// use std::collections::binary_heap::LazyHeap;
+    ///
+    /// let mut heap = LazyHeap::new();
+    /// heap.push(1);
+    /// let five = heap.push(5);
+    /// heap.mark_removed(five);
+    /// assert_eq!(heap.peek(), Some(&1));
+    /// ```
+    pub fn peek(&self) -> Option<&T> {
+        // Tombstones never stay at the top of the heap.
+        self.heap.peek().map(|entry| &entry.item)
+    }
+
+    /// Removes the greatest item that is not marked as removed from the
+    /// heap and returns it, or `None` if there is none.
+    ///
+    /// Tombstones that reach the top of the heap are discarded on the way.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(binary_heap_lazy)]
+    /// use std::collections::binary_heap::LazyHeap;
+    ///
+    /// let mut heap = LazyHeap::new();
+    /// heap.push(1);
+    /// let two = heap.push(2);
+    /// heap.push(3);
+    /// heap.mark_removed(two);
+    ///
+    /// assert_eq!(heap.pop(), Some(3));
+    /// assert_eq!(heap.pop(), Some(1));
+    /// assert_eq!(heap.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        let entry = match self.heap.pop() {
+            Some(entry) => entry,
+            None => return None,
+        };
+        self.free_slot(entry.slot);
+        self.discard_top();
+        self.compact_if_needed();
+        Some(entry.item)
+    }
+
+    /// Returns `true` if the item that `handle` refers to is in the heap
+    /// and not marked as removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(binary_heap_lazy)]
+    /// use std::collections::binary_heap::LazyHeap;
+    ///
+    /// let mut heap = LazyHeap::new();
+    /// let one = heap.push(1);
+    /// assert!(heap.contains(one));
+    /// heap.pop();
+    /// assert!(!heap.contains(one));
+    /// ```
+    pub fn contains(&self, handle: LazyHandle) -> bool {
+        match self.slots.get(handle.slot) {
+            Some(slot) => slot.generation == handle.generation && slot.state == SlotState::Live,
+            None => false,
+        }
+    }
+
+    /// Marks the item that `handle` refers to as removed, so that it will
+    /// never be returned by `peek` or `pop`.
+    ///
+    /// Returns `false` if the item was already popped or removed.
+    ///
+    /// This takes `O(1)` amortized time, unless the item is the greatest
+    /// one, in which case it is popped right away.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(binary_heap_lazy)]
+    /// use std::collections::binary_heap::LazyHeap;
+    ///
+    /// let mut heap = LazyHeap::new();
+    /// let one = heap.push(1);
+    /// heap.push(2);
+    ///
+    /// assert!(heap.mark_removed(one));
+    /// assert!(!heap.mark_removed(one));
+    /// assert_eq!(heap.pop(), Some(2));
+    /// assert_eq!(heap.pop(), None);
+    /// ```
+    pub fn mark_removed(&mut self, handle: LazyHandle) -> bool {
+        if !self.contains(handle) {
+            return false;
+        }
+        self.slots[handle.slot].state = SlotState::Removed;
+        self.removed += 1;
+        self.discard_top();
+        self.compact_if_needed();
+        true
+    }
+
+    /// Marks all items for which `f` returns `true` as removed.
+    ///
+    /// Returns the number of items that were marked. This takes `O(n)` time,
+    /// since every item is visited, but doesn't move any of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(binary_heap_lazy)]
+    /// use std::collections::binary_heap::LazyHeap;
+    ///
+    /// let mut heap = LazyHeap::new();
+    /// for i in 0..10 {
+    ///     heap.push(i);
+    /// }
+    /// assert_eq!(heap.mark_removed_where(|&i| i % 3 == 0), 4);
+    /// assert_eq!(heap.pop(), Some(8));
+    /// assert_eq!(heap.pop(), Some(7));
+    /// assert_eq!(heap.pop(), Some(5));
+    /// ```
+    pub fn mark_removed_where<F>(&mut self, mut f: F) -> usize
+        where F: FnMut(&T) -> bool
+    {
+        let mut marked = 0;
+        for entry in self.heap.iter() {
+            let slot = &mut self.slots[entry.slot];
+            if slot.state == SlotState::Live && f(&entry.item) {
+                slot.state = SlotState::Removed;
+                marked += 1;
+            }
+        }
+        self.removed += marked;
+        self.discard_top();
+        self.compact_if_needed();
+        marked
+    }
+
+    /// Discards all tombstones from the heap.
+    ///
+    /// This is done automatically whenever the tombstones outnumber the
+    /// other items, but can also be done explicitly, for example before the
+    /// heap is left alone for a while. It takes `O(n)` time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(binary_heap_lazy)]
+    /// use std::collections::binary_heap::LazyHeap;
+    ///
+    /// let mut heap = LazyHeap::new();
+    /// let one = heap.push(1);
+    /// heap.push(2);
+    /// heap.mark_removed(one);
+    /// assert_eq!(heap.tombstones(), 1);
+    ///
+    /// heap.compact();
+    /// assert_eq!(heap.tombstones(), 0);
+    /// assert_eq!(heap.len(), 1);
+    /// ```
+    pub fn compact(&mut self) {
+        if self.removed == 0 {
+            return;
+        }
+        let entries = mem::replace(&mut self.heap, BinaryHeap::new()).into_vec();
+        let mut live = Vec::with_capacity(entries.len() - self.removed);
+        for entry in entries {
+            if self.slots[entry.slot].state == SlotState::Removed {
+                self.free_slot(entry.slot);
+            } else {
+                live.push(entry);
+            }
+        }
+        self.removed = 0;
+        self.heap = BinaryHeap::from(live);
+    }
+
+    /// Removes all items from the heap.
+    ///
+    /// All handles to the items become invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(binary_heap_lazy)]
+    /// use std::collections::binary_heap::LazyHeap;
+    ///
+    /// let mut heap = LazyHeap::new();
+    /// let one = heap.push(1);
+    /// heap.clear();
+    /// assert!(heap.is_empty());
+    /// assert!(!heap.contains(one));
+    /// ```
+    pub fn clear(&mut self) {
+        for entry in self.heap.drain() {
+            let slot = &mut self.slots[entry.slot];
+            slot.state = SlotState::Free;
+            slot.generation = slot.generation.wrapping_add(1);
+            self.free.push(entry.slot);
+        }
+        self.removed = 0;
+    }
+
+    /// Returns an iterator visiting the items that are not marked as
+    /// removed, in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(binary_heap_lazy)]
+    /// use std::collections::binary_heap::LazyHeap;
+    ///
+    /// let mut heap = LazyHeap::new();
+    /// let one = heap.push(1);
+    /// heap.push(2);
+    /// heap.push(3);
+    /// heap.mark_removed(one);
+    ///
+    /// let mut items: Vec<_> = heap.iter().cloned().collect();
+    /// items.sort();
+    /// assert_eq!(items, [2, 3]);
+    /// ```
+    pub fn iter(&self) -> LazyIter<T> {
+        LazyIter {
+            iter: self.heap.iter(),
+            slots: &self.slots,
+            len: self.len(),
+        }
+    }
+
+    fn free_slot(&mut self, index: usize) {
+        let slot = &mut self.slots[index];
+        slot.state = SlotState::Free;
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free.push(index);
+    }
+
+    /// Pops tombstones off the top of the heap, so that `peek` can return
+    /// the top without checking it.
+    fn discard_top(&mut self) {
+        loop {
+            let slot = match self.heap.peek() {
+                Some(entry) if self.slots[entry.slot].state == SlotState::Removed => entry.slot,
+                _ => return,
+            };
+            self.heap.pop();
+            self.free_slot(slot);
+            self.removed -= 1;
+        }
+    }
+
+    fn compact_if_needed(&mut self) {
+        if self.removed > self.heap.len() - self.removed {
+            self.compact();
+        }
+    }
+}
+
+#[unstable(feature = "binary_heap_lazy", issue = "0")]
+impl<T: Ord> Default for LazyHeap<T> {
+    /// Creates an empty `LazyHeap`.
+    fn default() -> LazyHeap<T> {
+        LazyHeap::new()
+    }
+}
+
+#[unstable(feature = "binary_heap_lazy", issue = "0")]
+impl<T: Ord + fmt::Debug> fmt::Debug for LazyHeap<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// An iterator over the items of a `LazyHeap` that are not marked as
+/// removed.
+///
+/// This `struct` is created by the [`iter`] method on [`LazyHeap`]. See its
+/// documentation for more.
+///
+/// [`iter`]: struct.LazyHeap.html#method.iter
+/// [`LazyHeap`]: struct.LazyHeap.html
+#[unstable(feature = "binary_heap_lazy", issue = "0")]
+pub struct LazyIter<'a, T: 'a> {
+    iter: Iter<'a, LazyEntry<T>>,
+    slots: &'a [Slot],
+    len: usize,
+}
+
+#[unstable(feature = "binary_heap_lazy", issue = "0")]
+impl<'a, T> Clone for LazyIter<'a, T> {
+    fn clone(&self) -> LazyIter<'a, T> {
+        LazyIter {
+            iter: self.iter.clone(),
+            slots: self.slots,
+            len: self.len,
+        }
+    }
+}
+
+#[unstable(feature = "binary_heap_lazy", issue = "0")]
+impl<'a, T: fmt::Debug> fmt::Debug for LazyIter<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("LazyIter")
+         .field(&self.clone().collect::<Vec<_>>())
+         .finish()
+    }
+}
+
+#[unstable(feature = "binary_heap_lazy", issue = "0")]
+impl<'a, T> Iterator for LazyIter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        while let Some(entry) = self.iter.next() {
+            if self.slots[entry.slot].state == SlotState::Live {
+                self.len -= 1;
+                return Some(&entry.item);
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+#[unstable(feature = "binary_heap_lazy", issue = "0")]
+impl<'a, T> ExactSizeIterator for LazyIter<'a, T> {}
+
+#[unstable(feature = "binary_heap_lazy", issue = "0")]
+impl<'a, T> FusedIterator for LazyIter<'a, T> {}
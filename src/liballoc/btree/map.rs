@@ -11,7 +11,7 @@
 use core::cmp::Ordering;
 use core::fmt::Debug;
 use core::hash::{Hash, Hasher};
-use core::iter::{FromIterator, Peekable, FusedIterator};
+use core::iter::{self, FromIterator, Peekable, FusedIterator};
 use core::marker::PhantomData;
 use core::ops::Bound::{Excluded, Included, Unbounded};
 use core::ops::Index;
@@ -19,6 +19,8 @@ use core::ops::RangeBounds;
 use core::{fmt, intrinsics, mem, ptr};
 
 use borrow::Borrow;
+use dot;
+use string::String;
 
 use super::node::{self, Handle, NodeRef, marker};
 use super::search;
@@ -282,6 +284,25 @@ impl<'a, K: 'a + fmt::Debug, V: 'a + fmt::Debug> fmt::Debug for Iter<'a, K, V> {
     }
 }
 
+/// An iterator over clones of the entries of a `BTreeMap`.
+///
+/// This `struct` is created by the [`iter_cloned`] method on [`BTreeMap`].
+/// See its documentation for more.
+///
+/// [`iter_cloned`]: struct.BTreeMap.html#method.iter_cloned
+/// [`BTreeMap`]: struct.BTreeMap.html
+#[unstable(feature = "map_iter_cloned", issue = "0")]
+pub struct IterCloned<'a, K: 'a, V: 'a> {
+    inner: Iter<'a, K, V>,
+}
+
+#[unstable(feature = "map_iter_cloned", issue = "0")]
+impl<'a, K: 'a + fmt::Debug, V: 'a + fmt::Debug> fmt::Debug for IterCloned<'a, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.inner.clone()).finish()
+    }
+}
+
 /// A mutable iterator over the entries of a `BTreeMap`.
 ///
 /// This `struct` is created by the [`iter_mut`] method on [`BTreeMap`]. See its
@@ -419,6 +440,39 @@ impl<'a, K: 'a + fmt::Debug, V: 'a + fmt::Debug> fmt::Debug for RangeMut<'a, K,
     }
 }
 
+/// An iterator produced by calling `extract_if` on BTreeMap.
+///
+/// See the [`extract_if`] method on [`BTreeMap`] for more.
+///
+/// [`extract_if`]: struct.BTreeMap.html#method.extract_if
+/// [`BTreeMap`]: struct.BTreeMap.html
+#[unstable(feature = "extract_if", issue = "0")]
+pub struct ExtractIf<'a, K: 'a, V: 'a, F>
+    where K: Ord,
+          F: FnMut(&K, &mut V) -> bool,
+{
+    inner: ExtractIfInner<'a, K, V>,
+    pred: F,
+}
+
+/// The part of `ExtractIf` that does not depend on the predicate, shared
+/// with `BTreeSet`.
+pub(super) struct ExtractIfInner<'a, K: 'a, V: 'a> {
+    length: &'a mut usize,
+    // The leaf edge just before the next pair to test, or `None` once the
+    // end has been reached or the predicate has panicked.
+    front: Option<Handle<NodeRef<marker::Mut<'a>, K, V, marker::Leaf>, marker::Edge>>,
+}
+
+#[unstable(feature = "extract_if", issue = "0")]
+impl<'a, K: 'a + Ord, V: 'a, F> fmt::Debug for ExtractIf<'a, K, V, F>
+    where F: FnMut(&K, &mut V) -> bool,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad("ExtractIf { .. }")
+    }
+}
+
 /// A view into a single entry in a map, which may either be vacant or occupied.
 ///
 /// This `enum` is constructed from the [`entry`] method on [`BTreeMap`].
@@ -499,6 +553,49 @@ impl<'a, K: 'a + Debug + Ord, V: 'a + Debug> Debug for OccupiedEntry<'a, K, V> {
     }
 }
 
+/// A builder for a `BTreeMap` whose keys are supplied in strictly increasing order.
+///
+/// Unlike inserting into a `BTreeMap` one entry at a time, which leaves most
+/// nodes about half full, the builder appends each entry to the rightmost
+/// leaf and fills every node to capacity. The finished map therefore has
+/// fewer, fuller nodes, which suits maps that are built once and then
+/// mostly read.
+///
+/// Entries whose keys are not greater than every key pushed before them
+/// are rejected and handed back by [`push`].
+///
+/// [`push`]: #method.push
+///
+/// # Examples
+///
+/// ```
+/// #![feature(btree_builder)]
+/// use std::collections::btree_map::BTreeMapBuilder;
+///
+/// let mut builder = BTreeMapBuilder::new();
+/// for i in 0..100 {
+///     builder.push(i, i * i).unwrap();
+/// }
+/// assert_eq!(builder.push(50, 0), Err((50, 0)));
+///
+/// let map = builder.build();
+/// assert_eq!(map.len(), 100);
+/// assert_eq!(map[&9], 81);
+/// ```
+#[unstable(feature = "btree_builder", issue = "0")]
+pub struct BTreeMapBuilder<K, V> {
+    // The right edge of this map may contain underfull nodes until `build`
+    // fixes it up, so it must not be handed out before then.
+    map: BTreeMap<K, V>,
+}
+
+#[unstable(feature = "btree_builder", issue = "0")]
+impl<K: Debug, V: Debug> Debug for BTreeMapBuilder<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.map.iter()).finish()
+    }
+}
+
 // An iterator for merging two sorted sequences into one
 struct MergeIter<K, V, I: Iterator<Item = (K, V)>> {
     left: Peekable<I>,
@@ -696,6 +793,42 @@ impl<K: Ord, V> BTreeMap<K, V> {
         }
     }
 
+    /// Inserts a key-value pair into the map and returns a mutable reference
+    /// to the value in the map.
+    ///
+    /// If the map already had this key present, the old value is dropped and
+    /// replaced. Like [`insert`], this does not update the key.
+    ///
+    /// This is equivalent to calling [`insert`] and then [`get_mut`], without
+    /// looking the key up a second time.
+    ///
+    /// [`insert`]: #method.insert
+    /// [`get_mut`]: #method.get_mut
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(map_insert_get)]
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut map = BTreeMap::new();
+    /// *map.insert_get(37, 1) += 1;
+    /// assert_eq!(map[&37], 2);
+    ///
+    /// assert_eq!(*map.insert_get(37, 10), 10);
+    /// assert_eq!(map[&37], 10);
+    /// ```
+    #[unstable(feature = "map_insert_get", issue = "0")]
+    pub fn insert_get(&mut self, key: K, value: V) -> &mut V {
+        match self.entry(key) {
+            Occupied(mut entry) => {
+                entry.insert(value);
+                entry.into_mut()
+            }
+            Vacant(entry) => entry.insert(value),
+        }
+    }
+
     /// Removes a key from the map, returning the value at the key if the key
     /// was previously in the map.
     ///
@@ -732,6 +865,90 @@ impl<K: Ord, V> BTreeMap<K, V> {
         }
     }
 
+    /// Removes a key from the map, returning the stored key and value if the
+    /// key was previously in the map.
+    ///
+    /// The key may be any borrowed form of the map's key type, but the ordering
+    /// on the borrowed form *must* match the ordering on the key type.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(btree_map_remove_entry)]
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut map = BTreeMap::new();
+    /// map.insert(1, "a");
+    /// assert_eq!(map.remove_entry(&1), Some((1, "a")));
+    /// assert_eq!(map.remove_entry(&1), None);
+    /// ```
+    #[unstable(feature = "btree_map_remove_entry", issue = "0")]
+    pub fn remove_entry<Q: ?Sized>(&mut self, key: &Q) -> Option<(K, V)>
+        where K: Borrow<Q>,
+              Q: Ord
+    {
+        match search::search_tree(self.root.as_mut(), key) {
+            Found(handle) => {
+                Some(OccupiedEntry {
+                         handle,
+                         length: &mut self.length,
+                         _marker: PhantomData,
+                     }
+                     .remove_entry())
+            }
+            GoDown(_) => None,
+        }
+    }
+
+    /// Creates an iterator which uses a closure to determine if a key-value
+    /// pair should be removed.
+    ///
+    /// The pairs are visited in ascending order of their keys. If the
+    /// closure returns `true`, the pair is removed from the map and yielded
+    /// by the iterator. If it returns `false`, the pair stays in the map and
+    /// is not yielded. The closure gets a mutable reference to every value,
+    /// whether or not the pair is removed.
+    ///
+    /// Pairs are only removed as the iterator advances, so the rest of the
+    /// map is never rebuilt. If the iterator is dropped before it is
+    /// exhausted, the remaining pairs that match are still removed. If the
+    /// closure panics, the pair it was called on and all the pairs after it
+    /// stay in the map, and the closure is not called again.
+    ///
+    /// # Examples
+    ///
+    /// Splitting a map into even and odd keys:
+    ///
+    /// ```
+    /// #![feature(extract_if)]
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut map: BTreeMap<i32, i32> = (0..8).map(|x| (x, x * 10)).collect();
+    /// let evens: BTreeMap<_, _> = map.extract_if(|k, _v| k % 2 == 0).collect();
+    /// let odds = map;
+    ///
+    /// assert_eq!(evens.keys().cloned().collect::<Vec<_>>(), [0, 2, 4, 6]);
+    /// assert_eq!(odds.keys().cloned().collect::<Vec<_>>(), [1, 3, 5, 7]);
+    /// ```
+    #[unstable(feature = "extract_if", issue = "0")]
+    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<K, V, F>
+        where F: FnMut(&K, &mut V) -> bool
+    {
+        ExtractIf {
+            inner: self.extract_if_inner(),
+            pred,
+        }
+    }
+
+    pub(super) fn extract_if_inner(&mut self) -> ExtractIfInner<K, V> {
+        ExtractIfInner {
+            length: &mut self.length,
+            front: Some(first_leaf_edge(self.root.as_mut())),
+        }
+    }
+
     /// Moves all elements from `other` into `Self`, leaving `other` empty.
     ///
     /// # Examples
@@ -870,6 +1087,62 @@ impl<K: Ord, V> BTreeMap<K, V> {
         }
     }
 
+    /// Returns the entries closest to `key` on either side: the entry with
+    /// the largest key less than or equal to `key`, and the entry with the
+    /// smallest key greater than or equal to `key`.
+    ///
+    /// If the map contains `key`, both halves of the result are its entry.
+    /// Unlike two calls to [`range`], this descends the tree only once.
+    ///
+    /// The key may be any borrowed form of the map's key type, but the ordering
+    /// on the borrowed form *must* match the ordering on the key type.
+    ///
+    /// [`range`]: #method.range
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_closest)]
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map: BTreeMap<u32, &str> = vec![(10, "a"), (20, "b"), (30, "c")].into_iter().collect();
+    /// assert_eq!(map.closest(&15), (Some((&10, &"a")), Some((&20, &"b"))));
+    /// assert_eq!(map.closest(&20), (Some((&20, &"b")), Some((&20, &"b"))));
+    /// assert_eq!(map.closest(&5), (None, Some((&10, &"a"))));
+    /// assert_eq!(map.closest(&35), (Some((&30, &"c")), None));
+    /// ```
+    #[unstable(feature = "btree_closest", issue = "0")]
+    pub fn closest<Q: ?Sized>(&self, key: &Q) -> (Option<(&K, &V)>, Option<(&K, &V)>)
+        where K: Borrow<Q>,
+              Q: Ord
+    {
+        let mut below = None;
+        let mut above = None;
+        let mut node = self.root.as_ref();
+        loop {
+            match search::search_node(node, key) {
+                Found(handle) => {
+                    let kv = handle.into_kv();
+                    return (Some(kv), Some(kv));
+                }
+                GoDown(handle) => {
+                    // Every key below this edge lies between its neighbours,
+                    // so entries found further down are always closer.
+                    if let Ok(kv) = handle.left_kv() {
+                        below = Some(kv.into_kv());
+                    }
+                    if let Ok(kv) = handle.right_kv() {
+                        above = Some(kv.into_kv());
+                    }
+                    match handle.force() {
+                        Leaf(_) => return (below, above),
+                        Internal(internal) => node = internal.descend(),
+                    }
+                }
+            }
+        }
+    }
+
     /// Gets the given key's corresponding entry in the map for in-place manipulation.
     ///
     /// # Examples
@@ -1215,6 +1488,43 @@ impl<'a, K, V> Clone for Iter<'a, K, V> {
     }
 }
 
+#[unstable(feature = "map_iter_cloned", issue = "0")]
+impl<'a, K: 'a + Clone, V: 'a + Clone> Iterator for IterCloned<'a, K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<(K, V)> {
+        self.inner.next().map(|(k, v)| (k.clone(), v.clone()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[unstable(feature = "map_iter_cloned", issue = "0")]
+impl<'a, K: Clone, V: Clone> FusedIterator for IterCloned<'a, K, V> {}
+
+#[unstable(feature = "map_iter_cloned", issue = "0")]
+impl<'a, K: 'a + Clone, V: 'a + Clone> DoubleEndedIterator for IterCloned<'a, K, V> {
+    fn next_back(&mut self) -> Option<(K, V)> {
+        self.inner.next_back().map(|(k, v)| (k.clone(), v.clone()))
+    }
+}
+
+#[unstable(feature = "map_iter_cloned", issue = "0")]
+impl<'a, K: 'a + Clone, V: 'a + Clone> ExactSizeIterator for IterCloned<'a, K, V> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+#[unstable(feature = "map_iter_cloned", issue = "0")]
+impl<'a, K, V> Clone for IterCloned<'a, K, V> {
+    fn clone(&self) -> IterCloned<'a, K, V> {
+        IterCloned { inner: self.inner.clone() }
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<'a, K: 'a, V: 'a> IntoIterator for &'a mut BTreeMap<K, V> {
     type Item = (&'a K, &'a mut V);
@@ -1692,6 +2002,193 @@ impl<'a, K, V> RangeMut<'a, K, V> {
     }
 }
 
+#[unstable(feature = "extract_if", issue = "0")]
+impl<'a, K: Ord, V, F> Iterator for ExtractIf<'a, K, V, F>
+    where F: FnMut(&K, &mut V) -> bool,
+{
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<(K, V)> {
+        self.inner.next(&mut self.pred)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[unstable(feature = "extract_if", issue = "0")]
+impl<'a, K: Ord, V, F> Drop for ExtractIf<'a, K, V, F>
+    where F: FnMut(&K, &mut V) -> bool,
+{
+    fn drop(&mut self) {
+        for _ in self.by_ref() { }
+    }
+}
+
+impl<'a, K: Ord, V> ExtractIfInner<'a, K, V> {
+    pub(super) fn next<F>(&mut self, pred: &mut F) -> Option<(K, V)>
+        where F: FnMut(&K, &mut V) -> bool
+    {
+        // Taking `front` out first means that a panicking `pred` leaves it
+        // `None`, which ends the iteration.
+        while let Some(front) = self.front.take() {
+            let mut kv = next_kv(front)?;
+            let remove = {
+                let (k, v) = kv.kv_mut();
+                pred(k, v)
+            };
+
+            if !remove {
+                self.front = Some(match kv.force() {
+                    Leaf(leaf_kv) => leaf_kv.right_edge(),
+                    Internal(internal_kv) => first_leaf_edge(internal_kv.right_edge().descend()),
+                });
+                continue;
+            }
+
+            let root: *mut node::Root<K, V> = unsafe {
+                ptr::read(&kv).into_node().into_root_mut()
+            };
+            let (k, v) = OccupiedEntry {
+                             handle: kv,
+                             length: &mut *self.length,
+                             _marker: PhantomData,
+                         }
+                         .remove_kv();
+
+            // Removing the pair may have moved the pairs after it into other
+            // nodes, so search for the edge where the removed key would go.
+            let root = unsafe { (*root).as_mut() };
+            self.front = match search::search_tree(root, &k) {
+                GoDown(edge) => Some(edge),
+                Found(_) => unreachable!(),
+            };
+            return Some((k, v));
+        }
+        None
+    }
+
+    pub(super) fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.front {
+            Some(_) => (0, Some(*self.length)),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+impl<K: Ord, V> BTreeMapBuilder<K, V> {
+    /// Makes a new, empty `BTreeMapBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_builder)]
+    /// use std::collections::btree_map::BTreeMapBuilder;
+    ///
+    /// let builder: BTreeMapBuilder<i32, i32> = BTreeMapBuilder::new();
+    /// assert!(builder.build().is_empty());
+    /// ```
+    #[unstable(feature = "btree_builder", issue = "0")]
+    pub fn new() -> BTreeMapBuilder<K, V> {
+        BTreeMapBuilder { map: BTreeMap::new() }
+    }
+
+    /// Appends an entry to the map being built.
+    ///
+    /// The key must be strictly greater than every key pushed so far. If it
+    /// isn't, the entry is not added and is returned in the `Err` variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_builder)]
+    /// use std::collections::btree_map::BTreeMapBuilder;
+    ///
+    /// let mut builder = BTreeMapBuilder::new();
+    /// assert_eq!(builder.push("a", 1), Ok(()));
+    /// assert_eq!(builder.push("c", 3), Ok(()));
+    /// assert_eq!(builder.push("b", 2), Err(("b", 2)));
+    /// assert_eq!(builder.push("c", 4), Err(("c", 4)));
+    /// assert_eq!(builder.len(), 2);
+    /// ```
+    #[unstable(feature = "btree_builder", issue = "0")]
+    pub fn push(&mut self, key: K, value: V) -> Result<(), (K, V)> {
+        if let Some((last, _)) = self.map.iter().next_back() {
+            if key <= *last {
+                return Err((key, value));
+            }
+        }
+        self.map.from_sorted_iter(iter::once((key, value)));
+        Ok(())
+    }
+
+    /// Returns the number of entries pushed so far.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_builder)]
+    /// use std::collections::btree_map::BTreeMapBuilder;
+    ///
+    /// let mut builder = BTreeMapBuilder::new();
+    /// builder.push(1, "a").unwrap();
+    /// assert_eq!(builder.len(), 1);
+    /// ```
+    #[unstable(feature = "btree_builder", issue = "0")]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if no entries have been pushed yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_builder)]
+    /// use std::collections::btree_map::BTreeMapBuilder;
+    ///
+    /// let mut builder = BTreeMapBuilder::new();
+    /// assert!(builder.is_empty());
+    /// builder.push(1, "a").unwrap();
+    /// assert!(!builder.is_empty());
+    /// ```
+    #[unstable(feature = "btree_builder", issue = "0")]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Finishes the map, rebalancing the nodes along its right edge.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_builder)]
+    /// use std::collections::btree_map::BTreeMapBuilder;
+    ///
+    /// let mut builder = BTreeMapBuilder::new();
+    /// builder.push(1, "a").unwrap();
+    /// builder.push(2, "b").unwrap();
+    ///
+    /// let mut map = builder.build();
+    /// map.insert(0, "z");
+    /// assert_eq!(map.keys().collect::<Vec<_>>(), [&0, &1, &2]);
+    /// ```
+    #[unstable(feature = "btree_builder", issue = "0")]
+    pub fn build(mut self) -> BTreeMap<K, V> {
+        self.map.fix_right_edge();
+        self.map
+    }
+}
+
+#[unstable(feature = "btree_builder", issue = "0")]
+impl<K: Ord, V> Default for BTreeMapBuilder<K, V> {
+    /// Creates an empty `BTreeMapBuilder`.
+    fn default() -> BTreeMapBuilder<K, V> {
+        BTreeMapBuilder::new()
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<K: Ord, V> FromIterator<(K, V)> for BTreeMap<K, V> {
     fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> BTreeMap<K, V> {
@@ -1735,6 +2232,15 @@ impl<K: Ord, V> Default for BTreeMap<K, V> {
     }
 }
 
+// The compiler does not check stability attributes on macros, so `btreemap!`
+// creates its map through this function to require the
+// `collection_macros` feature.
+#[doc(hidden)]
+#[unstable(feature = "collection_macros", issue = "0")]
+pub fn __new_for_macro<K: Ord, V>() -> BTreeMap<K, V> {
+    BTreeMap::new()
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<K: PartialEq, V: PartialEq> PartialEq for BTreeMap<K, V> {
     fn eq(&self, other: &BTreeMap<K, V>) -> bool {
@@ -1812,6 +2318,22 @@ fn last_leaf_edge<BorrowType, K, V>
     }
 }
 
+/// Finds the key-value pair immediately to the right of a leaf edge, if any.
+fn next_kv<BorrowType, K, V>
+    (edge: Handle<NodeRef<BorrowType, K, V, marker::Leaf>, marker::Edge>)
+     -> Option<Handle<NodeRef<BorrowType, K, V, marker::LeafOrInternal>, marker::KV>> {
+    let mut cur_handle = match edge.right_kv() {
+        Ok(kv) => return Some(kv.forget_node_type()),
+        Err(last_edge) => last_edge.into_node().ascend().ok()?,
+    };
+    loop {
+        cur_handle = match cur_handle.right_kv() {
+            Ok(kv) => return Some(kv.forget_node_type()),
+            Err(last_edge) => last_edge.into_node().ascend().ok()?,
+        };
+    }
+}
+
 fn range_search<BorrowType, K, V, Q: ?Sized, R: RangeBounds<Q>>(
     root1: NodeRef<BorrowType, K, V, marker::LeafOrInternal>,
     root2: NodeRef<BorrowType, K, V, marker::LeafOrInternal>,
@@ -1965,6 +2487,34 @@ impl<K, V> BTreeMap<K, V> {
         }
     }
 
+    /// Gets an iterator over clones of the entries of the map, sorted by key.
+    ///
+    /// This is equivalent to `map.iter().map(|(k, v)| (k.clone(), v.clone()))`,
+    /// but the pairs are cloned straight out of the tree's nodes.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(map_iter_cloned)]
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut map = BTreeMap::new();
+    /// map.insert(3, 'c');
+    /// map.insert(1, 'a');
+    /// map.insert(2, 'b');
+    ///
+    /// let pairs: Vec<(i32, char)> = map.iter_cloned().collect();
+    /// assert_eq!(pairs, [(1, 'a'), (2, 'b'), (3, 'c')]);
+    /// ```
+    #[unstable(feature = "map_iter_cloned", issue = "0")]
+    pub fn iter_cloned(&self) -> IterCloned<K, V>
+        where K: Clone, V: Clone
+    {
+        IterCloned { inner: self.iter() }
+    }
+
     /// Gets an iterator over the keys of the map, in sorted order.
     ///
     /// # Examples
@@ -2070,6 +2620,89 @@ impl<K, V> BTreeMap<K, V> {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Describes the node structure of the map in the Graphviz DOT language.
+    ///
+    /// Every node of the B-tree becomes a record listing its key-value pairs,
+    /// with an edge to each of its children. This is meant for debugging and
+    /// the exact output is not specified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(collections_debug_dot)]
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map: BTreeMap<_, _> = (0..100).map(|i| (i, i * i)).collect();
+    /// let dot = map.debug_dot();
+    /// assert!(dot.starts_with("digraph BTreeMap {"));
+    /// assert!(dot.contains("99: 9801"));
+    /// ```
+    #[unstable(feature = "collections_debug_dot", issue = "0")]
+    pub fn debug_dot(&self) -> String
+        where K: Debug, V: Debug
+    {
+        self.debug_dot_graph("BTreeMap", true)
+    }
+
+    pub(super) fn debug_dot_graph(&self, name: &str, with_vals: bool) -> String
+        where K: Debug, V: Debug
+    {
+        let mut out = format!("digraph {} {{\n    node [shape=record];\n", name);
+        let mut next_id = 0;
+        debug_dot_node(self.root.as_ref(), with_vals, &mut next_id, &mut out);
+        out.push_str("}\n");
+        out
+    }
+}
+
+/// Writes the DOT description of the subtree rooted at `node`, numbering
+/// nodes in preorder, and returns the number given to `node` itself.
+/// Values are only included in the labels if `with_vals` is set.
+fn debug_dot_node<K: Debug, V: Debug>(node: NodeRef<marker::Immut, K, V, marker::LeafOrInternal>,
+                                      with_vals: bool,
+                                      next_id: &mut usize,
+                                      out: &mut String)
+                                      -> usize {
+    let id = *next_id;
+    *next_id += 1;
+
+    let internal = match node.force() {
+        Leaf(_) => None,
+        Internal(internal) => Some(internal),
+    };
+    let (keys, vals) = node.into_slices();
+
+    out.push_str(&format!("    n{} [label=\"", id));
+    for i in 0..keys.len() {
+        if internal.is_some() {
+            out.push_str(&format!("<e{}>|", i));
+        } else if i > 0 {
+            out.push('|');
+        }
+        dot::push_label(out, &keys[i], true);
+        if with_vals {
+            out.push_str(": ");
+            dot::push_label(out, &vals[i], true);
+        }
+        if internal.is_some() {
+            out.push('|');
+        }
+    }
+    if internal.is_some() {
+        out.push_str(&format!("<e{}>", keys.len()));
+    }
+    out.push_str("\"];\n");
+
+    if let Some(internal) = internal {
+        for i in 0..keys.len() + 1 {
+            let child = Handle::new_edge(internal, i).descend();
+            let child_id = debug_dot_node(child, with_vals, next_id, out);
+            out.push_str(&format!("    n{}:e{} -> n{};\n", id, i, child_id));
+        }
+    }
+
+    id
 }
 
 impl<'a, K: Ord, V> Entry<'a, K, V> {
@@ -812,6 +812,18 @@ impl<BorrowType, K, V, NodeType, HandleType>
             _marker: PhantomData
         }
     }
+
+    /// Removes any static information about whether the node this handle points
+    /// into is a `Leaf` or an `Internal` node.
+    pub fn forget_node_type(self)
+            -> Handle<NodeRef<BorrowType, K, V, marker::LeafOrInternal>, HandleType> {
+
+        Handle {
+            node: self.node.forget_type(),
+            idx: self.idx,
+            _marker: PhantomData
+        }
+    }
 }
 
 impl<'a, K, V, NodeType, HandleType>
@@ -19,8 +19,10 @@ use core::iter::{Peekable, FromIterator, FusedIterator};
 use core::ops::{BitOr, BitAnd, BitXor, Sub, RangeBounds};
 
 use borrow::Borrow;
-use btree_map::{BTreeMap, Keys};
+use string::String;
+use btree_map::{BTreeMap, BTreeMapBuilder, Keys};
 use super::Recover;
+use super::map::ExtractIfInner;
 
 // FIXME(conventions): implement bounded iterators
 
@@ -212,6 +214,66 @@ impl<'a, T: 'a + fmt::Debug> fmt::Debug for Union<'a, T> {
     }
 }
 
+/// An iterator produced by calling `extract_if` on BTreeSet.
+///
+/// See the [`extract_if`] method on [`BTreeSet`] for more.
+///
+/// [`extract_if`]: struct.BTreeSet.html#method.extract_if
+/// [`BTreeSet`]: struct.BTreeSet.html
+#[unstable(feature = "extract_if", issue = "0")]
+pub struct ExtractIf<'a, T: 'a, F>
+    where T: Ord,
+          F: FnMut(&T) -> bool,
+{
+    inner: ExtractIfInner<'a, T, ()>,
+    pred: F,
+}
+
+#[unstable(feature = "extract_if", issue = "0")]
+impl<'a, T: 'a + Ord, F> fmt::Debug for ExtractIf<'a, T, F>
+    where F: FnMut(&T) -> bool,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad("ExtractIf { .. }")
+    }
+}
+
+/// A builder for a `BTreeSet` whose elements are supplied in strictly increasing order.
+///
+/// See [`BTreeMapBuilder`] for details.
+///
+/// [`BTreeMapBuilder`]: ../btree_map/struct.BTreeMapBuilder.html
+///
+/// # Examples
+///
+/// ```
+/// #![feature(btree_builder)]
+/// use std::collections::btree_set::BTreeSetBuilder;
+///
+/// let mut builder = BTreeSetBuilder::new();
+/// for i in 0..100 {
+///     builder.push(i * 2).unwrap();
+/// }
+/// assert_eq!(builder.push(0), Err(0));
+///
+/// let set = builder.build();
+/// assert_eq!(set.len(), 100);
+/// assert!(set.contains(&42));
+/// ```
+#[unstable(feature = "btree_builder", issue = "0")]
+pub struct BTreeSetBuilder<T> {
+    map: BTreeMapBuilder<T, ()>,
+}
+
+#[unstable(feature = "btree_builder", issue = "0")]
+impl<T: Debug> Debug for BTreeSetBuilder<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("BTreeSetBuilder")
+         .field(&self.map)
+         .finish()
+    }
+}
+
 impl<T: Ord> BTreeSet<T> {
     /// Makes a new `BTreeSet` with a reasonable choice of B.
     ///
@@ -616,6 +678,45 @@ impl<T: Ord> BTreeSet<T> {
         Recover::take(&mut self.map, value)
     }
 
+    /// Creates an iterator which uses a closure to determine if a value
+    /// should be removed.
+    ///
+    /// The values are visited in ascending order. If the closure returns
+    /// `true`, the value is removed from the set and yielded by the
+    /// iterator. If it returns `false`, the value stays in the set and is not
+    /// yielded.
+    ///
+    /// Values are only removed as the iterator advances, so the rest of the
+    /// set is never rebuilt. If the iterator is dropped before it is
+    /// exhausted, the remaining values that match are still removed. If the
+    /// closure panics, the value it was called on and all the values after
+    /// it stay in the set, and the closure is not called again.
+    ///
+    /// # Examples
+    ///
+    /// Splitting a set into even and odd values:
+    ///
+    /// ```
+    /// #![feature(extract_if)]
+    /// use std::collections::BTreeSet;
+    ///
+    /// let mut set: BTreeSet<i32> = (0..8).collect();
+    /// let evens: BTreeSet<_> = set.extract_if(|v| v % 2 == 0).collect();
+    /// let odds = set;
+    ///
+    /// assert_eq!(evens.into_iter().collect::<Vec<_>>(), [0, 2, 4, 6]);
+    /// assert_eq!(odds.into_iter().collect::<Vec<_>>(), [1, 3, 5, 7]);
+    /// ```
+    #[unstable(feature = "extract_if", issue = "0")]
+    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<T, F>
+        where F: FnMut(&T) -> bool
+    {
+        ExtractIf {
+            inner: self.map.extract_if_inner(),
+            pred,
+        }
+    }
+
     /// Moves all elements from `other` into `Self`, leaving `other` empty.
     ///
     /// # Examples
@@ -682,6 +783,34 @@ impl<T: Ord> BTreeSet<T> {
     pub fn split_off<Q: ?Sized + Ord>(&mut self, key: &Q) -> Self where T: Borrow<Q> {
         BTreeSet { map: self.map.split_off(key) }
     }
+
+    /// Splits the set into two at the given value, returning the values less
+    /// than `value` and the values greater than or equal to `value`, in that
+    /// order.
+    ///
+    /// This is [`split_off`] for when both halves are wanted as new sets.
+    ///
+    /// [`split_off`]: #method.split_off
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_split_at_element)]
+    /// use std::collections::BTreeSet;
+    ///
+    /// let set: BTreeSet<_> = [1, 2, 3, 17, 41].iter().cloned().collect();
+    /// let (low, high) = set.split_at_element(&3);
+    ///
+    /// assert_eq!(low.into_iter().collect::<Vec<_>>(), [1, 2]);
+    /// assert_eq!(high.into_iter().collect::<Vec<_>>(), [3, 17, 41]);
+    /// ```
+    #[unstable(feature = "btree_split_at_element", issue = "0")]
+    pub fn split_at_element<Q: ?Sized + Ord>(mut self, value: &Q) -> (Self, Self)
+        where T: Borrow<Q>
+    {
+        let high = self.split_off(value);
+        (self, high)
+    }
 }
 
 impl<T> BTreeSet<T> {
@@ -750,6 +879,127 @@ impl<T> BTreeSet<T> {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Describes the node structure of the set in the Graphviz DOT language.
+    ///
+    /// See [`BTreeMap::debug_dot`] for details.
+    ///
+    /// [`BTreeMap::debug_dot`]: ../btree_map/struct.BTreeMap.html#method.debug_dot
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(collections_debug_dot)]
+    /// use std::collections::BTreeSet;
+    ///
+    /// let set: BTreeSet<_> = (0..100).collect();
+    /// assert!(set.debug_dot().starts_with("digraph BTreeSet {"));
+    /// ```
+    #[unstable(feature = "collections_debug_dot", issue = "0")]
+    pub fn debug_dot(&self) -> String
+        where T: Debug
+    {
+        self.map.debug_dot_graph("BTreeSet", false)
+    }
+}
+
+impl<T: Ord> BTreeSetBuilder<T> {
+    /// Makes a new, empty `BTreeSetBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_builder)]
+    /// use std::collections::btree_set::BTreeSetBuilder;
+    ///
+    /// let builder: BTreeSetBuilder<i32> = BTreeSetBuilder::new();
+    /// assert!(builder.build().is_empty());
+    /// ```
+    #[unstable(feature = "btree_builder", issue = "0")]
+    pub fn new() -> BTreeSetBuilder<T> {
+        BTreeSetBuilder { map: BTreeMapBuilder::new() }
+    }
+
+    /// Appends a value to the set being built.
+    ///
+    /// The value must be strictly greater than every value pushed so far.
+    /// If it isn't, it is not added and is returned in the `Err` variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_builder)]
+    /// use std::collections::btree_set::BTreeSetBuilder;
+    ///
+    /// let mut builder = BTreeSetBuilder::new();
+    /// assert_eq!(builder.push(1), Ok(()));
+    /// assert_eq!(builder.push(1), Err(1));
+    /// ```
+    #[unstable(feature = "btree_builder", issue = "0")]
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        self.map.push(value, ()).map_err(|(value, ())| value)
+    }
+
+    /// Returns the number of values pushed so far.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_builder)]
+    /// use std::collections::btree_set::BTreeSetBuilder;
+    ///
+    /// let mut builder = BTreeSetBuilder::new();
+    /// builder.push(1).unwrap();
+    /// assert_eq!(builder.len(), 1);
+    /// ```
+    #[unstable(feature = "btree_builder", issue = "0")]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if no values have been pushed yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_builder)]
+    /// use std::collections::btree_set::BTreeSetBuilder;
+    ///
+    /// let mut builder = BTreeSetBuilder::new();
+    /// assert!(builder.is_empty());
+    /// builder.push(1).unwrap();
+    /// assert!(!builder.is_empty());
+    /// ```
+    #[unstable(feature = "btree_builder", issue = "0")]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Finishes the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_builder)]
+    /// use std::collections::btree_set::BTreeSetBuilder;
+    ///
+    /// let mut builder = BTreeSetBuilder::new();
+    /// builder.push(1).unwrap();
+    /// builder.push(2).unwrap();
+    /// assert_eq!(builder.build().into_iter().collect::<Vec<_>>(), [1, 2]);
+    /// ```
+    #[unstable(feature = "btree_builder", issue = "0")]
+    pub fn build(self) -> BTreeSet<T> {
+        BTreeSet { map: self.map.build() }
+    }
+}
+
+#[unstable(feature = "btree_builder", issue = "0")]
+impl<T: Ord> Default for BTreeSetBuilder<T> {
+    /// Creates an empty `BTreeSetBuilder`.
+    fn default() -> BTreeSetBuilder<T> {
+        BTreeSetBuilder::new()
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -818,6 +1068,15 @@ impl<T: Ord> Default for BTreeSet<T> {
     }
 }
 
+// The compiler does not check stability attributes on macros, so `btreeset!`
+// creates its set through this function to require the
+// `collection_macros` feature.
+#[doc(hidden)]
+#[unstable(feature = "collection_macros", issue = "0")]
+pub fn __new_for_macro<T: Ord>() -> BTreeSet<T> {
+    BTreeSet::new()
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<'a, 'b, T: Ord + Clone> Sub<&'b BTreeSet<T>> for &'a BTreeSet<T> {
     type Output = BTreeSet<T>;
@@ -999,6 +1258,31 @@ impl<'a, T> DoubleEndedIterator for Range<'a, T> {
 #[stable(feature = "fused", since = "1.26.0")]
 impl<'a, T> FusedIterator for Range<'a, T> {}
 
+#[unstable(feature = "extract_if", issue = "0")]
+impl<'a, T: Ord, F> Iterator for ExtractIf<'a, T, F>
+    where F: FnMut(&T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let pred = &mut self.pred;
+        self.inner.next(&mut |value, _| pred(value)).map(|(value, ())| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[unstable(feature = "extract_if", issue = "0")]
+impl<'a, T: Ord, F> Drop for ExtractIf<'a, T, F>
+    where F: FnMut(&T) -> bool,
+{
+    fn drop(&mut self) {
+        for _ in self.by_ref() { }
+    }
+}
+
 /// Compare `x` and `y`, but return `short` if x is None and `long` if y is None
 fn cmp_opt<T: Ord>(x: Option<&T>, y: Option<&T>, short: Ordering, long: Ordering) -> Ordering {
     match (x, y) {
@@ -0,0 +1,50 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Helpers for the unstable `debug_dot` methods, which describe the internal
+//! structure of a collection in the Graphviz DOT language.
+
+use core::fmt::{self, Write};
+
+use string::String;
+
+/// Appends the `Debug` output of `value` to `out`, escaped for use inside a
+/// double-quoted DOT label.
+///
+/// With `record` set, the characters that delimit fields of a `record`
+/// shaped node are escaped as well.
+pub fn push_label<T: ?Sized + fmt::Debug>(out: &mut String, value: &T, record: bool) {
+    let mut escaper = Escaper { out, record };
+    // Writing to a `String` can't fail.
+    let _ = write!(escaper, "{:?}", value);
+}
+
+struct Escaper<'a> {
+    out: &'a mut String,
+    record: bool,
+}
+
+impl<'a> Write for Escaper<'a> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            match c {
+                '"' | '\\' => self.out.push('\\'),
+                '{' | '}' | '|' | '<' | '>' if self.record => self.out.push('\\'),
+                '\n' => {
+                    self.out.push_str("\\n");
+                    continue;
+                }
+                _ => {}
+            }
+            self.out.push(c);
+        }
+        Ok(())
+    }
+}
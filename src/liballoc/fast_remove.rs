@@ -0,0 +1,94 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Constant-time removal from collections that do not keep their order.
+//!
+//! Removing an element from the middle of a `Vec` shifts every element after
+//! it, which takes `O(n)` time. When the order of the elements does not
+//! matter, as in a list of live entities, the last element can be moved into
+//! the gap instead. [`FastRemove`] exposes that operation under one name so
+//! that such code can be written once for every collection that supports it.
+//!
+//! ```
+//! #![feature(fast_remove)]
+//!
+//! use std::collections::VecDeque;
+//! use std::collections::fast_remove::FastRemove;
+//!
+//! fn despawn<C: FastRemove<Handle = usize>>(entities: &mut C, dead: &[usize]) {
+//!     // Remove from the highest index down, so that the elements moved into
+//!     // the gaps are never ones that still have to be removed.
+//!     for &index in dead.iter().rev() {
+//!         entities.fast_remove(index);
+//!     }
+//! }
+//!
+//! let mut vec = vec!['a', 'b', 'c', 'd', 'e'];
+//! despawn(&mut vec, &[1, 3]);
+//! assert_eq!(vec, ['a', 'e', 'c']);
+//!
+//! let mut deque: VecDeque<_> = vec!['a', 'b', 'c', 'd', 'e'].into();
+//! despawn(&mut deque, &[1, 3]);
+//! assert_eq!(deque, ['a', 'e', 'c']);
+//! ```
+//!
+//! [`FastRemove`]: trait.FastRemove.html
+
+#![unstable(feature = "fast_remove", issue = "0")]
+
+use vec::Vec;
+use vec_deque::VecDeque;
+
+/// A collection that can remove any of its elements in `O(1)` time, at the
+/// cost of moving another element into its place.
+pub trait FastRemove {
+    /// The type of the elements in the collection.
+    type Item;
+
+    /// The type that identifies an element, such as an index.
+    type Handle;
+
+    /// Removes the element identified by `handle` and returns it, or returns
+    /// `None` if there is no such element.
+    ///
+    /// This takes `O(1)` time. Another element may be moved to take the place
+    /// of the removed one, so handles to other elements can be invalidated.
+    fn fast_remove(&mut self, handle: Self::Handle) -> Option<Self::Item>;
+}
+
+/// Removes elements with [`swap_remove`], which replaces the removed element
+/// with the last one.
+///
+/// [`swap_remove`]: ../../vec/struct.Vec.html#method.swap_remove
+impl<T> FastRemove for Vec<T> {
+    type Item = T;
+    type Handle = usize;
+
+    fn fast_remove(&mut self, index: usize) -> Option<T> {
+        if index < self.len() {
+            Some(self.swap_remove(index))
+        } else {
+            None
+        }
+    }
+}
+
+/// Removes elements with [`swap_remove_back`], which replaces the removed
+/// element with the last one.
+///
+/// [`swap_remove_back`]: ../vec_deque/struct.VecDeque.html#method.swap_remove_back
+impl<T> FastRemove for VecDeque<T> {
+    type Item = T;
+    type Handle = usize;
+
+    fn fast_remove(&mut self, index: usize) -> Option<T> {
+        self.swap_remove_back(index)
+    }
+}
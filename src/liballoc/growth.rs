@@ -0,0 +1,121 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Policies for growing the buffer of a `Vec` or `String`.
+//!
+//! When `push` or `reserve` runs out of room, the buffer is reallocated to
+//! twice its capacity, which keeps pushing amortized `O(1)` but can leave up
+//! to half of a large buffer unused. [`Vec::reserve_with`] and
+//! [`String::reserve_with`] take a [`GrowthPolicy`] that decides the new
+//! capacity instead, trading some extra reallocations for less slack.
+//!
+//! ```
+//! #![feature(growth_policy)]
+//!
+//! use std::collections::growth::{Factor, MaxStep};
+//!
+//! // Grow by half, but never by more than 4096 elements at once.
+//! let policy = MaxStep::new(Factor::new(3, 2), 4096);
+//!
+//! let mut v = Vec::new();
+//! for i in 0..100_000 {
+//!     v.reserve_with(1, &policy);
+//!     v.push(i);
+//! }
+//! assert!(v.capacity() - v.len() <= 4096);
+//! ```
+//!
+//! [`GrowthPolicy`]: trait.GrowthPolicy.html
+//! [`Vec::reserve_with`]: ../../vec/struct.Vec.html#method.reserve_with
+//! [`String::reserve_with`]: ../../string/struct.String.html#method.reserve_with
+
+#![unstable(feature = "growth_policy", issue = "0")]
+
+use core::cmp;
+
+/// Decides how much a buffer grows when it runs out of capacity.
+pub trait GrowthPolicy {
+    /// Returns the capacity to grow a buffer of capacity `cap` to, given that
+    /// it must hold at least `required` elements.
+    ///
+    /// This is only called when `required` is larger than `cap`. Returning
+    /// less than `required` is allowed, in which case exactly `required`
+    /// elements are allocated.
+    fn grow(&self, cap: usize, required: usize) -> usize;
+}
+
+/// Doubles the capacity, as `reserve` does.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Doubling;
+
+impl GrowthPolicy for Doubling {
+    fn grow(&self, cap: usize, _required: usize) -> usize {
+        cap.saturating_mul(2)
+    }
+}
+
+/// Multiplies the capacity by a fraction, such as 3/2.
+#[derive(Clone, Copy, Debug)]
+pub struct Factor {
+    numerator: usize,
+    denominator: usize,
+}
+
+impl Factor {
+    /// Creates a policy that grows a buffer to `numerator / denominator`
+    /// times its capacity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the fraction is not larger than one.
+    pub fn new(numerator: usize, denominator: usize) -> Factor {
+        assert!(denominator > 0 && numerator > denominator,
+                "growth factor must be larger than one");
+        Factor { numerator, denominator }
+    }
+}
+
+impl GrowthPolicy for Factor {
+    fn grow(&self, cap: usize, _required: usize) -> usize {
+        let step = (cap / self.denominator).saturating_mul(self.numerator - self.denominator);
+        cap.saturating_add(step)
+    }
+}
+
+/// Limits the number of elements another policy grows a buffer by at once.
+///
+/// Past the point where the limit kicks in growth becomes linear, so pushing
+/// is no longer amortized `O(1)`. A buffer still grows by at least as much
+/// as it must to fit the requested elements.
+#[derive(Clone, Copy, Debug)]
+pub struct MaxStep<P> {
+    policy: P,
+    max_step: usize,
+}
+
+impl<P: GrowthPolicy> MaxStep<P> {
+    /// Creates a policy that grows a buffer as `policy` does, but by at most
+    /// `max_step` elements.
+    pub fn new(policy: P, max_step: usize) -> MaxStep<P> {
+        MaxStep { policy, max_step }
+    }
+}
+
+impl<P: GrowthPolicy> GrowthPolicy for MaxStep<P> {
+    fn grow(&self, cap: usize, required: usize) -> usize {
+        cmp::min(self.policy.grow(cap, required), cap.saturating_add(self.max_step))
+    }
+}
+
+impl<'a, P: GrowthPolicy + ?Sized> GrowthPolicy for &'a P {
+    fn grow(&self, cap: usize, required: usize) -> usize {
+        (**self).grow(cap, required)
+    }
+}
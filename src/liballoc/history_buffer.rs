@@ -0,0 +1,392 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A fixed-size window over the most recent values pushed into it.
+//!
+//! [`HistoryBuffer`] is a ring buffer that never grows: once it holds as many
+//! values as its capacity, every push evicts the oldest value. This is the
+//! usual shape of telemetry samples, undo logs and debugging traces, where
+//! only the last `n` events are of interest.
+//!
+//! [`HistoryBuffer`]: struct.HistoryBuffer.html
+
+#![unstable(feature = "history_buffer", issue = "0")]
+
+use core::fmt;
+use core::iter::{FusedIterator, Rev};
+use core::ops::Index;
+
+use vec_deque::{self, VecDeque};
+
+/// A ring buffer holding the last `capacity` values pushed into it.
+///
+/// Pushing into a full `HistoryBuffer` drops the oldest value. Values can be
+/// addressed backwards from the newest with [`recent`], and iterated over in
+/// either direction.
+///
+/// [`recent`]: #method.recent
+///
+/// # Examples
+///
+/// ```
+/// #![feature(history_buffer)]
+/// use std::collections::history_buffer::HistoryBuffer;
+///
+/// let mut latencies = HistoryBuffer::new(3);
+/// for ms in &[12, 15, 11, 40] {
+///     latencies.push(*ms);
+/// }
+///
+/// // Only the three most recent samples are kept.
+/// assert_eq!(latencies.len(), 3);
+/// assert_eq!(latencies.recent(0), Some(&40));
+/// assert_eq!(latencies.recent(2), Some(&15));
+/// assert_eq!(latencies.recent(3), None);
+///
+/// let oldest_first: Vec<_> = latencies.iter_oldest_first().cloned().collect();
+/// assert_eq!(oldest_first, [15, 11, 40]);
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct HistoryBuffer<T> {
+    buf: VecDeque<T>,
+    capacity: usize,
+}
+
+impl<T> HistoryBuffer<T> {
+    /// Creates an empty `HistoryBuffer` that keeps the last `capacity`
+    /// values.
+    ///
+    /// The buffer's storage is allocated up front and never reallocated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(history_buffer)]
+    /// use std::collections::history_buffer::HistoryBuffer;
+    ///
+    /// let buffer: HistoryBuffer<u32> = HistoryBuffer::new(10);
+    /// assert_eq!(buffer.capacity(), 10);
+    /// ```
+    pub fn new(capacity: usize) -> HistoryBuffer<T> {
+        assert!(capacity > 0, "HistoryBuffer capacity must be nonzero");
+        HistoryBuffer {
+            buf: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Returns the maximum number of values the buffer keeps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(history_buffer)]
+    /// use std::collections::history_buffer::HistoryBuffer;
+    ///
+    /// let buffer: HistoryBuffer<u32> = HistoryBuffer::new(10);
+    /// assert_eq!(buffer.capacity(), 10);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of values in the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(history_buffer)]
+    /// use std::collections::history_buffer::HistoryBuffer;
+    ///
+    /// let mut buffer = HistoryBuffer::new(2);
+    /// assert_eq!(buffer.len(), 0);
+    /// buffer.push(1);
+    /// buffer.push(2);
+    /// buffer.push(3);
+    /// assert_eq!(buffer.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Returns `true` if the buffer holds no values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(history_buffer)]
+    /// use std::collections::history_buffer::HistoryBuffer;
+    ///
+    /// let mut buffer = HistoryBuffer::new(2);
+    /// assert!(buffer.is_empty());
+    /// buffer.push(1);
+    /// assert!(!buffer.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    /// Returns `true` if the buffer holds `capacity` values, so that the next
+    /// push will evict the oldest one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(history_buffer)]
+    /// use std::collections::history_buffer::HistoryBuffer;
+    ///
+    /// let mut buffer = HistoryBuffer::new(2);
+    /// buffer.push(1);
+    /// assert!(!buffer.is_full());
+    /// buffer.push(2);
+    /// assert!(buffer.is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.buf.len() == self.capacity
+    }
+
+    /// Pushes a value into the buffer as the newest one.
+    ///
+    /// If the buffer was full, the oldest value is removed and returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(history_buffer)]
+    /// use std::collections::history_buffer::HistoryBuffer;
+    ///
+    /// let mut buffer = HistoryBuffer::new(2);
+    /// assert_eq!(buffer.push(1), None);
+    /// assert_eq!(buffer.push(2), None);
+    /// assert_eq!(buffer.push(3), Some(1));
+    /// ```
+    pub fn push(&mut self, value: T) -> Option<T> {
+        let evicted = if self.is_full() { self.buf.pop_front() } else { None };
+        self.buf.push_back(value);
+        evicted
+    }
+
+    /// Returns the value pushed `index` pushes ago, so that `recent(0)` is
+    /// the newest value. Returns `None` if `index` is not less than the
+    /// buffer's length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(history_buffer)]
+    /// use std::collections::history_buffer::HistoryBuffer;
+    ///
+    /// let mut buffer = HistoryBuffer::new(3);
+    /// buffer.push('a');
+    /// buffer.push('b');
+    /// assert_eq!(buffer.recent(0), Some(&'b'));
+    /// assert_eq!(buffer.recent(1), Some(&'a'));
+    /// assert_eq!(buffer.recent(2), None);
+    /// ```
+    pub fn recent(&self, index: usize) -> Option<&T> {
+        let len = self.buf.len();
+        if index < len {
+            self.buf.get(len - 1 - index)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the newest value, or `None` if the buffer is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(history_buffer)]
+    /// use std::collections::history_buffer::HistoryBuffer;
+    ///
+    /// let mut buffer = HistoryBuffer::new(3);
+    /// assert_eq!(buffer.newest(), None);
+    /// buffer.push(1);
+    /// buffer.push(2);
+    /// assert_eq!(buffer.newest(), Some(&2));
+    /// ```
+    pub fn newest(&self) -> Option<&T> {
+        self.buf.back()
+    }
+
+    /// Returns the oldest value, or `None` if the buffer is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(history_buffer)]
+    /// use std::collections::history_buffer::HistoryBuffer;
+    ///
+    /// let mut buffer = HistoryBuffer::new(3);
+    /// assert_eq!(buffer.oldest(), None);
+    /// buffer.push(1);
+    /// buffer.push(2);
+    /// assert_eq!(buffer.oldest(), Some(&1));
+    /// ```
+    pub fn oldest(&self) -> Option<&T> {
+        self.buf.front()
+    }
+
+    /// Returns an iterator over the values from the oldest to the newest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(history_buffer)]
+    /// use std::collections::history_buffer::HistoryBuffer;
+    ///
+    /// let mut buffer = HistoryBuffer::new(3);
+    /// for i in 0..5 {
+    ///     buffer.push(i);
+    /// }
+    /// let values: Vec<_> = buffer.iter_oldest_first().collect();
+    /// assert_eq!(values, [&2, &3, &4]);
+    /// ```
+    pub fn iter_oldest_first(&self) -> Iter<T> {
+        Iter { iter: self.buf.iter() }
+    }
+
+    /// Returns an iterator over the values from the newest to the oldest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(history_buffer)]
+    /// use std::collections::history_buffer::HistoryBuffer;
+    ///
+    /// let mut buffer = HistoryBuffer::new(3);
+    /// for i in 0..5 {
+    ///     buffer.push(i);
+    /// }
+    /// let values: Vec<_> = buffer.iter_newest_first().collect();
+    /// assert_eq!(values, [&4, &3, &2]);
+    /// ```
+    pub fn iter_newest_first(&self) -> Rev<Iter<T>> {
+        self.iter_oldest_first().rev()
+    }
+
+    /// Removes all values from the buffer, keeping its capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(history_buffer)]
+    /// use std::collections::history_buffer::HistoryBuffer;
+    ///
+    /// let mut buffer = HistoryBuffer::new(3);
+    /// buffer.push(1);
+    /// buffer.clear();
+    /// assert!(buffer.is_empty());
+    /// assert_eq!(buffer.capacity(), 3);
+    /// ```
+    pub fn clear(&mut self) {
+        self.buf.clear();
+    }
+}
+
+impl<T> Index<usize> for HistoryBuffer<T> {
+    type Output = T;
+
+    /// Returns the value pushed `index` pushes ago, as [`recent`] does.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than the buffer's length.
+    ///
+    /// [`recent`]: #method.recent
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        self.recent(index).expect("HistoryBuffer index out of bounds")
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for HistoryBuffer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter_oldest_first()).finish()
+    }
+}
+
+impl<T> Extend<T> for HistoryBuffer<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<'a, T: 'a + Copy> Extend<&'a T> for HistoryBuffer<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().cloned());
+    }
+}
+
+impl<'a, T> IntoIterator for &'a HistoryBuffer<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter_oldest_first()
+    }
+}
+
+/// An iterator over the values of a `HistoryBuffer`, from the oldest to the
+/// newest.
+///
+/// This `struct` is created by the [`iter_oldest_first`] method on
+/// [`HistoryBuffer`]. See its documentation for more.
+///
+/// [`iter_oldest_first`]: struct.HistoryBuffer.html#method.iter_oldest_first
+/// [`HistoryBuffer`]: struct.HistoryBuffer.html
+pub struct Iter<'a, T: 'a> {
+    iter: vec_deque::Iter<'a, T>,
+}
+
+// FIXME(#26925) Remove in favor of `#[derive(Clone)]`
+impl<'a, T> Clone for Iter<'a, T> {
+    fn clone(&self) -> Iter<'a, T> {
+        Iter { iter: self.iter.clone() }
+    }
+}
+
+impl<'a, T: fmt::Debug> fmt::Debug for Iter<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a T> {
+        self.iter.next_back()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T> FusedIterator for Iter<'a, T> {}
@@ -94,6 +94,8 @@
 #![feature(custom_attribute)]
 #![feature(dropck_eyepatch)]
 #![feature(exact_size_is_empty)]
+#![feature(fixed_size_array)]
+#![feature(flt2dec)]
 #![feature(fmt_internals)]
 #![feature(from_ref)]
 #![feature(fundamental)]
@@ -110,6 +112,8 @@
 #![feature(ptr_internals)]
 #![feature(rustc_attrs)]
 #![feature(slice_get_slice)]
+#![feature(slice_internals)]
+#![feature(slice_memchr)]
 #![feature(slice_rsplit)]
 #![feature(specialization)]
 #![feature(staged_api)]
@@ -171,14 +175,28 @@ pub mod rc;
 pub mod raw_vec;
 
 // collections modules
+pub mod algorithms;
 pub mod binary_heap;
 mod btree;
+mod dot;
 pub mod borrow;
+pub mod fast_remove;
 pub mod fmt;
+pub mod growth;
+pub mod history_buffer;
 pub mod linked_list;
+pub mod range_map;
+#[cfg(target_has_atomic = "ptr")]
+pub mod rope;
+pub mod seg_vec;
+#[cfg(target_has_atomic = "ptr")]
+pub mod shared_string;
 pub mod slice;
+pub mod static_map;
 pub mod str;
 pub mod string;
+pub mod string_table;
+pub mod unrolled_list;
 pub mod vec;
 pub mod vec_deque;
 
@@ -27,10 +27,12 @@ use core::fmt;
 use core::hash::{Hasher, Hash};
 use core::iter::{FromIterator, FusedIterator};
 use core::marker::PhantomData;
+use core::heap::Alloc;
 use core::mem;
-use core::ptr::NonNull;
+use core::ptr::{self, NonNull};
 
 use boxed::Box;
+use heap::Heap;
 use super::SpecExtend;
 
 /// A doubly-linked list with owned nodes.
@@ -41,11 +43,20 @@ use super::SpecExtend;
 /// Almost always it is better to use `Vec` or `VecDeque` instead of
 /// `LinkedList`. In general, array-based containers are faster,
 /// more memory efficient and make better use of CPU cache.
+///
+/// Each node is allocated with the list's allocator, which is [`Heap`]
+/// unless the list was created with [`new_in`]. Giving the list an
+/// allocator that hands out nodes from a pool avoids a call into the global
+/// allocator for every insertion.
+///
+/// [`Heap`]: ../../heap/struct.Heap.html
+/// [`new_in`]: #method.new_in
 #[stable(feature = "rust1", since = "1.0.0")]
-pub struct LinkedList<T> {
+pub struct LinkedList<T, A: Alloc = Heap> {
     head: Option<NonNull<Node<T>>>,
     tail: Option<NonNull<Node<T>>>,
     len: usize,
+    a: A,
     marker: PhantomData<Box<Node<T>>>,
 }
 
@@ -95,15 +106,17 @@ impl<'a, T> Clone for Iter<'a, T> {
 /// [`iter_mut`]: struct.LinkedList.html#method.iter_mut
 /// [`LinkedList`]: struct.LinkedList.html
 #[stable(feature = "rust1", since = "1.0.0")]
-pub struct IterMut<'a, T: 'a> {
-    list: &'a mut LinkedList<T>,
+pub struct IterMut<'a, T: 'a, A: 'a + Alloc = Heap> {
+    list: &'a mut LinkedList<T, A>,
     head: Option<NonNull<Node<T>>>,
     tail: Option<NonNull<Node<T>>>,
     len: usize,
+    // The number of list elements before `head`.
+    front: usize,
 }
 
 #[stable(feature = "collection_debug", since = "1.17.0")]
-impl<'a, T: 'a + fmt::Debug> fmt::Debug for IterMut<'a, T> {
+impl<'a, T: 'a + fmt::Debug, A: Alloc> fmt::Debug for IterMut<'a, T, A> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_tuple("IterMut")
          .field(&self.list)
@@ -121,12 +134,12 @@ impl<'a, T: 'a + fmt::Debug> fmt::Debug for IterMut<'a, T> {
 /// [`LinkedList`]: struct.LinkedList.html
 #[derive(Clone)]
 #[stable(feature = "rust1", since = "1.0.0")]
-pub struct IntoIter<T> {
-    list: LinkedList<T>,
+pub struct IntoIter<T, A: Alloc = Heap> {
+    list: LinkedList<T, A>,
 }
 
 #[stable(feature = "collection_debug", since = "1.17.0")]
-impl<T: fmt::Debug> fmt::Debug for IntoIter<T> {
+impl<T: fmt::Debug, A: Alloc> fmt::Debug for IntoIter<T, A> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_tuple("IntoIter")
          .field(&self.list)
@@ -134,6 +147,73 @@ impl<T: fmt::Debug> fmt::Debug for IntoIter<T> {
     }
 }
 
+/// A handle to an element of a `LinkedList`.
+///
+/// This `struct` is returned by the [`push_front_handle`] and
+/// [`push_back_handle`] methods on [`LinkedList`]. It keeps referring to
+/// the same element while other elements are added and removed, and lets
+/// [`get_node`], [`get_node_mut`] and [`remove_node`] reach that element in
+/// `O(1)` time.
+///
+/// A handle does not borrow the list, and nothing checks that the element
+/// it refers to is still there, which is why the methods that take one are
+/// `unsafe`.
+///
+/// [`push_front_handle`]: struct.LinkedList.html#method.push_front_handle
+/// [`push_back_handle`]: struct.LinkedList.html#method.push_back_handle
+/// [`get_node`]: struct.LinkedList.html#method.get_node
+/// [`get_node_mut`]: struct.LinkedList.html#method.get_node_mut
+/// [`remove_node`]: struct.LinkedList.html#method.remove_node
+/// [`LinkedList`]: struct.LinkedList.html
+#[unstable(feature = "linked_list_handles", issue = "0")]
+pub struct NodeHandle<T> {
+    node: NonNull<Node<T>>,
+}
+
+#[unstable(feature = "linked_list_handles", issue = "0")]
+impl<T> Clone for NodeHandle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[unstable(feature = "linked_list_handles", issue = "0")]
+impl<T> Copy for NodeHandle<T> {}
+
+#[unstable(feature = "linked_list_handles", issue = "0")]
+impl<T> PartialEq for NodeHandle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.node == other.node
+    }
+}
+
+#[unstable(feature = "linked_list_handles", issue = "0")]
+impl<T> Eq for NodeHandle<T> {}
+
+#[unstable(feature = "linked_list_handles", issue = "0")]
+impl<T> Hash for NodeHandle<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.node.hash(state)
+    }
+}
+
+#[unstable(feature = "linked_list_handles", issue = "0")]
+impl<T> fmt::Debug for NodeHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("NodeHandle")
+         .field(&self.node)
+         .finish()
+    }
+}
+
+// A handle only gives access to its element through the list, so it can go
+// wherever the list can.
+#[unstable(feature = "linked_list_handles", issue = "0")]
+unsafe impl<T: Send> Send for NodeHandle<T> {}
+
+#[unstable(feature = "linked_list_handles", issue = "0")]
+unsafe impl<T: Sync> Sync for NodeHandle<T> {}
+
 impl<T> Node<T> {
     fn new(element: T) -> Self {
         Node {
@@ -142,21 +222,38 @@ impl<T> Node<T> {
             element,
         }
     }
-
-    fn into_element(self: Box<Self>) -> T {
-        self.element
-    }
 }
 
 // private methods
-impl<T> LinkedList<T> {
+impl<T, A: Alloc> LinkedList<T, A> {
+    /// Allocates an unlinked node holding `element`.
+    #[inline]
+    fn alloc_node(&mut self, element: T) -> NonNull<Node<T>> {
+        let node = match self.a.alloc_one::<Node<T>>() {
+            Ok(node) => node,
+            Err(err) => self.a.oom(err),
+        };
+        unsafe {
+            ptr::write(node.as_ptr(), Node::new(element));
+        }
+        node
+    }
+
+    /// Deallocates an unlinked node and returns its element.
+    #[inline]
+    unsafe fn free_node(&mut self, node: NonNull<Node<T>>) -> T {
+        let element = ptr::read(&node.as_ref().element);
+        self.a.dealloc_one(node);
+        element
+    }
+
     /// Adds the given node to the front of the list.
     #[inline]
-    fn push_front_node(&mut self, mut node: Box<Node<T>>) {
+    fn push_front_node(&mut self, mut node: NonNull<Node<T>>) {
         unsafe {
-            node.next = self.head;
-            node.prev = None;
-            let node = Some(Box::into_raw_non_null(node));
+            node.as_mut().next = self.head;
+            node.as_mut().prev = None;
+            let node = Some(node);
 
             match self.head {
                 None => self.tail = node,
@@ -170,10 +267,9 @@ impl<T> LinkedList<T> {
 
     /// Removes and returns the node at the front of the list.
     #[inline]
-    fn pop_front_node(&mut self) -> Option<Box<Node<T>>> {
+    fn pop_front_node(&mut self) -> Option<NonNull<Node<T>>> {
         self.head.map(|node| unsafe {
-            let node = Box::from_raw(node.as_ptr());
-            self.head = node.next;
+            self.head = node.as_ref().next;
 
             match self.head {
                 None => self.tail = None,
@@ -187,11 +283,11 @@ impl<T> LinkedList<T> {
 
     /// Adds the given node to the back of the list.
     #[inline]
-    fn push_back_node(&mut self, mut node: Box<Node<T>>) {
+    fn push_back_node(&mut self, mut node: NonNull<Node<T>>) {
         unsafe {
-            node.next = None;
-            node.prev = self.tail;
-            let node = Some(Box::into_raw_non_null(node));
+            node.as_mut().next = None;
+            node.as_mut().prev = self.tail;
+            let node = Some(node);
 
             match self.tail {
                 None => self.head = node,
@@ -205,10 +301,9 @@ impl<T> LinkedList<T> {
 
     /// Removes and returns the node at the back of the list.
     #[inline]
-    fn pop_back_node(&mut self) -> Option<Box<Node<T>>> {
+    fn pop_back_node(&mut self) -> Option<NonNull<Node<T>>> {
         self.tail.map(|node| unsafe {
-            let node = Box::from_raw(node.as_ptr());
-            self.tail = node.prev;
+            self.tail = node.as_ref().prev;
 
             match self.tail {
                 None => self.head = None,
@@ -241,6 +336,29 @@ impl<T> LinkedList<T> {
 
         self.len -= 1;
     }
+
+    /// Links the nodes from `start` to `end` in between `prev` and `next`,
+    /// which must be adjacent nodes of the list, or `None` for the ends of
+    /// the list.
+    #[inline]
+    unsafe fn splice_nodes(&mut self,
+                           prev: Option<NonNull<Node<T>>>,
+                           next: Option<NonNull<Node<T>>>,
+                           mut start: NonNull<Node<T>>,
+                           mut end: NonNull<Node<T>>,
+                           len: usize) {
+        match prev {
+            Some(mut prev) => prev.as_mut().next = Some(start),
+            None => self.head = Some(start),
+        }
+        match next {
+            Some(mut next) => next.as_mut().prev = Some(end),
+            None => self.tail = Some(end),
+        }
+        start.as_mut().prev = prev;
+        end.as_mut().next = next;
+        self.len += len;
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -265,10 +383,49 @@ impl<T> LinkedList<T> {
     #[inline]
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn new() -> Self {
+        // Every `Heap` can free what any other `Heap` allocated.
+        unsafe { LinkedList::new_in(Heap) }
+    }
+}
+
+impl<T, A: Alloc> LinkedList<T, A> {
+    /// Creates an empty `LinkedList` that allocates its nodes with `a`.
+    ///
+    /// # Safety
+    ///
+    /// Nodes move from one list to another without being reallocated, in
+    /// [`append`], [`split_off`], [`IterMut::split_off_next`] and
+    /// [`CursorMut::splice`], and are freed by the allocator of the list
+    /// they end up in. Lists also hand clones of their allocator to the lists
+    /// they split off. So `a`, every clone of it, and the allocators of all
+    /// lists that nodes are moved between must be able to free each other's
+    /// allocations, as clones of a handle to the same pool can.
+    ///
+    /// [`append`]: #method.append
+    /// [`split_off`]: #method.split_off
+    /// [`IterMut::split_off_next`]: struct.IterMut.html#method.split_off_next
+    /// [`CursorMut::splice`]: struct.CursorMut.html#method.splice
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(allocator_api, linked_list_alloc)]
+    /// use std::collections::LinkedList;
+    /// use std::heap::Heap;
+    ///
+    /// // Safe, since every `Heap` can free what any other allocated.
+    /// let mut list = unsafe { LinkedList::new_in(Heap) };
+    /// list.push_back(1);
+    /// assert_eq!(list.front(), Some(&1));
+    /// ```
+    #[inline]
+    #[unstable(feature = "linked_list_alloc", issue = "0")]
+    pub unsafe fn new_in(a: A) -> Self {
         LinkedList {
             head: None,
             tail: None,
             len: 0,
+            a,
             marker: PhantomData,
         }
     }
@@ -375,15 +532,105 @@ impl<T> LinkedList<T> {
     /// ```
     #[inline]
     #[stable(feature = "rust1", since = "1.0.0")]
-    pub fn iter_mut(&mut self) -> IterMut<T> {
+    pub fn iter_mut(&mut self) -> IterMut<T, A> {
         IterMut {
             head: self.head,
             tail: self.tail,
             len: self.len,
+            front: 0,
+            list: self,
+        }
+    }
+
+    /// Returns a cursor pointing at the first element of the list, or at the
+    /// "ghost" position if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(linked_list_cursors)]
+    /// use std::collections::LinkedList;
+    ///
+    /// let mut list: LinkedList<_> = vec![1, 2].into_iter().collect();
+    /// assert_eq!(list.cursor_front_mut().current(), Some(&mut 1));
+    /// ```
+    #[inline]
+    #[unstable(feature = "linked_list_cursors", issue = "0")]
+    pub fn cursor_front_mut(&mut self) -> CursorMut<T, A> {
+        CursorMut {
+            index: 0,
+            current: self.head,
+            list: self,
+        }
+    }
+
+    /// Returns a cursor pointing at the last element of the list, or at the
+    /// "ghost" position if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(linked_list_cursors)]
+    /// use std::collections::LinkedList;
+    ///
+    /// let mut list: LinkedList<_> = vec![1, 2].into_iter().collect();
+    /// assert_eq!(list.cursor_back_mut().current(), Some(&mut 2));
+    /// ```
+    #[inline]
+    #[unstable(feature = "linked_list_cursors", issue = "0")]
+    pub fn cursor_back_mut(&mut self) -> CursorMut<T, A> {
+        CursorMut {
+            index: self.len.saturating_sub(1),
+            current: self.tail,
             list: self,
         }
     }
 
+    /// Returns a cursor pointing at the first element that satisfies
+    /// `pred`, or `None` if there is no such element.
+    ///
+    /// Unlike [`contains`], this keeps the position of the element, so the
+    /// cursor can insert or remove elements there without walking the list
+    /// again.
+    ///
+    /// [`contains`]: #method.contains
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(linked_list_cursors)]
+    /// use std::collections::LinkedList;
+    ///
+    /// let mut list: LinkedList<_> = vec![1, 2, 4].into_iter().collect();
+    /// if let Some(mut cursor) = list.find(|&x| x > 2) {
+    ///     assert_eq!(cursor.index(), Some(2));
+    ///     cursor.insert_before(3);
+    /// }
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), [1, 2, 3, 4]);
+    /// assert!(LinkedList::<i32>::new().find(|_| true).is_none());
+    /// ```
+    #[unstable(feature = "linked_list_cursors", issue = "0")]
+    pub fn find<P>(&mut self, mut pred: P) -> Option<CursorMut<T, A>>
+        where P: FnMut(&T) -> bool
+    {
+        let mut index = 0;
+        let mut node = self.head;
+        while let Some(current) = node {
+            unsafe {
+                if pred(&current.as_ref().element) {
+                    return Some(CursorMut {
+                        index,
+                        current: node,
+                        list: self,
+                    });
+                }
+                node = current.as_ref().next;
+            }
+            index += 1;
+        }
+        None
+    }
+
     /// Returns `true` if the `LinkedList` is empty.
     ///
     /// This operation should compute in O(1) time.
@@ -454,7 +701,7 @@ impl<T> LinkedList<T> {
     #[inline]
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn clear(&mut self) {
-        *self = Self::new();
+        while let Some(_) = self.pop_front() {}
     }
 
     /// Returns `true` if the `LinkedList` contains an element equal to the
@@ -600,7 +847,8 @@ impl<T> LinkedList<T> {
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn push_front(&mut self, elt: T) {
-        self.push_front_node(box Node::new(elt));
+        let node = self.alloc_node(elt);
+        self.push_front_node(node);
     }
 
     /// Removes the first element and returns it, or `None` if the list is
@@ -624,7 +872,7 @@ impl<T> LinkedList<T> {
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn pop_front(&mut self) -> Option<T> {
-        self.pop_front_node().map(Node::into_element)
+        self.pop_front_node().map(|node| unsafe { self.free_node(node) })
     }
 
     /// Appends an element to the back of a list
@@ -641,7 +889,8 @@ impl<T> LinkedList<T> {
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn push_back(&mut self, elt: T) {
-        self.push_back_node(box Node::new(elt));
+        let node = self.alloc_node(elt);
+        self.push_back_node(node);
     }
 
     /// Removes the last element from a list and returns it, or `None` if
@@ -660,13 +909,155 @@ impl<T> LinkedList<T> {
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn pop_back(&mut self) -> Option<T> {
-        self.pop_back_node().map(Node::into_element)
+        self.pop_back_node().map(|node| unsafe { self.free_node(node) })
+    }
+
+    /// Removes the last element from a list and returns it if `predicate`
+    /// returns `true` for it, or `None` if the predicate returns `false` or
+    /// the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(pop_if)]
+    /// use std::collections::LinkedList;
+    ///
+    /// let mut d = LinkedList::new();
+    /// d.push_back(1);
+    /// d.push_back(3);
+    /// assert_eq!(d.pop_back_if(|x| *x > 2), Some(3));
+    /// assert_eq!(d.pop_back_if(|x| *x > 2), None);
+    /// assert_eq!(d.len(), 1);
+    /// ```
+    #[unstable(feature = "pop_if", issue = "0")]
+    pub fn pop_back_if<F>(&mut self, predicate: F) -> Option<T>
+        where F: FnOnce(&mut T) -> bool
+    {
+        let pop = match self.back_mut() {
+            Some(back) => predicate(back),
+            None => false,
+        };
+        if pop { self.pop_back() } else { None }
+    }
+
+    /// Adds an element first in the list and returns a handle to it.
+    ///
+    /// This operation should compute in O(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(linked_list_handles)]
+    /// use std::collections::LinkedList;
+    ///
+    /// let mut dl = LinkedList::new();
+    /// let one = dl.push_front_handle(1);
+    /// dl.push_front(2);
+    /// assert_eq!(unsafe { dl.get_node(one) }, &1);
+    /// ```
+    #[unstable(feature = "linked_list_handles", issue = "0")]
+    pub fn push_front_handle(&mut self, elt: T) -> NodeHandle<T> {
+        self.push_front(elt);
+        NodeHandle { node: self.head.unwrap() }
+    }
+
+    /// Appends an element to the back of the list and returns a handle to it.
+    ///
+    /// This operation should compute in O(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(linked_list_handles)]
+    /// use std::collections::LinkedList;
+    ///
+    /// let mut d = LinkedList::new();
+    /// let one = d.push_back_handle(1);
+    /// d.push_back(2);
+    /// assert_eq!(unsafe { d.get_node(one) }, &1);
+    /// ```
+    #[unstable(feature = "linked_list_handles", issue = "0")]
+    pub fn push_back_handle(&mut self, elt: T) -> NodeHandle<T> {
+        self.push_back(elt);
+        NodeHandle { node: self.tail.unwrap() }
+    }
+
+    /// Provides a reference to the element that `handle` refers to.
+    ///
+    /// This operation should compute in O(1) time.
+    ///
+    /// # Safety
+    ///
+    /// `handle` must have been returned for an element of this list that has
+    /// not been removed since. Elements that are moved into another list, as
+    /// by [`append`] or [`split_off`], belong to that list from then on.
+    ///
+    /// [`append`]: #method.append
+    /// [`split_off`]: #method.split_off
+    #[unstable(feature = "linked_list_handles", issue = "0")]
+    pub unsafe fn get_node(&self, handle: NodeHandle<T>) -> &T {
+        &(*handle.node.as_ptr()).element
+    }
+
+    /// Provides a mutable reference to the element that `handle` refers to.
+    ///
+    /// This operation should compute in O(1) time.
+    ///
+    /// # Safety
+    ///
+    /// `handle` must refer to an element of this list, as for [`get_node`].
+    ///
+    /// [`get_node`]: #method.get_node
+    #[unstable(feature = "linked_list_handles", issue = "0")]
+    pub unsafe fn get_node_mut(&mut self, handle: NodeHandle<T>) -> &mut T {
+        &mut (*handle.node.as_ptr()).element
+    }
+
+    /// Removes the element that `handle` refers to and returns it.
+    ///
+    /// This operation should compute in O(1) time.
+    ///
+    /// # Safety
+    ///
+    /// `handle` must refer to an element of this list, as for [`get_node`].
+    /// It, and any copies of it, must not be used again afterwards.
+    ///
+    /// [`get_node`]: #method.get_node
+    ///
+    /// # Examples
+    ///
+    /// Keeping timers ordered by deadline, and cancelling them out of order:
+    ///
+    /// ```
+    /// #![feature(linked_list_handles)]
+    /// use std::collections::LinkedList;
+    ///
+    /// let mut timers = LinkedList::new();
+    /// let a = timers.push_back_handle("a");
+    /// let b = timers.push_back_handle("b");
+    /// let c = timers.push_back_handle("c");
+    ///
+    /// unsafe {
+    ///     assert_eq!(timers.remove_node(b), "b");
+    ///     *timers.get_node_mut(c) = "d";
+    ///     assert_eq!(timers.remove_node(a), "a");
+    /// }
+    /// assert_eq!(timers.into_iter().collect::<Vec<_>>(), ["d"]);
+    /// ```
+    #[unstable(feature = "linked_list_handles", issue = "0")]
+    pub unsafe fn remove_node(&mut self, handle: NodeHandle<T>) -> T {
+        self.unlink_node(handle.node);
+        self.free_node(handle.node)
     }
 
     /// Splits the list into two at the given index. Returns everything after the given index,
     /// including the index.
     ///
-    /// This operation should compute in O(n) time.
+    /// This operation should compute in O(n) time. To split the list at a
+    /// position reached while iterating, use [`IterMut::split_off_next`]
+    /// instead, which takes O(1) time.
+    ///
+    /// [`IterMut::split_off_next`]: struct.IterMut.html#method.split_off_next
     ///
     /// # Panics
     ///
@@ -689,13 +1080,17 @@ impl<T> LinkedList<T> {
     /// assert_eq!(splitted.pop_front(), None);
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
-    pub fn split_off(&mut self, at: usize) -> LinkedList<T> {
+    pub fn split_off(&mut self, at: usize) -> LinkedList<T, A>
+        where A: Clone
+    {
         let len = self.len();
         assert!(at <= len, "Cannot split off at a nonexistent index");
         if at == 0 {
-            return mem::replace(self, Self::new());
+            // `new_in` already required clones of `a` to be interchangeable.
+            let empty = unsafe { LinkedList::new_in(self.a.clone()) };
+            return mem::replace(self, empty);
         } else if at == len {
-            return Self::new();
+            return unsafe { LinkedList::new_in(self.a.clone()) };
         }
 
         // Below, we iterate towards the `i-1`th node, either from the start or the end,
@@ -733,6 +1128,7 @@ impl<T> LinkedList<T> {
             head: second_part_head,
             tail: self.tail,
             len: len - at,
+            a: self.a.clone(),
             marker: PhantomData,
         };
 
@@ -743,6 +1139,118 @@ impl<T> LinkedList<T> {
         second_part
     }
 
+    /// Reverses the order of the elements in place.
+    ///
+    /// This swaps the links of every node, so it takes `O(n)` time and does
+    /// not move or reallocate any elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(linked_list_reverse)]
+    ///
+    /// use std::collections::LinkedList;
+    ///
+    /// let mut list: LinkedList<_> = (1..4).collect();
+    /// list.reverse();
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), [3, 2, 1]);
+    /// ```
+    #[unstable(feature = "linked_list_reverse", issue = "0")]
+    pub fn reverse(&mut self) {
+        let mut node = self.head;
+        while let Some(mut current) = node {
+            unsafe {
+                let current = current.as_mut();
+                mem::swap(&mut current.next, &mut current.prev);
+                node = current.prev;
+            }
+        }
+        mem::swap(&mut self.head, &mut self.tail);
+    }
+
+    /// Rotates the list so that the element at `index` becomes the first
+    /// element, keeping the cyclic order of the elements.
+    ///
+    /// Finding the element takes `O(min(index, len - index))` time, after
+    /// which the list is relinked in constant time without moving or
+    /// reallocating any elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the length of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rotate_to_front)]
+    ///
+    /// use std::collections::LinkedList;
+    ///
+    /// let mut list: LinkedList<_> = (1..6).collect();
+    /// list.rotate_to_front(3);
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), [4, 5, 1, 2, 3]);
+    /// ```
+    #[unstable(feature = "rotate_to_front", issue = "0")]
+    pub fn rotate_to_front(&mut self, index: usize) {
+        let len = self.len();
+        assert!(index <= len, "index out of bounds");
+        if index == 0 || index == len {
+            return;
+        }
+
+        let new_head = if index <= len - index {
+            let mut iter = self.iter_mut();
+            for _ in 0..index {
+                iter.next();
+            }
+            iter.head
+        } else {
+            let mut iter = self.iter_mut();
+            for _ in 0..len - 1 - index {
+                iter.next_back();
+            }
+            iter.tail
+        };
+
+        unsafe {
+            let mut new_head = new_head.unwrap();
+            let mut new_tail = new_head.as_ref().prev.unwrap();
+            let mut old_head = self.head.unwrap();
+            let mut old_tail = self.tail.unwrap();
+
+            old_tail.as_mut().next = Some(old_head);
+            old_head.as_mut().prev = Some(old_tail);
+            new_tail.as_mut().next = None;
+            new_head.as_mut().prev = None;
+
+            self.head = Some(new_head);
+            self.tail = Some(new_tail);
+        }
+    }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// In other words, remove all elements `e` such that `f(&e)` returns false.
+    /// This method operates in place and preserves the order of the retained
+    /// elements. Removed nodes are unlinked and freed as the list is walked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(linked_list_retain)]
+    /// use std::collections::LinkedList;
+    ///
+    /// let mut list: LinkedList<_> = (1..7).collect();
+    /// list.retain(|&x| x % 2 == 0);
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), [2, 4, 6]);
+    /// ```
+    #[unstable(feature = "linked_list_retain", issue = "0")]
+    pub fn retain<F>(&mut self, mut f: F)
+        where F: FnMut(&T) -> bool
+    {
+        self.drain_filter(|elem| !f(elem));
+    }
+
     /// Creates an iterator which uses a closure to determine if an element should be removed.
     ///
     /// If the closure returns true, then the element is removed and yielded.
@@ -770,7 +1278,7 @@ impl<T> LinkedList<T> {
     /// assert_eq!(odds.into_iter().collect::<Vec<_>>(), vec![1, 3, 5, 9, 11, 13, 15]);
     /// ```
     #[unstable(feature = "drain_filter", reason = "recently added", issue = "43244")]
-    pub fn drain_filter<F>(&mut self, filter: F) -> DrainFilter<T, F>
+    pub fn drain_filter<F>(&mut self, filter: F) -> DrainFilter<T, F, A>
         where F: FnMut(&mut T) -> bool
     {
         // avoid borrow issues.
@@ -788,9 +1296,9 @@ impl<T> LinkedList<T> {
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
-unsafe impl<#[may_dangle] T> Drop for LinkedList<T> {
+unsafe impl<#[may_dangle] T, A: Alloc> Drop for LinkedList<T, A> {
     fn drop(&mut self) {
-        while let Some(_) = self.pop_front_node() {}
+        while let Some(_) = self.pop_front() {}
     }
 }
 
@@ -844,7 +1352,7 @@ impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
 impl<'a, T> FusedIterator for Iter<'a, T> {}
 
 #[stable(feature = "rust1", since = "1.0.0")]
-impl<'a, T> Iterator for IterMut<'a, T> {
+impl<'a, T, A: Alloc> Iterator for IterMut<'a, T, A> {
     type Item = &'a mut T;
 
     #[inline]
@@ -856,6 +1364,7 @@ impl<'a, T> Iterator for IterMut<'a, T> {
                 // Need an unbound lifetime to get 'a
                 let node = &mut *node.as_ptr();
                 self.len -= 1;
+                self.front += 1;
                 self.head = node.next;
                 &mut node.element
             })
@@ -869,7 +1378,7 @@ impl<'a, T> Iterator for IterMut<'a, T> {
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
-impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+impl<'a, T, A: Alloc> DoubleEndedIterator for IterMut<'a, T, A> {
     #[inline]
     fn next_back(&mut self) -> Option<&'a mut T> {
         if self.len == 0 {
@@ -887,12 +1396,12 @@ impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
-impl<'a, T> ExactSizeIterator for IterMut<'a, T> {}
+impl<'a, T, A: Alloc> ExactSizeIterator for IterMut<'a, T, A> {}
 
 #[stable(feature = "fused", since = "1.26.0")]
-impl<'a, T> FusedIterator for IterMut<'a, T> {}
+impl<'a, T, A: Alloc> FusedIterator for IterMut<'a, T, A> {}
 
-impl<'a, T> IterMut<'a, T> {
+impl<'a, T, A: Alloc> IterMut<'a, T, A> {
     /// Inserts the given element just after the element most recently returned by `.next()`.
     /// The inserted element does not appear in the iteration.
     ///
@@ -921,6 +1430,8 @@ impl<'a, T> IterMut<'a, T> {
                reason = "this is probably better handled by a cursor type -- we'll see",
                issue = "27794")]
     pub fn insert_next(&mut self, element: T) {
+        // Whichever way it is inserted, the new element ends up before `head`.
+        self.front += 1;
         match self.head {
             None => self.list.push_back(element),
             Some(mut head) => unsafe {
@@ -929,11 +1440,10 @@ impl<'a, T> IterMut<'a, T> {
                     Some(prev) => prev,
                 };
 
-                let node = Some(Box::into_raw_non_null(box Node {
-                    next: Some(head),
-                    prev: Some(prev),
-                    element,
-                }));
+                let mut node = self.list.alloc_node(element);
+                node.as_mut().next = Some(head);
+                node.as_mut().prev = Some(prev);
+                let node = Some(node);
 
                 prev.as_mut().next = node;
                 head.as_mut().prev = node;
@@ -973,14 +1483,296 @@ impl<'a, T> IterMut<'a, T> {
             }
         }
     }
+
+    /// Splits the list in two just before the element that `next` would
+    /// return, and returns everything from that element to the end of the
+    /// list. Elements already returned by `next` stay in the original list.
+    ///
+    /// Unlike [`LinkedList::split_off`], this takes `O(1)` time. The iterator
+    /// is exhausted afterwards.
+    ///
+    /// [`LinkedList::split_off`]: struct.LinkedList.html#method.split_off
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(linked_list_extras)]
+    ///
+    /// use std::collections::LinkedList;
+    ///
+    /// let mut list: LinkedList<_> = vec![1, 2, 3, 4].into_iter().collect();
+    ///
+    /// let rest = {
+    ///     let mut it = list.iter_mut();
+    ///     while it.peek_next() != Some(&mut 3) {
+    ///         it.next();
+    ///     }
+    ///     it.split_off_next()
+    /// };
+    ///
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), [1, 2]);
+    /// assert_eq!(rest.into_iter().collect::<Vec<_>>(), [3, 4]);
+    /// ```
+    #[unstable(feature = "linked_list_extras",
+               reason = "this is probably better handled by a cursor type -- we'll see",
+               issue = "27794")]
+    pub fn split_off_next(&mut self) -> LinkedList<T, A>
+        where A: Clone
+    {
+        let mut head = match self.head {
+            None => return unsafe { LinkedList::new_in(self.list.a.clone()) },
+            Some(head) => head,
+        };
+        self.head = None;
+        self.tail = None;
+        self.len = 0;
+
+        let second_part = LinkedList {
+            head: Some(head),
+            tail: self.list.tail,
+            len: self.list.len - self.front,
+            a: self.list.a.clone(),
+            marker: PhantomData,
+        };
+
+        // The node before the split point becomes the tail of the first part.
+        let prev = unsafe { head.as_mut().prev.take() };
+        match prev {
+            None => self.list.head = None,
+            Some(mut prev) => unsafe { prev.as_mut().next = None },
+        }
+        self.list.tail = prev;
+        self.list.len = self.front;
+
+        second_part
+    }
+}
+
+/// A cursor over a `LinkedList` that can edit the list around it.
+///
+/// A cursor points either at an element of the list or at the "ghost"
+/// position, which lies between the last element and the first one. Moving
+/// past either end of the list goes through the ghost position and wraps
+/// around to the other end. Inserting and removing elements at the cursor,
+/// and [`splice`]-ing in another list, take `O(1)` time.
+///
+/// This `struct` is created by the [`cursor_front_mut`] and
+/// [`cursor_back_mut`] methods on [`LinkedList`].
+///
+/// [`splice`]: #method.splice
+/// [`cursor_front_mut`]: struct.LinkedList.html#method.cursor_front_mut
+/// [`cursor_back_mut`]: struct.LinkedList.html#method.cursor_back_mut
+/// [`LinkedList`]: struct.LinkedList.html
+#[unstable(feature = "linked_list_cursors", issue = "0")]
+pub struct CursorMut<'a, T: 'a, A: 'a + Alloc = Heap> {
+    // The index of `current`, or the length of the list at the ghost
+    // position.
+    index: usize,
+    current: Option<NonNull<Node<T>>>,
+    list: &'a mut LinkedList<T, A>,
+}
+
+#[unstable(feature = "linked_list_cursors", issue = "0")]
+impl<'a, T: 'a + fmt::Debug, A: Alloc> fmt::Debug for CursorMut<'a, T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("CursorMut")
+         .field(&self.list)
+         .field(&self.index())
+         .finish()
+    }
+}
+
+impl<'a, T, A: Alloc> CursorMut<'a, T, A> {
+    /// Returns the index of the element the cursor points at, or `None` at
+    /// the ghost position.
+    #[unstable(feature = "linked_list_cursors", issue = "0")]
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+
+    /// Moves the cursor to the next element. From the last element this
+    /// moves to the ghost position, and from the ghost position to the
+    /// first element.
+    #[unstable(feature = "linked_list_cursors", issue = "0")]
+    pub fn move_next(&mut self) {
+        match self.current {
+            None => {
+                self.current = self.list.head;
+                self.index = 0;
+            }
+            Some(current) => unsafe {
+                self.current = current.as_ref().next;
+                self.index += 1;
+            },
+        }
+    }
+
+    /// Moves the cursor to the previous element. From the first element
+    /// this moves to the ghost position, and from the ghost position to the
+    /// last element.
+    #[unstable(feature = "linked_list_cursors", issue = "0")]
+    pub fn move_prev(&mut self) {
+        match self.current {
+            None => {
+                self.current = self.list.tail;
+                self.index = self.list.len.saturating_sub(1);
+            }
+            Some(current) => unsafe {
+                self.current = current.as_ref().prev;
+                self.index = match self.index {
+                    0 => self.list.len,
+                    index => index - 1,
+                };
+            },
+        }
+    }
+
+    /// Returns a reference to the element the cursor points at, or `None`
+    /// at the ghost position.
+    #[unstable(feature = "linked_list_cursors", issue = "0")]
+    pub fn current(&mut self) -> Option<&mut T> {
+        unsafe {
+            self.current.map(|current| &mut (*current.as_ptr()).element)
+        }
+    }
+
+    /// Returns a reference to the element after the cursor, without moving
+    /// it. At the ghost position this is the first element of the list.
+    #[unstable(feature = "linked_list_cursors", issue = "0")]
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        unsafe {
+            let next = match self.current {
+                None => self.list.head,
+                Some(current) => current.as_ref().next,
+            };
+            next.map(|next| &mut (*next.as_ptr()).element)
+        }
+    }
+
+    /// Returns a reference to the element before the cursor, without moving
+    /// it. At the ghost position this is the last element of the list.
+    #[unstable(feature = "linked_list_cursors", issue = "0")]
+    pub fn peek_prev(&mut self) -> Option<&mut T> {
+        unsafe {
+            let prev = match self.current {
+                None => self.list.tail,
+                Some(current) => current.as_ref().prev,
+            };
+            prev.map(|prev| &mut (*prev.as_ptr()).element)
+        }
+    }
+
+    /// Inserts an element just after the cursor. At the ghost position the
+    /// element becomes the first element of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(linked_list_cursors)]
+    /// use std::collections::LinkedList;
+    ///
+    /// let mut list: LinkedList<_> = vec![1, 3].into_iter().collect();
+    /// {
+    ///     let mut cursor = list.cursor_front_mut();
+    ///     cursor.insert_after(2);
+    ///     assert_eq!(cursor.current(), Some(&mut 1));
+    /// }
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), [1, 2, 3]);
+    /// ```
+    #[unstable(feature = "linked_list_cursors", issue = "0")]
+    pub fn insert_after(&mut self, element: T) {
+        unsafe {
+            let node = self.list.alloc_node(element);
+            let next = match self.current {
+                None => self.list.head,
+                Some(current) => current.as_ref().next,
+            };
+            self.list.splice_nodes(self.current, next, node, node, 1);
+        }
+        if self.current.is_none() {
+            self.index += 1;
+        }
+    }
+
+    /// Inserts an element just before the cursor. At the ghost position the
+    /// element becomes the last element of the list.
+    #[unstable(feature = "linked_list_cursors", issue = "0")]
+    pub fn insert_before(&mut self, element: T) {
+        unsafe {
+            let node = self.list.alloc_node(element);
+            let prev = match self.current {
+                None => self.list.tail,
+                Some(current) => current.as_ref().prev,
+            };
+            self.list.splice_nodes(prev, self.current, node, node, 1);
+        }
+        self.index += 1;
+    }
+
+    /// Removes the element the cursor points at and returns it, moving the
+    /// cursor to the next element. Returns `None` and does nothing at the
+    /// ghost position.
+    #[unstable(feature = "linked_list_cursors", issue = "0")]
+    pub fn remove_current(&mut self) -> Option<T> {
+        let current = match self.current {
+            None => return None,
+            Some(current) => current,
+        };
+        unsafe {
+            self.current = current.as_ref().next;
+            self.list.unlink_node(current);
+            Some(self.list.free_node(current))
+        }
+    }
+
+    /// Moves all elements of `other` in just after the cursor, leaving
+    /// `other` empty. At the ghost position they end up at the front of the
+    /// list. The cursor keeps pointing at the same element.
+    ///
+    /// This reuses the nodes of `other`, so it takes `O(1)` time. As with
+    /// [`append`], `other`'s allocator must be able to free the nodes of
+    /// this list and the other way around.
+    ///
+    /// [`append`]: struct.LinkedList.html#method.append
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(linked_list_cursors)]
+    /// use std::collections::LinkedList;
+    ///
+    /// let mut list: LinkedList<_> = vec![1, 4].into_iter().collect();
+    /// let mut other: LinkedList<_> = vec![2, 3].into_iter().collect();
+    /// list.cursor_front_mut().splice(&mut other);
+    /// assert!(other.is_empty());
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), [1, 2, 3, 4]);
+    /// ```
+    #[unstable(feature = "linked_list_cursors", issue = "0")]
+    pub fn splice(&mut self, other: &mut LinkedList<T, A>) {
+        let (start, end) = match (other.head.take(), other.tail.take()) {
+            (Some(start), Some(end)) => (start, end),
+            _ => return,
+        };
+        let len = mem::replace(&mut other.len, 0);
+        unsafe {
+            let next = match self.current {
+                None => self.list.head,
+                Some(current) => current.as_ref().next,
+            };
+            self.list.splice_nodes(self.current, next, start, end, len);
+        }
+        if self.current.is_none() {
+            self.index += len;
+        }
+    }
 }
 
 /// An iterator produced by calling `drain_filter` on LinkedList.
 #[unstable(feature = "drain_filter", reason = "recently added", issue = "43244")]
-pub struct DrainFilter<'a, T: 'a, F: 'a>
+pub struct DrainFilter<'a, T: 'a, F: 'a, A: 'a + Alloc = Heap>
     where F: FnMut(&mut T) -> bool,
 {
-    list: &'a mut LinkedList<T>,
+    list: &'a mut LinkedList<T, A>,
     it: Option<NonNull<Node<T>>>,
     pred: F,
     idx: usize,
@@ -988,7 +1780,7 @@ pub struct DrainFilter<'a, T: 'a, F: 'a>
 }
 
 #[unstable(feature = "drain_filter", reason = "recently added", issue = "43244")]
-impl<'a, T, F> Iterator for DrainFilter<'a, T, F>
+impl<'a, T, F, A: Alloc> Iterator for DrainFilter<'a, T, F, A>
     where F: FnMut(&mut T) -> bool,
 {
     type Item = T;
@@ -1001,7 +1793,7 @@ impl<'a, T, F> Iterator for DrainFilter<'a, T, F>
 
                 if (self.pred)(&mut node.as_mut().element) {
                     self.list.unlink_node(node);
-                    return Some(Box::from_raw(node.as_ptr()).element);
+                    return Some(self.list.free_node(node));
                 }
             }
         }
@@ -1015,7 +1807,7 @@ impl<'a, T, F> Iterator for DrainFilter<'a, T, F>
 }
 
 #[unstable(feature = "drain_filter", reason = "recently added", issue = "43244")]
-impl<'a, T, F> Drop for DrainFilter<'a, T, F>
+impl<'a, T, F, A: Alloc> Drop for DrainFilter<'a, T, F, A>
     where F: FnMut(&mut T) -> bool,
 {
     fn drop(&mut self) {
@@ -1024,7 +1816,7 @@ impl<'a, T, F> Drop for DrainFilter<'a, T, F>
 }
 
 #[unstable(feature = "drain_filter", reason = "recently added", issue = "43244")]
-impl<'a, T: 'a + fmt::Debug, F> fmt::Debug for DrainFilter<'a, T, F>
+impl<'a, T: 'a + fmt::Debug, F, A: Alloc> fmt::Debug for DrainFilter<'a, T, F, A>
     where F: FnMut(&mut T) -> bool
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -1035,7 +1827,7 @@ impl<'a, T: 'a + fmt::Debug, F> fmt::Debug for DrainFilter<'a, T, F>
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
-impl<T> Iterator for IntoIter<T> {
+impl<T, A: Alloc> Iterator for IntoIter<T, A> {
     type Item = T;
 
     #[inline]
@@ -1050,7 +1842,7 @@ impl<T> Iterator for IntoIter<T> {
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
-impl<T> DoubleEndedIterator for IntoIter<T> {
+impl<T, A: Alloc> DoubleEndedIterator for IntoIter<T, A> {
     #[inline]
     fn next_back(&mut self) -> Option<T> {
         self.list.pop_back()
@@ -1058,10 +1850,10 @@ impl<T> DoubleEndedIterator for IntoIter<T> {
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
-impl<T> ExactSizeIterator for IntoIter<T> {}
+impl<T, A: Alloc> ExactSizeIterator for IntoIter<T, A> {}
 
 #[stable(feature = "fused", since = "1.26.0")]
-impl<T> FusedIterator for IntoIter<T> {}
+impl<T, A: Alloc> FusedIterator for IntoIter<T, A> {}
 
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T> FromIterator<T> for LinkedList<T> {
@@ -1073,19 +1865,19 @@ impl<T> FromIterator<T> for LinkedList<T> {
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
-impl<T> IntoIterator for LinkedList<T> {
+impl<T, A: Alloc> IntoIterator for LinkedList<T, A> {
     type Item = T;
-    type IntoIter = IntoIter<T>;
+    type IntoIter = IntoIter<T, A>;
 
     /// Consumes the list into an iterator yielding elements by value.
     #[inline]
-    fn into_iter(self) -> IntoIter<T> {
+    fn into_iter(self) -> IntoIter<T, A> {
         IntoIter { list: self }
     }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
-impl<'a, T> IntoIterator for &'a LinkedList<T> {
+impl<'a, T, A: Alloc> IntoIterator for &'a LinkedList<T, A> {
     type Item = &'a T;
     type IntoIter = Iter<'a, T>;
 
@@ -1095,23 +1887,23 @@ impl<'a, T> IntoIterator for &'a LinkedList<T> {
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
-impl<'a, T> IntoIterator for &'a mut LinkedList<T> {
+impl<'a, T, A: Alloc> IntoIterator for &'a mut LinkedList<T, A> {
     type Item = &'a mut T;
-    type IntoIter = IterMut<'a, T>;
+    type IntoIter = IterMut<'a, T, A>;
 
-    fn into_iter(self) -> IterMut<'a, T> {
+    fn into_iter(self) -> IterMut<'a, T, A> {
         self.iter_mut()
     }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
-impl<T> Extend<T> for LinkedList<T> {
+impl<T, A: Alloc> Extend<T> for LinkedList<T, A> {
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
         <Self as SpecExtend<I>>::spec_extend(self, iter);
     }
 }
 
-impl<I: IntoIterator> SpecExtend<I> for LinkedList<I::Item> {
+impl<I: IntoIterator, A: Alloc> SpecExtend<I> for LinkedList<I::Item, A> {
     default fn spec_extend(&mut self, iter: I) {
         for elt in iter {
             self.push_back(elt);
@@ -1119,21 +1911,21 @@ impl<I: IntoIterator> SpecExtend<I> for LinkedList<I::Item> {
     }
 }
 
-impl<T> SpecExtend<LinkedList<T>> for LinkedList<T> {
-    fn spec_extend(&mut self, ref mut other: LinkedList<T>) {
+impl<T, A: Alloc> SpecExtend<LinkedList<T, A>> for LinkedList<T, A> {
+    fn spec_extend(&mut self, ref mut other: LinkedList<T, A>) {
         self.append(other);
     }
 }
 
 #[stable(feature = "extend_ref", since = "1.2.0")]
-impl<'a, T: 'a + Copy> Extend<&'a T> for LinkedList<T> {
+impl<'a, T: 'a + Copy, A: Alloc> Extend<&'a T> for LinkedList<T, A> {
     fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
         self.extend(iter.into_iter().cloned());
     }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
-impl<T: PartialEq> PartialEq for LinkedList<T> {
+impl<T: PartialEq, A: Alloc> PartialEq for LinkedList<T, A> {
     fn eq(&self, other: &Self) -> bool {
         self.len() == other.len() && self.iter().eq(other)
     }
@@ -1144,17 +1936,17 @@ impl<T: PartialEq> PartialEq for LinkedList<T> {
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
-impl<T: Eq> Eq for LinkedList<T> {}
+impl<T: Eq, A: Alloc> Eq for LinkedList<T, A> {}
 
 #[stable(feature = "rust1", since = "1.0.0")]
-impl<T: PartialOrd> PartialOrd for LinkedList<T> {
+impl<T: PartialOrd, A: Alloc> PartialOrd for LinkedList<T, A> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         self.iter().partial_cmp(other)
     }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
-impl<T: Ord> Ord for LinkedList<T> {
+impl<T: Ord, A: Alloc> Ord for LinkedList<T, A> {
     #[inline]
     fn cmp(&self, other: &Self) -> Ordering {
         self.iter().cmp(other)
@@ -1162,21 +1954,23 @@ impl<T: Ord> Ord for LinkedList<T> {
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
-impl<T: Clone> Clone for LinkedList<T> {
+impl<T: Clone, A: Alloc + Clone> Clone for LinkedList<T, A> {
     fn clone(&self) -> Self {
-        self.iter().cloned().collect()
+        let mut list = unsafe { LinkedList::new_in(self.a.clone()) };
+        list.extend(self.iter().cloned());
+        list
     }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
-impl<T: fmt::Debug> fmt::Debug for LinkedList<T> {
+impl<T: fmt::Debug, A: Alloc> fmt::Debug for LinkedList<T, A> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_list().entries(self).finish()
     }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
-impl<T: Hash> Hash for LinkedList<T> {
+impl<T: Hash, A: Alloc> Hash for LinkedList<T, A> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.len().hash(state);
         for elt in self {
@@ -1200,10 +1994,10 @@ fn assert_covariance() {
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
-unsafe impl<T: Send> Send for LinkedList<T> {}
+unsafe impl<T: Send, A: Alloc + Send> Send for LinkedList<T, A> {}
 
 #[stable(feature = "rust1", since = "1.0.0")]
-unsafe impl<T: Sync> Sync for LinkedList<T> {}
+unsafe impl<T: Sync, A: Alloc + Sync> Sync for LinkedList<T, A> {}
 
 #[stable(feature = "rust1", since = "1.0.0")]
 unsafe impl<'a, T: Sync> Send for Iter<'a, T> {}
@@ -1212,16 +2006,25 @@ unsafe impl<'a, T: Sync> Send for Iter<'a, T> {}
 unsafe impl<'a, T: Sync> Sync for Iter<'a, T> {}
 
 #[stable(feature = "rust1", since = "1.0.0")]
-unsafe impl<'a, T: Send> Send for IterMut<'a, T> {}
+unsafe impl<'a, T: Send, A: Alloc + Send> Send for IterMut<'a, T, A> {}
 
 #[stable(feature = "rust1", since = "1.0.0")]
-unsafe impl<'a, T: Sync> Sync for IterMut<'a, T> {}
+unsafe impl<'a, T: Sync, A: Alloc + Sync> Sync for IterMut<'a, T, A> {}
+
+#[unstable(feature = "linked_list_cursors", issue = "0")]
+unsafe impl<'a, T: Send, A: Alloc + Send> Send for CursorMut<'a, T, A> {}
+
+#[unstable(feature = "linked_list_cursors", issue = "0")]
+unsafe impl<'a, T: Sync, A: Alloc + Sync> Sync for CursorMut<'a, T, A> {}
 
 #[cfg(test)]
 mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
     use std::thread;
     use std::vec::Vec;
 
+    use heap::{Alloc, AllocErr, Heap, Layout};
     use rand::{thread_rng, Rng};
 
     use super::{LinkedList, Node};
@@ -1231,7 +2034,7 @@ mod tests {
         v.iter().cloned().collect()
     }
 
-    pub fn check_links<T>(list: &LinkedList<T>) {
+    pub fn check_links<T, A: Alloc>(list: &LinkedList<T, A>) {
         unsafe {
             let mut len = 0;
             let mut last_ptr: Option<&Node<T>> = None;
@@ -1360,6 +2163,46 @@ mod tests {
                    [-2, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1]);
     }
 
+    #[test]
+    fn test_split_off_next() {
+        for len in 0..6 {
+            for at in 0..len + 1 {
+                let mut m = list_from(&(0..len).collect::<Vec<_>>());
+                let n = {
+                    let mut it = m.iter_mut();
+                    for _ in 0..at {
+                        it.next();
+                    }
+                    // Elements taken from the back do not move the split point.
+                    if at < len {
+                        it.next_back();
+                    }
+                    let n = it.split_off_next();
+                    assert!(it.next().is_none());
+                    assert!(it.next_back().is_none());
+                    n
+                };
+                check_links(&m);
+                check_links(&n);
+                assert_eq!(m.into_iter().collect::<Vec<_>>(), (0..at).collect::<Vec<_>>());
+                assert_eq!(n.into_iter().collect::<Vec<_>>(), (at..len).collect::<Vec<_>>());
+            }
+        }
+
+        // Elements inserted with `insert_next` count as already returned.
+        let mut m = list_from(&[1, 3]);
+        let n = {
+            let mut it = m.iter_mut();
+            it.next();
+            it.insert_next(2);
+            it.split_off_next()
+        };
+        check_links(&m);
+        check_links(&n);
+        assert_eq!(m.into_iter().collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(n.into_iter().collect::<Vec<_>>(), [3]);
+    }
+
     #[test]
     #[cfg_attr(target_os = "emscripten", ignore)]
     fn test_send() {
@@ -1483,4 +2326,221 @@ mod tests {
         assert_eq!(deleted, &[1, 2, 3, 4, 5, 6]);
         assert_eq!(m.into_iter().collect::<Vec<_>>(), &[]);
     }
+
+    #[test]
+    fn test_handles() {
+        let mut m = LinkedList::new();
+        let handles: Vec<_> = (0..8).map(|i| {
+            if i % 2 == 0 { m.push_back_handle(i) } else { m.push_front_handle(i) }
+        }).collect();
+        assert_eq!(m.iter().cloned().collect::<Vec<_>>(), [7, 5, 3, 1, 0, 2, 4, 6]);
+
+        unsafe {
+            for (i, &handle) in handles.iter().enumerate() {
+                assert_eq!(*m.get_node(handle), i);
+                *m.get_node_mut(handle) += 10;
+            }
+            // Remove from the middle and both ends.
+            assert_eq!(m.remove_node(handles[1]), 11);
+            assert_eq!(m.remove_node(handles[7]), 17);
+            assert_eq!(m.remove_node(handles[6]), 16);
+            check_links(&m);
+            assert_eq!(m.iter().cloned().collect::<Vec<_>>(), [15, 13, 10, 12, 14]);
+
+            // Handles stay valid as the list changes around them.
+            m.push_front(20);
+            m.pop_back();
+            assert_eq!(*m.get_node(handles[2]), 12);
+            for &i in &[0, 2, 3, 5] {
+                m.remove_node(handles[i]);
+            }
+        }
+        check_links(&m);
+        assert_eq!(m.iter().cloned().collect::<Vec<_>>(), [20]);
+    }
+
+    #[test]
+    fn retain_test() {
+        let mut m: LinkedList<u32> = (0..10).collect();
+        m.retain(|&v| v % 3 != 0);
+        check_links(&m);
+        assert_eq!(m.len(), 6);
+        assert_eq!(m.iter().cloned().collect::<Vec<_>>(), &[1, 2, 4, 5, 7, 8]);
+
+        m.retain(|_| false);
+        check_links(&m);
+        assert!(m.is_empty());
+    }
+
+    #[test]
+    fn test_alloc() {
+        // Counts the nodes that are currently allocated.
+        #[derive(Clone)]
+        struct CountingAlloc(Rc<Cell<usize>>);
+        unsafe impl Alloc for CountingAlloc {
+            unsafe fn alloc(&mut self, layout: Layout) -> Result<*mut u8, AllocErr> {
+                self.0.set(self.0.get() + 1);
+                Heap.alloc(layout)
+            }
+            unsafe fn dealloc(&mut self, ptr: *mut u8, layout: Layout) {
+                self.0.set(self.0.get() - 1);
+                Heap.dealloc(ptr, layout)
+            }
+        }
+
+        let live = Rc::new(Cell::new(0));
+        let mut m = unsafe { LinkedList::new_in(CountingAlloc(live.clone())) };
+        m.extend(0..10);
+        m.push_front(-1);
+        assert_eq!(live.get(), 11);
+        assert_eq!(m.pop_back(), Some(9));
+        assert_eq!(live.get(), 10);
+
+        let mut n = m.split_off(5);
+        check_links(&m);
+        check_links(&n);
+        {
+            let mut it = n.iter_mut();
+            it.next();
+            it.insert_next(20);
+        }
+        assert_eq!(live.get(), 11);
+        n.retain(|&v| v % 2 == 0);
+        assert_eq!(n.iter().cloned().collect::<Vec<_>>(), [4, 20, 6, 8]);
+        assert_eq!(live.get(), 9);
+
+        let copy = n.clone();
+        assert_eq!(live.get(), 13);
+        m.append(&mut n);
+        check_links(&m);
+        assert_eq!(m.iter().cloned().collect::<Vec<_>>(), [-1, 0, 1, 2, 3, 4, 20, 6, 8]);
+        drop(m);
+        assert_eq!(live.get(), 4);
+        assert_eq!(copy.into_iter().collect::<Vec<_>>(), [4, 20, 6, 8]);
+        assert_eq!(live.get(), 0);
+    }
+
+    #[test]
+    fn test_cursor_mut() {
+        let mut m = list_from(&[1, 2, 3]);
+        {
+            let mut c = m.cursor_front_mut();
+            assert_eq!(c.index(), Some(0));
+            assert_eq!(c.peek_prev(), None);
+            c.move_next();
+            assert_eq!(c.current(), Some(&mut 2));
+            c.insert_before(10);
+            c.insert_after(20);
+            assert_eq!(c.index(), Some(2));
+            assert_eq!(c.peek_next(), Some(&mut 20));
+
+            c.move_next();
+            c.move_next();
+            c.move_next();
+            assert_eq!(c.index(), None);
+            assert_eq!(c.current(), None);
+            assert_eq!(c.peek_next(), Some(&mut 1));
+            assert_eq!(c.peek_prev(), Some(&mut 3));
+            c.insert_after(0);
+            c.insert_before(4);
+            c.move_prev();
+            assert_eq!(c.index(), Some(6));
+            assert_eq!(c.remove_current(), Some(4));
+            assert_eq!(c.index(), None);
+            assert_eq!(c.remove_current(), None);
+            c.move_next();
+            assert_eq!(c.remove_current(), Some(0));
+            assert_eq!(c.current(), Some(&mut 1));
+            c.move_prev();
+            c.move_prev();
+            assert_eq!(c.index(), Some(4));
+        }
+        check_links(&m);
+        assert_eq!(m.iter().cloned().collect::<Vec<_>>(), [1, 10, 2, 20, 3]);
+
+        let mut m: LinkedList<u32> = LinkedList::new();
+        let mut c = m.cursor_back_mut();
+        assert_eq!(c.index(), None);
+        c.move_next();
+        assert_eq!(c.index(), None);
+        c.move_prev();
+        assert_eq!(c.current(), None);
+    }
+
+    #[test]
+    fn test_cursor_splice() {
+        let mut m = list_from(&[1, 2, 3]);
+        {
+            let mut c = m.cursor_front_mut();
+            let mut n = list_from(&[10, 11]);
+            c.splice(&mut n);
+            assert!(n.is_empty());
+            check_links(&n);
+            assert_eq!(c.current(), Some(&mut 1));
+            assert_eq!(c.peek_next(), Some(&mut 10));
+
+            c.splice(&mut n);
+            c.move_prev();
+            c.splice(&mut list_from(&[-2, -1]));
+            assert_eq!(c.index(), None);
+            c.move_prev();
+            c.splice(&mut list_from(&[4]));
+            assert_eq!(c.index(), Some(6));
+            c.move_next();
+            assert_eq!(c.current(), Some(&mut 4));
+        }
+        check_links(&m);
+        assert_eq!(m.len(), 8);
+        assert_eq!(m.iter().cloned().collect::<Vec<_>>(), [-2, -1, 1, 10, 11, 2, 3, 4]);
+
+        let mut e = LinkedList::new();
+        e.cursor_back_mut().splice(&mut m);
+        check_links(&e);
+        check_links(&m);
+        assert_eq!(e.len(), 8);
+        assert!(m.is_empty());
+    }
+
+    #[test]
+    fn test_reverse() {
+        let mut m: LinkedList<u32> = LinkedList::new();
+        m.reverse();
+        check_links(&m);
+        assert!(m.is_empty());
+
+        m.push_back(1);
+        m.reverse();
+        check_links(&m);
+        assert_eq!(m.front(), Some(&1));
+
+        m.extend(2..6);
+        m.reverse();
+        check_links(&m);
+        assert_eq!(m.iter().cloned().collect::<Vec<_>>(), [5, 4, 3, 2, 1]);
+        assert_eq!(m.iter().rev().cloned().collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+        m.reverse();
+        m.push_front(0);
+        check_links(&m);
+        assert_eq!(m.into_iter().collect::<Vec<_>>(), [0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_find() {
+        let mut m = list_from(&[1, 2, 3, 2]);
+        assert!(m.find(|&x| x > 3).is_none());
+        {
+            let mut c = m.find(|&x| x == 2).unwrap();
+            assert_eq!(c.index(), Some(1));
+            assert_eq!(c.remove_current(), Some(2));
+            c.insert_before(5);
+            assert_eq!(c.current(), Some(&mut 3));
+        }
+        check_links(&m);
+        assert_eq!(m.iter().cloned().collect::<Vec<_>>(), [1, 5, 3, 2]);
+
+        let mut c = m.find(|&x| x == 2).unwrap();
+        assert_eq!(c.index(), Some(3));
+        c.move_next();
+        assert_eq!(c.index(), None);
+    }
 }
@@ -105,3 +105,95 @@ macro_rules! vec {
 macro_rules! format {
     ($($arg:tt)*) => ($crate::fmt::format(format_args!($($arg)*)))
 }
+
+/// Creates a [`StaticMap`] from a list of `key => value` pairs.
+///
+/// The expansion is a constant expression, so the map can be stored in a
+/// `static` and needs no construction or allocation at runtime. The keys
+/// must be listed in strictly ascending order, since lookups are done with
+/// a binary search. Lookups in a map whose keys are out of order give
+/// unspecified results, so check the order with
+/// [`StaticMap::is_well_formed`] in a test.
+///
+/// [`StaticMap`]: ../std/collections/static_map/struct.StaticMap.html
+/// [`StaticMap::is_well_formed`]:
+/// ../std/collections/static_map/struct.StaticMap.html#method.is_well_formed
+///
+/// # Examples
+///
+/// ```
+/// #![feature(static_map)]
+/// use std::collections::static_map::StaticMap;
+///
+/// static KEYWORDS: StaticMap<&str, u32> = static_map! {
+///     "else" => 1,
+///     "fn" => 2,
+///     "if" => 3,
+///     "while" => 4,
+/// };
+/// assert!(KEYWORDS.is_well_formed());
+/// assert_eq!(KEYWORDS.get("if"), Some(&3));
+/// assert_eq!(KEYWORDS.get("loop"), None);
+/// ```
+#[macro_export]
+#[unstable(feature = "static_map", issue = "0")]
+macro_rules! static_map {
+    ($($k:expr => $v:expr),*) => (
+        $crate::static_map::StaticMap { __entries: &[$(($k, $v)),*] }
+    );
+    ($($k:expr => $v:expr,)*) => (static_map!($($k => $v),*))
+}
+
+/// Creates a [`BTreeMap`] from a list of `key => value` pairs.
+///
+/// The pairs are inserted in order, so a later pair replaces an earlier one
+/// with an equal key.
+///
+/// [`BTreeMap`]: ../std/collections/struct.BTreeMap.html
+///
+/// # Examples
+///
+/// ```
+/// #![feature(collection_macros)]
+///
+/// let map = btreemap! {
+///     "b" => 2,
+///     "a" => 1,
+/// };
+/// assert_eq!(map.keys().collect::<Vec<_>>(), [&"a", &"b"]);
+/// ```
+#[macro_export]
+#[unstable(feature = "collection_macros", issue = "0")]
+macro_rules! btreemap {
+    () => ($crate::btree_map::__new_for_macro());
+    ($($k:expr => $v:expr),+) => ({
+        let mut map = $crate::btree_map::__new_for_macro();
+        $( map.insert($k, $v); )+
+        map
+    });
+    ($($k:expr => $v:expr,)+) => (btreemap!($($k => $v),+))
+}
+
+/// Creates a [`BTreeSet`] containing the arguments.
+///
+/// [`BTreeSet`]: ../std/collections/struct.BTreeSet.html
+///
+/// # Examples
+///
+/// ```
+/// #![feature(collection_macros)]
+///
+/// let set = btreeset![3, 1, 2, 1];
+/// assert_eq!(set.into_iter().collect::<Vec<_>>(), [1, 2, 3]);
+/// ```
+#[macro_export]
+#[unstable(feature = "collection_macros", issue = "0")]
+macro_rules! btreeset {
+    () => ($crate::btree_set::__new_for_macro());
+    ($($x:expr),+) => ({
+        let mut set = $crate::btree_set::__new_for_macro();
+        $( set.insert($x); )+
+        set
+    });
+    ($($x:expr,)+) => (btreeset!($($x),+))
+}
@@ -0,0 +1,533 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A map from disjoint ranges of keys to values.
+//!
+//! [`RangeMap`] assigns a value to every key in a range at once, as memory
+//! protection maps or the spans of a syntax highlighter do. Inserting a
+//! range overwrites whatever the keys in it were mapped to before, cutting
+//! the ranges it overlaps down to the parts outside of it, and adjacent
+//! ranges with equal values are merged into one. The map therefore always
+//! holds the fewest ranges that describe its contents.
+//!
+//! [`RangeMap`]: struct.RangeMap.html
+
+#![unstable(feature = "range_map", issue = "0")]
+
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::{FromIterator, FusedIterator};
+use core::ops::Bound::{Excluded, Included, Unbounded};
+use core::ops::Range;
+
+use btree_map::{self, BTreeMap};
+use vec::Vec;
+
+/// A map from disjoint, half-open ranges of keys to values.
+///
+/// Every key is mapped to at most one value. [`insert`] maps a whole range
+/// of keys to a value, splitting or shortening the ranges it overlaps, and
+/// [`remove`] unmaps a range of keys in the same way. Two ranges that touch
+/// are merged if they have equal values, so iterating over the map yields
+/// the same ranges regardless of the order in which they were inserted.
+///
+/// The map is backed by a [`BTreeMap`] keyed by the start of each range.
+/// Looking up a key takes `O(log n)` time, and inserting or removing a
+/// range takes `O(log n)` time for every range it overlaps.
+///
+/// [`insert`]: #method.insert
+/// [`remove`]: #method.remove
+/// [`BTreeMap`]: ../btree_map/struct.BTreeMap.html
+///
+/// # Examples
+///
+/// ```
+/// #![feature(range_map)]
+/// use std::collections::range_map::RangeMap;
+///
+/// let mut pages = RangeMap::new();
+/// pages.insert(0x1000..0x5000, "r-x");
+/// pages.insert(0x5000..0x8000, "rw-");
+///
+/// // Make part of the code writable, splitting its range in two.
+/// pages.insert(0x2000..0x3000, "rw-");
+/// assert_eq!(pages.get(&0x2800), Some(&"rw-"));
+///
+/// // Protecting it again joins the pieces back together.
+/// pages.insert(0x2000..0x3000, "r-x");
+/// let ranges: Vec<_> = pages.iter().collect();
+/// assert_eq!(ranges, [(&(0x1000..0x5000), &"r-x"), (&(0x5000..0x8000), &"rw-")]);
+/// ```
+pub struct RangeMap<K, V> {
+    map: BTreeMap<Key<K>, V>,
+}
+
+/// A range stored in the map, ordered and borrowed as its start.
+///
+/// Since the ranges in the map are disjoint, comparing their starts orders
+/// them just like the keys they contain.
+#[derive(Clone)]
+struct Key<K>(Range<K>);
+
+impl<K> Borrow<K> for Key<K> {
+    fn borrow(&self) -> &K {
+        &self.0.start
+    }
+}
+
+impl<K: PartialEq> PartialEq for Key<K> {
+    fn eq(&self, other: &Key<K>) -> bool {
+        self.0.start == other.0.start
+    }
+}
+
+impl<K: Eq> Eq for Key<K> {}
+
+impl<K: PartialOrd> PartialOrd for Key<K> {
+    fn partial_cmp(&self, other: &Key<K>) -> Option<Ordering> {
+        self.0.start.partial_cmp(&other.0.start)
+    }
+}
+
+impl<K: Ord> Ord for Key<K> {
+    fn cmp(&self, other: &Key<K>) -> Ordering {
+        self.0.start.cmp(&other.0.start)
+    }
+}
+
+impl<K: Ord, V> RangeMap<K, V> {
+    /// Creates an empty `RangeMap`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(range_map)]
+    /// use std::collections::range_map::RangeMap;
+    ///
+    /// let map: RangeMap<u32, char> = RangeMap::new();
+    /// assert!(map.is_empty());
+    /// ```
+    pub fn new() -> RangeMap<K, V> {
+        RangeMap { map: BTreeMap::new() }
+    }
+
+    /// Returns the number of ranges in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(range_map)]
+    /// use std::collections::range_map::RangeMap;
+    ///
+    /// let mut map = RangeMap::new();
+    /// map.insert(0..10, 'a');
+    /// map.insert(3..5, 'b');
+    /// assert_eq!(map.len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the map contains no ranges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(range_map)]
+    /// use std::collections::range_map::RangeMap;
+    ///
+    /// let mut map = RangeMap::new();
+    /// assert!(map.is_empty());
+    /// map.insert(0..10, 'a');
+    /// assert!(!map.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Removes all ranges from the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(range_map)]
+    /// use std::collections::range_map::RangeMap;
+    ///
+    /// let mut map = RangeMap::new();
+    /// map.insert(0..10, 'a');
+    /// map.clear();
+    /// assert!(map.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// Returns a reference to the value that `key` is mapped to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(range_map)]
+    /// use std::collections::range_map::RangeMap;
+    ///
+    /// let mut map = RangeMap::new();
+    /// map.insert(0..10, 'a');
+    /// assert_eq!(map.get(&9), Some(&'a'));
+    /// assert_eq!(map.get(&10), None);
+    /// ```
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.get_key_value(key).map(|(_, value)| value)
+    }
+
+    /// Returns the range containing `key` and the value it is mapped to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(range_map)]
+    /// use std::collections::range_map::RangeMap;
+    ///
+    /// let mut map = RangeMap::new();
+    /// map.insert(0..10, 'a');
+    /// map.insert(10..20, 'a');
+    /// assert_eq!(map.get_key_value(&15), Some((&(0..20), &'a')));
+    /// assert_eq!(map.get_key_value(&25), None);
+    /// ```
+    pub fn get_key_value(&self, key: &K) -> Option<(&Range<K>, &V)> {
+        match self.map.range::<K, _>((Unbounded, Included(key))).next_back() {
+            Some((range, value)) if *key < range.0.end => Some((&range.0, value)),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if `key` is mapped to a value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(range_map)]
+    /// use std::collections::range_map::RangeMap;
+    ///
+    /// let mut map = RangeMap::new();
+    /// map.insert(0..10, 'a');
+    /// assert!(map.contains_key(&0));
+    /// assert!(!map.contains_key(&10));
+    /// ```
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get_key_value(key).is_some()
+    }
+
+    /// Gets an iterator over the ranges of the map and their values, sorted
+    /// by range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(range_map)]
+    /// use std::collections::range_map::RangeMap;
+    ///
+    /// let mut map = RangeMap::new();
+    /// map.insert(5..10, 'b');
+    /// map.insert(0..5, 'a');
+    ///
+    /// let mut iter = map.iter();
+    /// assert_eq!(iter.next(), Some((&(0..5), &'a')));
+    /// assert_eq!(iter.next(), Some((&(5..10), &'b')));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn iter(&self) -> Iter<K, V> {
+        Iter { iter: self.map.iter() }
+    }
+
+    /// Gets an iterator over the ranges of the map that overlap `range`, and
+    /// their values, sorted by range.
+    ///
+    /// The ranges are yielded whole, so the first and the last one may extend
+    /// beyond `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range.start > range.end`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(range_map)]
+    /// use std::collections::range_map::RangeMap;
+    ///
+    /// let mut spans = RangeMap::new();
+    /// spans.insert(0..4, "keyword");
+    /// spans.insert(5..9, "ident");
+    /// spans.insert(10..12, "number");
+    ///
+    /// let visible: Vec<_> = spans.overlapping(3..10).map(|(_, &style)| style).collect();
+    /// assert_eq!(visible, ["keyword", "ident"]);
+    /// ```
+    pub fn overlapping(&self, range: Range<K>) -> Overlapping<K, V> {
+        assert!(range.start <= range.end, "range start is greater than range end in RangeMap");
+        let first = match self.get_key_value(&range.start) {
+            Some((containing, _)) if range.start < range.end => &containing.start,
+            _ => &range.start,
+        };
+        Overlapping { iter: self.map.range::<K, _>((Included(first), Excluded(&range.end))) }
+    }
+}
+
+impl<K: Ord + Clone, V: Eq + Clone> RangeMap<K, V> {
+    /// Maps every key in `range` to `value`.
+    ///
+    /// Whatever the keys in `range` were mapped to before is overwritten:
+    /// ranges that lie within `range` are removed, and ranges that partly
+    /// overlap it are cut down to the part outside of it. A range that
+    /// contains all of `range` is split in two. Afterwards, `range` is merged
+    /// with the ranges directly before and after it if they are mapped to an
+    /// equal value.
+    ///
+    /// Inserting an empty range does nothing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range.start > range.end`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(range_map)]
+    /// use std::collections::range_map::RangeMap;
+    ///
+    /// let mut map = RangeMap::new();
+    /// map.insert(0..10, 'a');
+    /// map.insert(2..4, 'b');
+    /// assert_eq!(map.get(&3), Some(&'b'));
+    /// assert_eq!(map.len(), 3);
+    ///
+    /// map.insert(4..12, 'b');
+    /// let ranges: Vec<_> = map.iter().collect();
+    /// assert_eq!(ranges, [(&(0..2), &'a'), (&(2..12), &'b')]);
+    /// ```
+    pub fn insert(&mut self, range: Range<K>, value: V) {
+        if range.start == range.end {
+            return;
+        }
+        self.remove(range.clone());
+
+        let Range { mut start, mut end } = range;
+        let before = match self.map.range::<K, _>((Unbounded, Excluded(&start))).next_back() {
+            Some((prev, prev_value)) if prev.0.end == start && *prev_value == value => {
+                Some(prev.0.start.clone())
+            }
+            _ => None,
+        };
+        if let Some(prev_start) = before {
+            self.map.remove(&prev_start);
+            start = prev_start;
+        }
+        let after = match self.map.range::<K, _>((Included(&end), Unbounded)).next() {
+            Some((next, next_value)) if next.0.start == end && *next_value == value => {
+                Some(next.0.end.clone())
+            }
+            _ => None,
+        };
+        if let Some(next_end) = after {
+            self.map.remove(&end);
+            end = next_end;
+        }
+        self.map.insert(Key(start..end), value);
+    }
+
+    /// Unmaps every key in `range`.
+    ///
+    /// Ranges that lie within `range` are removed, and ranges that partly
+    /// overlap it are cut down to the part outside of it. A range that
+    /// contains all of `range` is split in two.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range.start > range.end`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(range_map)]
+    /// use std::collections::range_map::RangeMap;
+    ///
+    /// let mut map = RangeMap::new();
+    /// map.insert(0..10, 'a');
+    /// map.remove(3..5);
+    /// let ranges: Vec<_> = map.iter().collect();
+    /// assert_eq!(ranges, [(&(0..3), &'a'), (&(5..10), &'a')]);
+    /// ```
+    pub fn remove(&mut self, range: Range<K>) {
+        if range.start == range.end {
+            return;
+        }
+        let overlapping: Vec<Range<K>> = self.overlapping(range.clone())
+            .map(|(range, _)| range.clone())
+            .collect();
+        for old in overlapping {
+            let value = self.map.remove(&old.start).unwrap();
+            if old.end > range.end {
+                if old.start < range.start {
+                    self.map.insert(Key(old.start..range.start.clone()), value.clone());
+                }
+                self.map.insert(Key(range.end.clone()..old.end), value);
+            } else if old.start < range.start {
+                self.map.insert(Key(old.start..range.start.clone()), value);
+            }
+        }
+    }
+}
+
+impl<K: Clone, V: Clone> Clone for RangeMap<K, V> {
+    fn clone(&self) -> RangeMap<K, V> {
+        RangeMap { map: self.map.clone() }
+    }
+}
+
+// The keys of the `BTreeMap` only compare the starts of the ranges, so the
+// ends need to be compared separately.
+impl<K: Ord, V: PartialEq> PartialEq for RangeMap<K, V> {
+    fn eq(&self, other: &RangeMap<K, V>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<K: Ord, V: Eq> Eq for RangeMap<K, V> {}
+
+impl<K: Ord + fmt::Debug, V: fmt::Debug> fmt::Debug for RangeMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Ord, V> Default for RangeMap<K, V> {
+    /// Creates an empty `RangeMap`.
+    fn default() -> RangeMap<K, V> {
+        RangeMap::new()
+    }
+}
+
+impl<K: Ord + Clone, V: Eq + Clone> Extend<(Range<K>, V)> for RangeMap<K, V> {
+    /// Inserts the ranges in order, so later ranges overwrite earlier ones
+    /// where they overlap.
+    fn extend<I: IntoIterator<Item = (Range<K>, V)>>(&mut self, iter: I) {
+        for (range, value) in iter {
+            self.insert(range, value);
+        }
+    }
+}
+
+impl<K: Ord + Clone, V: Eq + Clone> FromIterator<(Range<K>, V)> for RangeMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (Range<K>, V)>>(iter: I) -> RangeMap<K, V> {
+        let mut map = RangeMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<'a, K: Ord, V> IntoIterator for &'a RangeMap<K, V> {
+    type Item = (&'a Range<K>, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+/// An iterator over the ranges of a `RangeMap` and their values.
+///
+/// This `struct` is created by the [`iter`] method on [`RangeMap`]. See its
+/// documentation for more.
+///
+/// [`iter`]: struct.RangeMap.html#method.iter
+/// [`RangeMap`]: struct.RangeMap.html
+pub struct Iter<'a, K: 'a, V: 'a> {
+    iter: btree_map::Iter<'a, Key<K>, V>,
+}
+
+impl<'a, K, V> Clone for Iter<'a, K, V> {
+    fn clone(&self) -> Iter<'a, K, V> {
+        Iter { iter: self.iter.clone() }
+    }
+}
+
+impl<'a, K: fmt::Debug, V: fmt::Debug> fmt::Debug for Iter<'a, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a Range<K>, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<(&'a Range<K>, &'a V)> {
+        self.iter.next().map(|(range, value)| (&range.0, value))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for Iter<'a, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<(&'a Range<K>, &'a V)> {
+        self.iter.next_back().map(|(range, value)| (&range.0, value))
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for Iter<'a, K, V> {}
+
+impl<'a, K, V> FusedIterator for Iter<'a, K, V> {}
+
+/// An iterator over the ranges of a `RangeMap` that overlap a given range,
+/// and their values.
+///
+/// This `struct` is created by the [`overlapping`] method on [`RangeMap`].
+/// See its documentation for more.
+///
+/// [`overlapping`]: struct.RangeMap.html#method.overlapping
+/// [`RangeMap`]: struct.RangeMap.html
+pub struct Overlapping<'a, K: 'a, V: 'a> {
+    iter: btree_map::Range<'a, Key<K>, V>,
+}
+
+impl<'a, K, V> Clone for Overlapping<'a, K, V> {
+    fn clone(&self) -> Overlapping<'a, K, V> {
+        Overlapping { iter: self.iter.clone() }
+    }
+}
+
+impl<'a, K: fmt::Debug, V: fmt::Debug> fmt::Debug for Overlapping<'a, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<'a, K, V> Iterator for Overlapping<'a, K, V> {
+    type Item = (&'a Range<K>, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<(&'a Range<K>, &'a V)> {
+        self.iter.next().map(|(range, value)| (&range.0, value))
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for Overlapping<'a, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<(&'a Range<K>, &'a V)> {
+        self.iter.next_back().map(|(range, value)| (&range.0, value))
+    }
+}
+
+impl<'a, K, V> FusedIterator for Overlapping<'a, K, V> {}
@@ -9,11 +9,12 @@
 // except according to those terms.
 
 use core::cmp;
-use core::heap::{Alloc, Layout};
+use core::heap::{Alloc, Excess, Layout};
 use core::mem;
 use core::ops::Drop;
 use core::ptr::{self, Unique};
 use core::slice;
+use growth::GrowthPolicy;
 use heap::Heap;
 use super::boxed::Box;
 use super::allocator::CollectionAllocErr;
@@ -37,6 +38,11 @@ use super::allocator::CollectionAllocErr::*;
 /// free its memory, but it *won't* try to Drop its contents. It is up to the user of RawVec
 /// to handle the actual things *stored* inside of a RawVec.
 ///
+/// When the buffer grows by doubling or through `reserve`, the allocator may hand back a
+/// larger block than was asked for; RawVec then takes the whole block, so `cap()` can end
+/// up larger than the requested capacity. `with_capacity`, `reserve_exact` and
+/// `shrink_to_fit` always set the capacity to exactly the requested amount.
+///
 /// Note that a RawVec always forces its capacity to be usize::MAX for zero-sized types.
 /// This enables you to use capacity growing logic catch the overflows in your length
 /// that might occur with zero-sized types.
@@ -214,6 +220,21 @@ impl<T, A: Alloc> RawVec<T, A> {
         }
     }
 
+    /// Gets the number of elements that the allocation could hold. This may be more
+    /// than `cap()` if the allocator rounded the buffer's size up.
+    ///
+    /// This will always be `usize::MAX` if `T` is zero-sized.
+    pub fn usable_cap(&self) -> usize {
+        let elem_size = mem::size_of::<T>();
+        if elem_size == 0 {
+            return !0;
+        }
+        match self.current_layout() {
+            Some(layout) => cmp::max(self.cap, self.a.usable_size(&layout).1 / elem_size),
+            None => 0,
+        }
+    }
+
     /// Returns a shared reference to the allocator backing this RawVec.
     pub fn alloc(&self) -> &A {
         &self.a
@@ -312,11 +333,14 @@ impl<T, A: Alloc> RawVec<T, A> {
                     let new_size = new_cap * elem_size;
                     let new_layout = Layout::from_size_align_unchecked(new_size, cur.align());
                     alloc_guard(new_size).expect("capacity overflow");
-                    let ptr_res = self.a.realloc(self.ptr.as_ptr() as *mut u8,
-                                                 cur,
-                                                 new_layout);
+                    let ptr_res = self.a.realloc_excess(self.ptr.as_ptr() as *mut u8,
+                                                        cur,
+                                                        new_layout);
                     match ptr_res {
-                        Ok(ptr) => (new_cap, Unique::new_unchecked(ptr as *mut T)),
+                        Ok(Excess(ptr, usable)) => {
+                            (excess_cap::<T>(new_cap, usable),
+                             Unique::new_unchecked(ptr as *mut T))
+                        }
                         Err(e) => self.a.oom(e),
                     }
                 }
@@ -324,8 +348,13 @@ impl<T, A: Alloc> RawVec<T, A> {
                     // skip to 4 because tiny Vec's are dumb; but not if that
                     // would cause overflow
                     let new_cap = if elem_size > (!0) / 8 { 1 } else { 4 };
-                    match self.a.alloc_array::<T>(new_cap) {
-                        Ok(ptr) => (new_cap, ptr.into()),
+                    let layout = Layout::from_size_align_unchecked(new_cap * elem_size,
+                                                                   mem::align_of::<T>());
+                    match self.a.alloc_excess(layout) {
+                        Ok(Excess(ptr, usable)) => {
+                            (excess_cap::<T>(new_cap, usable),
+                             Unique::new_unchecked(ptr as *mut T))
+                        }
                         Err(e) => self.a.oom(e),
                     }
                 }
@@ -539,13 +568,14 @@ impl<T, A: Alloc> RawVec<T, A> {
             let res = match self.current_layout() {
                 Some(layout) => {
                     let old_ptr = self.ptr.as_ptr() as *mut u8;
-                    self.a.realloc(old_ptr, layout, new_layout)
+                    self.a.realloc_excess(old_ptr, layout, new_layout)
                 }
-                None => self.a.alloc(new_layout),
+                None => self.a.alloc_excess(new_layout),
             };
 
-            self.ptr = Unique::new_unchecked(res? as *mut T);
-            self.cap = new_cap;
+            let Excess(ptr, usable) = res?;
+            self.ptr = Unique::new_unchecked(ptr as *mut T);
+            self.cap = excess_cap::<T>(new_cap, usable);
 
             Ok(())
         }
@@ -559,6 +589,21 @@ impl<T, A: Alloc> RawVec<T, A> {
             Ok(()) => { /* yay */ }
          }
      }
+
+    /// Like `reserve`, but lets `policy` pick the new capacity instead of
+    /// doubling the current one. The buffer is still grown to at least
+    /// `used_cap + needed_extra_cap` elements.
+    pub fn reserve_with<P: GrowthPolicy>(&mut self,
+                                         used_cap: usize,
+                                         needed_extra_cap: usize,
+                                         policy: &P) {
+        if self.cap().wrapping_sub(used_cap) >= needed_extra_cap {
+            return;
+        }
+        let required_cap = used_cap.checked_add(needed_extra_cap).expect("capacity overflow");
+        let new_cap = cmp::max(policy.grow(self.cap, required_cap), required_cap);
+        self.reserve_exact(used_cap, new_cap - used_cap);
+    }
     /// Attempts to ensure that the buffer contains at least enough space to hold
     /// `used_cap + needed_extra_cap` elements. If it doesn't already have
     /// enough capacity, will reallocate in place enough space plus comfortable slack
@@ -736,6 +781,18 @@ fn alloc_guard(alloc_size: usize) -> Result<(), CollectionAllocErr> {
     }
 }
 
+// The capacity of a block that was requested for `cap` elements and turned out to
+// have `usable` bytes. Any whole elements' worth of slack at the end of the block
+// is claimed; the block still fits the layout of the larger capacity, so it can be
+// freed or reallocated with that layout later.
+#[inline]
+fn excess_cap<T>(cap: usize, usable: usize) -> usize {
+    match mem::size_of::<T>() {
+        0 => cap,
+        elem_size => cmp::max(cap, usable / elem_size),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -780,23 +837,28 @@ mod tests {
         assert_eq!(v.a.fuel, 250);
     }
 
+    // The capacity that the heap actually gives a request for `cap` `u32`s.
+    fn heap_cap(cap: usize) -> usize {
+        Heap.usable_size(&Layout::array::<u32>(cap).unwrap()).1 / 4
+    }
+
     #[test]
     fn reserve_does_not_overallocate() {
         {
             let mut v: RawVec<u32> = RawVec::new();
             // First `reserve` allocates like `reserve_exact`
             v.reserve(0, 9);
-            assert_eq!(9, v.cap());
+            assert_eq!(heap_cap(9), v.cap());
         }
 
         {
             let mut v: RawVec<u32> = RawVec::new();
             v.reserve(0, 7);
-            assert_eq!(7, v.cap());
+            assert_eq!(heap_cap(7), v.cap());
             // 97 if more than double of 7, so `reserve` should work
             // like `reserve_exact`.
             v.reserve(7, 90);
-            assert_eq!(97, v.cap());
+            assert_eq!(heap_cap(97), v.cap());
         }
 
         {
@@ -812,5 +874,50 @@ mod tests {
         }
     }
 
+    #[test]
+    fn growth_claims_usable_size() {
+        use allocator::AllocErr;
+
+        // An allocator that rounds every block up to a multiple of 64 bytes.
+        struct RoundingAlloc;
+        fn round(layout: &Layout) -> Layout {
+            Layout::from_size_align((layout.size() + 63) / 64 * 64, layout.align()).unwrap()
+        }
+        unsafe impl Alloc for RoundingAlloc {
+            unsafe fn alloc(&mut self, layout: Layout) -> Result<*mut u8, AllocErr> {
+                Heap.alloc(round(&layout))
+            }
+            unsafe fn dealloc(&mut self, ptr: *mut u8, layout: Layout) {
+                Heap.dealloc(ptr, round(&layout))
+            }
+            fn usable_size(&self, layout: &Layout) -> (usize, usize) {
+                (layout.size(), round(layout).size())
+            }
+        }
+
+        // Exact requests keep their capacity, but know about the slack.
+        let mut v: RawVec<u32, _> = RawVec::with_capacity_in(3, RoundingAlloc);
+        assert_eq!(v.cap(), 3);
+        assert_eq!(v.usable_cap(), 16);
+
+        // Growing claims the whole block.
+        v.double();
+        assert_eq!(v.cap(), 16);
+        v.reserve(16, 51);
+        assert_eq!(v.cap(), 80);
+        assert_eq!(v.usable_cap(), 80);
+
+        v.reserve_exact(80, 1);
+        assert_eq!(v.cap(), 81);
+        assert_eq!(v.usable_cap(), 96);
+
+        v.shrink_to_fit(5);
+        assert_eq!(v.cap(), 5);
+        assert_eq!(v.usable_cap(), 16);
+
+        let v: RawVec<(), _> = RawVec::new_in(RoundingAlloc);
+        assert_eq!(v.usable_cap(), !0);
+    }
+
 
 }
@@ -0,0 +1,720 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A string type for large texts that are edited in the middle.
+//!
+//! A [`Rope`] stores its text in small chunks at the leaves of a balanced
+//! binary tree. Inserting or removing text anywhere only rebuilds the path
+//! from the root to the affected chunks, instead of moving all the text
+//! after the edit the way a `String` does. Positions are given in `char`s,
+//! which the tree counts alongside the bytes of every subtree.
+//!
+//! Subtrees are reference counted and never modified once built, so
+//! cloning a rope or taking a slice of it shares all of its text that is
+//! not cut through.
+//!
+//! [`Rope`]: struct.Rope.html
+
+#![unstable(feature = "rope", issue = "0")]
+
+use core::cmp;
+use core::fmt;
+use core::iter::FusedIterator;
+use core::mem;
+use core::ops::Bound::{Excluded, Included, Unbounded};
+use core::ops::RangeBounds;
+use core::str;
+
+use arc::Arc;
+use string::String;
+use vec::Vec;
+
+/// The largest number of bytes stored in a leaf, unless a single `char` is
+/// split across the limit.
+const MAX_LEAF: usize = 1024;
+
+/// A string stored as a balanced tree of chunks, supporting efficient
+/// edits at arbitrary positions.
+///
+/// Inserting, removing and slicing take `O(log n)` time, where `n` is the
+/// length of the rope, plus the time to copy the inserted text. Positions
+/// are counted in `char`s rather than bytes, and, unlike with `String`,
+/// finding a position doesn't take time proportional to its offset.
+///
+/// Cloning a `Rope` takes `O(1)` time, since the clone shares the whole
+/// tree with the original. Modifying either of them afterwards only copies
+/// the parts of the tree along the path of the modification.
+///
+/// The text is not contiguous in memory; use [`chunks`] or [`chars`] to
+/// read it, or convert the rope into a `String`.
+///
+/// [`chunks`]: #method.chunks
+/// [`chars`]: #method.chars
+///
+/// # Examples
+///
+/// ```
+/// #![feature(rope)]
+/// use std::collections::rope::Rope;
+///
+/// let mut rope = Rope::from("Hello world!");
+/// rope.insert(5, ",");
+/// rope.insert(13, " Ünïcödé");
+/// assert_eq!(rope, "Hello, world! Ünïcödé");
+///
+/// rope.remove(5..13);
+/// assert_eq!(rope.slice(6..), "Ünïcödé");
+/// assert_eq!(rope.len_chars(), 13);
+/// assert_eq!(String::from(rope), "Hello Ünïcödé");
+/// ```
+#[derive(Clone)]
+pub struct Rope {
+    root: Arc<Node>,
+}
+
+struct Node {
+    /// The number of bytes in this subtree.
+    len: usize,
+    /// The number of `char`s in this subtree.
+    chars: usize,
+    /// The length of the longest path from this node to a leaf. The heights
+    /// of the children of a branch differ by at most one.
+    height: usize,
+    kind: NodeKind,
+}
+
+enum NodeKind {
+    Leaf(String),
+    Branch(Arc<Node>, Arc<Node>),
+}
+
+fn leaf(text: String) -> Arc<Node> {
+    Arc::new(Node {
+        len: text.len(),
+        chars: text.chars().count(),
+        height: 0,
+        kind: NodeKind::Leaf(text),
+    })
+}
+
+fn branch(left: Arc<Node>, right: Arc<Node>) -> Arc<Node> {
+    Arc::new(Node {
+        len: left.len + right.len,
+        chars: left.chars + right.chars,
+        height: cmp::max(left.height, right.height) + 1,
+        kind: NodeKind::Branch(left, right),
+    })
+}
+
+fn children(node: &Node) -> (Arc<Node>, Arc<Node>) {
+    match node.kind {
+        NodeKind::Branch(ref left, ref right) => (left.clone(), right.clone()),
+        NodeKind::Leaf(_) => unreachable!(),
+    }
+}
+
+/// Builds a balanced tree holding `text`.
+fn build(text: &str) -> Arc<Node> {
+    let mut leaves = Vec::with_capacity(text.len() / MAX_LEAF + 1);
+    let mut rest = text;
+    while rest.len() > MAX_LEAF {
+        let mut end = MAX_LEAF;
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        leaves.push(leaf(String::from(&rest[..end])));
+        rest = &rest[end..];
+    }
+    leaves.push(leaf(String::from(rest)));
+    build_from_leaves(&leaves)
+}
+
+fn build_from_leaves(leaves: &[Arc<Node>]) -> Arc<Node> {
+    if leaves.len() == 1 {
+        return leaves[0].clone();
+    }
+    let (left, right) = leaves.split_at(leaves.len() / 2);
+    branch(build_from_leaves(left), build_from_leaves(right))
+}
+
+/// Concatenates two balanced trees into one.
+///
+/// This takes time proportional to the difference of their heights.
+fn join(left: Arc<Node>, right: Arc<Node>) -> Arc<Node> {
+    if left.len == 0 {
+        return right;
+    }
+    if right.len == 0 {
+        return left;
+    }
+    if left.len + right.len <= MAX_LEAF {
+        if let (&NodeKind::Leaf(ref a), &NodeKind::Leaf(ref b)) = (&left.kind, &right.kind) {
+            let mut text = String::with_capacity(a.len() + b.len());
+            text.push_str(a);
+            text.push_str(b);
+            return leaf(text);
+        }
+    }
+    if left.height > right.height + 1 {
+        join_right(&left, right)
+    } else if right.height > left.height + 1 {
+        join_left(left, &right)
+    } else {
+        branch(left, right)
+    }
+}
+
+/// Attaches `right` to the right edge of the taller tree `left`.
+fn join_right(left: &Node, right: Arc<Node>) -> Arc<Node> {
+    let (a, c) = children(left);
+    let t = if c.height > right.height + 1 {
+        join_right(&c, right)
+    } else {
+        branch(c, right)
+    };
+    if t.height <= a.height + 1 {
+        return branch(a, t);
+    }
+    // `t` is two levels taller than `a`, so rotate it to the left.
+    let (t1, t2) = children(&t);
+    if t1.height > t2.height {
+        let (t11, t12) = children(&t1);
+        branch(branch(a, t11), branch(t12, t2))
+    } else {
+        branch(branch(a, t1), t2)
+    }
+}
+
+/// Attaches `left` to the left edge of the taller tree `right`.
+fn join_left(left: Arc<Node>, right: &Node) -> Arc<Node> {
+    let (c, b) = children(right);
+    let t = if c.height > left.height + 1 {
+        join_left(left, &c)
+    } else {
+        branch(left, c)
+    };
+    if t.height <= b.height + 1 {
+        return branch(t, b);
+    }
+    // `t` is two levels taller than `b`, so rotate it to the right.
+    let (t1, t2) = children(&t);
+    if t2.height > t1.height {
+        let (t21, t22) = children(&t2);
+        branch(branch(t1, t21), branch(t22, b))
+    } else {
+        branch(t1, branch(t2, b))
+    }
+}
+
+/// Splits a tree into the trees holding its first `at` `char`s and the
+/// rest.
+fn split(node: &Arc<Node>, at: usize) -> (Arc<Node>, Arc<Node>) {
+    if at == 0 {
+        return (leaf(String::new()), node.clone());
+    }
+    if at == node.chars {
+        return (node.clone(), leaf(String::new()));
+    }
+    match node.kind {
+        NodeKind::Leaf(ref text) => {
+            let mid = text.char_indices().nth(at).map_or(text.len(), |(i, _)| i);
+            (leaf(String::from(&text[..mid])), leaf(String::from(&text[mid..])))
+        }
+        NodeKind::Branch(ref left, ref right) => {
+            if at <= left.chars {
+                let (a, b) = split(left, at);
+                (a, join(b, right.clone()))
+            } else {
+                let (a, b) = split(right, at - left.chars);
+                (join(left.clone(), a), b)
+            }
+        }
+    }
+}
+
+impl Rope {
+    /// Creates an empty `Rope`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rope)]
+    /// use std::collections::rope::Rope;
+    ///
+    /// let rope = Rope::new();
+    /// assert!(rope.is_empty());
+    /// ```
+    pub fn new() -> Rope {
+        Rope { root: leaf(String::new()) }
+    }
+
+    /// Returns the length of the rope in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rope)]
+    /// use std::collections::rope::Rope;
+    ///
+    /// let rope = Rope::from("größe");
+    /// assert_eq!(rope.len(), 7);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.root.len
+    }
+
+    /// Returns the number of `char`s in the rope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rope)]
+    /// use std::collections::rope::Rope;
+    ///
+    /// let rope = Rope::from("größe");
+    /// assert_eq!(rope.len_chars(), 5);
+    /// ```
+    pub fn len_chars(&self) -> usize {
+        self.root.chars
+    }
+
+    /// Returns `true` if the rope has a length of zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rope)]
+    /// use std::collections::rope::Rope;
+    ///
+    /// let mut rope = Rope::new();
+    /// assert!(rope.is_empty());
+    /// rope.push_str("a");
+    /// assert!(!rope.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Inserts `text` before the `char` at index `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the number of `char`s in the rope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rope)]
+    /// use std::collections::rope::Rope;
+    ///
+    /// let mut rope = Rope::from("façade");
+    /// rope.insert(3, "--");
+    /// assert_eq!(rope, "faç--ade");
+    /// ```
+    pub fn insert(&mut self, idx: usize, text: &str) {
+        assert!(idx <= self.len_chars(), "insertion index is out of bounds");
+        if text.is_empty() {
+            return;
+        }
+        let (left, right) = split(&self.root, idx);
+        self.root = join(join(left, build(text)), right);
+    }
+
+    /// Appends `text` to the end of the rope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rope)]
+    /// use std::collections::rope::Rope;
+    ///
+    /// let mut rope = Rope::from("foo");
+    /// rope.push_str("bar");
+    /// assert_eq!(rope, "foobar");
+    /// ```
+    pub fn push_str(&mut self, text: &str) {
+        let root = mem::replace(&mut self.root, leaf(String::new()));
+        self.root = join(root, build(text));
+    }
+
+    /// Moves all the text of `other` to the end of `self`, leaving `other`
+    /// empty.
+    ///
+    /// This takes `O(log n)` time, however long `other` is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rope)]
+    /// use std::collections::rope::Rope;
+    ///
+    /// let mut rope = Rope::from("foo");
+    /// let mut other = Rope::from("bar");
+    /// rope.append(&mut other);
+    /// assert_eq!(rope, "foobar");
+    /// assert!(other.is_empty());
+    /// ```
+    pub fn append(&mut self, other: &mut Rope) {
+        let root = mem::replace(&mut self.root, leaf(String::new()));
+        let other = mem::replace(&mut other.root, leaf(String::new()));
+        self.root = join(root, other);
+    }
+
+    /// Splits the rope in two at the `char` index `at`.
+    ///
+    /// Returns a rope holding the text from `at` onwards, and leaves the
+    /// text before `at` in `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is larger than the number of `char`s in the rope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rope)]
+    /// use std::collections::rope::Rope;
+    ///
+    /// let mut rope = Rope::from("Hello, World!");
+    /// let world = rope.split_off(7);
+    /// assert_eq!(rope, "Hello, ");
+    /// assert_eq!(world, "World!");
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> Rope {
+        assert!(at <= self.len_chars(), "split index is out of bounds");
+        let (left, right) = split(&self.root, at);
+        self.root = left;
+        Rope { root: right }
+    }
+
+    /// Removes the `char`s in the given range from the rope.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if the
+    /// end point is greater than the number of `char`s in the rope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rope)]
+    /// use std::collections::rope::Rope;
+    ///
+    /// let mut rope = Rope::from("α is alpha, β is beta");
+    /// rope.remove(..12);
+    /// assert_eq!(rope, "β is beta");
+    /// ```
+    pub fn remove<R>(&mut self, range: R)
+        where R: RangeBounds<usize>
+    {
+        let (start, end) = self.char_range(range);
+        let (left, rest) = split(&self.root, start);
+        let (_, right) = split(&rest, end - start);
+        self.root = join(left, right);
+    }
+
+    /// Returns a new rope holding the `char`s in the given range.
+    ///
+    /// The new rope shares all the chunks that lie completely within the
+    /// range with `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if the
+    /// end point is greater than the number of `char`s in the rope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rope)]
+    /// use std::collections::rope::Rope;
+    ///
+    /// let rope = Rope::from("α is alpha, β is beta");
+    /// assert_eq!(rope.slice(..10), "α is alpha");
+    /// assert_eq!(rope.slice(12..), "β is beta");
+    /// ```
+    pub fn slice<R>(&self, range: R) -> Rope
+        where R: RangeBounds<usize>
+    {
+        let (start, end) = self.char_range(range);
+        let (_, rest) = split(&self.root, start);
+        let (middle, _) = split(&rest, end - start);
+        Rope { root: middle }
+    }
+
+    /// Returns an iterator over the chunks of text the rope is stored in.
+    ///
+    /// The chunks are never empty, and concatenating them gives the text of
+    /// the rope. Their sizes are unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rope)]
+    /// use std::collections::rope::Rope;
+    ///
+    /// let mut rope = Rope::from("foo");
+    /// rope.push_str("bar");
+    ///
+    /// let text: String = rope.chunks().collect();
+    /// assert_eq!(text, "foobar");
+    /// ```
+    pub fn chunks(&self) -> Chunks {
+        Chunks { stack: vec![&*self.root] }
+    }
+
+    /// Returns an iterator over the `char`s of the rope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rope)]
+    /// use std::collections::rope::Rope;
+    ///
+    /// let rope = Rope::from("größe");
+    /// assert_eq!(rope.chars().rev().collect::<String>(), "eßörg");
+    /// ```
+    pub fn chars(&self) -> Chars {
+        Chars {
+            chunks: self.chunks(),
+            front: "".chars(),
+            back: "".chars(),
+        }
+    }
+
+    /// Resolves a range of `char` indices, checking that it lies within the
+    /// rope.
+    fn char_range<R>(&self, range: R) -> (usize, usize)
+        where R: RangeBounds<usize>
+    {
+        let start = match range.start_bound() {
+            Included(&n) => n,
+            Excluded(&n) => n + 1,
+            Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Included(&n) => n + 1,
+            Excluded(&n) => n,
+            Unbounded => self.len_chars(),
+        };
+        assert!(start <= end, "range start is greater than range end in Rope");
+        assert!(end <= self.len_chars(), "range end is out of bounds in Rope");
+        (start, end)
+    }
+}
+
+impl Default for Rope {
+    /// Creates an empty `Rope`.
+    fn default() -> Rope {
+        Rope::new()
+    }
+}
+
+impl<'a> From<&'a str> for Rope {
+    fn from(text: &'a str) -> Rope {
+        Rope { root: build(text) }
+    }
+}
+
+impl From<String> for Rope {
+    fn from(text: String) -> Rope {
+        if text.len() <= MAX_LEAF {
+            Rope { root: leaf(text) }
+        } else {
+            Rope::from(&*text)
+        }
+    }
+}
+
+impl<'a> From<&'a Rope> for String {
+    fn from(rope: &'a Rope) -> String {
+        let mut text = String::with_capacity(rope.len());
+        for chunk in rope.chunks() {
+            text.push_str(chunk);
+        }
+        text
+    }
+}
+
+impl From<Rope> for String {
+    fn from(rope: Rope) -> String {
+        String::from(&rope)
+    }
+}
+
+impl fmt::Display for Rope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for chunk in self.chunks() {
+            f.write_str(chunk)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Rope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&String::from(self), f)
+    }
+}
+
+impl PartialEq for Rope {
+    fn eq(&self, other: &Rope) -> bool {
+        self.len() == other.len() &&
+            self.chunks().flat_map(|c| c.bytes()).eq(other.chunks().flat_map(|c| c.bytes()))
+    }
+}
+
+impl Eq for Rope {}
+
+impl PartialEq<str> for Rope {
+    fn eq(&self, other: &str) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+        let mut rest = other;
+        for chunk in self.chunks() {
+            if !rest.starts_with(chunk) {
+                return false;
+            }
+            rest = &rest[chunk.len()..];
+        }
+        true
+    }
+}
+
+impl<'a> PartialEq<&'a str> for Rope {
+    fn eq(&self, other: &&'a str) -> bool {
+        *self == **other
+    }
+}
+
+impl PartialEq<String> for Rope {
+    fn eq(&self, other: &String) -> bool {
+        *self == **other
+    }
+}
+
+/// An iterator over the chunks of text in a `Rope`.
+///
+/// This `struct` is created by the [`chunks`] method on [`Rope`]. See its
+/// documentation for more.
+///
+/// [`chunks`]: struct.Rope.html#method.chunks
+/// [`Rope`]: struct.Rope.html
+#[derive(Clone)]
+pub struct Chunks<'a> {
+    /// The subtrees still to be visited, the next one on top.
+    stack: Vec<&'a Node>,
+}
+
+impl<'a> fmt::Debug for Chunks<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<'a> Iterator for Chunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        while let Some(node) = self.stack.pop() {
+            match node.kind {
+                NodeKind::Leaf(ref text) => {
+                    if !text.is_empty() {
+                        return Some(text);
+                    }
+                }
+                NodeKind::Branch(ref left, ref right) => {
+                    self.stack.push(right);
+                    self.stack.push(left);
+                }
+            }
+        }
+        None
+    }
+}
+
+impl<'a> DoubleEndedIterator for Chunks<'a> {
+    fn next_back(&mut self) -> Option<&'a str> {
+        // The last subtree is at the bottom of the stack.
+        while !self.stack.is_empty() {
+            let node = self.stack[0];
+            match node.kind {
+                NodeKind::Leaf(ref text) => {
+                    self.stack.remove(0);
+                    if !text.is_empty() {
+                        return Some(text);
+                    }
+                }
+                NodeKind::Branch(ref left, ref right) => {
+                    self.stack[0] = &**left;
+                    self.stack.insert(0, right);
+                }
+            }
+        }
+        None
+    }
+}
+
+impl<'a> FusedIterator for Chunks<'a> {}
+
+/// An iterator over the `char`s of a `Rope`.
+///
+/// This `struct` is created by the [`chars`] method on [`Rope`]. See its
+/// documentation for more.
+///
+/// [`chars`]: struct.Rope.html#method.chars
+/// [`Rope`]: struct.Rope.html
+#[derive(Clone)]
+pub struct Chars<'a> {
+    chunks: Chunks<'a>,
+    front: str::Chars<'a>,
+    back: str::Chars<'a>,
+}
+
+impl<'a> fmt::Debug for Chars<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<'a> Iterator for Chars<'a> {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        loop {
+            if let Some(c) = self.front.next() {
+                return Some(c);
+            }
+            match self.chunks.next() {
+                Some(chunk) => self.front = chunk.chars(),
+                None => return self.back.next(),
+            }
+        }
+    }
+}
+
+impl<'a> DoubleEndedIterator for Chars<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<char> {
+        loop {
+            if let Some(c) = self.back.next_back() {
+                return Some(c);
+            }
+            match self.chunks.next_back() {
+                Some(chunk) => self.back = chunk.chars(),
+                None => return self.front.next_back(),
+            }
+        }
+    }
+}
+
+impl<'a> FusedIterator for Chars<'a> {}
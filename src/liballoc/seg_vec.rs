@@ -0,0 +1,684 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A growable sequence stored in fixed-size chunks.
+//!
+//! [`SegVec`] is a vector that never moves its elements once they have been
+//! pushed. Instead of reallocating one large buffer as it grows, it
+//! allocates another chunk of the same size. Only the list of chunk
+//! pointers is ever reallocated, which is cheap. This avoids the long
+//! pauses that come with copying a very large `Vec` into a buffer twice
+//! its size, and never needs more than one chunk of memory beyond what its
+//! elements occupy.
+//!
+//! [`SegVec`]: struct.SegVec.html
+
+#![unstable(feature = "seg_vec", issue = "0")]
+
+use core::cmp;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::{FromIterator, FusedIterator};
+use core::mem;
+use core::ops::{Index, IndexMut};
+use core::slice;
+
+use vec::{self, Vec};
+
+/// The target size of a chunk, in bytes.
+const CHUNK_BYTES: usize = 64 * 1024;
+
+/// A vector of values stored in fixed-size chunks, which are never
+/// reallocated.
+///
+/// Pushing takes amortized `O(1)` time like [`Vec::push`], but without
+/// the occasional `O(n)` copy, since existing elements stay where they
+/// are. Indexing takes `O(1)` time: the chunk and the position within it
+/// are found with a shift and a mask.
+///
+/// Each chunk holds the largest power of two of elements that fits in
+/// 64 KiB, or a single element if `T` is larger than that. Unlike a `Vec`,
+/// the elements are not contiguous, so a `SegVec` can't be viewed as one
+/// slice; [`chunks`] gives access to the underlying slices instead.
+///
+/// Because elements are never moved, a raw pointer to an element stays
+/// valid until that element is popped or the `SegVec` is dropped, even
+/// across pushes.
+///
+/// [`Vec::push`]: ../../vec/struct.Vec.html#method.push
+/// [`chunks`]: #method.chunks
+///
+/// # Examples
+///
+/// ```
+/// #![feature(seg_vec)]
+/// use std::collections::seg_vec::SegVec;
+///
+/// let mut v = SegVec::new();
+/// v.push(1);
+/// v.push(2);
+/// let first: *const i32 = &v[0];
+///
+/// v.extend(3..100_000);
+/// assert_eq!(v.len(), 99_999);
+/// assert_eq!(v[50_000], 50_001);
+///
+/// // The first element hasn't moved.
+/// assert_eq!(first, &v[0] as *const i32);
+/// ```
+pub struct SegVec<T> {
+    chunks: Vec<Vec<T>>,
+    len: usize,
+}
+
+/// Returns the base-2 logarithm of the number of elements of `T` in a chunk.
+#[inline]
+fn chunk_shift<T>() -> u32 {
+    let bits = mem::size_of::<usize>() as u32 * 8;
+    match mem::size_of::<T>() {
+        // A single chunk never needs to allocate, and can hold every
+        // element there could be.
+        0 => bits - 1,
+        size => bits - 1 - cmp::max(CHUNK_BYTES / size, 1).leading_zeros(),
+    }
+}
+
+impl<T> SegVec<T> {
+    /// Creates an empty `SegVec`.
+    ///
+    /// No memory is allocated until an element is pushed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(seg_vec)]
+    /// use std::collections::seg_vec::SegVec;
+    ///
+    /// let v: SegVec<u32> = SegVec::new();
+    /// assert!(v.is_empty());
+    /// ```
+    pub fn new() -> SegVec<T> {
+        SegVec {
+            chunks: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of elements in each chunk.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(seg_vec)]
+    /// use std::collections::seg_vec::SegVec;
+    ///
+    /// assert_eq!(SegVec::<u64>::new().chunk_len(), 8192);
+    /// assert_eq!(SegVec::<[u8; 100_000]>::new().chunk_len(), 1);
+    /// ```
+    pub fn chunk_len(&self) -> usize {
+        1 << chunk_shift::<T>()
+    }
+
+    /// Returns the number of elements in the `SegVec`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(seg_vec)]
+    /// use std::collections::seg_vec::SegVec;
+    ///
+    /// let v: SegVec<_> = (0..10).collect();
+    /// assert_eq!(v.len(), 10);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the `SegVec` contains no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(seg_vec)]
+    /// use std::collections::seg_vec::SegVec;
+    ///
+    /// let mut v = SegVec::new();
+    /// assert!(v.is_empty());
+    /// v.push(1);
+    /// assert!(!v.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Appends an element to the back of the `SegVec`.
+    ///
+    /// No existing element is moved. If the last chunk is full, a new one
+    /// is allocated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(seg_vec)]
+    /// use std::collections::seg_vec::SegVec;
+    ///
+    /// let mut v = SegVec::new();
+    /// v.push(1);
+    /// v.push(2);
+    /// assert_eq!(v[1], 2);
+    /// ```
+    pub fn push(&mut self, value: T) {
+        let chunk = self.len >> chunk_shift::<T>();
+        if chunk == self.chunks.len() {
+            self.chunks.push(Vec::with_capacity(self.chunk_len()));
+        }
+        self.chunks[chunk].push(value);
+        self.len += 1;
+    }
+
+    /// Removes the last element and returns it, or `None` if the `SegVec`
+    /// is empty.
+    ///
+    /// Chunks that become empty are kept for later pushes; use
+    /// [`shrink_to_fit`] to free them.
+    ///
+    /// [`shrink_to_fit`]: #method.shrink_to_fit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(seg_vec)]
+    /// use std::collections::seg_vec::SegVec;
+    ///
+    /// let mut v: SegVec<_> = (1..4).collect();
+    /// assert_eq!(v.pop(), Some(3));
+    /// assert_eq!(v.len(), 2);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        self.chunks[self.len >> chunk_shift::<T>()].pop()
+    }
+
+    /// Returns a reference to the element at `index`, or `None` if it is
+    /// out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(seg_vec)]
+    /// use std::collections::seg_vec::SegVec;
+    ///
+    /// let v: SegVec<_> = (0..10).collect();
+    /// assert_eq!(v.get(3), Some(&3));
+    /// assert_eq!(v.get(10), None);
+    /// ```
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.len {
+            let shift = chunk_shift::<T>();
+            let chunk = unsafe { self.chunks.get_unchecked(index >> shift) };
+            Some(unsafe { chunk.get_unchecked(index & ((1 << shift) - 1)) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the element at `index`, or `None` if
+    /// it is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(seg_vec)]
+    /// use std::collections::seg_vec::SegVec;
+    ///
+    /// let mut v: SegVec<_> = (0..10).collect();
+    /// if let Some(x) = v.get_mut(3) {
+    ///     *x = 30;
+    /// }
+    /// assert_eq!(v[3], 30);
+    /// ```
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index < self.len {
+            let shift = chunk_shift::<T>();
+            let chunk = unsafe { self.chunks.get_unchecked_mut(index >> shift) };
+            Some(unsafe { chunk.get_unchecked_mut(index & ((1 << shift) - 1)) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the last element, or `None` if the `SegVec` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(seg_vec)]
+    /// use std::collections::seg_vec::SegVec;
+    ///
+    /// let mut v = SegVec::new();
+    /// assert_eq!(v.last(), None);
+    /// v.push(1);
+    /// v.push(2);
+    /// assert_eq!(v.last(), Some(&2));
+    /// ```
+    pub fn last(&self) -> Option<&T> {
+        match self.len {
+            0 => None,
+            len => self.get(len - 1),
+        }
+    }
+
+    /// Returns the elements as a sequence of slices, one per chunk.
+    ///
+    /// Every slice but the last holds exactly [`chunk_len`] elements, and
+    /// none of them is empty.
+    ///
+    /// [`chunk_len`]: #method.chunk_len
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(seg_vec)]
+    /// use std::collections::seg_vec::SegVec;
+    ///
+    /// let v: SegVec<u64> = (0..20_000).collect();
+    /// let lens: Vec<_> = v.chunks().map(|c| c.len()).collect();
+    /// assert_eq!(lens, [8192, 8192, 3616]);
+    /// let sum: u64 = v.chunks().map(|c| c.iter().sum::<u64>()).sum();
+    /// assert_eq!(sum, 19_999 * 20_000 / 2);
+    /// ```
+    pub fn chunks(&self) -> Chunks<T> {
+        Chunks { iter: self.chunks.iter() }
+    }
+
+    /// Returns an iterator over the elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(seg_vec)]
+    /// use std::collections::seg_vec::SegVec;
+    ///
+    /// let v: SegVec<_> = (1..4).collect();
+    /// let doubled: Vec<_> = v.iter().map(|x| x * 2).collect();
+    /// assert_eq!(doubled, [2, 4, 6]);
+    /// ```
+    pub fn iter(&self) -> Iter<T> {
+        Iter {
+            chunks: self.chunks.iter(),
+            front: [].iter(),
+            back: [].iter(),
+            len: self.len,
+        }
+    }
+
+    /// Returns an iterator that allows modifying each element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(seg_vec)]
+    /// use std::collections::seg_vec::SegVec;
+    ///
+    /// let mut v: SegVec<_> = (1..4).collect();
+    /// for x in v.iter_mut() {
+    ///     *x *= 10;
+    /// }
+    /// assert_eq!(v.iter().cloned().collect::<Vec<_>>(), [10, 20, 30]);
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<T> {
+        IterMut {
+            chunks: self.chunks.iter_mut(),
+            front: [].iter_mut(),
+            back: [].iter_mut(),
+            len: self.len,
+        }
+    }
+
+    /// Removes all elements, and frees all chunks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(seg_vec)]
+    /// use std::collections::seg_vec::SegVec;
+    ///
+    /// let mut v: SegVec<_> = (1..4).collect();
+    /// v.clear();
+    /// assert!(v.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.chunks.clear();
+        self.len = 0;
+    }
+
+    /// Frees the chunks left empty by [`pop`].
+    ///
+    /// [`pop`]: #method.pop
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(seg_vec)]
+    /// use std::collections::seg_vec::SegVec;
+    ///
+    /// let mut v: SegVec<u64> = (0..20_000).collect();
+    /// while v.len() > 10 {
+    ///     v.pop();
+    /// }
+    /// v.shrink_to_fit();
+    /// assert_eq!(v.chunks().count(), 1);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        let used = (self.len + self.chunk_len() - 1) >> chunk_shift::<T>();
+        self.chunks.truncate(used);
+        self.chunks.shrink_to_fit();
+    }
+}
+
+impl<T> Index<usize> for SegVec<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        self.get(index).expect("SegVec index out of bounds")
+    }
+}
+
+impl<T> IndexMut<usize> for SegVec<T> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        self.get_mut(index).expect("SegVec index out of bounds")
+    }
+}
+
+impl<T: Clone> Clone for SegVec<T> {
+    fn clone(&self) -> SegVec<T> {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T> Default for SegVec<T> {
+    /// Creates an empty `SegVec<T>`.
+    fn default() -> SegVec<T> {
+        SegVec::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SegVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for SegVec<T> {
+    fn eq(&self, other: &SegVec<T>) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for SegVec<T> {}
+
+impl<T: Hash> Hash for SegVec<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        for value in self.iter() {
+            value.hash(state);
+        }
+    }
+}
+
+impl<T> Extend<T> for SegVec<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<'a, T: 'a + Copy> Extend<&'a T> for SegVec<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().cloned());
+    }
+}
+
+impl<T> FromIterator<T> for SegVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> SegVec<T> {
+        let mut v = SegVec::new();
+        v.extend(iter);
+        v
+    }
+}
+
+impl<'a, T> IntoIterator for &'a SegVec<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut SegVec<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T> IntoIterator for SegVec<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// Creates a consuming iterator, that is, one that moves each value out
+    /// of the `SegVec`, from the first to the last.
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter {
+            chunks: self.chunks.into_iter(),
+            front: Vec::new().into_iter(),
+            back: Vec::new().into_iter(),
+            len: self.len,
+        }
+    }
+}
+
+/// An iterator over the chunks of a `SegVec`, as slices.
+///
+/// This `struct` is created by the [`chunks`] method on [`SegVec`]. See its
+/// documentation for more.
+///
+/// [`chunks`]: struct.SegVec.html#method.chunks
+/// [`SegVec`]: struct.SegVec.html
+pub struct Chunks<'a, T: 'a> {
+    iter: slice::Iter<'a, Vec<T>>,
+}
+
+// FIXME(#26925) Remove in favor of `#[derive(Clone)]`
+impl<'a, T> Clone for Chunks<'a, T> {
+    fn clone(&self) -> Chunks<'a, T> {
+        Chunks { iter: self.iter.clone() }
+    }
+}
+
+impl<'a, T: fmt::Debug> fmt::Debug for Chunks<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<'a, T> Iterator for Chunks<'a, T> {
+    type Item = &'a [T];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [T]> {
+        match self.iter.next() {
+            Some(chunk) if !chunk.is_empty() => Some(chunk),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.iter.len()))
+    }
+}
+
+impl<'a, T> FusedIterator for Chunks<'a, T> {}
+
+/// An iterator over the elements of a `SegVec`.
+///
+/// This `struct` is created by the [`iter`] method on [`SegVec`]. See its
+/// documentation for more.
+///
+/// [`iter`]: struct.SegVec.html#method.iter
+/// [`SegVec`]: struct.SegVec.html
+pub struct Iter<'a, T: 'a> {
+    chunks: slice::Iter<'a, Vec<T>>,
+    front: slice::Iter<'a, T>,
+    back: slice::Iter<'a, T>,
+    len: usize,
+}
+
+// FIXME(#26925) Remove in favor of `#[derive(Clone)]`
+impl<'a, T> Clone for Iter<'a, T> {
+    fn clone(&self) -> Iter<'a, T> {
+        Iter {
+            chunks: self.chunks.clone(),
+            front: self.front.clone(),
+            back: self.back.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<'a, T: fmt::Debug> fmt::Debug for Iter<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+/// Implements `Iterator` and `DoubleEndedIterator` for an iterator with
+/// `chunks`, `front`, `back` and `len` fields, where `front` and `back` are
+/// the partly consumed chunks at either end.
+macro_rules! chunked_iterator {
+    (impl[$($params:tt)*] $ty:ty => $item:ty, $into_iter:ident) => {
+        impl<$($params)*> Iterator for $ty {
+            type Item = $item;
+
+            #[inline]
+            fn next(&mut self) -> Option<$item> {
+                loop {
+                    if let Some(value) = self.front.next() {
+                        self.len -= 1;
+                        return Some(value);
+                    }
+                    match self.chunks.next() {
+                        Some(chunk) => self.front = chunk.$into_iter(),
+                        None => {
+                            let value = self.back.next();
+                            if value.is_some() {
+                                self.len -= 1;
+                            }
+                            return value;
+                        }
+                    }
+                }
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.len, Some(self.len))
+            }
+        }
+
+        impl<$($params)*> DoubleEndedIterator for $ty {
+            #[inline]
+            fn next_back(&mut self) -> Option<$item> {
+                loop {
+                    if let Some(value) = self.back.next_back() {
+                        self.len -= 1;
+                        return Some(value);
+                    }
+                    match self.chunks.next_back() {
+                        Some(chunk) => self.back = chunk.$into_iter(),
+                        None => {
+                            let value = self.front.next_back();
+                            if value.is_some() {
+                                self.len -= 1;
+                            }
+                            return value;
+                        }
+                    }
+                }
+            }
+        }
+
+        impl<$($params)*> ExactSizeIterator for $ty {}
+
+        impl<$($params)*> FusedIterator for $ty {}
+    }
+}
+
+chunked_iterator!(impl['a, T] Iter<'a, T> => &'a T, iter);
+
+/// A mutable iterator over the elements of a `SegVec`.
+///
+/// This `struct` is created by the [`iter_mut`] method on [`SegVec`]. See its
+/// documentation for more.
+///
+/// [`iter_mut`]: struct.SegVec.html#method.iter_mut
+/// [`SegVec`]: struct.SegVec.html
+pub struct IterMut<'a, T: 'a> {
+    chunks: slice::IterMut<'a, Vec<T>>,
+    front: slice::IterMut<'a, T>,
+    back: slice::IterMut<'a, T>,
+    len: usize,
+}
+
+impl<'a, T: fmt::Debug> fmt::Debug for IterMut<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("IterMut")
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+chunked_iterator!(impl['a, T] IterMut<'a, T> => &'a mut T, iter_mut);
+
+/// An owning iterator over the elements of a `SegVec`.
+///
+/// This `struct` is created by the [`into_iter`] method on [`SegVec`]
+/// (provided by the `IntoIterator` trait). See its documentation for more.
+///
+/// [`into_iter`]: struct.SegVec.html#method.into_iter
+/// [`SegVec`]: struct.SegVec.html
+pub struct IntoIter<T> {
+    chunks: vec::IntoIter<Vec<T>>,
+    front: vec::IntoIter<T>,
+    back: vec::IntoIter<T>,
+    len: usize,
+}
+
+impl<T: fmt::Debug> fmt::Debug for IntoIter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("IntoIter")
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+chunked_iterator!(impl[T] IntoIter<T> => T, into_iter);
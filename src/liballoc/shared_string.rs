@@ -0,0 +1,306 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! An immutable string whose substrings share its buffer.
+//!
+//! Splitting a document into thousands of tokens leaves a choice between
+//! copying every token into a `String` of its own and borrowing `&str`s
+//! from the document, which ties every structure that holds a token to the
+//! document's lifetime. A [`SharedString`] keeps its text in a reference
+//! counted buffer, so taking a substring of it only bumps a count, and the
+//! substring owns its text like a `String` would.
+//!
+//! Text is built up in a `String` as usual and then converted into a
+//! `SharedString`, which copies it into a shared buffer once.
+//!
+//! [`SharedString`]: struct.SharedString.html
+
+#![unstable(feature = "shared_string", issue = "0")]
+
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Bound::{Excluded, Included, Unbounded};
+use core::ops::{Deref, RangeBounds};
+
+use arc::Arc;
+use string::String;
+
+/// An immutable, reference counted string that can be sliced without
+/// copying.
+///
+/// A `SharedString` is a view of a range of a shared buffer. Cloning it,
+/// or taking a substring with [`slice`] or [`slice_ref`], takes `O(1)` time
+/// and returns a new view of the same buffer. The buffer is freed when the
+/// last view of it is dropped, so a short substring can keep a long text
+/// alive; [`to_owned_if_small`] copies such a substring out of its buffer.
+///
+/// `SharedString` dereferences to `str`, and compares and hashes like the
+/// `str` it views.
+///
+/// [`slice`]: #method.slice
+/// [`slice_ref`]: #method.slice_ref
+/// [`to_owned_if_small`]: #method.to_owned_if_small
+///
+/// # Examples
+///
+/// ```
+/// #![feature(shared_string)]
+/// use std::collections::shared_string::SharedString;
+///
+/// let text = SharedString::from(String::from("one two three"));
+/// let words: Vec<SharedString> = text.split(' ').map(|word| text.slice_ref(word)).collect();
+/// assert_eq!(words, ["one", "two", "three"]);
+///
+/// // The words share `text`'s buffer, and outlive the `text` handle.
+/// drop(text);
+/// assert_eq!(words[1].slice(1..), "wo");
+/// ```
+#[derive(Clone)]
+pub struct SharedString {
+    buf: Arc<str>,
+    start: usize,
+    end: usize,
+}
+
+impl SharedString {
+    /// Creates an empty `SharedString`.
+    pub fn new() -> SharedString {
+        SharedString::from("")
+    }
+
+    /// Extracts a string slice containing the entire `SharedString`.
+    pub fn as_str(&self) -> &str {
+        &self.buf[self.start..self.end]
+    }
+
+    /// Returns the substring in the given byte range, sharing `self`'s
+    /// buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point, if the
+    /// end point is greater than the length of the string, or if either
+    /// point does not lie on a `char` boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(shared_string)]
+    /// use std::collections::shared_string::SharedString;
+    ///
+    /// let text = SharedString::from("α is alpha");
+    /// assert_eq!(text.slice(..2), "α");
+    /// assert_eq!(text.slice(6..).slice(..3), "alp");
+    /// ```
+    pub fn slice<R>(&self, range: R) -> SharedString
+        where R: RangeBounds<usize>
+    {
+        let start = match range.start_bound() {
+            Included(&n) => n,
+            Excluded(&n) => n + 1,
+            Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Included(&n) => n + 1,
+            Excluded(&n) => n,
+            Unbounded => self.len(),
+        };
+        // Let `str` check the bounds and `char` boundaries.
+        let _ = &self.as_str()[start..end];
+        SharedString {
+            buf: self.buf.clone(),
+            start: self.start + start,
+            end: self.start + end,
+        }
+    }
+
+    /// Returns the substring that `sub` is a slice of, sharing `self`'s
+    /// buffer.
+    ///
+    /// This turns the `&str`s produced by `str`'s methods, such as `split`
+    /// or `lines`, into `SharedString`s without copying their text.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sub` is not a slice of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(shared_string)]
+    /// use std::collections::shared_string::SharedString;
+    ///
+    /// let text = SharedString::from("key = value");
+    /// let value = text.slice_ref(text.split(" = ").nth(1).unwrap());
+    /// assert_eq!(value, "value");
+    /// ```
+    pub fn slice_ref(&self, sub: &str) -> SharedString {
+        let base = self.as_ptr() as usize;
+        let start = (sub.as_ptr() as usize).wrapping_sub(base);
+        assert!(start <= self.len() && sub.len() <= self.len() - start,
+                "substring is not a slice of the SharedString");
+        SharedString {
+            buf: self.buf.clone(),
+            start: self.start + start,
+            end: self.start + start + sub.len(),
+        }
+    }
+
+    /// Returns a copy of `self` in a buffer of its own if `self` only views
+    /// a small part of its buffer, and a clone of `self` otherwise.
+    ///
+    /// A string counts as small if it is less than a quarter of the length
+    /// of its buffer. Keeping such a string around keeps the whole buffer
+    /// alive, so this is worth calling before storing a substring that will
+    /// outlive the text it was taken from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(shared_string)]
+    /// use std::collections::shared_string::SharedString;
+    ///
+    /// let text = SharedString::from("a word in a long document");
+    /// let word = text.slice(2..6).to_owned_if_small();
+    /// assert_eq!(word, "word");
+    /// assert!(!word.shares_buffer_with(&text));
+    ///
+    /// let most = text.slice(2..).to_owned_if_small();
+    /// assert!(most.shares_buffer_with(&text));
+    /// ```
+    pub fn to_owned_if_small(&self) -> SharedString {
+        if self.len() < self.buf.len() / 4 {
+            SharedString::from(self.as_str())
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Returns `true` if `self` and `other` are views of the same buffer.
+    pub fn shares_buffer_with(&self, other: &SharedString) -> bool {
+        Arc::ptr_eq(&self.buf, &other.buf)
+    }
+}
+
+impl Deref for SharedString {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for SharedString {
+    fn as_ref(&self) -> &str {
+        self
+    }
+}
+
+impl Borrow<str> for SharedString {
+    fn borrow(&self) -> &str {
+        self
+    }
+}
+
+impl Default for SharedString {
+    /// Creates an empty `SharedString`.
+    fn default() -> SharedString {
+        SharedString::new()
+    }
+}
+
+impl<'a> From<&'a str> for SharedString {
+    fn from(text: &'a str) -> SharedString {
+        SharedString::from(Arc::<str>::from(text))
+    }
+}
+
+impl From<String> for SharedString {
+    fn from(text: String) -> SharedString {
+        SharedString::from(Arc::<str>::from(text))
+    }
+}
+
+impl From<Arc<str>> for SharedString {
+    fn from(buf: Arc<str>) -> SharedString {
+        let end = buf.len();
+        SharedString { buf, start: 0, end }
+    }
+}
+
+impl<'a> From<&'a SharedString> for String {
+    fn from(text: &'a SharedString) -> String {
+        String::from(text.as_str())
+    }
+}
+
+impl From<SharedString> for String {
+    fn from(text: SharedString) -> String {
+        String::from(&text)
+    }
+}
+
+impl fmt::Display for SharedString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Debug for SharedString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl Hash for SharedString {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl PartialEq for SharedString {
+    fn eq(&self, other: &SharedString) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for SharedString {}
+
+impl PartialOrd for SharedString {
+    fn partial_cmp(&self, other: &SharedString) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SharedString {
+    fn cmp(&self, other: &SharedString) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl PartialEq<str> for SharedString {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<'a> PartialEq<&'a str> for SharedString {
+    fn eq(&self, other: &&'a str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<String> for SharedString {
+    fn eq(&self, other: &String) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
@@ -97,7 +97,10 @@
 // It's cleaner to just turn off the unused_imports warning than to fix them.
 #![cfg_attr(test, allow(unused_imports, dead_code))]
 
+use core::array::FixedSizeArray;
+use core::ascii::CharClass;
 use core::cmp::Ordering::{self, Less};
+use core::iter::FusedIterator;
 use core::mem::size_of;
 use core::mem;
 use core::ptr;
@@ -124,8 +127,14 @@ pub use core::slice::{from_raw_parts, from_raw_parts_mut};
 pub use core::slice::{from_ref, from_ref_mut};
 #[unstable(feature = "slice_get_slice", issue = "35729")]
 pub use core::slice::SliceIndex;
+#[unstable(feature = "get_many_mut", issue = "0")]
+pub use core::slice::DisjointIndices;
 #[unstable(feature = "exact_chunks", issue = "47115")]
 pub use core::slice::{ExactChunks, ExactChunksMut};
+#[unstable(feature = "slice_group_by", issue = "0")]
+pub use core::slice::{GroupBy, GroupByMut};
+#[unstable(feature = "slice_memchr", issue = "0")]
+pub use core::slice::{memchr, memchr2, memrchr};
 
 ////////////////////////////////////////////////////////////////////////////////
 // Basic slice extension methods
@@ -947,6 +956,80 @@ impl<T> [T] {
         core_slice::SliceExt::rsplit_mut(self, pred)
     }
 
+    /// Returns an iterator over the slice producing non-overlapping runs
+    /// of elements using the predicate to separate them.
+    ///
+    /// The predicate is called on two elements following themselves,
+    /// it means the predicate is called on `slice[0]` and `slice[1]`
+    /// then on `slice[1]` and `slice[2]` and so on. A new run starts
+    /// wherever the predicate returns `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_group_by)]
+    ///
+    /// let slice = &[1, 1, 1, 3, 3, 2, 2, 2];
+    ///
+    /// let mut iter = slice.group_by(|a, b| a == b);
+    ///
+    /// assert_eq!(iter.next(), Some(&[1, 1, 1][..]));
+    /// assert_eq!(iter.next(), Some(&[3, 3][..]));
+    /// assert_eq!(iter.next(), Some(&[2, 2, 2][..]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// This method can be used to extract the sorted subslices:
+    ///
+    /// ```
+    /// #![feature(slice_group_by)]
+    ///
+    /// let slice = &[1, 1, 2, 3, 2, 3, 2, 3, 4];
+    ///
+    /// let mut iter = slice.group_by(|a, b| a <= b);
+    ///
+    /// assert_eq!(iter.next(), Some(&[1, 1, 2, 3][..]));
+    /// assert_eq!(iter.next(), Some(&[2, 3][..]));
+    /// assert_eq!(iter.next(), Some(&[2, 3, 4][..]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[unstable(feature = "slice_group_by", issue = "0")]
+    #[inline]
+    pub fn group_by<F>(&self, pred: F) -> GroupBy<T, F>
+        where F: FnMut(&T, &T) -> bool
+    {
+        core_slice::SliceExt::group_by(self, pred)
+    }
+
+    /// Returns an iterator over the slice producing non-overlapping mutable
+    /// runs of elements using the predicate to separate them.
+    ///
+    /// The predicate is called on two elements following themselves,
+    /// it means the predicate is called on `slice[0]` and `slice[1]`
+    /// then on `slice[1]` and `slice[2]` and so on. A new run starts
+    /// wherever the predicate returns `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_group_by)]
+    ///
+    /// let slice = &mut [1, 1, 1, 3, 3, 2, 2, 2];
+    ///
+    /// for run in slice.group_by_mut(|a, b| a == b) {
+    ///     let len = run.len();
+    ///     run[0] *= len;
+    /// }
+    /// assert_eq!(slice, &[3, 1, 1, 6, 3, 6, 2, 2]);
+    /// ```
+    #[unstable(feature = "slice_group_by", issue = "0")]
+    #[inline]
+    pub fn group_by_mut<F>(&mut self, pred: F) -> GroupByMut<T, F>
+        where F: FnMut(&T, &T) -> bool
+    {
+        core_slice::SliceExt::group_by_mut(self, pred)
+    }
+
     /// Returns an iterator over subslices separated by elements that match
     /// `pred`, limited to returning at most `n` items. The matched element is
     /// not contained in the subslices.
@@ -1230,6 +1313,471 @@ impl<T> [T] {
         core_slice::SliceExt::binary_search_by_key(self, b, f)
     }
 
+    /// Returns the index of the partition point according to the given
+    /// predicate (the index of the first element of the second partition).
+    ///
+    /// The slice is assumed to be partitioned according to the given predicate.
+    /// This means that all elements for which the predicate returns true are at
+    /// the start of the slice and all elements for which the predicate returns
+    /// false are at the end. For example, `[7, 15, 3, 5, 4, 12, 6]` is
+    /// partitioned under the predicate `x % 2 != 0` (all odd numbers are at
+    /// the start, all even at the end).
+    ///
+    /// If this slice is not partitioned, the returned result is unspecified
+    /// and meaningless, as this method performs a kind of binary search.
+    ///
+    /// See also [`binary_search`], [`binary_search_by`], and
+    /// [`binary_search_by_key`].
+    ///
+    /// [`binary_search`]: #method.binary_search
+    /// [`binary_search_by`]: #method.binary_search_by
+    /// [`binary_search_by_key`]: #method.binary_search_by_key
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_partition_point)]
+    ///
+    /// let v = [1, 2, 3, 3, 5, 6, 7];
+    /// let i = v.partition_point(|&x| x < 5);
+    ///
+    /// assert_eq!(i, 4);
+    /// assert!(v[..i].iter().all(|&x| x < 5));
+    /// assert!(v[i..].iter().all(|&x| !(x < 5)));
+    /// ```
+    #[unstable(feature = "slice_partition_point", issue = "0")]
+    #[inline]
+    pub fn partition_point<P>(&self, pred: P) -> usize
+        where P: FnMut(&T) -> bool
+    {
+        core_slice::SliceExt::partition_point(self, pred)
+    }
+
+    /// Checks if the elements of this slice are sorted.
+    ///
+    /// That is, for each element `a` and its following element `b`, `a <= b`
+    /// must hold. If the slice yields exactly zero or one element, `true` is
+    /// returned.
+    ///
+    /// Note that if `T` is only `PartialOrd`, but not `Ord`, the above
+    /// definition implies that this function returns `false` if any two
+    /// consecutive items are not comparable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(is_sorted)]
+    /// let empty: [i32; 0] = [];
+    ///
+    /// assert!([1, 2, 2, 9].is_sorted());
+    /// assert!(![1, 3, 2, 4].is_sorted());
+    /// assert!([0].is_sorted());
+    /// assert!(empty.is_sorted());
+    /// assert!(![0.0, 1.0, std::f32::NAN].is_sorted());
+    /// ```
+    #[unstable(feature = "is_sorted", issue = "0")]
+    #[inline]
+    pub fn is_sorted(&self) -> bool
+        where T: PartialOrd
+    {
+        core_slice::SliceExt::is_sorted(self)
+    }
+
+    /// Returns references to the minimum and the maximum element of the
+    /// slice, or `None` if it is empty.
+    ///
+    /// This finds both in a single pass, using about `3n / 2` comparisons
+    /// rather than the `2n` of separate calls to `iter().min()` and
+    /// `iter().max()`.
+    ///
+    /// If several elements are equally minimum, the first one is returned.
+    /// If several elements are equally maximum, the last one is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_min_max)]
+    ///
+    /// let v = [3, 1, 4, 1, 5, 9, 2, 6];
+    /// assert_eq!(v.min_max(), Some((&1, &9)));
+    ///
+    /// let empty: [i32; 0] = [];
+    /// assert_eq!(empty.min_max(), None);
+    /// ```
+    #[unstable(feature = "slice_min_max", issue = "0")]
+    #[inline]
+    pub fn min_max(&self) -> Option<(&T, &T)>
+        where T: Ord
+    {
+        core_slice::SliceExt::min_max(self)
+    }
+
+    /// Rearranges the elements into the next permutation in lexicographic
+    /// order, returning `true` if there is one.
+    ///
+    /// If the slice is already at its last permutation (its elements are in
+    /// descending order), it is left unchanged and `false` is returned.
+    /// Equal elements are treated as indistinguishable, so starting from a
+    /// sorted slice every distinct permutation is visited exactly once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_permutations)]
+    ///
+    /// let mut v = [1, 2, 3];
+    /// assert!(v.next_permutation());
+    /// assert_eq!(v, [1, 3, 2]);
+    /// assert!(v.next_permutation());
+    /// assert_eq!(v, [2, 1, 3]);
+    ///
+    /// let mut v = [3, 2, 1];
+    /// assert!(!v.next_permutation());
+    /// assert_eq!(v, [3, 2, 1]);
+    /// ```
+    ///
+    /// Visiting every distinct permutation:
+    ///
+    /// ```
+    /// #![feature(slice_permutations)]
+    ///
+    /// let mut v = ['a', 'a', 'b'];
+    /// let mut seen = vec![v];
+    /// while v.next_permutation() {
+    ///     seen.push(v);
+    /// }
+    /// assert_eq!(seen, [['a', 'a', 'b'], ['a', 'b', 'a'], ['b', 'a', 'a']]);
+    /// ```
+    #[unstable(feature = "slice_permutations", issue = "0")]
+    #[inline]
+    pub fn next_permutation(&mut self) -> bool
+        where T: Ord
+    {
+        core_slice::SliceExt::next_permutation(self)
+    }
+
+    /// Rearranges the elements into the previous permutation in
+    /// lexicographic order, returning `true` if there is one.
+    ///
+    /// If the slice is already at its first permutation (its elements are in
+    /// ascending order), it is left unchanged and `false` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_permutations)]
+    ///
+    /// let mut v = [2, 1, 3];
+    /// assert!(v.prev_permutation());
+    /// assert_eq!(v, [1, 3, 2]);
+    /// assert!(v.prev_permutation());
+    /// assert_eq!(v, [1, 2, 3]);
+    /// assert!(!v.prev_permutation());
+    /// assert_eq!(v, [1, 2, 3]);
+    /// ```
+    #[unstable(feature = "slice_permutations", issue = "0")]
+    #[inline]
+    pub fn prev_permutation(&mut self) -> bool
+        where T: Ord
+    {
+        core_slice::SliceExt::prev_permutation(self)
+    }
+
+    /// Transmute the slice to a slice of another type, ensuring alignment of
+    /// the types is maintained.
+    ///
+    /// This method splits the slice into three distinct slices: a prefix, a
+    /// correctly aligned middle slice of the new type, and a suffix. The
+    /// middle slice is made as long as possible for the given input; the
+    /// prefix and suffix hold the elements that don't fit into it.
+    ///
+    /// If either `T` or `U` is zero-sized, or the slice can't be aligned for
+    /// `U` without splitting an element of `T`, the whole slice is returned
+    /// as the prefix.
+    ///
+    /// # Safety
+    ///
+    /// This method is essentially a `transmute` with respect to the elements
+    /// in the returned middle slice, so all the usual caveats pertaining to
+    /// `transmute::<T, U>` also apply here.
+    ///
+    /// # Examples
+    ///
+    /// Counting the set bits of a byte buffer a word at a time:
+    ///
+    /// ```
+    /// #![feature(slice_align_to)]
+    ///
+    /// let bytes = [0b1011u8; 67];
+    /// let (prefix, words, suffix) = unsafe { bytes.align_to::<u32>() };
+    /// assert!(prefix.len() < 4 && suffix.len() < 4);
+    /// assert_eq!(prefix.len() + words.len() * 4 + suffix.len(), 67);
+    ///
+    /// let ones = prefix.iter().chain(suffix).map(|b| b.count_ones()).sum::<u32>() +
+    ///            words.iter().map(|w| w.count_ones()).sum::<u32>();
+    /// assert_eq!(ones, 3 * 67);
+    /// ```
+    #[unstable(feature = "slice_align_to", issue = "0")]
+    #[inline]
+    pub unsafe fn align_to<U>(&self) -> (&[T], &[U], &[T]) {
+        core_slice::SliceExt::align_to(self)
+    }
+
+    /// Transmute the mutable slice to a mutable slice of another type,
+    /// ensuring alignment of the types is maintained.
+    ///
+    /// This is the mutable version of [`align_to`]; see its documentation
+    /// for how the slice is split.
+    ///
+    /// [`align_to`]: #method.align_to
+    ///
+    /// # Safety
+    ///
+    /// This method is essentially a `transmute` with respect to the elements
+    /// in the returned middle slice, so all the usual caveats pertaining to
+    /// `transmute::<T, U>` also apply here.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_align_to)]
+    ///
+    /// let mut bytes = [0u8; 16];
+    /// {
+    ///     let (prefix, words, suffix) = unsafe { bytes.align_to_mut::<u32>() };
+    ///     for b in prefix.iter_mut().chain(suffix) {
+    ///         *b = 0xff;
+    ///     }
+    ///     for w in words {
+    ///         *w = !0;
+    ///     }
+    /// }
+    /// assert_eq!(bytes, [0xff; 16]);
+    /// ```
+    #[unstable(feature = "slice_align_to", issue = "0")]
+    #[inline]
+    pub unsafe fn align_to_mut<U>(&mut self) -> (&mut [T], &mut [U], &mut [T]) {
+        core_slice::SliceExt::align_to_mut(self)
+    }
+
+    /// Views the slice as a slice of `N`-element arrays, starting at the
+    /// beginning of the slice, plus a remainder of fewer than `N` elements.
+    ///
+    /// The array type `A` is `[T; N]`, and is usually given explicitly, as
+    /// in `as_chunks::<[T; 4]>()`. No elements are copied.
+    ///
+    /// See [`as_chunks_mut`] for a mutable version, and [`exact_chunks`] for
+    /// an iterator over subslices of a length only known at runtime.
+    ///
+    /// [`as_chunks_mut`]: #method.as_chunks_mut
+    /// [`exact_chunks`]: #method.exact_chunks
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_as_chunks)]
+    ///
+    /// let bytes = [1, 2, 3, 4, 5, 6, 7];
+    /// let (chunks, rest) = bytes.as_chunks::<[u8; 3]>();
+    /// assert_eq!(chunks, [[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(rest, [7]);
+    ///
+    /// let pixels = [0xffu8, 0x80, 0x00, 0x10, 0x20, 0x30];
+    /// let rgb: Vec<u32> = pixels.as_chunks::<[u8; 3]>().0.iter()
+    ///     .map(|&[r, g, b]| (r as u32) << 16 | (g as u32) << 8 | b as u32)
+    ///     .collect();
+    /// assert_eq!(rgb, [0xff8000, 0x102030]);
+    /// ```
+    #[unstable(feature = "slice_as_chunks", issue = "0")]
+    #[inline]
+    pub fn as_chunks<A>(&self) -> (&[A], &[T])
+        where A: FixedSizeArray<T>
+    {
+        core_slice::SliceExt::as_chunks(self)
+    }
+
+    /// Views the slice as a mutable slice of `N`-element arrays, starting at
+    /// the beginning of the slice, plus a remainder of fewer than `N`
+    /// elements.
+    ///
+    /// This is the mutable version of [`as_chunks`].
+    ///
+    /// [`as_chunks`]: #method.as_chunks
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_as_chunks)]
+    ///
+    /// let mut v = [1, 2, 3, 4, 5];
+    /// {
+    ///     let (pairs, rest) = v.as_chunks_mut::<[i32; 2]>();
+    ///     for pair in pairs {
+    ///         pair.swap(0, 1);
+    ///     }
+    ///     rest[0] = 0;
+    /// }
+    /// assert_eq!(v, [2, 1, 4, 3, 0]);
+    /// ```
+    #[unstable(feature = "slice_as_chunks", issue = "0")]
+    #[inline]
+    pub fn as_chunks_mut<A>(&mut self) -> (&mut [A], &mut [T])
+        where A: FixedSizeArray<T>
+    {
+        core_slice::SliceExt::as_chunks_mut(self)
+    }
+
+    /// Views the slice as an `N`-element array, or returns `None` if its
+    /// length isn't exactly `N`.
+    ///
+    /// The array type `A` is `[T; N]`, and is usually given explicitly, as
+    /// in `as_array::<[T; 4]>()`. The length is checked once, so indexing
+    /// into the array afterwards needs no further bounds checks.
+    ///
+    /// See [`to_array`] for a version that copies the elements out.
+    ///
+    /// [`to_array`]: #method.to_array
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_as_array)]
+    ///
+    /// let packet = [0x12, 0x34, 0x00, 0x08, 0xff, 0xff];
+    /// let header = packet[..4].as_array::<[u8; 4]>().unwrap();
+    /// assert_eq!(header[0], 0x12);
+    /// assert_eq!(header, &[0x12, 0x34, 0x00, 0x08]);
+    ///
+    /// assert!(packet.as_array::<[u8; 4]>().is_none());
+    /// ```
+    #[unstable(feature = "slice_as_array", issue = "0")]
+    #[inline]
+    pub fn as_array<A>(&self) -> Option<&A>
+        where A: FixedSizeArray<T>
+    {
+        core_slice::SliceExt::as_array(self)
+    }
+
+    /// Copies the slice into an `N`-element array, or returns `None` if its
+    /// length isn't exactly `N`.
+    ///
+    /// The array type `A` is `[T; N]`, and is usually given explicitly or
+    /// inferred from how the result is used.
+    ///
+    /// See [`as_array`] for a version that borrows the elements instead.
+    ///
+    /// [`as_array`]: #method.as_array
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_as_array)]
+    ///
+    /// let packet = [0x00, 0x00, 0x01, 0x02, 0xff];
+    /// let word: [u8; 4] = packet[..4].to_array().unwrap();
+    /// assert_eq!(word, [0, 0, 1, 2]);
+    ///
+    /// assert_eq!(packet[..3].to_array::<[u8; 4]>(), None);
+    /// ```
+    #[unstable(feature = "slice_as_array", issue = "0")]
+    #[inline]
+    pub fn to_array<A>(&self) -> Option<A>
+        where A: FixedSizeArray<T> + Copy
+    {
+        core_slice::SliceExt::to_array(self)
+    }
+
+    /// Returns mutable references to several elements at once, or `None` if
+    /// any of the indices is out of bounds or two of them are equal.
+    ///
+    /// The indices are given as an array of up to eight `usize`s, and the
+    /// elements come back as an array of the same length in the same order.
+    /// Checking that they are distinct takes time quadratic in their number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(get_many_mut)]
+    ///
+    /// let mut v = vec![1, 2, 3, 4];
+    /// {
+    ///     let [a, b] = v.get_many_mut([0, 3]).unwrap();
+    ///     ::std::mem::swap(a, b);
+    /// }
+    /// assert_eq!(v, [4, 2, 3, 1]);
+    ///
+    /// assert!(v.get_many_mut([1, 1]).is_none());
+    /// assert!(v.get_many_mut([1, 4]).is_none());
+    /// ```
+    #[unstable(feature = "get_many_mut", issue = "0")]
+    #[inline]
+    pub fn get_many_mut<'a, I>(&'a mut self, indices: I) -> Option<I::Output>
+        where I: DisjointIndices<'a, T>
+    {
+        core_slice::SliceExt::get_many_mut(self, indices)
+    }
+
+    /// Checks if the elements of this slice are sorted using the given
+    /// comparator function.
+    ///
+    /// Instead of using `PartialOrd::partial_cmp`, this function uses the
+    /// given `compare` function to determine the ordering of two elements.
+    /// Apart from that, it's equivalent to [`is_sorted`]; see its
+    /// documentation for more information.
+    ///
+    /// [`is_sorted`]: #method.is_sorted
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(is_sorted)]
+    ///
+    /// assert!([4, 2, 2, 1].is_sorted_by(|a, b| Some(b.cmp(a))));
+    /// assert!(![1, 2, 2, 4].is_sorted_by(|a, b| Some(b.cmp(a))));
+    /// ```
+    #[unstable(feature = "is_sorted", issue = "0")]
+    #[inline]
+    pub fn is_sorted_by<F>(&self, compare: F) -> bool
+        where F: FnMut(&T, &T) -> Option<Ordering>
+    {
+        core_slice::SliceExt::is_sorted_by(self, compare)
+    }
+
+    /// Checks if the elements of this slice are sorted using the given key
+    /// extraction function.
+    ///
+    /// Instead of comparing the slice's elements directly, this function
+    /// compares the keys of the elements, as determined by `f`. Apart from
+    /// that, it's equivalent to [`is_sorted`]; see its documentation for more
+    /// information.
+    ///
+    /// [`is_sorted`]: #method.is_sorted
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(is_sorted)]
+    ///
+    /// assert!(["c", "bb", "aaa"].is_sorted_by_key(|s| s.len()));
+    /// assert!(![-2i32, -1, 0, 3].is_sorted_by_key(|n| n.abs()));
+    /// ```
+    #[unstable(feature = "is_sorted", issue = "0")]
+    #[inline]
+    pub fn is_sorted_by_key<K, F>(&self, f: F) -> bool
+        where F: FnMut(&T) -> K,
+              K: PartialOrd
+    {
+        core_slice::SliceExt::is_sorted_by_key(self, f)
+    }
+
     /// Sorts the slice.
     ///
     /// This sort is stable (i.e. does not reorder equal elements) and `O(n log n)` worst-case.
@@ -1383,13 +1931,18 @@ impl<T> [T] {
                 // stable with respect to the original slice. We use `sort_unstable` here because
                 // it requires less memory allocation.
                 indices.sort_unstable();
+                // Every index stored in `indices` is below its length, which is the length of
+                // the slice, so none of the lookups in this loop can go out of bounds.
                 for i in 0..$slice.len() {
-                    let mut index = indices[i].1;
-                    while (index as usize) < i {
-                        index = indices[index as usize].1;
+                    unsafe {
+                        let mut index = indices.get_unchecked(i).1;
+                        while (index as usize) < i {
+                            index = indices.get_unchecked(index as usize).1;
+                        }
+                        indices.get_unchecked_mut(i).1 = index;
+                        let base = $slice.as_mut_ptr();
+                        ptr::swap(base.offset(i as isize), base.offset(index as isize));
                     }
-                    indices[i].1 = index;
-                    $slice.swap(i, index as usize);
                 }
             })
         }
@@ -1510,6 +2063,115 @@ impl<T> [T] {
         core_slice::SliceExt::sort_unstable_by_key(self, f);
     }
 
+    /// Reorders the slice such that the element at `index` is at its final sorted position.
+    ///
+    /// After reordering, every element before `index` is less than or equal to the element at
+    /// `index`, and every element after it is greater than or equal to it. The two sides are not
+    /// otherwise ordered. Returns the part before `index`, the element at `index`, and the part
+    /// after it.
+    ///
+    /// This reordering is unstable (i.e. may reorder equal elements), in-place (i.e. does not
+    /// allocate), and `O(n)` on average. The worst case is `O(n log n)`.
+    ///
+    /// # Current implementation
+    ///
+    /// The current algorithm is a quickselect built on the same partitioning and pivot selection
+    /// as [`sort_unstable`](#method.sort_unstable). It falls back to heapsort if too many
+    /// pivot choices turn out badly.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `index >= len()`, meaning it always panics on empty slices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_select_nth_unstable)]
+    ///
+    /// let mut v = [-5i32, 4, 1, -3, 2];
+    ///
+    /// // Find the median.
+    /// let (lesser, median, greater) = v.select_nth_unstable(2);
+    /// assert_eq!(*median, 1);
+    /// assert!(lesser.iter().all(|&x| x <= 1));
+    /// assert!(greater.iter().all(|&x| x >= 1));
+    /// ```
+    #[unstable(feature = "slice_select_nth_unstable", issue = "0")]
+    #[inline]
+    pub fn select_nth_unstable(&mut self, index: usize) -> (&mut [T], &mut T, &mut [T])
+        where T: Ord
+    {
+        core_slice::SliceExt::select_nth_unstable(self, index)
+    }
+
+    /// Reorders the slice with a comparator function such that the element at `index` is at its
+    /// final sorted position.
+    ///
+    /// After reordering, every element before `index` compares less than or equal to the element
+    /// at `index`, and every element after it compares greater than or equal to it. Returns the
+    /// part before `index`, the element at `index`, and the part after it.
+    ///
+    /// This reordering is unstable (i.e. may reorder equal elements), in-place (i.e. does not
+    /// allocate), and `O(n)` on average. The worst case is `O(n log n)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `index >= len()`, meaning it always panics on empty slices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_select_nth_unstable)]
+    ///
+    /// let mut v = [-5i32, 4, 1, -3, 2];
+    ///
+    /// // Find the second largest element.
+    /// let (_, second, _) = v.select_nth_unstable_by(1, |a, b| b.cmp(a));
+    /// assert_eq!(*second, 2);
+    /// ```
+    #[unstable(feature = "slice_select_nth_unstable", issue = "0")]
+    #[inline]
+    pub fn select_nth_unstable_by<F>(&mut self, index: usize, compare: F)
+        -> (&mut [T], &mut T, &mut [T])
+        where F: FnMut(&T, &T) -> Ordering
+    {
+        core_slice::SliceExt::select_nth_unstable_by(self, index, compare)
+    }
+
+    /// Reorders the slice with a key extraction function such that the element at `index` is at
+    /// its final sorted position.
+    ///
+    /// After reordering, every element before `index` has a key less than or equal to the key of
+    /// the element at `index`, and every element after it has a key greater than or equal to it.
+    /// Returns the part before `index`, the element at `index`, and the part after it.
+    ///
+    /// This reordering is unstable (i.e. may reorder equal elements), in-place (i.e. does not
+    /// allocate), and `O(m n)` on average, where the key function is `O(m)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `index >= len()`, meaning it always panics on empty slices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_select_nth_unstable)]
+    ///
+    /// let mut v = [-5i32, 4, 1, -3, 2];
+    ///
+    /// // Find the element with the smallest absolute value.
+    /// let (_, smallest, _) = v.select_nth_unstable_by_key(0, |k| k.abs());
+    /// assert_eq!(*smallest, 1);
+    /// ```
+    #[unstable(feature = "slice_select_nth_unstable", issue = "0")]
+    #[inline]
+    pub fn select_nth_unstable_by_key<K, F>(&mut self, index: usize, f: F)
+        -> (&mut [T], &mut T, &mut [T])
+        where F: FnMut(&T) -> K, K: Ord
+    {
+        core_slice::SliceExt::select_nth_unstable_by_key(self, index, f)
+    }
+
     /// Rotates the slice in-place such that the first `mid` elements of the
     /// slice move to the end while the last `self.len() - mid` elements move to
     /// the front. After calling `rotate_left`, the element previously at index
@@ -1882,8 +2544,275 @@ impl [u8] {
             byte.make_ascii_lowercase();
         }
     }
+
+    /// Returns an iterator over subslices separated by the byte `sep`.
+    ///
+    /// This yields the same subslices as `split(|&b| b == sep)`, but
+    /// searches for the separator with `memchr`, a word at a time, which
+    /// is several times faster on long runs between separators.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_split_byte)]
+    ///
+    /// let line = b"name,,age";
+    /// let fields: Vec<&[u8]> = line.split_byte(b',').collect();
+    /// assert_eq!(fields, [&b"name"[..], b"", b"age"]);
+    ///
+    /// let last = line.split_byte(b',').next_back();
+    /// assert_eq!(last, Some(&b"age"[..]));
+    /// ```
+    #[unstable(feature = "slice_split_byte", issue = "0")]
+    #[inline]
+    pub fn split_byte(&self, sep: u8) -> SplitByte {
+        SplitByte { v: self, sep: sep, finished: false }
+    }
+
+    /// Concatenates `pieces`, placing a copy of this slice between each
+    /// pair of adjacent pieces.
+    ///
+    /// The length of the result is computed up front, so it is allocated
+    /// exactly once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_split_byte)]
+    ///
+    /// let joined = b", ".join_bytes(&[&b"a"[..], b"b", b"c"]);
+    /// assert_eq!(joined, b"a, b, c");
+    /// assert_eq!(joined.capacity(), joined.len());
+    /// ```
+    #[unstable(feature = "slice_split_byte", issue = "0")]
+    pub fn join_bytes<S: Borrow<[u8]>>(&self, pieces: &[S]) -> Vec<u8> {
+        let len = pieces.iter().fold(self.len() * pieces.len().saturating_sub(1),
+                                     |acc, p| acc + p.borrow().len());
+        let mut result = Vec::with_capacity(len);
+        for (i, piece) in pieces.iter().enumerate() {
+            if i != 0 {
+                result.extend_from_slice(self);
+            }
+            result.extend_from_slice(piece.borrow());
+        }
+        result
+    }
+
+    /// Returns the length of the longest prefix of this slice that consists
+    /// of bytes in `class`.
+    ///
+    /// Each byte is tested with a single table lookup.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_char_class)]
+    /// use std::ascii::CharClass;
+    ///
+    /// assert_eq!(b"0x1f".span_of(&CharClass::HEX_DIGIT), 1);
+    /// assert_eq!(b"1f;".span_of(&CharClass::HEX_DIGIT), 2);
+    /// ```
+    #[unstable(feature = "ascii_char_class", issue = "0")]
+    #[inline]
+    pub fn span_of(&self, class: &CharClass) -> usize {
+        self.find_not_of(class).unwrap_or(self.len())
+    }
+
+    /// Returns the index of the first byte of this slice that is not in
+    /// `class`, or `None` if all of them are.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_char_class)]
+    /// use std::ascii::CharClass;
+    ///
+    /// assert_eq!(b"  x".find_not_of(&CharClass::WHITESPACE), Some(2));
+    /// assert_eq!(b"\xff\xfe".find_not_of(&CharClass::ASCII), Some(0));
+    /// assert_eq!(b"".find_not_of(&CharClass::ASCII), None);
+    /// ```
+    #[unstable(feature = "ascii_char_class", issue = "0")]
+    #[inline]
+    pub fn find_not_of(&self, class: &CharClass) -> Option<usize> {
+        self.iter().position(|&b| !class.contains(b))
+    }
+
+    /// Returns a subslice with all prefixes and suffixes of bytes in `class`
+    /// removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_char_class)]
+    /// use std::ascii::CharClass;
+    ///
+    /// assert_eq!(b"\t key \n".trim_matches_class(&CharClass::WHITESPACE), b"key");
+    /// assert_eq!(b"\0\0".trim_matches_class(&CharClass::CONTROL), b"");
+    /// ```
+    #[unstable(feature = "ascii_char_class", issue = "0")]
+    pub fn trim_matches_class(&self, class: &CharClass) -> &[u8] {
+        let start = self.span_of(class);
+        let end = self.iter().rposition(|&b| !class.contains(b)).map_or(start, |i| i + 1);
+        &self[start..end]
+    }
 }
 
+/// An iterator over subslices of a byte slice separated by a single byte.
+///
+/// This struct is created by the [`split_byte`] method on byte slices.
+///
+/// [`split_byte`]: ../../std/primitive.slice.html#method.split_byte
+#[derive(Clone, Debug)]
+#[unstable(feature = "slice_split_byte", issue = "0")]
+pub struct SplitByte<'a> {
+    v: &'a [u8],
+    sep: u8,
+    finished: bool,
+}
+
+impl<'a> SplitByte<'a> {
+    #[inline]
+    fn finish(&mut self) -> Option<&'a [u8]> {
+        if self.finished {
+            None
+        } else {
+            self.finished = true;
+            Some(self.v)
+        }
+    }
+}
+
+#[unstable(feature = "slice_split_byte", issue = "0")]
+impl<'a> Iterator for SplitByte<'a> {
+    type Item = &'a [u8];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [u8]> {
+        if self.finished {
+            return None;
+        }
+
+        match memchr(self.sep, self.v) {
+            None => self.finish(),
+            Some(idx) => {
+                let ret = &self.v[..idx];
+                self.v = &self.v[idx + 1..];
+                Some(ret)
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            (1, Some(self.v.len() + 1))
+        }
+    }
+}
+
+#[unstable(feature = "slice_split_byte", issue = "0")]
+impl<'a> DoubleEndedIterator for SplitByte<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a [u8]> {
+        if self.finished {
+            return None;
+        }
+
+        match memrchr(self.sep, self.v) {
+            None => self.finish(),
+            Some(idx) => {
+                let ret = &self.v[idx + 1..];
+                self.v = &self.v[..idx];
+                Some(ret)
+            }
+        }
+    }
+}
+
+#[unstable(feature = "slice_split_byte", issue = "0")]
+impl<'a> FusedIterator for SplitByte<'a> {}
+
+// Below this many elements pairwise summation adds the remaining elements in
+// order; the rounding error stays `O(log n)` while most of the work is a
+// simple loop.
+const PAIRWISE_SUM_BLOCK: usize = 32;
+
+/// An extension trait for computing statistics of slices of floats.
+///
+/// It is implemented for `[f32]` and `[f64]`, and has to be in scope, with
+/// `use std::slice::FloatSliceExt;`, to call its methods on a slice.
+#[unstable(feature = "float_slice_stats", issue = "0")]
+pub trait FloatSliceExt {
+    /// The type of the elements, and of the statistics.
+    type Output;
+
+    /// Returns the sum of the elements of the slice.
+    ///
+    /// The elements are added with pairwise summation, so the rounding error
+    /// grows with the logarithm of the length of the slice rather than with
+    /// the length itself, as it does when adding the elements in order with
+    /// `iter().sum()`.
+    ///
+    /// The sum of an empty slice is `0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(float_slice_stats)]
+    /// use std::slice::FloatSliceExt;
+    ///
+    /// let v = vec![0.1f32; 1_000_000];
+    /// assert!((v.sum() - 100_000.0).abs() < 0.1);
+    /// ```
+    fn sum(&self) -> Self::Output;
+
+    /// Returns the arithmetic mean of the elements of the slice, computed
+    /// from the pairwise [`sum`].
+    ///
+    /// The mean of an empty slice is `NaN`.
+    ///
+    /// [`sum`]: #tymethod.sum
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(float_slice_stats)]
+    /// use std::slice::FloatSliceExt;
+    ///
+    /// assert_eq!([1.0f64, 2.0, 4.5].mean(), 2.5);
+    /// assert!(FloatSliceExt::mean(&[] as &[f64]).is_nan());
+    /// ```
+    fn mean(&self) -> Self::Output;
+}
+
+macro_rules! float_slice_ext {
+    ($t:ident) => {
+        #[unstable(feature = "float_slice_stats", issue = "0")]
+        impl FloatSliceExt for [$t] {
+            type Output = $t;
+
+            fn sum(&self) -> $t {
+                if self.len() <= PAIRWISE_SUM_BLOCK {
+                    self.iter().fold(0.0, |acc, &x| acc + x)
+                } else {
+                    let (left, right) = self.split_at(self.len() / 2);
+                    left.sum() + right.sum()
+                }
+            }
+
+            #[inline]
+            fn mean(&self) -> $t {
+                self.sum() / self.len() as $t
+            }
+        }
+    }
+}
+
+float_slice_ext!(f32);
+float_slice_ext!(f64);
+
 ////////////////////////////////////////////////////////////////////////////////
 // Extension traits for slices over specific kinds of data
 ////////////////////////////////////////////////////////////////////////////////
@@ -1951,7 +2880,7 @@ impl<T: Clone, V: Borrow<[T]>> SliceConcatExt<T> for [V] {
 
     fn join(&self, sep: &T) -> Vec<T> {
         let size = self.iter().fold(0, |acc, v| acc + v.borrow().len());
-        let mut result = Vec::with_capacity(size + self.len());
+        let mut result = Vec::with_capacity(size + self.len().saturating_sub(1));
         let mut first = true;
         for v in self {
             if first {
@@ -2014,6 +2943,64 @@ impl<T: Clone> ToOwned for [T] {
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// Merging
+////////////////////////////////////////////////////////////////////////////////
+
+/// Merges two sorted slices into `out`, keeping only one copy of each
+/// distinct element.
+///
+/// `a` and `b` must both be sorted in ascending order. Their elements are
+/// cloned onto the end of `out` in ascending order, with the duplicates from
+/// within and across the two slices removed, in a single pass. Room for
+/// every element of both slices is reserved up front, so `out` is
+/// reallocated at most once.
+///
+/// If either slice is not sorted the order of the result is unspecified.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(merge_dedup)]
+///
+/// use std::slice;
+///
+/// let mut out = Vec::new();
+/// slice::merge_dedup(&[1, 3, 5, 7], &[2, 3, 4, 7, 7, 8], &mut out);
+/// assert_eq!(out, [1, 2, 3, 4, 5, 7, 8]);
+/// ```
+#[unstable(feature = "merge_dedup", issue = "0")]
+pub fn merge_dedup<T: Ord + Clone>(a: &[T], b: &[T], out: &mut Vec<T>) {
+    out.reserve(a.len() + b.len());
+    let start = out.len();
+    let (mut i, mut j) = (0, 0);
+    loop {
+        let next = match (a.get(i), b.get(j)) {
+            (Some(x), Some(y)) => {
+                if x <= y {
+                    i += 1;
+                    x
+                } else {
+                    j += 1;
+                    y
+                }
+            }
+            (Some(x), None) => {
+                i += 1;
+                x
+            }
+            (None, Some(y)) => {
+                j += 1;
+                y
+            }
+            (None, None) => break,
+        };
+        if out.len() == start || out[out.len() - 1] != *next {
+            out.push(next.clone());
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Sorting
 ////////////////////////////////////////////////////////////////////////////////
@@ -2024,7 +3011,8 @@ impl<T: Clone> ToOwned for [T] {
 fn insert_head<T, F>(v: &mut [T], is_less: &mut F)
     where F: FnMut(&T, &T) -> bool
 {
-    if v.len() >= 2 && is_less(&v[1], &v[0]) {
+    let len = v.len();
+    if len >= 2 && is_less(&v[1], &v[0]) {
         unsafe {
             // There are three ways to implement insertion here:
             //
@@ -2043,7 +3031,7 @@ fn insert_head<T, F>(v: &mut [T], is_less: &mut F)
             //    performance than with the 2nd method.
             //
             // All methods were benchmarked, and the 3rd showed best results. So we chose that one.
-            let mut tmp = mem::ManuallyDrop::new(ptr::read(&v[0]));
+            let mut tmp = mem::ManuallyDrop::new(ptr::read(v.get_unchecked(0)));
 
             // Intermediate state of the insertion process is always tracked by `hole`, which
             // serves two purposes:
@@ -2057,16 +3045,16 @@ fn insert_head<T, F>(v: &mut [T], is_less: &mut F)
             // initially held exactly once.
             let mut hole = InsertionHole {
                 src: &mut *tmp,
-                dest: &mut v[1],
+                dest: v.get_unchecked_mut(1),
             };
-            ptr::copy_nonoverlapping(&v[1], &mut v[0], 1);
+            ptr::copy_nonoverlapping(v.get_unchecked(1), v.get_unchecked_mut(0), 1);
 
-            for i in 2..v.len() {
-                if !is_less(&v[i], &*tmp) {
+            for i in 2..len {
+                if !is_less(v.get_unchecked(i), &*tmp) {
                     break;
                 }
-                ptr::copy_nonoverlapping(&v[i], &mut v[i - 1], 1);
-                hole.dest = &mut v[i];
+                ptr::copy_nonoverlapping(v.get_unchecked(i), v.get_unchecked_mut(i - 1), 1);
+                hole.dest = v.get_unchecked_mut(i);
             }
             // `hole` gets dropped and thus copies `tmp` into the remaining hole in `v`.
         }
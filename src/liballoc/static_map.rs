@@ -0,0 +1,273 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A lookup table that can be stored in a `static`.
+//!
+//! The table is created by the [`static_map!`] macro and needs no runtime
+//! construction or allocation at all: it is nothing more than a sorted,
+//! `'static` slice of key-value pairs that is searched with a binary search.
+//!
+//! [`static_map!`]: ../../std/macro.static_map.html
+
+#![unstable(feature = "static_map", issue = "0")]
+
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::FusedIterator;
+use core::ops::Index;
+use core::slice;
+
+/// A read-only map backed by a sorted `'static` slice.
+///
+/// `StaticMap`s are created with the [`static_map!`] macro, which accepts
+/// the entries in the same `key => value` syntax as a `match`. The entries
+/// must be listed in strictly ascending order of their keys, since the macro
+/// cannot reorder them and lookups do not check the order. Looking up a key
+/// in a map whose keys are out of order gives an unspecified result, which
+/// may be `None` even if the key is present. Use [`is_well_formed`] in a
+/// test to check the order.
+///
+/// Lookups take `O(log n)` time.
+///
+/// [`static_map!`]: ../../std/macro.static_map.html
+/// [`is_well_formed`]: #method.is_well_formed
+///
+/// # Examples
+///
+/// ```
+/// #![feature(static_map)]
+/// use std::collections::static_map::StaticMap;
+///
+/// static PRECEDENCE: StaticMap<&str, u8> = static_map! {
+///     "*" => 2,
+///     "+" => 1,
+///     "-" => 1,
+///     "/" => 2,
+/// };
+/// assert_eq!(PRECEDENCE.get("*"), Some(&2));
+/// assert_eq!(PRECEDENCE.get("%"), None);
+/// assert_eq!(PRECEDENCE["-"], 1);
+/// ```
+pub struct StaticMap<K: 'static, V: 'static> {
+    // Only public so that `static_map!` can build the map in a constant
+    // expression. It is unstable under the same feature as the macro, so
+    // that using the macro requires that feature.
+    #[doc(hidden)]
+    #[unstable(feature = "static_map", issue = "0")]
+    pub __entries: &'static [(K, V)],
+}
+
+impl<K, V> StaticMap<K, V> {
+    /// Returns the number of elements in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(static_map)]
+    /// use std::collections::static_map::StaticMap;
+    ///
+    /// static MAP: StaticMap<u32, &str> = static_map! { 1 => "a", 2 => "b" };
+    /// assert_eq!(MAP.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.__entries.len()
+    }
+
+    /// Returns `true` if the map contains no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(static_map)]
+    /// use std::collections::static_map::StaticMap;
+    ///
+    /// static MAP: StaticMap<u32, &str> = static_map! {};
+    /// assert!(MAP.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.__entries.is_empty()
+    }
+
+    /// Gets an iterator over the entries of the map, in the order they were
+    /// listed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(static_map)]
+    /// use std::collections::static_map::StaticMap;
+    ///
+    /// static MAP: StaticMap<u32, &str> = static_map! { 1 => "a", 2 => "b" };
+    /// let entries: Vec<_> = MAP.iter().collect();
+    /// assert_eq!(entries, [(&1, &"a"), (&2, &"b")]);
+    /// ```
+    pub fn iter(&self) -> Iter<K, V> {
+        Iter { inner: self.__entries.iter() }
+    }
+}
+
+impl<K: Ord, V> StaticMap<K, V> {
+    fn search<Q: ?Sized>(&self, key: &Q) -> Option<&'static (K, V)>
+        where K: Borrow<Q>,
+              Q: Ord
+    {
+        let entries = self.__entries;
+        entries.binary_search_by(|&(ref k, _)| k.borrow().cmp(key))
+               .ok()
+               .map(|idx| &entries[idx])
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    ///
+    /// The key may be any borrowed form of the map's key type, but the ordering
+    /// on the borrowed form *must* match the ordering on the key type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(static_map)]
+    /// use std::collections::static_map::StaticMap;
+    ///
+    /// static MAP: StaticMap<u32, &str> = static_map! { 1 => "a" };
+    /// assert_eq!(MAP.get(&1), Some(&"a"));
+    /// assert_eq!(MAP.get(&2), None);
+    /// ```
+    pub fn get<Q: ?Sized>(&self, key: &Q) -> Option<&V>
+        where K: Borrow<Q>,
+              Q: Ord
+    {
+        self.search(key).map(|&(_, ref v)| v)
+    }
+
+    /// Returns `true` if the map contains a value for the specified key.
+    ///
+    /// The key may be any borrowed form of the map's key type, but the ordering
+    /// on the borrowed form *must* match the ordering on the key type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(static_map)]
+    /// use std::collections::static_map::StaticMap;
+    ///
+    /// static MAP: StaticMap<u32, &str> = static_map! { 1 => "a" };
+    /// assert_eq!(MAP.contains_key(&1), true);
+    /// assert_eq!(MAP.contains_key(&2), false);
+    /// ```
+    pub fn contains_key<Q: ?Sized>(&self, key: &Q) -> bool
+        where K: Borrow<Q>,
+              Q: Ord
+    {
+        self.search(key).is_some()
+    }
+
+    /// Returns `true` if the keys are listed in strictly ascending order,
+    /// which is required for lookups to work.
+    ///
+    /// This is intended to be called from a test, since a `static` cannot
+    /// be checked when it is defined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(static_map)]
+    /// use std::collections::static_map::StaticMap;
+    ///
+    /// static GOOD: StaticMap<u32, ()> = static_map! { 1 => (), 2 => () };
+    /// static BAD: StaticMap<u32, ()> = static_map! { 2 => (), 1 => () };
+    /// assert!(GOOD.is_well_formed());
+    /// assert!(!BAD.is_well_formed());
+    /// ```
+    pub fn is_well_formed(&self) -> bool {
+        self.__entries.windows(2).all(|w| w[0].0.cmp(&w[1].0) == Ordering::Less)
+    }
+}
+
+impl<'a, K, Q: ?Sized, V> Index<&'a Q> for StaticMap<K, V>
+    where K: Ord + Borrow<Q>,
+          Q: Ord
+{
+    type Output = V;
+
+    /// Returns a reference to the value corresponding to the supplied key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is not present in the `StaticMap`.
+    #[inline]
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("no entry found for key")
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for StaticMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a StaticMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+/// An iterator over the entries of a `StaticMap`.
+///
+/// This `struct` is created by the [`iter`] method on [`StaticMap`]. See its
+/// documentation for more.
+///
+/// [`iter`]: struct.StaticMap.html#method.iter
+/// [`StaticMap`]: struct.StaticMap.html
+pub struct Iter<'a, K: 'a, V: 'a> {
+    inner: slice::Iter<'a, (K, V)>,
+}
+
+// FIXME(#26925) Remove in favor of `#[derive(Clone)]`
+impl<'a, K, V> Clone for Iter<'a, K, V> {
+    fn clone(&self) -> Iter<'a, K, V> {
+        Iter { inner: self.inner.clone() }
+    }
+}
+
+impl<'a, K: fmt::Debug, V: fmt::Debug> fmt::Debug for Iter<'a, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        self.inner.next().map(|&(ref k, ref v)| (k, v))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for Iter<'a, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<(&'a K, &'a V)> {
+        self.inner.next_back().map(|&(ref k, ref v)| (k, v))
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for Iter<'a, K, V> {}
+
+impl<'a, K, V> FusedIterator for Iter<'a, K, V> {}
@@ -38,6 +38,7 @@
 // It's cleaner to just turn off the unused_imports warning than to fix them.
 #![allow(unused_imports)]
 
+use core::ascii::CharClass;
 use core::fmt;
 use core::str as core_str;
 use core::str::pattern::Pattern;
@@ -45,10 +46,12 @@ use core::str::pattern::{Searcher, ReverseSearcher, DoubleEndedSearcher};
 use core::mem;
 use core::ptr;
 use core::iter::FusedIterator;
+use std_unicode::normalization::{canonical_combining_class, compose};
+use std_unicode::normalization::{decompose_canonical, decompose_compatible};
 use std_unicode::str::{UnicodeStr, Utf16Encoder};
 
 use vec_deque::VecDeque;
-use borrow::{Borrow, ToOwned};
+use borrow::{Borrow, Cow, ToOwned};
 use string::String;
 use std_unicode;
 use vec::Vec;
@@ -76,6 +79,8 @@ pub use core::str::{from_utf8, from_utf8_mut, Chars, CharIndices, Bytes};
 pub use core::str::{from_utf8_unchecked, from_utf8_unchecked_mut, ParseBoolError};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use std_unicode::str::SplitWhitespace;
+#[unstable(feature = "str_graphemes", issue = "0")]
+pub use std_unicode::str::Graphemes;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::str::pattern;
 
@@ -175,6 +180,223 @@ impl<'a> Iterator for EncodeUtf16<'a> {
 #[stable(feature = "fused", since = "1.26.0")]
 impl<'a> FusedIterator for EncodeUtf16<'a> {}
 
+#[derive(Clone, Copy, Debug)]
+enum DecompositionType {
+    Canonical,
+    Compatible,
+}
+
+/// An iterator over the chars of a string in Normalization Form D or KD.
+///
+/// This struct is created by the [`nfd`] and [`nfkd`] methods on [`str`].
+/// See their documentation for more.
+///
+/// [`nfd`]: ../../std/primitive.str.html#method.nfd
+/// [`nfkd`]: ../../std/primitive.str.html#method.nfkd
+/// [`str`]: ../../std/primitive.str.html
+#[derive(Clone, Debug)]
+#[unstable(feature = "str_normalization", issue = "0")]
+pub struct Decompositions<'a> {
+    kind: DecompositionType,
+    iter: Chars<'a>,
+    /// Decomposed chars along with their canonical combining class.
+    buffer: Vec<(char, u8)>,
+    /// The chars in `buffer[..ready]` are in canonical order and can be
+    /// handed out; the rest may still have to be reordered.
+    ready: usize,
+    /// The next char of `buffer` to hand out.
+    pos: usize,
+}
+
+impl<'a> Decompositions<'a> {
+    fn new(s: &'a str, kind: DecompositionType) -> Decompositions<'a> {
+        Decompositions {
+            kind,
+            iter: s.chars(),
+            buffer: Vec::new(),
+            ready: 0,
+            pos: 0,
+        }
+    }
+}
+
+/// Sorts a run of combining characters by their combining class, keeping
+/// characters of the same class in order.
+fn canonical_sort(run: &mut [(char, u8)]) {
+    run.sort_by_key(|&(_, class)| class);
+}
+
+#[unstable(feature = "str_normalization", issue = "0")]
+impl<'a> Iterator for Decompositions<'a> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        while self.pos == self.ready {
+            self.buffer.drain(..self.ready);
+            self.pos = 0;
+            self.ready = 0;
+
+            let c = match self.iter.next() {
+                Some(c) => c,
+                None => {
+                    // Reorder whatever is left after the last starter.
+                    if self.buffer.is_empty() {
+                        return None;
+                    }
+                    canonical_sort(&mut self.buffer);
+                    self.ready = self.buffer.len();
+                    break;
+                }
+            };
+
+            // A starter ends the run of combining characters after the
+            // previous one, so everything before it is final.
+            let buffer = &mut self.buffer;
+            let ready = &mut self.ready;
+            let push = |d| {
+                let class = canonical_combining_class(d);
+                if class == 0 {
+                    canonical_sort(&mut buffer[*ready..]);
+                    *ready = buffer.len();
+                }
+                buffer.push((d, class));
+            };
+            match self.kind {
+                DecompositionType::Canonical => decompose_canonical(c, push),
+                DecompositionType::Compatible => decompose_compatible(c, push),
+            }
+        }
+
+        let c = self.buffer[self.pos].0;
+        self.pos += 1;
+        Some(c)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, _) = self.iter.size_hint();
+        (lower + self.buffer.len() - self.pos, None)
+    }
+}
+
+#[unstable(feature = "str_normalization", issue = "0")]
+impl<'a> FusedIterator for Decompositions<'a> {}
+
+#[derive(Clone, Copy, Debug)]
+enum RecompositionState {
+    Composing,
+    Purging,
+    Finished,
+}
+
+/// An iterator over the chars of a string in Normalization Form C or KC.
+///
+/// This struct is created by the [`nfc`] and [`nfkc`] methods on [`str`].
+/// See their documentation for more.
+///
+/// [`nfc`]: ../../std/primitive.str.html#method.nfc
+/// [`nfkc`]: ../../std/primitive.str.html#method.nfkc
+/// [`str`]: ../../std/primitive.str.html
+#[derive(Clone, Debug)]
+#[unstable(feature = "str_normalization", issue = "0")]
+pub struct Recompositions<'a> {
+    iter: Decompositions<'a>,
+    state: RecompositionState,
+    /// Combining characters that could not be composed with `composee`.
+    buffer: VecDeque<char>,
+    /// The last starter, which following characters may compose with.
+    composee: Option<char>,
+    /// The combining class of the last char pushed to `buffer`.
+    last_ccc: Option<u8>,
+}
+
+impl<'a> Recompositions<'a> {
+    fn new(s: &'a str, kind: DecompositionType) -> Recompositions<'a> {
+        Recompositions {
+            iter: Decompositions::new(s, kind),
+            state: RecompositionState::Composing,
+            buffer: VecDeque::new(),
+            composee: None,
+            last_ccc: None,
+        }
+    }
+}
+
+#[unstable(feature = "str_normalization", issue = "0")]
+impl<'a> Iterator for Recompositions<'a> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        use self::RecompositionState::*;
+
+        loop {
+            match self.state {
+                Composing => {
+                    for ch in self.iter.by_ref() {
+                        let ch_class = canonical_combining_class(ch);
+                        let k = match self.composee {
+                            Some(k) => k,
+                            None => {
+                                if ch_class != 0 {
+                                    return Some(ch);
+                                }
+                                self.composee = Some(ch);
+                                continue;
+                            }
+                        };
+
+                        if let Some(l_class) = self.last_ccc {
+                            if l_class >= ch_class {
+                                // `ch` is blocked from `composee`
+                                if ch_class == 0 {
+                                    self.composee = Some(ch);
+                                    self.last_ccc = None;
+                                    self.state = Purging;
+                                    return Some(k);
+                                }
+                                self.buffer.push_back(ch);
+                                self.last_ccc = Some(ch_class);
+                                continue;
+                            }
+                        }
+
+                        match compose(k, ch) {
+                            Some(r) => self.composee = Some(r),
+                            None if ch_class == 0 && self.last_ccc.is_none() => {
+                                self.composee = Some(ch);
+                                return Some(k);
+                            }
+                            None => {
+                                self.buffer.push_back(ch);
+                                self.last_ccc = Some(ch_class);
+                            }
+                        }
+                    }
+                    self.state = Finished;
+                    if self.composee.is_some() {
+                        return self.composee.take();
+                    }
+                }
+                Purging => {
+                    match self.buffer.pop_front() {
+                        None => self.state = Composing,
+                        s => return s,
+                    }
+                }
+                Finished => {
+                    match self.buffer.pop_front() {
+                        None => return self.composee.take(),
+                        s => return s,
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[unstable(feature = "str_normalization", issue = "0")]
+impl<'a> FusedIterator for Recompositions<'a> {}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl Borrow<str> for String {
     #[inline]
@@ -804,6 +1026,47 @@ impl str {
         UnicodeStr::split_whitespace(self)
     }
 
+    /// Returns an iterator over the grapheme clusters of a string slice.
+    ///
+    /// A grapheme cluster is what a user would usually think of as a single
+    /// character, even when it is made up of several `char`s, such as a letter
+    /// followed by combining accents, a Hangul syllable written as individual
+    /// jamo, or a flag made of two regional indicators. The clusters are the
+    /// *extended grapheme clusters* of [Unicode Standard Annex #29][uax29].
+    ///
+    /// [uax29]: http://www.unicode.org/reports/tr29/
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(str_graphemes)]
+    ///
+    /// let s = "a\u{310}e\u{301}o\u{308}\u{332}";
+    /// assert_eq!(s.chars().count(), 7);
+    ///
+    /// let g: Vec<&str> = s.graphemes().collect();
+    /// assert_eq!(g, ["a\u{310}", "e\u{301}", "o\u{308}\u{332}"]);
+    /// ```
+    ///
+    /// The iterator can also be run from the back:
+    ///
+    /// ```
+    /// #![feature(str_graphemes)]
+    ///
+    /// let mut iter = "\u{1f1e8}\u{1f1ed}\u{1f1ee}\r\n".graphemes();
+    /// assert_eq!(iter.next_back(), Some("\r\n"));
+    /// assert_eq!(iter.next_back(), Some("\u{1f1ee}"));
+    /// assert_eq!(iter.next_back(), Some("\u{1f1e8}\u{1f1ed}"));
+    /// assert_eq!(iter.next_back(), None);
+    /// ```
+    #[unstable(feature = "str_graphemes", issue = "0")]
+    #[inline]
+    pub fn graphemes(&self) -> Graphemes {
+        UnicodeStr::graphemes(self)
+    }
+
     /// An iterator over the lines of a string, as string slices.
     ///
     /// Lines are ended with either a newline (`\n`) or a carriage return with
@@ -1748,6 +2011,78 @@ impl str {
         core_str::StrExt::trim_right_matches(self, pat)
     }
 
+    /// Returns the length in bytes of the longest prefix of this string
+    /// slice that consists of characters in `class`.
+    ///
+    /// Only the ASCII part of `class` is used: non-ASCII characters are
+    /// never considered to be in it. Each byte is tested with a single table
+    /// lookup.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_char_class)]
+    /// use std::ascii::CharClass;
+    ///
+    /// assert_eq!("2018-05-14".span_of(&CharClass::DIGIT), 4);
+    /// assert_eq!("abc".span_of(&CharClass::DIGIT), 0);
+    /// assert_eq!("aé".span_of(&CharClass::ALPHABETIC), 1);
+    /// ```
+    #[unstable(feature = "ascii_char_class", issue = "0")]
+    #[inline]
+    pub fn span_of(&self, class: &CharClass) -> usize {
+        self.as_bytes().span_of(&class.intersection(&CharClass::ASCII))
+    }
+
+    /// Returns the byte index of the first character of this string slice
+    /// that is not in `class`, or `None` if all of them are.
+    ///
+    /// Only the ASCII part of `class` is used: non-ASCII characters are
+    /// never considered to be in it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_char_class)]
+    /// use std::ascii::CharClass;
+    ///
+    /// assert_eq!("  \tx y".find_not_of(&CharClass::WHITESPACE), Some(3));
+    /// assert_eq!("   ".find_not_of(&CharClass::WHITESPACE), None);
+    /// ```
+    #[unstable(feature = "ascii_char_class", issue = "0")]
+    #[inline]
+    pub fn find_not_of(&self, class: &CharClass) -> Option<usize> {
+        self.as_bytes().find_not_of(&class.intersection(&CharClass::ASCII))
+    }
+
+    /// Returns a string slice with all prefixes and suffixes of characters in
+    /// `class` removed.
+    ///
+    /// Only the ASCII part of `class` is used: non-ASCII characters are
+    /// never considered to be in it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_char_class)]
+    /// use std::ascii::CharClass;
+    ///
+    /// assert_eq!("  hello\r\n".trim_matches_class(&CharClass::WHITESPACE), "hello");
+    ///
+    /// let quotes = CharClass::from_bytes(b"'\"");
+    /// assert_eq!(r#""'résumé'""#.trim_matches_class(&quotes), "résumé");
+    /// ```
+    #[unstable(feature = "ascii_char_class", issue = "0")]
+    pub fn trim_matches_class(&self, class: &CharClass) -> &str {
+        let class = class.intersection(&CharClass::ASCII);
+        let bytes = self.as_bytes();
+        // Non-ASCII bytes are never in `class`, so both ends fall on
+        // character boundaries.
+        let start = bytes.span_of(&class);
+        let end = bytes.iter().rposition(|&b| !class.contains(b)).map_or(start, |i| i + 1);
+        &self[start..end]
+    }
+
     /// Parses this string slice into another type.
     ///
     /// Because `parse` is so general, it can cause problems with type
@@ -2003,6 +2338,94 @@ impl str {
         return s;
     }
 
+    /// Returns an iterator over the chars of the string in Unicode
+    /// Normalization Form D, canonical decomposition.
+    ///
+    /// Strings that are canonically equivalent, such as a precomposed
+    /// character and the same character spelled with combining marks, have
+    /// the same normalized form. See [Unicode Standard Annex #15][uax15] for
+    /// the details.
+    ///
+    /// [uax15]: http://www.unicode.org/reports/tr15/
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_normalization)]
+    ///
+    /// let nfd: String = "\u{e9}\u{1e0b}\u{323}".nfd().collect();
+    /// assert_eq!(nfd, "e\u{301}d\u{323}\u{307}");
+    /// ```
+    #[unstable(feature = "str_normalization", issue = "0")]
+    #[inline]
+    pub fn nfd(&self) -> Decompositions {
+        Decompositions::new(self, DecompositionType::Canonical)
+    }
+
+    /// Returns an iterator over the chars of the string in Unicode
+    /// Normalization Form KD, compatibility decomposition.
+    ///
+    /// Besides the canonical decompositions of [`nfd`], this also replaces
+    /// compatibility characters such as ligatures, full-width forms and
+    /// superscripts by their plain counterparts.
+    ///
+    /// [`nfd`]: #method.nfd
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_normalization)]
+    ///
+    /// let nfkd: String = "\u{fb01}\u{ff21}\u{b2}\u{e9}".nfkd().collect();
+    /// assert_eq!(nfkd, "fiA2e\u{301}");
+    /// ```
+    #[unstable(feature = "str_normalization", issue = "0")]
+    #[inline]
+    pub fn nfkd(&self) -> Decompositions {
+        Decompositions::new(self, DecompositionType::Compatible)
+    }
+
+    /// Returns an iterator over the chars of the string in Unicode
+    /// Normalization Form C, canonical decomposition followed by canonical
+    /// composition.
+    ///
+    /// This is the form most text is already in, which makes it a good
+    /// choice for normalizing keys before comparing or hashing them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_normalization)]
+    ///
+    /// let a: String = "Cafe\u{301}".nfc().collect();
+    /// let b: String = "Caf\u{e9}".nfc().collect();
+    /// assert_eq!(a, "Caf\u{e9}");
+    /// assert_eq!(a, b);
+    /// ```
+    #[unstable(feature = "str_normalization", issue = "0")]
+    #[inline]
+    pub fn nfc(&self) -> Recompositions {
+        Recompositions::new(self, DecompositionType::Canonical)
+    }
+
+    /// Returns an iterator over the chars of the string in Unicode
+    /// Normalization Form KC, compatibility decomposition followed by
+    /// canonical composition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_normalization)]
+    ///
+    /// let nfkc: String = "\u{fb01}ance\u{301}".nfkc().collect();
+    /// assert_eq!(nfkc, "fianc\u{e9}");
+    /// ```
+    #[unstable(feature = "str_normalization", issue = "0")]
+    #[inline]
+    pub fn nfkc(&self) -> Recompositions {
+        Recompositions::new(self, DecompositionType::Compatible)
+    }
+
     /// Escapes each char in `s` with [`char::escape_debug`].
     ///
     /// [`char::escape_debug`]: primitive.char.html#method.escape_debug
@@ -2310,3 +2733,195 @@ impl str {
 pub unsafe fn from_boxed_utf8_unchecked(v: Box<[u8]>) -> Box<str> {
     Box::from_raw(Box::into_raw(v) as *mut str)
 }
+
+/// An iterator that splits a string into tokens, honoring quotes and escapes.
+///
+/// By default, tokens are separated by runs of whitespace, as on a command
+/// line. Text enclosed in `"` or `'` quotes belongs to a single token even if
+/// it contains delimiters, and a backslash makes the following character
+/// literal. The quotes and backslashes themselves are removed from the
+/// tokens. The delimiters, quote characters and escape character can be
+/// changed with the builder methods.
+///
+/// Tokens are borrowed from the input where possible: this is the case for
+/// tokens without any quotes or escapes, and for tokens consisting of exactly
+/// one quoted section without escapes. Only the remaining tokens are
+/// unescaped into a newly allocated `String`.
+///
+/// A quote that is never closed extends to the end of the input, and a
+/// trailing escape character is kept as is.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(str_tokenizer)]
+///
+/// use std::borrow::Cow;
+/// use std::str::Tokenizer;
+///
+/// let line = r#"cp -r "My Documents" it\'s\ here 'a "b" c'"#;
+/// let tokens: Vec<_> = Tokenizer::new(line).collect();
+/// assert_eq!(tokens, ["cp", "-r", "My Documents", "it's here", "a \"b\" c"]);
+/// assert_eq!(tokens[2], Cow::Borrowed("My Documents"));
+///
+/// // Fields separated by commas, with empty fields kept.
+/// let fields: Vec<_> = Tokenizer::new(r#"1,"Smith, J.",,\,,"#)
+///     .delimiters(&[','])
+///     .collect();
+/// assert_eq!(fields, ["1", "Smith, J.", "", ",", ""]);
+/// ```
+#[unstable(feature = "str_tokenizer", issue = "0")]
+#[derive(Clone, Debug)]
+pub struct Tokenizer<'a> {
+    rest: &'a str,
+    /// `None` splits on runs of whitespace.
+    delimiters: Option<&'a [char]>,
+    quotes: &'a [char],
+    escape: Option<char>,
+    finished: bool,
+}
+
+impl<'a> Tokenizer<'a> {
+    /// Creates a tokenizer that splits `s` on whitespace, with `"` and `'` as
+    /// quotes and `\` as the escape character.
+    #[unstable(feature = "str_tokenizer", issue = "0")]
+    pub fn new(s: &'a str) -> Tokenizer<'a> {
+        Tokenizer {
+            rest: s,
+            delimiters: None,
+            quotes: &['"', '\''],
+            escape: Some('\\'),
+            finished: s.is_empty(),
+        }
+    }
+
+    /// Separates tokens by any one of `delimiters` instead of by whitespace.
+    ///
+    /// Every delimiter ends a token, so two adjacent delimiters enclose an
+    /// empty token, and a trailing delimiter is followed by one. Empty input
+    /// still produces no tokens at all.
+    #[unstable(feature = "str_tokenizer", issue = "0")]
+    pub fn delimiters(mut self, delimiters: &'a [char]) -> Tokenizer<'a> {
+        self.delimiters = Some(delimiters);
+        self
+    }
+
+    /// Sets the characters that start and end a quoted section.
+    #[unstable(feature = "str_tokenizer", issue = "0")]
+    pub fn quotes(mut self, quotes: &'a [char]) -> Tokenizer<'a> {
+        self.quotes = quotes;
+        self
+    }
+
+    /// Sets the escape character, or disables escaping with `None`.
+    #[unstable(feature = "str_tokenizer", issue = "0")]
+    pub fn escape(mut self, escape: Option<char>) -> Tokenizer<'a> {
+        self.escape = escape;
+        self
+    }
+
+    /// Returns the part of the input that has not been tokenized yet.
+    #[unstable(feature = "str_tokenizer", issue = "0")]
+    pub fn as_str(&self) -> &'a str {
+        self.rest
+    }
+
+    fn is_delimiter(&self, c: char) -> bool {
+        match self.delimiters {
+            None => c.is_whitespace(),
+            Some(delimiters) => delimiters.contains(&c),
+        }
+    }
+
+    /// Removes the quotes and escapes from `token`.
+    fn unquote(&self, token: &'a str) -> Cow<'a, str> {
+        let mut chars = token.chars();
+        if let (Some(first), Some(last)) = (chars.next(), chars.next_back()) {
+            if first == last && self.quotes.contains(&first) {
+                let inner = chars.as_str();
+                if !inner.contains(first) && self.escape.map_or(true, |e| !inner.contains(e)) {
+                    return Cow::Borrowed(inner);
+                }
+            }
+        }
+
+        let mut unquoted = String::with_capacity(token.len());
+        let mut quote = None;
+        let mut escaped = false;
+        for c in token.chars() {
+            if escaped {
+                unquoted.push(c);
+                escaped = false;
+            } else if Some(c) == self.escape {
+                escaped = true;
+            } else if let Some(q) = quote {
+                if c == q {
+                    quote = None;
+                } else {
+                    unquoted.push(c);
+                }
+            } else if self.quotes.contains(&c) {
+                quote = Some(c);
+            } else {
+                unquoted.push(c);
+            }
+        }
+        if escaped {
+            unquoted.push(self.escape.unwrap());
+        }
+        Cow::Owned(unquoted)
+    }
+}
+
+#[unstable(feature = "str_tokenizer", issue = "0")]
+impl<'a> Iterator for Tokenizer<'a> {
+    type Item = Cow<'a, str>;
+
+    fn next(&mut self) -> Option<Cow<'a, str>> {
+        if self.delimiters.is_none() {
+            self.rest = self.rest.trim_left_matches(|c: char| c.is_whitespace());
+            if self.rest.is_empty() {
+                return None;
+            }
+        } else if self.finished {
+            return None;
+        }
+
+        // Find the delimiter ending the token, noting whether the token needs
+        // to be unquoted.
+        let mut end = self.rest.len();
+        let mut plain = true;
+        let mut quote = None;
+        let mut escaped = false;
+        for (i, c) in self.rest.char_indices() {
+            if escaped {
+                escaped = false;
+            } else if Some(c) == self.escape {
+                escaped = true;
+                plain = false;
+            } else if let Some(q) = quote {
+                if c == q {
+                    quote = None;
+                }
+            } else if self.quotes.contains(&c) {
+                quote = Some(c);
+                plain = false;
+            } else if self.is_delimiter(c) {
+                end = i;
+                break;
+            }
+        }
+
+        let token = &self.rest[..end];
+        let mut rest = self.rest[end..].chars();
+        if rest.next().is_none() {
+            self.finished = true;
+        }
+        self.rest = rest.as_str();
+
+        Some(if plain { Cow::Borrowed(token) } else { self.unquote(token) })
+    }
+}
+
+#[unstable(feature = "str_tokenizer", issue = "0")]
+impl<'a> FusedIterator for Tokenizer<'a> {}
@@ -59,6 +59,7 @@
 use core::fmt;
 use core::hash;
 use core::iter::{FromIterator, FusedIterator};
+use core::num::flt2dec;
 use core::ops::Bound::{Excluded, Included, Unbounded};
 use core::ops::{self, Add, AddAssign, Index, IndexMut, RangeBounds};
 use core::ptr;
@@ -70,6 +71,7 @@ use borrow::{Cow, ToOwned};
 use str::{self, from_boxed_utf8_unchecked, FromStr, Utf8Error, Chars};
 use vec::Vec;
 use boxed::Box;
+use growth::GrowthPolicy;
 use super::allocator::CollectionAllocErr;
 
 /// A UTF-8 encoded, growable string.
@@ -695,6 +697,33 @@ impl String {
         String { vec: Vec::from_raw_parts(buf, length, capacity) }
     }
 
+    /// Decomposes a `String` into its raw components: the pointer to its
+    /// buffer, its length in bytes and its capacity.
+    ///
+    /// After calling this function the caller is responsible for the memory
+    /// previously managed by the `String`. The only way to release it is to
+    /// turn the parts back into a `String` with [`from_raw_parts`], which
+    /// lets the destructor clean up.
+    ///
+    /// [`from_raw_parts`]: #method.from_raw_parts
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vec_into_raw_parts)]
+    ///
+    /// let s = String::from("hello");
+    ///
+    /// let (ptr, len, cap) = s.into_raw_parts();
+    ///
+    /// let rebuilt = unsafe { String::from_raw_parts(ptr, len, cap) };
+    /// assert_eq!(rebuilt, "hello");
+    /// ```
+    #[unstable(feature = "vec_into_raw_parts", issue = "0")]
+    pub fn into_raw_parts(self) -> (*mut u8, usize, usize) {
+        self.vec.into_raw_parts()
+    }
+
     /// Converts a vector of bytes to a `String` without checking that the
     /// string contains valid UTF-8.
     ///
@@ -805,6 +834,30 @@ impl String {
         self.vec.extend_from_slice(string.as_bytes())
     }
 
+    /// Appends the decimal representation of a number to the end of this
+    /// `String`.
+    ///
+    /// The output is the same as that of `write!(s, "{}", n)`, but it is
+    /// produced without going through the formatting machinery, which makes
+    /// it considerably faster.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(string_push_num)]
+    ///
+    /// let mut s = String::from("x=");
+    /// s.push_num(-42);
+    /// s.push_str(", y=");
+    /// s.push_num(0.1f32);
+    /// assert_eq!(s, "x=-42, y=0.1");
+    /// ```
+    #[inline]
+    #[unstable(feature = "string_push_num", issue = "0")]
+    pub fn push_num<N: PushNum>(&mut self, n: N) {
+        n.push_to(self)
+    }
+
     /// Returns this `String`'s capacity, in bytes.
     ///
     /// # Examples
@@ -822,6 +875,26 @@ impl String {
         self.vec.capacity()
     }
 
+    /// Returns the number of bytes that this `String`'s allocation could
+    /// hold, which may be more than its [`capacity`] if the allocator
+    /// rounded the size of the allocation up.
+    ///
+    /// [`capacity`]: #method.capacity
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(allocated_capacity)]
+    ///
+    /// let s = String::with_capacity(10);
+    /// assert!(s.allocated_capacity() >= s.capacity());
+    /// ```
+    #[inline]
+    #[unstable(feature = "allocated_capacity", issue = "0")]
+    pub fn allocated_capacity(&self) -> usize {
+        self.vec.allocated_capacity()
+    }
+
     /// Ensures that this `String`'s capacity is at least `additional` bytes
     /// larger than its length.
     ///
@@ -873,6 +946,35 @@ impl String {
         self.vec.reserve(additional)
     }
 
+    /// Reserves capacity for at least `additional` more bytes, letting
+    /// `policy` decide how much to grow by if the `String` is full.
+    ///
+    /// See [`Vec::reserve_with`] for details.
+    ///
+    /// [`Vec::reserve_with`]: ../vec/struct.Vec.html#method.reserve_with
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(growth_policy)]
+    ///
+    /// use std::collections::growth::{Doubling, MaxStep};
+    ///
+    /// let mut s = String::with_capacity(1000);
+    /// s.extend((0..1000).map(|_| 'x'));
+    /// s.reserve_with(1, &MaxStep::new(Doubling, 64));
+    /// assert_eq!(s.capacity(), 1064);
+    /// ```
+    #[inline]
+    #[unstable(feature = "growth_policy", issue = "0")]
+    pub fn reserve_with<P: GrowthPolicy>(&mut self, additional: usize, policy: &P) {
+        self.vec.reserve_with(additional, policy)
+    }
+
     /// Ensures that this `String`'s capacity is `additional` bytes
     /// larger than its length.
     ///
@@ -1517,12 +1619,13 @@ impl String {
         }
     }
 
-    /// Creates a splicing iterator that removes the specified range in the string,
-    /// and replaces it with the given string.
-    /// The given string doesn't need to be the same length as the range.
+    /// Removes the specified range in the string, and replaces it with the
+    /// given string. The given string doesn't need to be the same length as
+    /// the range.
     ///
-    /// Note: Unlike [`Vec::splice`], the replacement happens eagerly, and this
-    /// method does not return the removed chars.
+    /// The bytes after the range are moved at most once, so this is `O(n)`
+    /// in the length of the string regardless of the size of either the
+    /// range or the replacement.
     ///
     /// # Panics
     ///
@@ -1530,44 +1633,63 @@ impl String {
     /// boundary, or if they're out of bounds.
     ///
     /// [`char`]: ../../std/primitive.char.html
-    /// [`Vec::splice`]: ../../std/vec/struct.Vec.html#method.splice
     ///
     /// # Examples
     ///
     /// Basic usage:
     ///
     /// ```
-    /// #![feature(splice)]
+    /// #![feature(replace_range)]
     /// let mut s = String::from("α is alpha, β is beta");
     /// let beta_offset = s.find('β').unwrap_or(s.len());
     ///
     /// // Replace the range up until the β from the string
-    /// s.splice(..beta_offset, "Α is capital alpha; ");
+    /// s.replace_range(..beta_offset, "Α is capital alpha; ");
     /// assert_eq!(s, "Α is capital alpha; β is beta");
     /// ```
-    #[unstable(feature = "splice", reason = "recently added", issue = "44643")]
-    pub fn splice<R>(&mut self, range: R, replace_with: &str)
+    #[unstable(feature = "replace_range", reason = "recently added", issue = "44643")]
+    pub fn replace_range<R>(&mut self, range: R, replace_with: &str)
         where R: RangeBounds<usize>
     {
-        // Memory safety
-        //
-        // The String version of Splice does not have the memory safety issues
-        // of the vector version. The data is just plain bytes.
-
-        match range.start() {
-             Included(&n) => assert!(self.is_char_boundary(n)),
-             Excluded(&n) => assert!(self.is_char_boundary(n + 1)),
-             Unbounded => {},
+        let len = self.len();
+        let start = match range.start() {
+             Included(&n) => n,
+             Excluded(&n) => n + 1,
+             Unbounded => 0,
         };
-        match range.end() {
-             Included(&n) => assert!(self.is_char_boundary(n + 1)),
-             Excluded(&n) => assert!(self.is_char_boundary(n)),
-             Unbounded => {},
+        let end = match range.end() {
+             Included(&n) => n + 1,
+             Excluded(&n) => n,
+             Unbounded => len,
         };
+        assert!(start <= end);
+        assert!(self.is_char_boundary(start));
+        assert!(self.is_char_boundary(end));
 
+        let amt = replace_with.len();
+        if amt > end - start {
+            self.vec.reserve(amt - (end - start));
+        }
         unsafe {
-            self.as_mut_vec()
-        }.splice(range, replace_with.bytes());
+            let ptr = self.vec.as_mut_ptr();
+            ptr::copy(ptr.offset(end as isize),
+                      ptr.offset((start + amt) as isize),
+                      len - end);
+            ptr::copy_nonoverlapping(replace_with.as_ptr(),
+                                     ptr.offset(start as isize),
+                                     amt);
+            self.vec.set_len(len - (end - start) + amt);
+        }
+    }
+
+    /// Removes the specified range in the string, and replaces it with the
+    /// given string.
+    #[unstable(feature = "splice", reason = "recently added", issue = "44643")]
+    #[rustc_deprecated(since = "1.27.0", reason = "renamed to replace_range")]
+    pub fn splice<R>(&mut self, range: R, replace_with: &str)
+        where R: RangeBounds<usize>
+    {
+        self.replace_range(range, replace_with)
     }
 
     /// Converts this `String` into a [`Box`]`<`[`str`]`>`.
@@ -2180,6 +2302,135 @@ impl ToString for String {
     }
 }
 
+/// A number that can be appended to a `String` in decimal.
+///
+/// This is implemented for all of the primitive integer and floating point
+/// types, and is what [`String::push_num`] accepts.
+///
+/// [`String::push_num`]: struct.String.html#method.push_num
+#[unstable(feature = "string_push_num", issue = "0")]
+pub trait PushNum: Copy {
+    #[doc(hidden)]
+    fn push_to(self, buf: &mut String);
+}
+
+const DEC_DIGITS_LUT: &'static [u8] =
+    b"0001020304050607080910111213141516171819\
+      2021222324252627282930313233343536373839\
+      4041424344454647484950515253545556575859\
+      6061626364656667686970717273747576777879\
+      8081828384858687888990919293949596979899";
+
+// Writes the digits of `n` two at a time from the end of a stack buffer,
+// the same way `Display` for integers does.
+macro_rules! push_decimal {
+    ($name:ident, $u:ty, $digits:expr) => {
+        fn $name(buf: &mut String, mut n: $u, negative: bool) {
+            let mut digits = [0u8; $digits];
+            let mut curr = digits.len();
+            while n >= 100 {
+                let d = (n % 100) as usize * 2;
+                n /= 100;
+                curr -= 2;
+                digits[curr..curr + 2].copy_from_slice(&DEC_DIGITS_LUT[d..d + 2]);
+            }
+            if n >= 10 {
+                let d = n as usize * 2;
+                curr -= 2;
+                digits[curr..curr + 2].copy_from_slice(&DEC_DIGITS_LUT[d..d + 2]);
+            } else {
+                curr -= 1;
+                digits[curr] = b'0' + n as u8;
+            }
+            if negative {
+                buf.push('-');
+            }
+            buf.push_str(unsafe { str::from_utf8_unchecked(&digits[curr..]) });
+        }
+    }
+}
+
+push_decimal!(push_decimal_u64, u64, 20);
+push_decimal!(push_decimal_u128, u128, 39);
+
+macro_rules! push_num_unsigned {
+    ($via:ident as $u:ty: $($t:ty)*) => {$(
+        #[unstable(feature = "string_push_num", issue = "0")]
+        impl PushNum for $t {
+            #[inline]
+            fn push_to(self, buf: &mut String) {
+                $via(buf, self as $u, false)
+            }
+        }
+    )*}
+}
+
+macro_rules! push_num_signed {
+    ($via:ident as $u:ty: $($t:ty)*) => {$(
+        #[unstable(feature = "string_push_num", issue = "0")]
+        impl PushNum for $t {
+            #[inline]
+            fn push_to(self, buf: &mut String) {
+                // Widening first keeps the magnitude of `MIN` representable.
+                let n = self as i128;
+                $via(buf, (if n < 0 { n.wrapping_neg() } else { n }) as $u, n < 0)
+            }
+        }
+    )*}
+}
+
+push_num_unsigned!(push_decimal_u64 as u64: u8 u16 u32 u64 usize);
+push_num_unsigned!(push_decimal_u128 as u128: u128);
+push_num_signed!(push_decimal_u64 as u64: i8 i16 i32 i64 isize);
+push_num_signed!(push_decimal_u128 as u128: i128);
+
+fn push_float<T: flt2dec::DecodableFloat>(buf: &mut String, n: T) {
+    let mut digits = [0u8; flt2dec::MAX_SIG_DIGITS];
+    let mut parts = [flt2dec::Part::Zero(0); 4];
+    let formatted = flt2dec::to_shortest_str(flt2dec::strategy::grisu::format_shortest, n,
+                                             flt2dec::Sign::Minus, 0, false,
+                                             &mut digits, &mut parts);
+    let len = formatted.len();
+    buf.reserve(len);
+    unsafe {
+        let vec = buf.as_mut_vec();
+        let start = vec.len();
+        vec.set_len(start + len);
+        // The parts are all ASCII, and fill exactly `len` bytes.
+        formatted.write(&mut vec[start..]);
+    }
+}
+
+macro_rules! push_num_float {
+    ($($t:ty)*) => {$(
+        #[unstable(feature = "string_push_num", issue = "0")]
+        impl PushNum for $t {
+            #[inline]
+            fn push_to(self, buf: &mut String) {
+                push_float(buf, self)
+            }
+        }
+    )*}
+}
+
+push_num_float!(f32 f64);
+
+macro_rules! to_string_num {
+    ($($t:ty)*) => {$(
+        #[stable(feature = "num_to_string_specialization", since = "1.27.0")]
+        impl ToString for $t {
+            #[inline]
+            fn to_string(&self) -> String {
+                let mut buf = String::new();
+                buf.push_num(*self);
+                buf
+            }
+        }
+    )*}
+}
+
+to_string_num!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64);
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl AsRef<str> for String {
     #[inline]
@@ -2188,6 +2439,14 @@ impl AsRef<str> for String {
     }
 }
 
+#[stable(feature = "string_as_mut", since = "1.27.0")]
+impl AsMut<str> for String {
+    #[inline]
+    fn as_mut(&mut self) -> &mut str {
+        self
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl AsRef<[u8]> for String {
     #[inline]
@@ -2283,6 +2542,14 @@ impl fmt::Write for String {
         self.push(c);
         Ok(())
     }
+
+    #[inline]
+    fn write_fmt(&mut self, args: fmt::Arguments) -> fmt::Result {
+        // Reserve once up front like `format!` does, rather than growing
+        // piece by piece as the arguments are written.
+        self.reserve(args.estimated_capacity());
+        fmt::write(self, args)
+    }
 }
 
 /// A draining iterator for `String`.
@@ -2354,3 +2621,216 @@ impl<'a> DoubleEndedIterator for Drain<'a> {
 
 #[stable(feature = "fused", since = "1.26.0")]
 impl<'a> FusedIterator for Drain<'a> {}
+
+/// A builder for assembling a [`String`] out of many small pieces.
+///
+/// Pushing pieces onto a `String` one at a time can grow its buffer many
+/// times over while a line is put together. A `StringBuilder` instead
+/// remembers the pieces passed to [`append`], and [`build`] estimates the
+/// length of the result up front so that the buffer is reserved in one go
+/// before the pieces are written out.
+///
+/// String slices, characters, integers and floating point numbers can be
+/// appended; numbers are written the same way as by their `Display`
+/// implementations.
+///
+/// [`String`]: struct.String.html
+/// [`append`]: #method.append
+/// [`build`]: #method.build
+///
+/// # Examples
+///
+/// ```
+/// #![feature(string_builder)]
+///
+/// use std::string::StringBuilder;
+///
+/// let name = String::from("x");
+/// let s = StringBuilder::new()
+///     .append("let ")
+///     .append(&name)
+///     .append(": f32 = ")
+///     .append(-12)
+///     .append('.')
+///     .append(5u8)
+///     .append(';')
+///     .build();
+/// assert_eq!(s, "let x: f32 = -12.5;");
+/// ```
+#[unstable(feature = "string_builder", issue = "0")]
+#[derive(Clone, Debug, Default)]
+pub struct StringBuilder<'a> {
+    pieces: Vec<Piece<'a>>,
+}
+
+/// A single piece of a [`StringBuilder`].
+///
+/// This is created through the `From` implementations for the types that
+/// can be passed to [`StringBuilder::append`].
+///
+/// [`StringBuilder`]: struct.StringBuilder.html
+/// [`StringBuilder::append`]: struct.StringBuilder.html#method.append
+#[unstable(feature = "string_builder", issue = "0")]
+#[derive(Clone, Copy, Debug)]
+pub struct Piece<'a> {
+    kind: PieceKind<'a>,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum PieceKind<'a> {
+    Str(&'a str),
+    Char(char),
+    Signed(i64),
+    Unsigned(u64),
+    F32(f32),
+    F64(f64),
+}
+
+impl<'a> StringBuilder<'a> {
+    /// Creates a new, empty `StringBuilder`.
+    #[unstable(feature = "string_builder", issue = "0")]
+    pub fn new() -> StringBuilder<'a> {
+        StringBuilder { pieces: Vec::new() }
+    }
+
+    /// Appends a piece to the end of the string being built.
+    ///
+    /// Returns `self` so that calls can be chained.
+    #[unstable(feature = "string_builder", issue = "0")]
+    pub fn append<P: Into<Piece<'a>>>(&mut self, piece: P) -> &mut StringBuilder<'a> {
+        self.pieces.push(piece.into());
+        self
+    }
+
+    /// Returns an estimate of the length in bytes of the built string.
+    ///
+    /// The estimate is exact unless floating point numbers were appended, whose
+    /// length is only guessed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(string_builder)]
+    ///
+    /// use std::string::StringBuilder;
+    ///
+    /// let mut b = StringBuilder::new();
+    /// b.append("abc").append('ß').append(-100).append(0u64);
+    /// assert_eq!(b.len_hint(), 3 + 2 + 4 + 1);
+    /// ```
+    #[unstable(feature = "string_builder", issue = "0")]
+    pub fn len_hint(&self) -> usize {
+        self.pieces.iter().fold(0, |len, piece| len.saturating_add(piece.len_hint()))
+    }
+
+    /// Writes all appended pieces to the end of `buf`, reserving space for them
+    /// once beforehand.
+    #[unstable(feature = "string_builder", issue = "0")]
+    pub fn build_into(&self, buf: &mut String) {
+        buf.reserve(self.len_hint());
+        for piece in &self.pieces {
+            piece.write_to(buf);
+        }
+    }
+
+    /// Builds a new `String` out of all appended pieces.
+    #[unstable(feature = "string_builder", issue = "0")]
+    pub fn build(&self) -> String {
+        let mut buf = String::new();
+        self.build_into(&mut buf);
+        buf
+    }
+
+    /// Removes all appended pieces, keeping the allocated memory for reuse.
+    #[unstable(feature = "string_builder", issue = "0")]
+    pub fn clear(&mut self) {
+        self.pieces.clear();
+    }
+}
+
+impl<'a> Piece<'a> {
+    fn len_hint(&self) -> usize {
+        match self.kind {
+            PieceKind::Str(s) => s.len(),
+            PieceKind::Char(c) => c.len_utf8(),
+            PieceKind::Signed(n) if n < 0 => 1 + decimal_len((n as u64).wrapping_neg()),
+            PieceKind::Signed(n) => decimal_len(n as u64),
+            PieceKind::Unsigned(n) => decimal_len(n),
+            // Most floats that show up in text print in well under this many
+            // bytes; anything longer just grows the buffer as usual.
+            PieceKind::F32(_) | PieceKind::F64(_) => 16,
+        }
+    }
+
+    fn write_to(&self, buf: &mut String) {
+        use core::fmt::Write;
+
+        // Writing to a `String` cannot fail.
+        let _ = match self.kind {
+            PieceKind::Str(s) => buf.write_str(s),
+            PieceKind::Char(c) => buf.write_char(c),
+            PieceKind::Signed(n) => write!(buf, "{}", n),
+            PieceKind::Unsigned(n) => write!(buf, "{}", n),
+            PieceKind::F32(n) => write!(buf, "{}", n),
+            PieceKind::F64(n) => write!(buf, "{}", n),
+        };
+    }
+}
+
+fn decimal_len(mut n: u64) -> usize {
+    let mut len = 1;
+    while n >= 10 {
+        n /= 10;
+        len += 1;
+    }
+    len
+}
+
+#[unstable(feature = "string_builder", issue = "0")]
+impl<'a> From<&'a str> for Piece<'a> {
+    fn from(s: &'a str) -> Piece<'a> {
+        Piece { kind: PieceKind::Str(s) }
+    }
+}
+
+#[unstable(feature = "string_builder", issue = "0")]
+impl<'a> From<&'a String> for Piece<'a> {
+    fn from(s: &'a String) -> Piece<'a> {
+        Piece { kind: PieceKind::Str(s) }
+    }
+}
+
+#[unstable(feature = "string_builder", issue = "0")]
+impl<'a> From<char> for Piece<'a> {
+    fn from(c: char) -> Piece<'a> {
+        Piece { kind: PieceKind::Char(c) }
+    }
+}
+
+#[unstable(feature = "string_builder", issue = "0")]
+impl<'a> From<f32> for Piece<'a> {
+    fn from(n: f32) -> Piece<'a> {
+        Piece { kind: PieceKind::F32(n) }
+    }
+}
+
+#[unstable(feature = "string_builder", issue = "0")]
+impl<'a> From<f64> for Piece<'a> {
+    fn from(n: f64) -> Piece<'a> {
+        Piece { kind: PieceKind::F64(n) }
+    }
+}
+
+macro_rules! impl_piece_from_int {
+    ($kind:ident: $($t:ty)*) => ($(
+        #[unstable(feature = "string_builder", issue = "0")]
+        impl<'a> From<$t> for Piece<'a> {
+            fn from(n: $t) -> Piece<'a> {
+                Piece { kind: PieceKind::$kind(n as _) }
+            }
+        }
+    )*)
+}
+
+impl_piece_from_int!(Signed: i8 i16 i32 i64 isize);
+impl_piece_from_int!(Unsigned: u8 u16 u32 u64 usize);
@@ -0,0 +1,352 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A compact table of strings stored back to back in one buffer.
+//!
+//! A `Vec<String>` spends a pointer, a length and a capacity on every
+//! string, plus a separate allocation for its contents. [`StringTable`]
+//! keeps all of the strings in a single `String` and only records where each
+//! one ends, which makes large numbers of short strings far cheaper to hold
+//! and lets the whole table be written out as one blob.
+//!
+//! [`StringTable`]: struct.StringTable.html
+
+#![unstable(feature = "string_table", issue = "0")]
+
+use core::fmt;
+use core::iter::{FromIterator, FusedIterator};
+use core::ops::{Index, Range};
+use core::str;
+
+use string::String;
+use vec::Vec;
+
+/// A list of strings stored contiguously in one buffer.
+///
+/// Strings are pushed onto the end of the table and referred to by their
+/// index, the order in which they were pushed. [`sort`] reorders them so
+/// that [`binary_search`] can find a string by its contents, and [`dedup`]
+/// removes repeated strings from a sorted table.
+///
+/// [`sort`]: #method.sort
+/// [`binary_search`]: #method.binary_search
+/// [`dedup`]: #method.dedup
+///
+/// # Examples
+///
+/// ```
+/// #![feature(string_table)]
+///
+/// use std::collections::string_table::StringTable;
+///
+/// let mut table = StringTable::new();
+/// let apple = table.push("apple");
+/// table.push("cherry");
+/// table.push("banana");
+/// assert_eq!(&table[apple], "apple");
+///
+/// table.sort();
+/// assert_eq!(table.binary_search("banana"), Ok(1));
+/// assert_eq!(table.binary_search("blueberry"), Err(2));
+///
+/// let copy = StringTable::from_bytes(&table.to_bytes()).unwrap();
+/// assert_eq!(copy, table);
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct StringTable {
+    buf: String,
+    ends: Vec<usize>,
+}
+
+impl StringTable {
+    /// Creates an empty table.
+    pub fn new() -> StringTable {
+        StringTable { buf: String::new(), ends: Vec::new() }
+    }
+
+    /// Creates an empty table with room for `strings` strings with a total
+    /// length of `bytes` bytes.
+    pub fn with_capacity(strings: usize, bytes: usize) -> StringTable {
+        StringTable { buf: String::with_capacity(bytes), ends: Vec::with_capacity(strings) }
+    }
+
+    /// Returns the number of strings in the table.
+    pub fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Returns `true` if the table holds no strings.
+    pub fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+
+    /// Returns the total length in bytes of the strings in the table.
+    pub fn total_len(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Removes all strings from the table, keeping its allocations.
+    pub fn clear(&mut self) {
+        self.buf.clear();
+        self.ends.clear();
+    }
+
+    /// Appends a string to the table and returns its index.
+    pub fn push(&mut self, s: &str) -> usize {
+        self.buf.push_str(s);
+        self.ends.push(self.buf.len());
+        self.ends.len() - 1
+    }
+
+    /// Removes the last string from the table and returns it, or `None` if
+    /// the table is empty.
+    pub fn pop(&mut self) -> Option<String> {
+        self.ends.pop()?;
+        let start = self.ends.last().cloned().unwrap_or(0);
+        Some(self.buf.split_off(start))
+    }
+
+    /// Returns the string at `index`, or `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&str> {
+        self.range(index).map(|range| &self.buf[range])
+    }
+
+    /// Returns the last string in the table, or `None` if it is empty.
+    pub fn last(&self) -> Option<&str> {
+        match self.len() {
+            0 => None,
+            len => self.get(len - 1),
+        }
+    }
+
+    /// Returns an iterator over the strings in the table, in index order.
+    pub fn iter(&self) -> Iter {
+        Iter { table: self, front: 0, back: self.len() }
+    }
+
+    /// Sorts the strings in the table.
+    ///
+    /// The indices returned by [`push`] no longer refer to the same strings
+    /// afterwards.
+    ///
+    /// [`push`]: #method.push
+    pub fn sort(&mut self) {
+        let mut order: Vec<usize> = (0..self.len()).collect();
+        order.sort_by(|&a, &b| self[a].cmp(&self[b]));
+        let mut sorted = StringTable::with_capacity(self.len(), self.total_len());
+        for &i in &order {
+            sorted.push(&self[i]);
+        }
+        *self = sorted;
+    }
+
+    /// Removes consecutive repeated strings, so that a sorted table holds
+    /// every string only once.
+    pub fn dedup(&mut self) {
+        let mut deduped = StringTable::with_capacity(self.len(), self.total_len());
+        for s in self.iter() {
+            if deduped.last() != Some(s) {
+                deduped.push(s);
+            }
+        }
+        *self = deduped;
+    }
+
+    /// Searches a sorted table for a string, as [`slice::binary_search`]
+    /// does.
+    ///
+    /// Returns `Ok` with the index of the string if it is found, and `Err`
+    /// with the index it could be inserted at to keep the table sorted
+    /// otherwise.
+    ///
+    /// [`slice::binary_search`]: ../../std/primitive.slice.html#method.binary_search
+    pub fn binary_search(&self, s: &str) -> Result<usize, usize> {
+        let (mut low, mut high) = (0, self.len());
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if &self[mid] < s {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        if low < self.len() && &self[low] == s { Ok(low) } else { Err(low) }
+    }
+
+    /// Encodes the table as a single blob of bytes that [`from_bytes`]
+    /// turns back into an equal table.
+    ///
+    /// The blob holds the number of strings and the end offset of each as
+    /// little-endian `u64`s, followed by the contents of the strings.
+    ///
+    /// [`from_bytes`]: #method.from_bytes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(8 * (self.len() + 1) + self.total_len());
+        push_u64(&mut bytes, self.len() as u64);
+        for &end in &self.ends {
+            push_u64(&mut bytes, end as u64);
+        }
+        bytes.extend_from_slice(self.buf.as_bytes());
+        bytes
+    }
+
+    /// Decodes a table from a blob created by [`to_bytes`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the blob is truncated, its offsets are out of
+    /// order or do not fall on character boundaries, or the strings are not
+    /// valid UTF-8.
+    ///
+    /// [`to_bytes`]: #method.to_bytes
+    pub fn from_bytes(bytes: &[u8]) -> Result<StringTable, InvalidBlob> {
+        let (count, mut rest) = read_u64(bytes)?;
+        if count > (rest.len() / 8) as u64 {
+            return Err(InvalidBlob(()));
+        }
+        let mut ends = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let (end, tail) = read_u64(rest)?;
+            if end < ends.last().cloned().unwrap_or(0) as u64 {
+                return Err(InvalidBlob(()));
+            }
+            ends.push(end as usize);
+            rest = tail;
+        }
+        let buf = str::from_utf8(rest).map_err(|_| InvalidBlob(()))?;
+        if ends.last().cloned().unwrap_or(0) != buf.len() ||
+           !ends.iter().all(|&end| buf.is_char_boundary(end)) {
+            return Err(InvalidBlob(()));
+        }
+        Ok(StringTable { buf: String::from(buf), ends })
+    }
+
+    fn range(&self, index: usize) -> Option<Range<usize>> {
+        let end = *self.ends.get(index)?;
+        let start = if index == 0 { 0 } else { self.ends[index - 1] };
+        Some(start..end)
+    }
+}
+
+fn push_u64(bytes: &mut Vec<u8>, n: u64) {
+    for i in 0..8 {
+        bytes.push((n >> (8 * i)) as u8);
+    }
+}
+
+fn read_u64(bytes: &[u8]) -> Result<(u64, &[u8]), InvalidBlob> {
+    if bytes.len() < 8 {
+        return Err(InvalidBlob(()));
+    }
+    let (head, tail) = bytes.split_at(8);
+    let n = head.iter().rev().fold(0, |n, &b| n << 8 | b as u64);
+    Ok((n, tail))
+}
+
+impl Index<usize> for StringTable {
+    type Output = str;
+
+    fn index(&self, index: usize) -> &str {
+        match self.range(index) {
+            Some(range) => &self.buf[range],
+            None => panic!("index out of bounds: the len is {} but the index is {}",
+                           self.len(), index),
+        }
+    }
+}
+
+impl fmt::Debug for StringTable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a> Extend<&'a str> for StringTable {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        for s in iter {
+            self.push(s);
+        }
+    }
+}
+
+impl<'a> FromIterator<&'a str> for StringTable {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> StringTable {
+        let mut table = StringTable::new();
+        table.extend(iter);
+        table
+    }
+}
+
+impl<'a> IntoIterator for &'a StringTable {
+    type Item = &'a str;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+/// An iterator over the strings in a `StringTable`.
+///
+/// This `struct` is created by the [`iter`] method on [`StringTable`].
+///
+/// [`iter`]: struct.StringTable.html#method.iter
+/// [`StringTable`]: struct.StringTable.html
+#[derive(Clone, Debug)]
+pub struct Iter<'a> {
+    table: &'a StringTable,
+    front: usize,
+    back: usize,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(&self.table[self.front - 1])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a> DoubleEndedIterator for Iter<'a> {
+    fn next_back(&mut self) -> Option<&'a str> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(&self.table[self.back])
+    }
+}
+
+impl<'a> ExactSizeIterator for Iter<'a> {}
+
+impl<'a> FusedIterator for Iter<'a> {}
+
+/// The error returned by [`StringTable::from_bytes`] for a blob that was not
+/// produced by [`StringTable::to_bytes`].
+///
+/// [`StringTable::from_bytes`]: struct.StringTable.html#method.from_bytes
+/// [`StringTable::to_bytes`]: struct.StringTable.html#method.to_bytes
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvalidBlob(());
+
+impl fmt::Display for InvalidBlob {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("invalid string table blob")
+    }
+}
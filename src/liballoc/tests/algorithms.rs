@@ -0,0 +1,69 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::collections::algorithms::topo_sort;
+
+#[test]
+fn test_topo_sort() {
+    let graph = vec![
+        (5, vec![11]),
+        (7, vec![11, 8]),
+        (3, vec![8, 10]),
+        (11, vec![2, 9, 10]),
+        (8, vec![9]),
+    ];
+    let order = topo_sort(graph.clone()).unwrap();
+    assert_eq!(order, [2, 9, 8, 10, 3, 11, 5, 7]);
+
+    // Every node comes after its dependencies.
+    for (node, deps) in graph {
+        let at = order.iter().position(|&n| n == node).unwrap();
+        assert!(deps.iter().all(|dep| order[..at].contains(dep)));
+    }
+
+    assert_eq!(topo_sort(Vec::<(u8, Vec<u8>)>::new()), Ok(vec![]));
+    assert_eq!(topo_sort(vec![(1, vec![])]), Ok(vec![1]));
+    // Repeated dependencies are fine.
+    assert_eq!(topo_sort(vec![(1, vec![0, 0]), (0, vec![])]), Ok(vec![0, 1]));
+}
+
+#[test]
+fn test_topo_sort_is_deterministic() {
+    let mut hashed = HashMap::new();
+    let mut sorted = BTreeMap::new();
+    for i in 0..100u32 {
+        let deps: BTreeSet<u32> = (0..i).filter(|j| (i * j) % 7 == 3).collect();
+        hashed.insert(i, deps.iter().cloned().collect::<Vec<_>>());
+        sorted.insert(i, deps);
+    }
+    let order = topo_sort(&sorted).unwrap();
+    assert_eq!(order.len(), 100);
+    assert_eq!(topo_sort(&hashed), Ok(order.clone()));
+    assert_eq!(topo_sort(hashed), Ok(order.into_iter().cloned().collect()));
+}
+
+#[test]
+fn test_topo_sort_cycle() {
+    let err = topo_sort(vec![(1, vec![1])]).unwrap_err();
+    assert_eq!(err.cycle(), [1]);
+
+    // 0 and 4 only depend on the cycle, they aren't part of it.
+    let graph = vec![(0, vec![1]), (1, vec![2]), (2, vec![3]), (3, vec![1]), (4, vec![3])];
+    let err = topo_sort(graph).unwrap_err();
+    assert_eq!(err.cycle(), [1, 2, 3]);
+    assert_eq!(err.to_string(), "dependency cycle detected");
+    assert_eq!(err.into_cycle(), [1, 2, 3]);
+
+    // The cycle can be reached through nodes outside of it.
+    let graph = vec![("a", vec!["b"]), ("b", vec!["c"]), ("c", vec!["d"]), ("d", vec!["c"])];
+    let err = topo_sort(graph).unwrap_err();
+    assert_eq!(err.cycle(), ["c", "d"]);
+}
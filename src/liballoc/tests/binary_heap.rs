@@ -10,7 +10,7 @@
 
 use std::cmp;
 use std::collections::BinaryHeap;
-use std::collections::binary_heap::{Drain, PeekMut};
+use std::collections::binary_heap::{Drain, HeapStorage, LazyHeap, PeekMut};
 use std::panic::{self, AssertUnwindSafe};
 use std::sync::atomic::{AtomicUsize, ATOMIC_USIZE_INIT, Ordering};
 
@@ -112,6 +112,17 @@ fn test_peek_mut_pop() {
     assert_eq!(heap.peek(), Some(&9));
 }
 
+#[test]
+fn test_min_heap_reverse() {
+    let data = vec![5, 9, 3, 7, 1];
+    let mut heap: BinaryHeap<_> = data.into_iter().map(cmp::Reverse).collect();
+    assert_eq!(heap.peek(), Some(&cmp::Reverse(1)));
+    heap.push(cmp::Reverse(0));
+    let popped: Vec<_> = (0..heap.len()).map(|_| heap.pop().unwrap().0).collect();
+    assert_eq!(popped, [0, 1, 3, 5, 7, 9]);
+    assert!(heap.is_empty());
+}
+
 #[test]
 fn test_push() {
     let mut heap = BinaryHeap::from(vec![2, 4, 9]);
@@ -156,6 +167,37 @@ fn test_push_unique() {
     assert!(**heap.peek().unwrap() == 103);
 }
 
+#[test]
+fn test_push_pop() {
+    let mut heap = BinaryHeap::new();
+    assert_eq!(heap.push_pop(5), 5);
+    assert!(heap.is_empty());
+
+    // Keep the five smallest items seen, as a max-heap of size five.
+    let data = vec![2, 4, 6, 2, 1, 8, 10, 3, 5, 7, 0, 9, 1];
+    let (first, rest) = data.split_at(5);
+    heap.extend(first.iter().cloned());
+    let mut evicted: Vec<_> = rest.iter().map(|&x| heap.push_pop(x)).collect();
+    assert_eq!(heap.clone().into_sorted_vec(), [0, 1, 1, 2, 2]);
+
+    evicted.extend(heap.into_vec());
+    let mut all = data.clone();
+    all.sort();
+    evicted.sort();
+    assert_eq!(evicted, all);
+}
+
+#[test]
+fn test_replace_top() {
+    let mut heap = BinaryHeap::new();
+    assert_eq!(heap.replace_top(box 3), None);
+    assert_eq!(heap.replace_top(box 7), Some(box 3));
+    heap.extend(vec![box 2, box 9, box 4]);
+    assert_eq!(heap.replace_top(box 1), Some(box 9));
+    assert_eq!(heap.replace_top(box 8), Some(box 7));
+    assert_eq!(heap.into_sorted_vec(), [box 1, box 2, box 4, box 8]);
+}
+
 fn check_to_vec(mut data: Vec<i32>) {
     let heap = BinaryHeap::from(data.clone());
     let mut v = heap.clone().into_vec();
@@ -361,3 +403,161 @@ fn panic_safe() {
         }
     }
 }
+
+#[test]
+fn test_debug_dot() {
+    let heap = BinaryHeap::from(vec![5, 2, 8, 1, 9]);
+    let dot = heap.debug_dot();
+    assert!(dot.starts_with("digraph BinaryHeap {\n"));
+    assert!(dot.ends_with("}\n"));
+    assert!(dot.contains("n0 [label=\"9\"];"));
+    assert_eq!(dot.lines().filter(|l| l.contains(" -> ")).count(), 4);
+    assert!(dot.contains("n1 -> n3;"));
+    assert!(dot.contains("n1 -> n4;"));
+
+    let heap = BinaryHeap::from(vec!["\"quoted\""]);
+    assert!(heap.debug_dot().contains(r#"n0 [label="\"\\\"quoted\\\"\""];"#));
+}
+
+struct Fixed {
+    buf: [i32; 8],
+    len: usize,
+}
+
+impl HeapStorage<i32> for Fixed {
+    fn as_slice(&self) -> &[i32] {
+        &self.buf[..self.len]
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [i32] {
+        &mut self.buf[..self.len]
+    }
+
+    fn push(&mut self, item: i32) -> Result<(), i32> {
+        if self.len == self.buf.len() {
+            return Err(item);
+        }
+        self.buf[self.len] = item;
+        self.len += 1;
+        Ok(())
+    }
+
+    fn pop(&mut self) -> Option<i32> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        Some(self.buf[self.len])
+    }
+}
+
+#[test]
+fn test_fixed_storage() {
+    let mut heap = BinaryHeap::from_storage(Fixed { buf: [0; 8], len: 0 });
+    assert!(heap.is_empty());
+    assert_eq!(heap.peek(), None);
+
+    for &x in &[5, -3, 9, 0, 9, 2, 7, 1] {
+        assert_eq!(heap.try_push(x), Ok(()));
+    }
+    assert_eq!(heap.len(), 8);
+    assert_eq!(heap.try_push(4), Err(4));
+    assert_eq!(heap.iter().count(), 8);
+
+    let mut popped = vec![];
+    while let Some(x) = heap.pop() {
+        popped.push(x);
+    }
+    assert_eq!(popped, [9, 9, 7, 5, 2, 1, 0, -3]);
+    assert_eq!(heap.into_storage().len, 0);
+}
+
+#[test]
+fn test_fixed_storage_heapifies() {
+    let storage = Fixed { buf: [3, 1, 4, 1, 5, 9, 2, 6], len: 8 };
+    let mut heap = BinaryHeap::from_storage(storage);
+    assert_eq!(heap.peek(), Some(&9));
+    assert_eq!(heap.pop(), Some(9));
+    assert_eq!(heap.try_push(8), Ok(()));
+    assert_eq!(heap.pop(), Some(8));
+    assert_eq!(heap.pop(), Some(6));
+}
+
+#[test]
+fn test_lazy_heap_remove() {
+    let mut heap = LazyHeap::new();
+    let handles: Vec<_> = (0..10).map(|i| heap.push(i)).collect();
+    assert_eq!(heap.len(), 10);
+
+    assert!(heap.mark_removed(handles[3]));
+    assert!(!heap.mark_removed(handles[3]));
+    assert!(heap.mark_removed(handles[9]));
+    assert!(!heap.contains(handles[9]));
+    assert_eq!(heap.len(), 8);
+    // The greatest item is discarded right away.
+    assert_eq!(heap.tombstones(), 1);
+    assert_eq!(heap.peek(), Some(&8));
+
+    assert!(heap.mark_removed(handles[8]));
+    assert!(heap.mark_removed(handles[7]));
+    assert_eq!(heap.peek(), Some(&6));
+    assert_eq!(heap.pop(), Some(6));
+    assert!(!heap.mark_removed(handles[6]));
+
+    let mut rest = vec![];
+    while let Some(x) = heap.pop() {
+        rest.push(x);
+    }
+    assert_eq!(rest, [5, 4, 2, 1, 0]);
+    assert_eq!(heap.tombstones(), 0);
+}
+
+#[test]
+fn test_lazy_heap_stale_handles() {
+    let mut heap = LazyHeap::new();
+    let a = heap.push(1);
+    assert_eq!(heap.pop(), Some(1));
+
+    // The slot of `a` is reused, but `a` doesn't refer to the new item.
+    let b = heap.push(2);
+    assert!(!heap.contains(a));
+    assert!(!heap.mark_removed(a));
+    assert!(heap.contains(b));
+
+    heap.clear();
+    assert!(!heap.contains(b));
+    let c = heap.push(3);
+    assert!(!heap.mark_removed(b));
+    assert_eq!(heap.peek(), Some(&3));
+    assert!(heap.mark_removed(c));
+    assert!(heap.is_empty());
+}
+
+#[test]
+fn test_lazy_heap_compaction() {
+    let mut heap = LazyHeap::new();
+    let handles: Vec<_> = (0..100).map(|i| heap.push(i)).collect();
+
+    // Removing the smallest items leaves tombstones behind, until they
+    // outnumber the live items.
+    for (i, &handle) in handles[..50].iter().enumerate() {
+        assert!(heap.mark_removed(handle));
+        assert_eq!(heap.tombstones(), i + 1);
+    }
+    assert!(heap.mark_removed(handles[50]));
+    assert_eq!(heap.tombstones(), 0);
+    assert_eq!(heap.len(), 49);
+
+    assert_eq!(heap.mark_removed_where(|&i| i % 2 == 0), 24);
+    assert_eq!(heap.len(), 25);
+    let mut items: Vec<_> = heap.iter().cloned().collect();
+    items.sort();
+    assert_eq!(items, (51..100).filter(|i| i % 2 == 1).collect::<Vec<_>>());
+    assert_eq!(heap.iter().len(), 25);
+
+    let mut popped = vec![];
+    while let Some(x) = heap.pop() {
+        popped.push(x);
+    }
+    assert_eq!(popped, (51..100).rev().filter(|i| i % 2 == 1).collect::<Vec<_>>());
+}
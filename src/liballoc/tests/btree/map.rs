@@ -9,8 +9,10 @@
 // except according to those terms.
 
 use std::collections::BTreeMap;
+use std::collections::btree_map::BTreeMapBuilder;
 use std::collections::btree_map::Entry::{Occupied, Vacant};
 use std::ops::Bound::{self, Excluded, Included, Unbounded};
+use std::panic::{self, AssertUnwindSafe};
 use std::rc::Rc;
 
 use std::iter::FromIterator;
@@ -77,6 +79,23 @@ fn test_basic_small() {
     assert_eq!(map.remove(&1), None);
 }
 
+#[test]
+fn test_remove_entry() {
+    let keys: Vec<Rc<str>> = (0..100).map(|i| Rc::from(i.to_string())).collect();
+    let mut map: BTreeMap<_, _> = keys.iter().cloned().zip(0..).collect();
+    assert_eq!(map.remove_entry("100"), None);
+
+    for (i, key) in keys.iter().enumerate() {
+        // The key handed back is the one stored in the map, not a copy.
+        let (k, v) = map.remove_entry(&**key).unwrap();
+        assert!(Rc::ptr_eq(&k, key));
+        assert_eq!(v, i);
+        assert_eq!(map.len(), keys.len() - i - 1);
+        assert_eq!(map.remove_entry(&**key), None);
+    }
+    assert!(keys.iter().all(|key| Rc::strong_count(key) == 1));
+}
+
 #[test]
 fn test_iter() {
     let size = 10000;
@@ -95,10 +114,24 @@ fn test_iter() {
         assert_eq!(iter.next(), None);
     }
     test(size, map.iter().map(|(&k, &v)| (k, v)));
+    test(size, map.iter_cloned());
     test(size, map.iter_mut().map(|(&k, &mut v)| (k, v)));
     test(size, map.into_iter());
 }
 
+#[test]
+fn test_iter_cloned() {
+    let map: BTreeMap<_, _> = (0..5).map(|i| (i.to_string(), Rc::new(i))).collect();
+    let mut iter = map.iter_cloned();
+    let (k, v) = iter.next().unwrap();
+    assert_eq!((&k[..], *v), ("0", 0));
+    assert_eq!(Rc::strong_count(&v), 2);
+    assert_eq!(iter.next_back().map(|(k, _)| k), Some("4".to_string()));
+    assert_eq!(iter.len(), 3);
+    drop(v);
+    assert!(map.values().all(|v| Rc::strong_count(v) == 1));
+}
+
 #[test]
 fn test_iter_rev() {
     let size = 10000;
@@ -117,6 +150,7 @@ fn test_iter_rev() {
         assert_eq!(iter.next(), None);
     }
     test(size, map.iter().rev().map(|(&k, &v)| (k, v)));
+    test(size, map.iter_cloned().rev());
     test(size, map.iter_mut().rev().map(|(&k, &mut v)| (k, v)));
     test(size, map.into_iter().rev());
 }
@@ -294,6 +328,73 @@ fn test_range_borrowed_key() {
     assert_eq!(iter.next(), None);
 }
 
+#[test]
+fn test_closest() {
+    let size = 1000;
+    let map: BTreeMap<_, _> = (0..size).map(|i| (i * 2, i)).collect();
+
+    for i in -1..size * 2 + 1 {
+        let expected = (map.range(..=i).next_back(), map.range(i..).next());
+        assert_eq!(map.closest(&i), expected);
+    }
+    assert_eq!(BTreeMap::<i32, ()>::new().closest(&0), (None, None));
+
+    let map: BTreeMap<String, ()> = vec!["b".to_string(), "d".to_string()]
+        .into_iter()
+        .map(|s| (s, ()))
+        .collect();
+    assert_eq!(map.closest("c").0.unwrap().0, "b");
+    assert_eq!(map.closest("c").1.unwrap().0, "d");
+}
+
+#[test]
+fn test_extract_if() {
+    // Large enough for a tree of height two, so that pairs are removed from
+    // internal nodes and nodes get merged along the way.
+    let size = 1000;
+    let mut map: BTreeMap<_, _> = (0..size).map(|i| (i, i * 10)).collect();
+
+    let mut seen = Vec::new();
+    let removed: Vec<_> = map.extract_if(|&k, v| {
+        seen.push(k);
+        *v += 1;
+        k % 3 != 1
+    }).collect();
+    assert_eq!(seen, (0..size).collect::<Vec<_>>());
+    let expected: Vec<_> = (0..size).filter(|k| k % 3 != 1).map(|k| (k, k * 10 + 1)).collect();
+    assert_eq!(removed, expected);
+    assert_eq!(map.len(), size as usize - removed.len());
+    assert!(map.into_iter().eq((0..size).filter(|k| k % 3 == 1).map(|k| (k, k * 10 + 1))));
+
+    // Dropping the iterator removes the rest of the matching pairs.
+    let mut map: BTreeMap<_, _> = (0..size).map(|i| (i, ())).collect();
+    assert_eq!(map.extract_if(|&k, _| k % 2 == 0).next(), Some((0, ())));
+    assert!(map.keys().cloned().eq((0..size).filter(|k| k % 2 == 1)));
+}
+
+#[test]
+fn test_extract_if_pred_panic() {
+    let size = 1000;
+    let mut map: BTreeMap<_, _> = (0..size).map(|i| (i, i.to_string())).collect();
+    let mut calls = 0;
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        for _ in map.extract_if(|&k, _| {
+            calls += 1;
+            if k == 500 {
+                panic!("panic in predicate");
+            }
+            k % 2 == 0
+        }) {}
+    }));
+    assert!(result.is_err());
+
+    // The predicate was not called again while dropping the iterator, and
+    // only the pairs that were yielded before the panic are gone.
+    assert_eq!(calls, 501);
+    assert_eq!(map.len(), size as usize - 250);
+    assert!(map.keys().cloned().eq((0..size).filter(|&k| k % 2 == 1 || k >= 500)));
+}
+
 #[test]
 fn test_range() {
     let size = 200;
@@ -360,6 +461,20 @@ fn test_borrow() {
     }
 }
 
+#[test]
+fn test_insert_get() {
+    let mut map = BTreeMap::new();
+    for i in 0..100 {
+        *map.insert_get(i, i) *= 2;
+    }
+    assert_eq!(map.len(), 100);
+    assert!(map.iter().all(|(k, v)| *v == k * 2));
+
+    assert_eq!(*map.insert_get(50, 0), 0);
+    assert_eq!(map.len(), 100);
+    assert_eq!(map[&50], 0);
+}
+
 #[test]
 fn test_entry() {
     let xs = [(1, 10), (2, 20), (3, 30), (4, 40), (5, 50), (6, 60)];
@@ -685,3 +800,73 @@ fn test_split_off_large_random_sorted() {
     assert!(map.into_iter().eq(data.clone().into_iter().filter(|x| x.0 < key)));
     assert!(right.into_iter().eq(data.into_iter().filter(|x| x.0 >= key)));
 }
+
+#[test]
+fn test_builder() {
+    // Sizes around the node capacity and the first few tree heights.
+    for &size in &[0, 1, 10, 11, 12, 100, 143, 1000, 1729] {
+        let mut builder = BTreeMapBuilder::new();
+        for i in 0..size {
+            assert_eq!(builder.push(i * 2, i), Ok(()));
+        }
+        assert_eq!(builder.len(), size);
+        let mut map = builder.build();
+        assert_eq!(map.len(), size);
+        assert!(map.iter().map(|(&k, &v)| (k, v)).eq((0..size).map(|i| (i * 2, i))));
+
+        // The finished map must still be a valid tree.
+        for i in 0..size {
+            assert_eq!(map.insert(i * 2 + 1, i), None);
+        }
+        for i in 0..size {
+            assert_eq!(map.remove(&(i * 2)), Some(i));
+        }
+        assert!(map.keys().cloned().eq((0..size).map(|i| i * 2 + 1)));
+    }
+}
+
+#[test]
+fn test_builder_rejects_out_of_order() {
+    let mut builder = BTreeMapBuilder::new();
+    builder.push(5, 'a').unwrap();
+    assert_eq!(builder.push(5, 'b'), Err((5, 'b')));
+    assert_eq!(builder.push(4, 'c'), Err((4, 'c')));
+    assert_eq!(builder.push(6, 'd'), Ok(()));
+    let map = builder.build();
+    assert!(map.into_iter().eq(vec![(5, 'a'), (6, 'd')]));
+}
+
+#[test]
+fn test_debug_dot() {
+    let map: BTreeMap<_, _> = (0..1000).map(|i| (i, i)).collect();
+    let dot = map.debug_dot();
+    assert!(dot.starts_with("digraph BTreeMap {\n"));
+    assert!(dot.ends_with("}\n"));
+    let nodes = dot.lines().filter(|l| l.contains("[label=")).count();
+    let edges = dot.lines().filter(|l| l.contains(" -> ")).count();
+    assert!(nodes > 1);
+    assert_eq!(edges, nodes - 1);
+    for i in 0..1000 {
+        assert!(dot.contains(&format!("{}: {}", i, i)));
+    }
+
+    let empty: BTreeMap<i32, i32> = BTreeMap::new();
+    assert_eq!(empty.debug_dot().lines().filter(|l| l.contains("[label=")).count(), 1);
+}
+
+#[test]
+fn test_debug_dot_escaping() {
+    let mut map = BTreeMap::new();
+    map.insert("a|b", "{c}");
+    let dot = map.debug_dot();
+    assert!(dot.contains(r#"\"a\|b\": \"\{c\}\""#));
+}
+
+#[test]
+fn test_btreemap_macro() {
+    let empty: BTreeMap<i32, i32> = btreemap!{};
+    assert!(empty.is_empty());
+
+    let map = btreemap! { 3 => 'c', 1 => 'a', 3 => 'C' };
+    assert_eq!(map.into_iter().collect::<Vec<_>>(), [(1, 'a'), (3, 'C')]);
+}
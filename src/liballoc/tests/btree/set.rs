@@ -9,6 +9,7 @@
 // except according to those terms.
 
 use std::collections::BTreeSet;
+use std::collections::btree_set::BTreeSetBuilder;
 
 use std::iter::FromIterator;
 use super::DeterministicRng;
@@ -329,3 +330,50 @@ fn test_split_off_large_random_sorted() {
     assert!(set.into_iter().eq(data.clone().into_iter().filter(|x| *x < key)));
     assert!(right.into_iter().eq(data.into_iter().filter(|x| *x >= key)));
 }
+
+#[test]
+fn test_split_at_element() {
+    let data = rand_data(1529);
+    let set = BTreeSet::from_iter(data.clone());
+    let key = data[data.len() / 2];
+    let (low, high) = set.split_at_element(&key);
+
+    assert!(low.into_iter().eq(BTreeSet::from_iter(data.iter().cloned().filter(|x| *x < key))));
+    assert!(high.into_iter().eq(BTreeSet::from_iter(data.into_iter().filter(|x| *x >= key))));
+}
+
+#[test]
+fn test_extract_if() {
+    let mut set: BTreeSet<_> = (0..1000).collect();
+    let evens: Vec<_> = set.extract_if(|v| v % 2 == 0).collect();
+    assert_eq!(evens, (0..500).map(|v| v * 2).collect::<Vec<_>>());
+    assert!(set.iter().cloned().eq((0..500).map(|v| v * 2 + 1)));
+
+    set.extract_if(|&v| v < 100).next();
+    assert_eq!(set.len(), 450);
+    assert_eq!(set.iter().next(), Some(&101));
+}
+
+#[test]
+fn test_builder() {
+    let mut builder = BTreeSetBuilder::new();
+    for i in 0..500 {
+        builder.push(i).unwrap();
+    }
+    assert_eq!(builder.push(499), Err(499));
+    let mut set = builder.build();
+    assert!(set.iter().cloned().eq(0..500));
+    for i in 0..250 {
+        assert!(set.remove(&(i * 2)));
+    }
+    assert!(set.into_iter().eq((0..250).map(|i| i * 2 + 1)));
+}
+
+#[test]
+fn test_btreeset_macro() {
+    let empty: BTreeSet<i32> = btreeset![];
+    assert!(empty.is_empty());
+
+    let set = btreeset!["b", "a", "b",];
+    assert_eq!(set.into_iter().collect::<Vec<_>>(), ["a", "b"]);
+}
@@ -0,0 +1,53 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::VecDeque;
+use std::collections::fast_remove::FastRemove;
+
+fn remove_all<C>(c: &mut C, len: usize) -> Vec<C::Item>
+    where C: FastRemove<Handle = usize>
+{
+    assert!(c.fast_remove(len).is_none());
+    let mut removed = Vec::new();
+    for i in 0..len {
+        // Remove from the middle of whatever is left.
+        let index = (len - i) / 2;
+        removed.push(c.fast_remove(index).unwrap());
+    }
+    assert!(c.fast_remove(0).is_none());
+    removed
+}
+
+#[test]
+fn test_vec() {
+    let mut vec: Vec<_> = (0..10).map(|i| box i).collect();
+    assert_eq!(vec.fast_remove(2), Some(box 2));
+    assert_eq!(vec[2], box 9);
+    assert_eq!(vec.len(), 9);
+
+    let mut removed = remove_all(&mut vec, 9);
+    removed.sort();
+    assert_eq!(removed, [box 0, box 1, box 3, box 4, box 5, box 6, box 7, box 8, box 9]);
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn test_vec_deque() {
+    let mut deque: VecDeque<_> = (0..10).map(|i| box i).collect();
+    deque.push_front(box -1);
+    assert_eq!(deque.fast_remove(3), Some(box 2));
+    assert_eq!(deque[3], box 9);
+    assert_eq!(deque.len(), 10);
+
+    let mut removed = remove_all(&mut deque, 10);
+    removed.sort();
+    assert_eq!(removed, [box -1, box 0, box 1, box 3, box 4, box 5, box 6, box 7, box 8, box 9]);
+    assert!(deque.is_empty());
+}
@@ -0,0 +1,69 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::history_buffer::HistoryBuffer;
+
+#[test]
+fn test_push_evicts_oldest() {
+    let mut buffer = HistoryBuffer::new(3);
+    assert_eq!(buffer.push(0), None);
+    assert_eq!(buffer.push(1), None);
+    assert_eq!(buffer.push(2), None);
+    assert!(buffer.is_full());
+    for i in 3..10 {
+        assert_eq!(buffer.push(i), Some(i - 3));
+        assert_eq!(buffer.len(), 3);
+        assert_eq!(buffer.oldest(), Some(&(i - 2)));
+        assert_eq!(buffer.newest(), Some(&i));
+    }
+}
+
+#[test]
+fn test_recent() {
+    let mut buffer = HistoryBuffer::new(4);
+    assert_eq!(buffer.recent(0), None);
+
+    buffer.extend(0..10);
+    for i in 0..4 {
+        assert_eq!(buffer.recent(i), Some(&(9 - i)));
+        assert_eq!(buffer[i], 9 - i);
+    }
+    assert_eq!(buffer.recent(4), None);
+}
+
+#[test]
+#[should_panic(expected = "HistoryBuffer index out of bounds")]
+fn test_index_out_of_bounds() {
+    let mut buffer = HistoryBuffer::new(4);
+    buffer.push(1);
+    buffer[1];
+}
+
+#[test]
+#[should_panic(expected = "HistoryBuffer capacity must be nonzero")]
+fn test_zero_capacity() {
+    HistoryBuffer::<u8>::new(0);
+}
+
+#[test]
+fn test_iter() {
+    let mut buffer: HistoryBuffer<i32> = HistoryBuffer::new(3);
+    assert_eq!(buffer.iter_oldest_first().next(), None);
+
+    buffer.extend(&[1, 2, 3, 4, 5]);
+    assert!(buffer.iter_oldest_first().eq(&[3, 4, 5]));
+    assert!(buffer.iter_newest_first().eq(&[5, 4, 3]));
+    assert_eq!(buffer.iter_oldest_first().len(), 3);
+    assert_eq!(format!("{:?}", buffer), "[3, 4, 5]");
+
+    buffer.clear();
+    assert!(buffer.is_empty());
+    assert_eq!(buffer.capacity(), 3);
+}
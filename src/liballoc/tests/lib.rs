@@ -10,24 +10,69 @@
 
 #![deny(warnings)]
 
+#![feature(allocated_capacity)]
 #![feature(allocator_api)]
 #![feature(alloc_system)]
+#![feature(ascii_char_class)]
 #![feature(attr_literals)]
 #![feature(box_syntax)]
+#![feature(binary_heap_lazy)]
+#![feature(binary_heap_push_pop)]
+#![feature(binary_heap_storage)]
+#![feature(btree_builder)]
+#![feature(btree_closest)]
+#![feature(btree_map_remove_entry)]
+#![feature(btree_split_at_element)]
+#![feature(collection_macros)]
+#![feature(collections_debug_dot)]
 #![cfg_attr(stage0, feature(inclusive_range_syntax))]
 #![feature(const_fn)]
+#![feature(deque_contiguous)]
+#![feature(deque_range)]
 #![feature(drain_filter)]
 #![feature(exact_size_is_empty)]
+#![feature(extract_if)]
+#![feature(fast_remove)]
+#![feature(float_slice_stats)]
+#![feature(growth_policy)]
+#![feature(history_buffer)]
 #![feature(iterator_step_by)]
+#![feature(map_insert_get)]
+#![feature(map_iter_cloned)]
+#![feature(merge_dedup)]
 #![feature(pattern)]
+#![feature(pop_if)]
 #![feature(rand)]
+#![feature(range_map)]
+#![feature(replace_range)]
+#![feature(rope)]
+#![feature(rotate_to_front)]
+#![feature(seg_vec)]
+#![feature(shared_string)]
 #![feature(slice_sort_by_cached_key)]
-#![feature(splice)]
+#![feature(slice_split_byte)]
+#![feature(static_map)]
 #![feature(str_escape)]
+#![feature(str_graphemes)]
+#![feature(str_normalization)]
+#![feature(str_tokenizer)]
+#![feature(string_builder)]
+#![feature(string_push_num)]
 #![feature(string_retain)]
+#![feature(string_table)]
+#![feature(topo_sort)]
 #![feature(try_reserve)]
 #![feature(unboxed_closures)]
 #![feature(unicode)]
+#![feature(unrolled_list)]
+#![feature(vec_concat_into)]
+#![feature(vec_into_raw_parts)]
+#![feature(vec_pop_front_swap)]
+#![feature(vec_push_get)]
+#![feature(vecdeque_binary_search)]
+#![feature(vecdeque_into_vec)]
+#![feature(vecdeque_raw_parts)]
+#![feature(vecdeque_rotate)]
 #![feature(exact_chunks)]
 #![feature(inclusive_range_fields)]
 
@@ -38,15 +83,25 @@ extern crate rand;
 use std::hash::{Hash, Hasher};
 use std::collections::hash_map::DefaultHasher;
 
+mod algorithms;
 mod binary_heap;
 mod btree;
 mod cow_str;
+mod fast_remove;
 mod fmt;
 mod heap;
+mod history_buffer;
 mod linked_list;
+mod range_map;
+mod rope;
+mod seg_vec;
+mod shared_string;
 mod slice;
+mod static_map;
 mod str;
 mod string;
+mod string_table;
+mod unrolled_list;
 mod vec_deque;
 mod vec;
 
@@ -51,6 +51,18 @@ fn test_basic() {
 }
 
 #[cfg(test)]
+#[test]
+fn test_pop_back_if() {
+    let mut m = list_from(&[1, 2, 3]);
+    assert_eq!(m.pop_back_if(|x| *x == 3), Some(3));
+    assert_eq!(m.pop_back_if(|x| *x == 3), None);
+    assert_eq!(m.pop_back_if(|x| { *x += 1; false }), None);
+    assert_eq!(m, list_from(&[1, 3]));
+
+    let mut n: LinkedList<i32> = LinkedList::new();
+    assert_eq!(n.pop_back_if(|_| true), None);
+}
+
 fn generate_test() -> LinkedList<i32> {
     list_from(&[0, 1, 2, 3, 4, 5, 6])
 }
@@ -117,6 +129,21 @@ fn test_split_off() {
 
 }
 
+#[test]
+fn test_rotate_to_front() {
+    for len in 0..6 {
+        for index in 0..len + 1 {
+            let mut m = list_from(&(0..len).collect::<Vec<_>>());
+            m.rotate_to_front(index);
+            let expected: Vec<_> = (index..len).chain(0..index).collect();
+            assert_eq!(m.len(), len);
+            assert_eq!(m.iter().cloned().collect::<Vec<_>>(), expected);
+            assert_eq!(m.iter().rev().cloned().collect::<Vec<_>>(),
+                       expected.iter().rev().cloned().collect::<Vec<_>>());
+        }
+    }
+}
+
 #[test]
 fn test_iterator() {
     let m = generate_test();
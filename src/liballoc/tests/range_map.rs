@@ -0,0 +1,146 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::range_map::RangeMap;
+use std::ops::Range;
+
+fn ranges<V: Copy + Eq>(map: &RangeMap<u32, V>) -> Vec<(Range<u32>, V)> {
+    map.iter().map(|(range, &value)| (range.clone(), value)).collect()
+}
+
+#[test]
+fn test_insert_splits_and_merges() {
+    let mut map = RangeMap::new();
+    map.insert(10..20, 'a');
+    map.insert(30..40, 'b');
+    assert_eq!(ranges(&map), [(10..20, 'a'), (30..40, 'b')]);
+
+    // Inside an existing range.
+    map.insert(12..14, 'c');
+    assert_eq!(ranges(&map), [(10..12, 'a'), (12..14, 'c'), (14..20, 'a'), (30..40, 'b')]);
+
+    // Overlapping several ranges at once.
+    map.insert(13..35, 'd');
+    assert_eq!(ranges(&map), [(10..12, 'a'), (12..13, 'c'), (13..35, 'd'), (35..40, 'b')]);
+
+    // Touching a range with an equal value on either side.
+    map.insert(35..45, 'd');
+    assert_eq!(ranges(&map), [(10..12, 'a'), (12..13, 'c'), (13..45, 'd')]);
+    map.insert(5..10, 'a');
+    assert_eq!(ranges(&map), [(5..12, 'a'), (12..13, 'c'), (13..45, 'd')]);
+
+    // Touching ranges with different values are kept apart.
+    map.insert(45..50, 'e');
+    assert_eq!(ranges(&map), [(5..12, 'a'), (12..13, 'c'), (13..45, 'd'), (45..50, 'e')]);
+
+    // Overwriting with the same value changes nothing.
+    map.insert(20..30, 'd');
+    assert_eq!(ranges(&map), [(5..12, 'a'), (12..13, 'c'), (13..45, 'd'), (45..50, 'e')]);
+
+    // Bridging the gap between two equal ranges.
+    map.insert(12..13, 'a');
+    map.insert(12..13, 'd');
+    assert_eq!(ranges(&map), [(5..12, 'a'), (12..45, 'd'), (45..50, 'e')]);
+
+    // Empty ranges are ignored.
+    map.insert(20..20, 'f');
+    assert_eq!(map.len(), 3);
+}
+
+#[test]
+fn test_insert_order_independent() {
+    let parts = [(0..4, 1), (4..8, 1), (8..12, 2), (2..6, 1), (12..16, 2), (6..10, 2)];
+    let expected = [(0..6, 1), (6..16, 2)];
+
+    let forward: RangeMap<u32, i32> = parts.iter().cloned().collect();
+    assert_eq!(ranges(&forward), expected);
+
+    // Later ranges win where they overlap, so only reorder disjoint ones.
+    let mut backward = RangeMap::new();
+    backward.insert(12..16, 2);
+    backward.insert(8..12, 2);
+    backward.insert(0..4, 1);
+    backward.insert(4..6, 1);
+    backward.insert(6..8, 2);
+    assert_eq!(ranges(&backward), expected);
+    assert_eq!(forward, backward);
+}
+
+#[test]
+fn test_get() {
+    let mut map = RangeMap::new();
+    map.insert(10..20, 'a');
+    map.insert(20..30, 'b');
+
+    assert_eq!(map.get(&9), None);
+    assert_eq!(map.get(&10), Some(&'a'));
+    assert_eq!(map.get(&19), Some(&'a'));
+    assert_eq!(map.get(&20), Some(&'b'));
+    assert_eq!(map.get(&30), None);
+    assert_eq!(map.get_key_value(&25), Some((&(20..30), &'b')));
+    assert!(map.contains_key(&29));
+    assert!(!map.contains_key(&0));
+}
+
+#[test]
+fn test_remove() {
+    let mut map = RangeMap::new();
+    map.insert(0..10, 'a');
+    map.insert(10..20, 'b');
+    map.insert(20..30, 'c');
+
+    map.remove(12..14);
+    assert_eq!(ranges(&map), [(0..10, 'a'), (10..12, 'b'), (14..20, 'b'), (20..30, 'c')]);
+
+    map.remove(5..25);
+    assert_eq!(ranges(&map), [(0..5, 'a'), (25..30, 'c')]);
+
+    map.remove(3..3);
+    map.remove(40..50);
+    assert_eq!(ranges(&map), [(0..5, 'a'), (25..30, 'c')]);
+
+    map.remove(0..30);
+    assert!(map.is_empty());
+}
+
+#[test]
+fn test_overlapping() {
+    let mut map = RangeMap::new();
+    map.insert(0..10, 'a');
+    map.insert(10..20, 'b');
+    map.insert(25..30, 'c');
+
+    let overlapping = |range: Range<u32>| {
+        map.overlapping(range).map(|(_, &value)| value).collect::<String>()
+    };
+    assert_eq!(overlapping(0..30), "abc");
+    assert_eq!(overlapping(5..15), "ab");
+    assert_eq!(overlapping(10..25), "b");
+    assert_eq!(overlapping(20..25), "");
+    assert_eq!(overlapping(9..10), "a");
+    assert_eq!(overlapping(5..5), "");
+    assert_eq!(overlapping(30..40), "");
+    assert_eq!(map.overlapping(0..30).rev().next(), Some((&(25..30), &'c')));
+}
+
+#[test]
+#[should_panic]
+fn test_overlapping_backwards_range() {
+    let map: RangeMap<u32, char> = RangeMap::new();
+    map.overlapping(5..4);
+}
+
+#[test]
+fn test_debug() {
+    let mut map = RangeMap::new();
+    map.insert(0..10, 'a');
+    map.insert(10..20, 'b');
+    assert_eq!(format!("{:?}", map), "{0..10: 'a', 10..20: 'b'}");
+}
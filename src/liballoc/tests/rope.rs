@@ -0,0 +1,124 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::rope::Rope;
+
+/// Returns the byte offset of the `char` at `idx` in `s`.
+fn byte_offset(s: &str, idx: usize) -> usize {
+    s.char_indices().nth(idx).map_or(s.len(), |(i, _)| i)
+}
+
+#[test]
+fn test_from_and_into_string() {
+    let text: String = (0..5000).map(|i| if i % 7 == 0 { 'ü' } else { 'x' }).collect();
+    let rope = Rope::from(&*text);
+    assert_eq!(rope.len(), text.len());
+    assert_eq!(rope.len_chars(), 5000);
+    assert!(rope.chunks().count() > 1);
+    assert!(rope.chunks().all(|chunk| !chunk.is_empty()));
+    assert_eq!(String::from(&rope), text);
+    assert_eq!(rope.to_string(), text);
+    assert_eq!(rope, Rope::from(text.clone()));
+    assert_eq!(rope, text);
+
+    let empty = Rope::from("");
+    assert!(empty.is_empty());
+    assert_eq!(empty.chunks().count(), 0);
+    assert_eq!(empty, Rope::new());
+}
+
+#[test]
+fn test_edits_match_string() {
+    let mut rope = Rope::new();
+    let mut text = String::new();
+    let mut seed = 0x2545_f491_u32;
+    let mut random = |n: usize| {
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        seed as usize % n
+    };
+
+    for round in 0..2000 {
+        let chars = text.chars().count();
+        let at = random(chars + 1);
+        if round % 3 == 2 {
+            let end = at + random(chars - at + 1).min(500);
+            rope.remove(at..end);
+            let (start, end) = (byte_offset(&text, at), byte_offset(&text, end));
+            text.drain(start..end);
+        } else {
+            let piece = ["a", "βγ", "\u{1F600}", "long line of text\n"][random(4)];
+            let piece = piece.repeat(random(80));
+            rope.insert(at, &piece);
+            text.insert_str(byte_offset(&text, at), &piece);
+        }
+        assert_eq!(rope.len(), text.len());
+        assert_eq!(rope.len_chars(), text.chars().count());
+    }
+    assert_eq!(rope, text);
+    assert!(rope.chars().eq(text.chars()));
+    assert!(rope.chars().rev().eq(text.chars().rev()));
+}
+
+#[test]
+fn test_slice_and_split_off() {
+    let text = "0123456789".repeat(300);
+    let rope = Rope::from(&*text);
+
+    assert_eq!(rope.slice(..), text);
+    assert_eq!(rope.slice(1000..2005), text[1000..2005]);
+    assert_eq!(rope.slice(3000..), "");
+    assert_eq!(rope.slice(5..=5), "5");
+
+    let mut left = rope.clone();
+    let mut right = left.split_off(1234);
+    assert_eq!(left, text[..1234]);
+    assert_eq!(right, text[1234..]);
+    left.append(&mut right);
+    assert!(right.is_empty());
+    assert_eq!(left, rope);
+}
+
+#[test]
+fn test_clone_is_independent() {
+    let mut a = Rope::from("shared text");
+    let b = a.clone();
+    a.insert(6, " and changed");
+    a.push_str("!");
+    assert_eq!(a, "shared and changed text!");
+    assert_eq!(b, "shared text");
+}
+
+#[test]
+fn test_chars_both_ends() {
+    let mut rope = Rope::from("abc");
+    rope.push_str("δεζ");
+    rope.insert(0, "xy");
+    let mut chars = rope.chars();
+    assert_eq!(chars.next(), Some('x'));
+    assert_eq!(chars.next_back(), Some('ζ'));
+    assert_eq!(chars.next_back(), Some('ε'));
+    assert_eq!(chars.collect::<String>(), "yabcδ");
+}
+
+#[test]
+#[should_panic]
+fn test_insert_out_of_bounds() {
+    let mut rope = Rope::from("ab");
+    rope.insert(3, "c");
+}
+
+#[test]
+#[should_panic]
+fn test_remove_out_of_bounds() {
+    let mut rope = Rope::from("äb");
+    rope.remove(1..3);
+}
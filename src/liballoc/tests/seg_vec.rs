@@ -0,0 +1,171 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::seg_vec::SegVec;
+use std::rc::Rc;
+
+#[test]
+fn test_push_pop_index() {
+    let mut v = SegVec::new();
+    let n = v.chunk_len() * 3 + 5;
+    for i in 0..n {
+        v.push(i as u32);
+        assert_eq!(v.len(), i + 1);
+        assert_eq!(v.last(), Some(&(i as u32)));
+    }
+    for i in 0..n {
+        assert_eq!(v[i], i as u32);
+    }
+    assert_eq!(v.get(n), None);
+
+    v[7] = 70;
+    *v.get_mut(n - 1).unwrap() = 1;
+    assert_eq!(v[7], 70);
+    assert_eq!(v[n - 1], 1);
+
+    assert_eq!(v.pop(), Some(1));
+    for i in (0..n - 1).rev() {
+        assert_eq!(v.pop(), Some(if i == 7 { 70 } else { i as u32 }));
+    }
+    assert_eq!(v.pop(), None);
+    assert!(v.is_empty());
+}
+
+#[test]
+fn test_elements_do_not_move() {
+    let mut v = SegVec::new();
+    v.push(0u8);
+    let first: *const u8 = &v[0];
+    let chunk_len = v.chunk_len();
+    v.extend((0..chunk_len * 4).map(|i| i as u8));
+    assert_eq!(first, &v[0] as *const u8);
+
+    let last: *const u8 = &v[chunk_len * 2];
+    for _ in 0..chunk_len {
+        v.pop();
+    }
+    v.extend((0..chunk_len * 2).map(|i| i as u8));
+    assert_eq!(last, &v[chunk_len * 2] as *const u8);
+}
+
+#[test]
+fn test_chunk_len() {
+    assert_eq!(SegVec::<u8>::new().chunk_len(), 64 * 1024);
+    assert_eq!(SegVec::<[u8; 3]>::new().chunk_len(), 16 * 1024);
+    assert_eq!(SegVec::<[u8; 1 << 20]>::new().chunk_len(), 1);
+    assert!(SegVec::<()>::new().chunk_len() > 1 << 30);
+}
+
+#[test]
+fn test_chunks_and_shrink() {
+    let mut v: SegVec<u32> = SegVec::new();
+    assert_eq!(v.chunks().count(), 0);
+
+    let chunk_len = v.chunk_len();
+    v.extend(0..(chunk_len * 2) as u32);
+    let lens: Vec<_> = v.chunks().map(|c| c.len()).collect();
+    assert_eq!(lens, [chunk_len, chunk_len]);
+
+    // Emptied chunks are kept, but not reported.
+    for _ in 0..chunk_len + 1 {
+        v.pop();
+    }
+    let lens: Vec<_> = v.chunks().map(|c| c.len()).collect();
+    assert_eq!(lens, [chunk_len - 1]);
+
+    v.shrink_to_fit();
+    assert_eq!(v.chunks().count(), 1);
+    v.push(0);
+    v.push(1);
+    let lens: Vec<_> = v.chunks().map(|c| c.len()).collect();
+    assert_eq!(lens, [chunk_len, 1]);
+}
+
+#[test]
+fn test_iter_both_ends() {
+    let chunk_len = SegVec::<u32>::new().chunk_len();
+    let n = chunk_len * 2 + 3;
+    let mut v: SegVec<_> = (0..n).map(|i| i as u32).collect();
+
+    let mut iter = v.iter();
+    assert_eq!(iter.len(), n);
+    assert_eq!(iter.next(), Some(&0));
+    assert_eq!(iter.next_back(), Some(&((n - 1) as u32)));
+    assert_eq!(iter.len(), n - 2);
+    let rest: Vec<u32> = iter.cloned().collect();
+    assert_eq!(rest.len(), n - 2);
+    assert!(rest.iter().enumerate().all(|(i, &x)| x as usize == i + 1));
+
+    let mut iter = v.iter();
+    for i in 0..n / 2 {
+        assert_eq!(iter.next(), Some(&(i as u32)));
+        assert_eq!(iter.next_back(), Some(&((n - 1 - i) as u32)));
+    }
+    assert_eq!(iter.next(), Some(&((n / 2) as u32)));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    for x in &mut v {
+        *x *= 2;
+    }
+    assert_eq!(v.iter_mut().rev().next(), Some(&mut ((n - 1) as u32 * 2)));
+
+    let owned: Vec<_> = v.into_iter().rev().collect();
+    assert_eq!(owned.len(), n);
+    assert_eq!(owned[0], (n - 1) as u32 * 2);
+    assert_eq!(owned[n - 1], 0);
+}
+
+#[test]
+fn test_into_iter_drops_rest() {
+    let marker = Rc::new(());
+    let mut v = SegVec::new();
+    let chunk_len = v.chunk_len();
+    for _ in 0..chunk_len * 2 + 1 {
+        v.push(marker.clone());
+    }
+    let mut iter = v.into_iter();
+    iter.next();
+    iter.next_back();
+    assert_eq!(Rc::strong_count(&marker), chunk_len * 2);
+    drop(iter);
+    assert_eq!(Rc::strong_count(&marker), 1);
+}
+
+#[test]
+fn test_zero_sized() {
+    let mut v = SegVec::new();
+    for _ in 0..100 {
+        v.push(());
+    }
+    assert_eq!(v.len(), 100);
+    assert_eq!(v.chunks().count(), 1);
+    assert_eq!(v.iter().count(), 100);
+    assert_eq!(v.pop(), Some(()));
+    assert_eq!(v.get(99), None);
+}
+
+#[test]
+fn test_clone_eq_debug() {
+    let v: SegVec<_> = (0..5).collect();
+    let mut w = v.clone();
+    assert_eq!(v, w);
+    w.push(5);
+    assert!(v != w);
+    assert_eq!(format!("{:?}", v), "[0, 1, 2, 3, 4]");
+    assert_eq!(format!("{:?}", v.iter()), "[0, 1, 2, 3, 4]");
+}
+
+#[test]
+#[should_panic]
+fn test_index_out_of_bounds() {
+    let v: SegVec<_> = (0..5).collect();
+    v[5];
+}
@@ -0,0 +1,87 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::HashSet;
+use std::collections::shared_string::SharedString;
+use std::panic;
+
+#[test]
+fn test_from_and_into_string() {
+    let text = SharedString::from(String::from("Ünïcödé"));
+    assert_eq!(text, "Ünïcödé");
+    assert_eq!(text.len(), "Ünïcödé".len());
+    assert_eq!(String::from(&text), "Ünïcödé");
+    assert_eq!(text.to_string(), "Ünïcödé");
+    assert_eq!(format!("{:?}", text), "\"Ünïcödé\"");
+    assert_eq!(String::from(text), "Ünïcödé");
+
+    assert!(SharedString::new().is_empty());
+    assert_eq!(SharedString::default(), "");
+}
+
+#[test]
+fn test_slice() {
+    let text = SharedString::from("α is alpha, β is beta");
+    let alpha = text.slice(..12);
+    assert_eq!(alpha, "α is alpha,");
+    assert!(alpha.shares_buffer_with(&text));
+    assert_eq!(alpha.slice(6..=10), "alpha");
+    assert_eq!(alpha.slice(6..).slice(..1), "a");
+    assert_eq!(alpha.slice(12..), "");
+    assert_eq!(text.slice(..), text);
+
+    assert!(panic::catch_unwind(|| text.slice(1..)).is_err());
+    assert!(panic::catch_unwind(|| alpha.slice(..13)).is_err());
+    assert!(panic::catch_unwind(|| alpha.slice(4..3)).is_err());
+}
+
+#[test]
+fn test_slice_ref() {
+    let text = SharedString::from("fn main() {\n    println!(\"hi\");\n}\n");
+    let body = text.slice(12..);
+    let tokens: Vec<_> = body.split_whitespace().map(|t| body.slice_ref(t)).collect();
+    assert_eq!(tokens, ["println!(\"hi\");", "}"]);
+    assert!(tokens.iter().all(|t| t.shares_buffer_with(&text)));
+    assert_eq!(body.slice_ref(&body[body.len()..]), "");
+
+    // Slices of the buffer that lie outside of `body` are rejected.
+    assert!(panic::catch_unwind(|| body.slice_ref(&text[..2])).is_err());
+    assert!(panic::catch_unwind(|| body.slice_ref(&text[11..13])).is_err());
+    assert!(panic::catch_unwind(|| body.slice_ref("}")).is_err());
+}
+
+#[test]
+fn test_to_owned_if_small() {
+    let text = SharedString::from("x".repeat(100));
+    let small = text.slice(10..34).to_owned_if_small();
+    assert_eq!(small, "x".repeat(24));
+    assert!(!small.shares_buffer_with(&text));
+    assert!(small.shares_buffer_with(&small.to_owned_if_small()));
+
+    let large = text.slice(10..35).to_owned_if_small();
+    assert!(large.shares_buffer_with(&text));
+}
+
+#[test]
+fn test_compare_and_hash() {
+    let text = SharedString::from("b a b");
+    let a = text.slice(2..3);
+    let b1 = text.slice(..1);
+    let b2 = text.slice(4..);
+    assert_eq!(b1, b2);
+    assert!(a < b1);
+    assert_eq!(b1, String::from("b"));
+
+    let set: HashSet<SharedString> = vec![a, b1, b2].into_iter().collect();
+    assert_eq!(set.len(), 2);
+    assert!(set.contains("a"));
+    assert!(set.contains("b"));
+    assert!(!set.contains("b a"));
+}
@@ -14,6 +14,7 @@ use std::cmp::Ordering;
 use std::mem;
 use std::panic;
 use std::rc::Rc;
+use std::slice::FloatSliceExt;
 use std::sync::atomic::Ordering::Relaxed;
 use std::sync::atomic::{ATOMIC_USIZE_INIT, AtomicUsize};
 use std::thread;
@@ -512,6 +513,31 @@ fn test_sort_stability() {
     }
 }
 
+#[test]
+fn test_merge_dedup() {
+    use std::slice::merge_dedup;
+
+    let mut rng = thread_rng();
+    for a_len in 0..20 {
+        for b_len in 0..20 {
+            let mut a: Vec<u32> = rng.gen_iter::<u32>().map(|x| x % 16).take(a_len).collect();
+            let mut b: Vec<u32> = rng.gen_iter::<u32>().map(|x| x % 16).take(b_len).collect();
+            a.sort();
+            b.sort();
+
+            let mut expected: Vec<u32> = a.iter().chain(&b).cloned().collect();
+            expected.sort();
+            expected.dedup();
+
+            let mut out = vec![100];
+            merge_dedup(&a, &b, &mut out);
+            assert_eq!(out[0], 100);
+            assert_eq!(out[1..], expected[..]);
+            assert!(out.capacity() >= 1 + a_len + b_len);
+        }
+    }
+}
+
 #[test]
 fn test_rotate_left() {
     let expected: Vec<_> = (0..13).collect();
@@ -609,6 +635,77 @@ fn test_join() {
     assert_eq!(v.join(&0), [1, 0, 2, 0, 3]);
 }
 
+#[test]
+fn test_concat_join_allocate_exactly() {
+    let v = [vec![1, 2], vec![], vec![3, 4, 5]];
+    let c = v.concat();
+    assert_eq!(c, [1, 2, 3, 4, 5]);
+    assert_eq!(c.capacity(), c.len());
+    let j = v.join(&0);
+    assert_eq!(j, [1, 2, 0, 0, 3, 4, 5]);
+    assert_eq!(j.capacity(), j.len());
+}
+
+#[test]
+fn test_split_byte() {
+    fn check(v: &[u8]) {
+        let expected: Vec<_> = v.split(|&b| b == b',').collect();
+        assert_eq!(v.split_byte(b',').collect::<Vec<_>>(), expected);
+        let mut rev: Vec<_> = v.split_byte(b',').rev().collect();
+        rev.reverse();
+        assert_eq!(rev, expected);
+    }
+    check(b"");
+    check(b",");
+    check(b"a,b,,c");
+    check(b",abc,");
+    check(b"no separators here, except this one and the next,");
+    check(&[b'x'; 100]);
+
+    let mut long = vec![b'a'; 1000];
+    long[3] = b',';
+    long[500] = b',';
+    long[999] = b',';
+    check(&long);
+
+    let mut it = b"a,b,c".split_byte(b',');
+    assert_eq!(it.next(), Some(&b"a"[..]));
+    assert_eq!(it.next_back(), Some(&b"c"[..]));
+    assert_eq!(it.next(), Some(&b"b"[..]));
+    assert_eq!(it.next_back(), None);
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn test_join_bytes() {
+    let empty: [&[u8]; 0] = [];
+    assert_eq!(b",".join_bytes(&empty), b"");
+    assert_eq!(b",".join_bytes(&[&b"a"[..]]), b"a");
+    assert_eq!(b"".join_bytes(&[&b"a"[..], b"b"]), b"ab");
+
+    let joined = b" | ".join_bytes(&[vec![1, 2], vec![], vec![3]]);
+    assert_eq!(joined, [1, 2, b' ', b'|', b' ', b' ', b'|', b' ', 3]);
+    assert_eq!(joined.capacity(), joined.len());
+}
+
+#[test]
+fn test_char_class_scanning() {
+    use std::ascii::CharClass;
+
+    let hex = CharClass::HEX_DIGIT;
+    assert_eq!(b"deadBEEF!".span_of(&hex), 8);
+    assert_eq!(b"".span_of(&hex), 0);
+    assert_eq!(b"0x1f".find_not_of(&hex), Some(1));
+    assert_eq!(b"c0ffee".find_not_of(&hex), None);
+
+    // Unlike `str`, bytes outside the ASCII range can be members.
+    let high = CharClass::ASCII.complement();
+    assert_eq!([0xff, 0x80, b'a', 0x90].span_of(&high), 2);
+    assert_eq!([0xff, b'a', 0x80].trim_matches_class(&high), b"a");
+    assert_eq!(b"\r\n  \r\n".trim_matches_class(&CharClass::WHITESPACE), b"");
+    assert_eq!(b" line \n".trim_matches_class(&CharClass::WHITESPACE), b"line");
+}
+
 #[test]
 fn test_insert() {
     let mut a = vec![1, 2, 4];
@@ -1361,6 +1458,58 @@ fn test_copy_from_slice_dst_shorter() {
     dst.copy_from_slice(&src);
 }
 
+#[test]
+fn test_clone_from_slice() {
+    let src = [String::from("a"), String::from("bc"), String::new()];
+    let mut dst = [String::from("x"), String::new(), String::from("yz")];
+    dst.clone_from_slice(&src);
+    assert_eq!(src, dst);
+
+    let mut empty: [String; 0] = [];
+    empty.clone_from_slice(&[]);
+}
+
+#[test]
+#[should_panic(expected = "destination and source slices have different lengths")]
+fn test_clone_from_slice_dst_longer() {
+    let src = [String::new(), String::new()];
+    let mut dst = [String::new(), String::new(), String::new()];
+    dst.clone_from_slice(&src);
+}
+
+#[test]
+#[should_panic(expected = "destination and source slices have different lengths")]
+fn test_clone_from_slice_dst_shorter() {
+    let src = [String::new(), String::new()];
+    let mut dst = [String::new()];
+    dst.clone_from_slice(&src);
+}
+
+#[test]
+fn test_float_sum_mean() {
+    let empty: [f64; 0] = [];
+    assert_eq!(empty.sum(), 0.0);
+    assert!(empty.mean().is_nan());
+    assert_eq!([1.5f64].sum(), 1.5);
+    assert_eq!([1.0f64, 2.0, 3.0, 4.0].mean(), 2.5);
+
+    let v: Vec<f64> = (1..1001).map(|i| i as f64).collect();
+    assert_eq!(v.sum(), 500500.0);
+    assert_eq!(v.mean(), 500.5);
+
+    let v: Vec<f32> = (1..1001).map(|i| i as f32).collect();
+    assert_eq!(v.sum(), 500500.0);
+    assert_eq!(v.mean(), 500.5);
+
+    // Adding in order loses the small elements once the running total is
+    // large; pairwise summation keeps them.
+    let v = vec![0.1f32; 1 << 20];
+    let naive = v.iter().fold(0.0f32, |acc, &x| acc + x);
+    let exact = (1 << 20) as f64 * 0.1f32 as f64;
+    assert!((v.sum() as f64 - exact).abs() < 0.1);
+    assert!((naive as f64 - exact).abs() > 100.0);
+}
+
 const MAX_LEN: usize = 80;
 
 static DROP_COUNTS: [AtomicUsize; MAX_LEN] = [
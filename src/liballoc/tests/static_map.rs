@@ -0,0 +1,71 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::static_map::StaticMap;
+
+static EMPTY: StaticMap<u32, u32> = static_map! {};
+
+static DIGITS: StaticMap<&str, u32> = static_map! {
+    "eight" => 8,
+    "five" => 5,
+    "four" => 4,
+    "nine" => 9,
+    "one" => 1,
+    "seven" => 7,
+    "six" => 6,
+    "three" => 3,
+    "two" => 2,
+    "zero" => 0,
+};
+
+#[test]
+fn test_well_formed() {
+    assert!(EMPTY.is_well_formed());
+    assert!(DIGITS.is_well_formed());
+
+    static DUPLICATE: StaticMap<u32, ()> = static_map! { 1 => (), 1 => () };
+    assert!(!DUPLICATE.is_well_formed());
+}
+
+#[test]
+fn test_get() {
+    assert_eq!(EMPTY.get(&0), None);
+    assert_eq!(DIGITS.len(), 10);
+    for (name, &digit) in &DIGITS {
+        assert_eq!(DIGITS.get(name), Some(&digit));
+        assert_eq!(DIGITS[*name], digit);
+    }
+    assert_eq!(DIGITS.get("ten"), None);
+    assert_eq!(DIGITS.get(""), None);
+    assert!(DIGITS.contains_key("zero"));
+    assert!(!DIGITS.contains_key("zer"));
+}
+
+#[test]
+fn test_iter() {
+    let mut iter = DIGITS.iter();
+    assert_eq!(iter.len(), 10);
+    assert_eq!(iter.next(), Some((&"eight", &8)));
+    assert_eq!(iter.next_back(), Some((&"zero", &0)));
+    assert_eq!(iter.len(), 8);
+    assert_eq!(EMPTY.iter().next(), None);
+}
+
+#[test]
+fn test_debug() {
+    static SMALL: StaticMap<u32, char> = static_map! { 1 => 'a', 2 => 'b' };
+    assert_eq!(format!("{:?}", SMALL), "{1: 'a', 2: 'b'}");
+}
+
+#[test]
+#[should_panic]
+fn test_index_nonexistent() {
+    DIGITS["eleven"];
+}
@@ -162,6 +162,17 @@ fn test_join_for_different_lengths() {
     test_join!("-a-bc", ["", "a", "bc"], "-");
 }
 
+#[test]
+fn test_concat_join_allocate_exactly() {
+    let v = ["ab", "", "cde"];
+    let c = v.concat();
+    assert_eq!(c, "abcde");
+    assert_eq!(c.capacity(), c.len());
+    let j = v.join(", ");
+    assert_eq!(j, "ab, , cde");
+    assert_eq!(j.capacity(), j.len());
+}
+
 #[test]
 fn test_unsafe_slice() {
     assert_eq!("ab", unsafe {"abc".slice_unchecked(0, 2)});
@@ -506,6 +517,26 @@ fn test_trim_matches() {
     assert_eq!("123foo1bar123".trim_matches(|c: char| c.is_numeric()), "foo1bar");
 }
 
+#[test]
+fn test_char_class_scanning() {
+    use std::ascii::CharClass;
+
+    let word = CharClass::ALPHANUMERIC.union(&CharClass::from_bytes(b"_"));
+    assert_eq!("snake_case42 = 1".span_of(&word), 12);
+    assert_eq!("".span_of(&word), 0);
+    assert_eq!("naïve".span_of(&word), 2);
+    assert_eq!("  x".find_not_of(&CharClass::WHITESPACE), Some(2));
+    assert_eq!("  \t".find_not_of(&CharClass::WHITESPACE), None);
+    assert_eq!(" \u{3000}x".find_not_of(&CharClass::WHITESPACE), Some(1));
+
+    // Non-ASCII members of a class never match a `str`.
+    let all = CharClass::EMPTY.complement();
+    assert_eq!("aé".span_of(&all), 1);
+    assert_eq!("--é--".trim_matches_class(&all), "é");
+    assert_eq!(" \tfoo bar\n".trim_matches_class(&CharClass::WHITESPACE), "foo bar");
+    assert_eq!("12345".trim_matches_class(&CharClass::DIGIT), "");
+}
+
 #[test]
 fn test_trim_left() {
     assert_eq!("".trim_left(), "");
@@ -1010,6 +1041,67 @@ fn test_split_whitespace() {
     assert_eq!(words, ["Märy", "häd", "ä", "little", "lämb", "Little", "lämb"])
 }
 
+#[test]
+fn test_graphemes() {
+    let cases: &[(&str, &[&str])] = &[
+        ("", &[]),
+        ("abc", &["a", "b", "c"]),
+        ("\r\n\n\r", &["\r\n", "\n", "\r"]),
+        // combining marks and a zero width joiner extend the base
+        ("e\u{301}\u{302}x\u{200d}", &["e\u{301}\u{302}", "x\u{200d}"]),
+        // ... but not across a control character
+        ("\u{301}\t\u{301}", &["\u{301}", "\t", "\u{301}"]),
+        // spacing marks and prepended concatenation marks
+        ("\u{915}\u{94b}\u{600}1", &["\u{915}\u{94b}", "\u{600}1"]),
+        // Hangul jamo and precomposed syllables
+        ("\u{1100}\u{1161}\u{11a8}\u{ac00}\u{11a8}\u{1100}",
+         &["\u{1100}\u{1161}\u{11a8}", "\u{ac00}\u{11a8}", "\u{1100}"]),
+        // regional indicators pair up into flags
+        ("\u{1f1e8}\u{1f1ed}\u{1f1e9}\u{1f1ea}\u{1f1eb}",
+         &["\u{1f1e8}\u{1f1ed}", "\u{1f1e9}\u{1f1ea}", "\u{1f1eb}"]),
+        // emoji modifiers and ZWJ sequences
+        ("\u{1f466}\u{1f3fb}a\u{1f3fb}", &["\u{1f466}\u{1f3fb}", "a", "\u{1f3fb}"]),
+        ("\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}",
+         &["\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}"]),
+    ];
+    for &(s, expected) in cases {
+        let forward: Vec<&str> = s.graphemes().collect();
+        assert_eq!(forward, expected);
+
+        let mut backward: Vec<&str> = s.graphemes().rev().collect();
+        backward.reverse();
+        assert_eq!(backward, expected);
+    }
+}
+
+#[test]
+fn test_graphemes_both_ends() {
+    let mut iter = "\u{1f1e8}\u{1f1ed}\u{1f1e9}a\u{308}b".graphemes();
+    assert_eq!(iter.next_back(), Some("b"));
+    assert_eq!(iter.next(), Some("\u{1f1e8}\u{1f1ed}"));
+    assert_eq!(iter.as_str(), "\u{1f1e9}a\u{308}");
+    assert_eq!(iter.next_back(), Some("a\u{308}"));
+    assert_eq!(iter.next_back(), Some("\u{1f1e9}"));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn test_split_whitespace_double_ended() {
+    let data = "\n \tMäry   häd\tä  little lämb\nLittle lämb\n";
+    let words: Vec<&str> = data.split_whitespace().rev().collect();
+    assert_eq!(words, ["lämb", "Little", "lämb", "little", "ä", "häd", "Märy"]);
+
+    let mut words = "key = value  # comment".split_whitespace();
+    assert_eq!(words.next(), Some("key"));
+    assert_eq!(words.next_back(), Some("comment"));
+    assert_eq!(words.next_back(), Some("#"));
+    assert_eq!(words.next(), Some("="));
+    assert_eq!(words.next(), Some("value"));
+    assert_eq!(words.next_back(), None);
+    assert_eq!(words.next(), None);
+}
+
 #[test]
 fn test_lines() {
     let data = "\nMäry häd ä little lämb\n\r\nLittle lämb\n";
@@ -1095,6 +1187,24 @@ fn test_empty_match_indices() {
     assert_eq!(vec, [(0, ""), (1, ""), (3, ""), (6, ""), (7, "")]);
 }
 
+#[test]
+fn test_match_indices_non_overlapping() {
+    // Searching from either end picks different non-overlapping matches.
+    let data = "aaaaa";
+    let fwd: Vec<_> = data.match_indices("aa").collect();
+    assert_eq!(fwd, [(0, "aa"), (2, "aa")]);
+    let rev: Vec<_> = data.rmatch_indices("aa").collect();
+    assert_eq!(rev, [(3, "aa"), (1, "aa")]);
+    assert_eq!(data.rmatches("aa").count(), 2);
+
+    // Indices are byte offsets, not char counts.
+    let data = "ää中ää中";
+    let fwd: Vec<_> = data.match_indices("ä中").collect();
+    assert_eq!(fwd, [(2, "ä中"), (9, "ä中")]);
+    let rev: Vec<_> = data.rmatch_indices('ä').map(|(i, _)| i).collect();
+    assert_eq!(rev, [9, 7, 2, 0]);
+}
+
 #[test]
 fn test_bool_from_str() {
     assert_eq!("true".parse().ok(), Some(true));
@@ -1209,6 +1319,23 @@ fn test_utf16_code_units() {
                [0xE9, 0xD83D, 0xDCA9])
 }
 
+#[test]
+fn test_encode_utf16() {
+    let s = "a\u{e9}\u{1F4A9}\u{10000}";
+    let units: Vec<u16> = s.encode_utf16().collect();
+    assert_eq!(units, [0x61, 0xE9, 0xD83D, 0xDCA9, 0xD800, 0xDC00]);
+    assert_eq!(String::from_utf16(&units).unwrap(), s);
+
+    let (lower, upper) = s.encode_utf16().size_hint();
+    assert!(lower <= units.len());
+    assert!(upper.map_or(true, |upper| upper >= units.len()));
+
+    // A NUL-terminated wide string, as passed to Windows APIs.
+    let wide: Vec<u16> = "C:\\".encode_utf16().chain(Some(0)).collect();
+    assert_eq!(wide, [0x43, 0x3A, 0x5C, 0]);
+    assert_eq!("".encode_utf16().next(), None);
+}
+
 #[test]
 fn starts_with_in_unicode() {
     assert!(!"├── Cargo.toml".starts_with("# "));
@@ -1295,6 +1422,155 @@ fn to_uppercase() {
     assert_eq!("aéǅßﬁᾀ".to_uppercase(), "AÉǄSSFIἈΙ");
 }
 
+#[test]
+fn test_case_mapping_changes_length() {
+    // Mappings from SpecialCasing.txt that do not depend on the locale may
+    // map one char to several.
+    assert_eq!("straße".to_uppercase(), "STRASSE");
+    assert_eq!("\u{149}".to_uppercase(), "\u{2bc}N");
+    assert_eq!("\u{390}".to_uppercase(), "\u{399}\u{308}\u{301}");
+    assert_eq!("\u{130}".to_lowercase(), "i\u{307}");
+
+    // The Turkish and Lithuanian rules are not applied.
+    assert_eq!("I".to_lowercase(), "i");
+    assert_eq!("i".to_uppercase(), "I");
+
+    let s = String::from("ﬀ ß");
+    assert_eq!(s.to_uppercase(), "FF SS");
+    assert_eq!(s.to_uppercase().to_lowercase(), "ff ss");
+}
+
+#[test]
+fn test_nfd() {
+    macro_rules! t {
+        ($input: expr, $expected: expr) => {
+            assert_eq!($input.nfd().collect::<String>(), $expected);
+        }
+    }
+    t!("abc", "abc");
+    t!("\u{1e0b}\u{1c4}", "d\u{307}\u{1c4}");
+    t!("\u{2026}", "\u{2026}");
+    t!("\u{2126}", "\u{3a9}");
+    t!("\u{1e0b}\u{323}", "d\u{323}\u{307}");
+    t!("\u{1e0d}\u{307}", "d\u{323}\u{307}");
+    t!("a\u{301}", "a\u{301}");
+    t!("\u{301}a", "\u{301}a");
+    t!("\u{d4db}", "\u{1111}\u{1171}\u{11b6}");
+    t!("\u{ac1c}", "\u{1100}\u{1162}");
+}
+
+#[test]
+fn test_nfkd() {
+    macro_rules! t {
+        ($input: expr, $expected: expr) => {
+            assert_eq!($input.nfkd().collect::<String>(), $expected);
+        }
+    }
+    t!("abc", "abc");
+    t!("\u{1e0b}\u{1c4}", "d\u{307}DZ\u{30c}");
+    t!("\u{2026}", "...");
+    t!("\u{2126}", "\u{3a9}");
+    t!("\u{1e0b}\u{323}", "d\u{323}\u{307}");
+    t!("\u{1e0d}\u{307}", "d\u{323}\u{307}");
+    t!("a\u{301}", "a\u{301}");
+    t!("\u{301}a", "\u{301}a");
+    t!("\u{d4db}", "\u{1111}\u{1171}\u{11b6}");
+    t!("\u{ac1c}", "\u{1100}\u{1162}");
+}
+
+#[test]
+fn test_nfc() {
+    macro_rules! t {
+        ($input: expr, $expected: expr) => {
+            assert_eq!($input.nfc().collect::<String>(), $expected);
+        }
+    }
+    t!("abc", "abc");
+    t!("\u{1e0b}\u{1c4}", "\u{1e0b}\u{1c4}");
+    t!("\u{2026}", "\u{2026}");
+    t!("\u{2126}", "\u{3a9}");
+    t!("\u{1e0b}\u{323}", "\u{1e0d}\u{307}");
+    t!("\u{1e0d}\u{307}", "\u{1e0d}\u{307}");
+    t!("a\u{301}", "\u{e1}");
+    t!("\u{301}a", "\u{301}a");
+    t!("\u{d4db}", "\u{d4db}");
+    t!("\u{ac1c}", "\u{ac1c}");
+    t!("a\u{300}\u{305}\u{315}\u{5ae}b", "\u{e0}\u{5ae}\u{305}\u{315}b");
+}
+
+#[test]
+fn test_nfkc() {
+    macro_rules! t {
+        ($input: expr, $expected: expr) => {
+            assert_eq!($input.nfkc().collect::<String>(), $expected);
+        }
+    }
+    t!("abc", "abc");
+    t!("\u{1e0b}\u{1c4}", "\u{1e0b}D\u{17d}");
+    t!("\u{2026}", "...");
+    t!("\u{2126}", "\u{3a9}");
+    t!("\u{1e0b}\u{323}", "\u{1e0d}\u{307}");
+    t!("\u{1e0d}\u{307}", "\u{1e0d}\u{307}");
+    t!("a\u{301}", "\u{e1}");
+    t!("\u{301}a", "\u{301}a");
+    t!("\u{d4db}", "\u{d4db}");
+    t!("\u{ac1c}", "\u{ac1c}");
+    t!("a\u{300}\u{305}\u{315}\u{5ae}b", "\u{e0}\u{5ae}\u{305}\u{315}b");
+}
+
+#[test]
+fn test_normalized_keys() {
+    use std::collections::HashSet;
+
+    let mut set = HashSet::new();
+    for s in &["Cafe\u{301}", "Caf\u{e9}", "Cafe\u{301}\u{323}", "Cafe\u{323}\u{301}"] {
+        set.insert(s.nfc().collect::<String>());
+    }
+    assert_eq!(set.len(), 2);
+    assert!(set.contains("Caf\u{e9}"));
+    assert!(set.contains("Caf\u{1eb9}\u{301}"));
+}
+
+#[test]
+fn test_tokenizer() {
+    use std::str::Tokenizer;
+
+    let line = r#"cp -r "My Documents" it\'s\ here 'a "b" c'"#;
+    let tokens: Vec<_> = Tokenizer::new(line).collect();
+    assert_eq!(tokens, ["cp", "-r", "My Documents", "it's here", "a \"b\" c"]);
+
+    let tokens: Vec<_> = Tokenizer::new(r#"  a"b c"d   'open end"#).collect();
+    assert_eq!(tokens, ["ab cd", "open end"]);
+    let tokens: Vec<_> = Tokenizer::new(r"trailing\").collect();
+    assert_eq!(tokens, [r"trailing\"]);
+    assert_eq!(Tokenizer::new("").count(), 0);
+    assert_eq!(Tokenizer::new(" \t\n ").count(), 0);
+
+    let fields: Vec<_> = Tokenizer::new(r#"1,"Smith, J.",,\,,"#).delimiters(&[',']).collect();
+    assert_eq!(fields, ["1", "Smith, J.", "", ",", ""]);
+    assert_eq!(Tokenizer::new("").delimiters(&[',']).count(), 0);
+
+    let tokens: Vec<_> = Tokenizer::new(r#"a\ b |c d|"#).quotes(&['|']).escape(None).collect();
+    assert_eq!(tokens, [r"a\", "b", "c d"]);
+}
+
+#[test]
+fn test_tokenizer_borrows_when_possible() {
+    use std::str::Tokenizer;
+
+    fn borrowed(token: &Cow<str>) -> bool {
+        match *token {
+            Cow::Borrowed(_) => true,
+            Cow::Owned(_) => false,
+        }
+    }
+
+    let tokens: Vec<_> = Tokenizer::new(r#"plain "quoted" 'a "b"' x\ y "a"b "\"""#).collect();
+    assert_eq!(tokens, ["plain", "quoted", "a \"b\"", "x y", "ab", "\""]);
+    let borrowed: Vec<_> = tokens.iter().map(borrowed).collect();
+    assert_eq!(borrowed, [true, true, true, false, false, false]);
+}
+
 #[test]
 fn test_into_string() {
     // The only way to acquire a Box<str> in the first place is through a String, so just
@@ -11,6 +11,7 @@
 use std::borrow::Cow;
 use std::collections::CollectionAllocErr::*;
 use std::mem::size_of;
+use std::string::StringBuilder;
 use std::{usize, isize};
 
 pub trait IntoCow<'a, B: ?Sized> where B: ToOwned {
@@ -61,6 +62,29 @@ fn test_from_utf8() {
     assert_eq!(err.into_bytes(), b"hello\xff".to_vec());
 }
 
+#[test]
+fn test_from_utf8_error_resume() {
+    // A decoder that got "中" split across two reads keeps the partial
+    // character and continues once the rest arrives.
+    let xs = b"ab\xE4\xB8".to_vec();
+    let ptr = xs.as_ptr();
+    let err = String::from_utf8(xs).unwrap_err();
+    assert_eq!(err.utf8_error().valid_up_to(), 2);
+    assert_eq!(err.utf8_error().error_len(), None);
+    assert_eq!(err.as_bytes(), b"ab\xE4\xB8");
+
+    // The original buffer comes back, without a copy.
+    let mut xs = err.into_bytes();
+    assert_eq!(xs.as_ptr(), ptr);
+    xs.extend_from_slice(b"\xADc");
+    assert_eq!(String::from_utf8(xs).unwrap(), "ab中c");
+
+    // A byte that can never be valid has an error length.
+    let err = String::from_utf8(b"ab\xFFc".to_vec()).unwrap_err();
+    assert_eq!(err.utf8_error().valid_up_to(), 2);
+    assert_eq!(err.utf8_error().error_len(), Some(1));
+}
+
 #[test]
 fn test_from_utf8_lossy() {
     let xs = b"hello";
@@ -101,6 +125,26 @@ fn test_from_utf8_lossy() {
                String::from("\u{FFFD}\u{FFFD}\u{FFFD}foo\u{FFFD}\u{FFFD}\u{FFFD}bar").into_cow());
 }
 
+#[test]
+fn test_from_utf8_lossy_borrows_valid() {
+    // Valid input is returned as is, without allocating.
+    let xs = "ศไทย中华Việt Nam".as_bytes();
+    match String::from_utf8_lossy(xs) {
+        Cow::Borrowed(s) => assert_eq!(s.as_ptr(), xs.as_ptr()),
+        Cow::Owned(_) => panic!("valid UTF-8 was copied"),
+    }
+    match String::from_utf8_lossy(b"") {
+        Cow::Borrowed(s) => assert_eq!(s, ""),
+        Cow::Owned(_) => panic!("valid UTF-8 was copied"),
+    }
+
+    // Only invalid input is copied.
+    match String::from_utf8_lossy(b"caf\xC3") {
+        Cow::Borrowed(_) => panic!("invalid UTF-8 was borrowed"),
+        Cow::Owned(s) => assert_eq!(s, "caf\u{FFFD}"),
+    }
+}
+
 #[test]
 fn test_from_utf16() {
     let pairs = [(String::from("𐍅𐌿𐌻𐍆𐌹𐌻𐌰\n"),
@@ -209,6 +253,51 @@ fn test_add_assign() {
     assert_eq!(s.as_str(), "abcประเทศไทย中华Việt Nam");
 }
 
+#[test]
+fn test_add_and_write() {
+    use std::fmt::Write;
+
+    let greeting = String::from("hello") + ", " + "world";
+    assert_eq!(greeting, "hello, world");
+
+    let mut s = String::new();
+    write!(s, "{}-{:03}", "id", 7).unwrap();
+    s.write_char('!').unwrap();
+    writeln!(&mut s, " {:?}", Some(1.5)).unwrap();
+    assert_eq!(s, "id-007! Some(1.5)\n");
+
+    let mut s = String::with_capacity(64);
+    let ptr = s.as_ptr();
+    write!(s, "{} + {} = {}", 1, 2, 3).unwrap();
+    assert_eq!(s, "1 + 2 = 3");
+    assert_eq!(s.as_ptr(), ptr);
+}
+
+#[test]
+fn test_push_num() {
+    macro_rules! check {
+        ($($t:ident)*) => {$(
+            for &n in [0, 1, 9, 10, 99, 100, $t::max_value(), $t::min_value()].iter() {
+                let mut s = String::from("n=");
+                s.push_num(n);
+                assert_eq!(s, format!("n={}", n));
+                assert_eq!(n.to_string(), format!("{}", n));
+            }
+        )*}
+    }
+    check!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+    let floats = [0.0, -0.0, 0.1, -1.5, 1e-7, 123456.789, 1e300, ::std::f64::MIN_POSITIVE,
+                  ::std::f64::INFINITY, ::std::f64::NEG_INFINITY, ::std::f64::NAN];
+    for &f in floats.iter() {
+        let mut s = String::new();
+        s.push_num(f);
+        s.push_num(f as f32);
+        assert_eq!(s, format!("{}{}", f, f as f32));
+        assert_eq!(f.to_string(), format!("{}", f));
+    }
+}
+
 #[test]
 fn test_push() {
     let mut data = String::from("ประเทศไทย中");
@@ -443,56 +532,76 @@ fn test_drain() {
 }
 
 #[test]
-fn test_splice() {
+fn test_replace_range() {
     let mut s = "Hello, world!".to_owned();
-    s.splice(7..12, "世界");
+    s.replace_range(7..12, "世界");
     assert_eq!(s, "Hello, 世界!");
 }
 
 #[test]
 #[should_panic]
-fn test_splice_char_boundary() {
+fn test_replace_range_char_boundary() {
     let mut s = "Hello, 世界!".to_owned();
-    s.splice(..8, "");
+    s.replace_range(..8, "");
 }
 
 #[test]
-fn test_splice_inclusive_range() {
+fn test_replace_range_inclusive_range() {
     let mut v = String::from("12345");
-    v.splice(2..=3, "789");
+    v.replace_range(2..=3, "789");
     assert_eq!(v, "127895");
-    v.splice(1..=2, "A");
+    v.replace_range(1..=2, "A");
     assert_eq!(v, "1A895");
 }
 
 #[test]
 #[should_panic]
-fn test_splice_out_of_bounds() {
+fn test_replace_range_out_of_bounds() {
     let mut s = String::from("12345");
-    s.splice(5..6, "789");
+    s.replace_range(5..6, "789");
 }
 
 #[test]
 #[should_panic]
-fn test_splice_inclusive_out_of_bounds() {
+fn test_replace_range_inclusive_out_of_bounds() {
     let mut s = String::from("12345");
-    s.splice(5..=5, "789");
+    s.replace_range(5..=5, "789");
 }
 
 #[test]
-fn test_splice_empty() {
+fn test_replace_range_empty() {
     let mut s = String::from("12345");
-    s.splice(1..2, "");
+    s.replace_range(1..2, "");
     assert_eq!(s, "1345");
 }
 
 #[test]
-fn test_splice_unbounded() {
+fn test_replace_range_unbounded() {
     let mut s = String::from("12345");
-    s.splice(.., "");
+    s.replace_range(.., "");
     assert_eq!(s, "");
 }
 
+#[test]
+fn test_replace_range_grow_and_shrink() {
+    let mut s = String::from("abcdef");
+    s.replace_range(1..3, "XYZW");
+    assert_eq!(s, "aXYZWdef");
+    s.replace_range(2..7, "-");
+    assert_eq!(s, "aX-f");
+    s.replace_range(4..4, "αβ");
+    assert_eq!(s, "aX-fαβ");
+    s.replace_range(..0, "!");
+    assert_eq!(s, "!aX-fαβ");
+}
+
+#[test]
+#[should_panic]
+fn test_replace_range_start_after_end() {
+    let mut s = String::from("12345");
+    s.replace_range(3..2, "");
+}
+
 #[test]
 fn test_extend_ref() {
     let mut a = "foo".to_string();
@@ -508,6 +617,29 @@ fn test_into_boxed_str() {
     assert_eq!(&*ys, "hello my name is bob");
 }
 
+#[test]
+fn test_into_raw_parts() {
+    let mut s = String::with_capacity(20);
+    s.push_str("hello");
+    let ptr = s.as_ptr();
+
+    let (raw, len, cap) = s.into_raw_parts();
+    assert_eq!((raw as *const u8, len, cap), (ptr, 5, 20));
+    let mut s = unsafe { String::from_raw_parts(raw, len, cap) };
+    s.push_str(", world");
+    assert_eq!(s, "hello, world");
+}
+
+#[test]
+fn test_allocated_capacity() {
+    let mut s = String::with_capacity(3);
+    assert_eq!(s.capacity(), 3);
+    assert!(s.allocated_capacity() >= 3);
+
+    s.push_str("grows past its capacity");
+    assert_eq!(s.capacity(), s.allocated_capacity());
+}
+
 #[test]
 fn test_reserve_exact() {
     // This is all the same as test_reserve
@@ -667,3 +799,45 @@ fn test_try_reserve_exact() {
     }
 
 }
+
+#[test]
+fn test_string_builder() {
+    let word = String::from("words");
+    let mut b = StringBuilder::new();
+    b.append("some ").append(&word).append(' ').append('\u{1f600}')
+     .append(0u8).append(-1i8).append(i64::min_value()).append(u64::max_value())
+     .append(1.5f32).append(-0.25f64);
+    let expected = format!("some words \u{1f600}0-1{}{}1.5-0.25",
+                           i64::min_value(), u64::max_value());
+    assert_eq!(b.build(), expected);
+
+    let mut s = String::from(">");
+    b.build_into(&mut s);
+    assert_eq!(s, format!(">{}", expected));
+
+    b.clear();
+    assert_eq!(b.len_hint(), 0);
+    assert_eq!(b.build(), "");
+}
+
+#[test]
+fn test_string_builder_reserves_once() {
+    let mut b = StringBuilder::new();
+    for i in 0..100 {
+        b.append("item ").append(i).append(',');
+    }
+    let len = b.len_hint();
+    let s = b.build();
+    assert_eq!(s.len(), len);
+    assert_eq!(s.capacity(), len);
+}
+
+#[test]
+fn test_as_mut_str() {
+    fn shout<S: AsMut<str>>(mut s: S) -> S {
+        s.as_mut().make_ascii_uppercase();
+        s
+    }
+
+    assert_eq!(shout(String::from("hello")), "HELLO");
+}
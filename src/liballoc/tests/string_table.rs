@@ -0,0 +1,98 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::string_table::StringTable;
+
+#[test]
+fn test_push_and_get() {
+    let mut table = StringTable::new();
+    assert!(table.is_empty());
+    assert_eq!(table.push("one"), 0);
+    assert_eq!(table.push(""), 1);
+    assert_eq!(table.push("τρία"), 2);
+
+    assert_eq!(table.len(), 3);
+    assert_eq!(table.total_len(), 11);
+    assert_eq!(table.get(0), Some("one"));
+    assert_eq!(table.get(1), Some(""));
+    assert_eq!(&table[2], "τρία");
+    assert_eq!(table.get(3), None);
+    assert_eq!(table.last(), Some("τρία"));
+
+    assert_eq!(table.iter().collect::<Vec<_>>(), ["one", "", "τρία"]);
+    assert_eq!(table.iter().rev().collect::<Vec<_>>(), ["τρία", "", "one"]);
+    assert_eq!(table.iter().len(), 3);
+    assert_eq!(format!("{:?}", table), r#"["one", "", "τρία"]"#);
+
+    assert_eq!(table.pop(), Some(String::from("τρία")));
+    assert_eq!(table.pop(), Some(String::new()));
+    assert_eq!(table.total_len(), 3);
+    table.clear();
+    assert_eq!(table.pop(), None);
+    assert_eq!(table, StringTable::new());
+}
+
+#[test]
+fn test_sort_dedup_and_search() {
+    let mut table: StringTable = "the quick brown fox jumps over the lazy dog the end"
+        .split(' ')
+        .collect();
+    table.sort();
+    assert_eq!(table.iter().collect::<Vec<_>>(),
+               ["brown", "dog", "end", "fox", "jumps", "lazy", "over", "quick", "the", "the",
+                "the"]);
+    table.dedup();
+    assert_eq!(table.len(), 9);
+    assert_eq!(table.last(), Some("the"));
+
+    for (i, s) in table.iter().enumerate() {
+        assert_eq!(table.binary_search(s), Ok(i));
+    }
+    assert_eq!(table.binary_search(""), Err(0));
+    assert_eq!(table.binary_search("cat"), Err(1));
+    assert_eq!(table.binary_search("zebra"), Err(9));
+    assert_eq!(StringTable::new().binary_search("a"), Err(0));
+}
+
+#[test]
+fn test_bytes_round_trip() {
+    let table: StringTable = vec!["", "a", "ünïcødé", ""].into_iter().collect();
+    let bytes = table.to_bytes();
+    assert_eq!(bytes.len(), 8 * 5 + table.total_len());
+    assert_eq!(StringTable::from_bytes(&bytes), Ok(table.clone()));
+    assert_eq!(StringTable::from_bytes(&StringTable::new().to_bytes()), Ok(StringTable::new()));
+
+    // Truncated anywhere.
+    for len in 0..bytes.len() {
+        assert!(StringTable::from_bytes(&bytes[..len]).is_err());
+    }
+
+    // An offset inside a multi-byte character.
+    let mut bad = bytes.clone();
+    bad[8 * 3] -= 1;
+    assert!(StringTable::from_bytes(&bad).is_err());
+
+    // Offsets out of order.
+    let mut bad = bytes.clone();
+    bad[8 * 3] = 0;
+    assert!(StringTable::from_bytes(&bad).is_err());
+
+    // A huge count must not allocate.
+    let mut bad = bytes.clone();
+    bad[7] = 0xff;
+    assert!(StringTable::from_bytes(&bad).is_err());
+}
+
+#[test]
+#[should_panic]
+fn test_index_out_of_bounds() {
+    let table: StringTable = vec!["a"].into_iter().collect();
+    let _ = &table[1];
+}
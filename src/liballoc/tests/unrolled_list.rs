@@ -0,0 +1,171 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::VecDeque;
+use std::collections::unrolled_list::UnrolledList;
+
+fn check_chunks<T>(list: &UnrolledList<T>) {
+    assert!(list.chunks().all(|chunk| !chunk.is_empty() && chunk.len() <= 32));
+    assert_eq!(list.chunks().map(|chunk| chunk.len()).sum::<usize>(), list.len());
+}
+
+#[test]
+fn test_push_pop() {
+    let mut list = UnrolledList::new();
+    for i in 0..100 {
+        list.push_back(i);
+        list.push_front(-i);
+    }
+    check_chunks(&list);
+    assert_eq!(list.len(), 200);
+    assert_eq!(list.front(), Some(&-99));
+    assert_eq!(list.back(), Some(&99));
+    assert_eq!(list[100], 0);
+
+    *list.front_mut().unwrap() = 1000;
+    list[199] = 2000;
+    assert_eq!(list.pop_front(), Some(1000));
+    assert_eq!(list.pop_back(), Some(2000));
+    for i in (0..99).rev() {
+        assert_eq!(list.pop_back(), Some(i));
+        assert_eq!(list.pop_front(), Some(-i));
+    }
+    assert_eq!(list.pop_front(), None);
+    assert_eq!(list.pop_back(), None);
+    assert_eq!(list.chunks().count(), 0);
+}
+
+#[test]
+fn test_edits_match_vec_deque() {
+    let mut list = UnrolledList::new();
+    let mut model = VecDeque::new();
+    let mut seed = 0x2545_f491_u32;
+    let mut random = |n: usize| {
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        seed as usize % n
+    };
+
+    for round in 0..5000 {
+        match random(4) {
+            0 | 1 => {
+                let at = random(model.len() + 1);
+                list.insert(at, round);
+                model.insert(at, round);
+            }
+            2 => {
+                let at = random(model.len() + 1);
+                assert_eq!(list.remove(at), model.remove(at));
+            }
+            _ => {
+                let at = random(model.len() + 1);
+                let mut back = list.split_off(at);
+                let model_back = model.split_off(at);
+                check_chunks(&list);
+                check_chunks(&back);
+                assert!(back.iter().eq(model_back.iter()));
+                list.append(&mut back);
+                model.extend(model_back);
+                assert!(back.is_empty());
+            }
+        }
+        assert_eq!(list.len(), model.len());
+        if round % 100 == 0 {
+            check_chunks(&list);
+            assert!(list.iter().eq(model.iter()));
+            assert!(list.iter().rev().eq(model.iter().rev()));
+            for i in 0..model.len() {
+                assert_eq!(list.get(i), model.get(i));
+            }
+        }
+    }
+    assert!(list.into_iter().eq(model.into_iter()));
+}
+
+#[test]
+fn test_split_off_at_chunk_boundary() {
+    let mut list: UnrolledList<_> = (0..96).collect();
+    assert_eq!(list.chunks().count(), 3);
+    let back = list.split_off(32);
+    assert_eq!(list.chunks().count(), 1);
+    assert_eq!(back.chunks().count(), 2);
+    assert!(back.iter().cloned().eq(32..96));
+
+    let mut list: UnrolledList<_> = (0..96).collect();
+    let back = list.split_off(40);
+    assert_eq!(list.chunks().map(|chunk| chunk.len()).collect::<Vec<_>>(), [32, 8]);
+    assert_eq!(back.chunks().map(|chunk| chunk.len()).collect::<Vec<_>>(), [24, 32]);
+
+    let mut all = list.split_off(0);
+    assert!(list.is_empty());
+    assert!(all.split_off(40).is_empty());
+    assert!(all.iter().cloned().eq(0..40));
+}
+
+#[test]
+fn test_insert_splits_full_chunk() {
+    let mut list: UnrolledList<_> = (0..32).collect();
+    list.insert(10, -1);
+    check_chunks(&list);
+    assert_eq!(list.chunks().map(|chunk| chunk.len()).collect::<Vec<_>>(), [17, 16]);
+    assert_eq!(list[10], -1);
+    assert_eq!(list[11], 10);
+}
+
+#[test]
+fn test_remove_keeps_chunks_half_full() {
+    let mut list: UnrolledList<_> = (0..320).collect();
+    let mut model: Vec<_> = (0..320).collect();
+    let mut at = 7;
+    while model.len() > 32 {
+        // Stay clear of either end, where removing pops without rebalancing.
+        at = 1 + (at * 31 + 11) % (model.len() - 2);
+        assert_eq!(list.remove(at), Some(model.remove(at)));
+        check_chunks(&list);
+        assert!(list.chunks().all(|chunk| chunk.len() >= 16));
+    }
+    assert!(list.iter().eq(model.iter()));
+}
+
+#[test]
+fn test_iterators() {
+    let mut list: UnrolledList<_> = (0..70).collect();
+    for value in list.iter_mut() {
+        *value *= 2;
+    }
+    let mut iter = list.iter();
+    assert_eq!(iter.len(), 70);
+    assert_eq!(iter.next(), Some(&0));
+    assert_eq!(iter.next_back(), Some(&138));
+    assert_eq!(iter.len(), 68);
+    assert!(iter.eq((1..69).map(|i| i * 2).collect::<Vec<_>>().iter()));
+
+    let mut iter = list.clone().into_iter();
+    assert_eq!(iter.next_back(), Some(138));
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.len(), 68);
+}
+
+#[test]
+fn test_traits() {
+    let list: UnrolledList<_> = (0..5).collect();
+    assert_eq!(format!("{:?}", list), "[0, 1, 2, 3, 4]");
+    assert_eq!(list, list.clone());
+    assert!(list != (0..4).collect());
+    assert_eq!(::hash(&list), ::hash(&list.clone()));
+    assert_eq!(UnrolledList::<i32>::default(), UnrolledList::new());
+
+    let mut list = list;
+    list.extend(&[5, 6]);
+    assert!(list.iter().cloned().eq(0..7));
+    list.clear();
+    assert!(list.is_empty());
+}
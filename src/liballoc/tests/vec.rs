@@ -10,6 +10,7 @@
 
 use std::borrow::Cow;
 use std::mem::size_of;
+use std::rc::Rc;
 use std::{usize, isize};
 use std::vec::{Drain, IntoIter};
 use std::collections::CollectionAllocErr::*;
@@ -79,6 +80,72 @@ fn test_reserve() {
     assert!(v.capacity() >= 33)
 }
 
+#[test]
+fn test_allocated_capacity() {
+    let mut v: Vec<u16> = Vec::new();
+    assert_eq!(v.allocated_capacity(), 0);
+
+    // Exact requests get exactly what they asked for, however much room
+    // the allocation really has.
+    v.reserve_exact(5);
+    assert_eq!(v.capacity(), 5);
+    assert!(v.allocated_capacity() >= 5);
+
+    // Growing on demand takes all the room there is.
+    for i in 0..100 {
+        v.push(i);
+        assert!(v.allocated_capacity() >= v.capacity());
+    }
+    assert_eq!(v.capacity(), v.allocated_capacity());
+
+    v.shrink_to_fit();
+    assert_eq!(v.capacity(), 100);
+    assert!(v.allocated_capacity() >= 100);
+
+    let v = vec![(); 10];
+    assert_eq!(v.allocated_capacity(), usize::MAX);
+}
+
+#[test]
+fn test_reserve_with() {
+    use std::collections::growth::{Doubling, Factor, MaxStep};
+
+    let mut v: Vec<u32> = Vec::new();
+    v.reserve_with(3, &Doubling);
+    assert_eq!(v.capacity(), 3);
+    v.extend(0..3);
+    v.reserve_with(1, &Doubling);
+    assert_eq!(v.capacity(), 6);
+
+    // Already enough room, so nothing changes.
+    v.reserve_with(3, &Factor::new(3, 2));
+    assert_eq!(v.capacity(), 6);
+
+    v.extend(3..6);
+    v.reserve_with(1, &Factor::new(3, 2));
+    assert_eq!(v.capacity(), 9);
+
+    // A policy can't grow the buffer by less than was asked for.
+    v.reserve_with(10, &MaxStep::new(Doubling, 2));
+    assert_eq!(v.capacity(), 16);
+    v.extend(6..16);
+    v.reserve_with(1, &MaxStep::new(Doubling, 2));
+    assert_eq!(v.capacity(), 18);
+
+    // Zero-sized types never need to grow.
+    let mut v = vec![(); 10];
+    v.reserve_with(1, &Factor::new(3, 2));
+    assert_eq!(v.capacity(), usize::MAX);
+}
+
+#[test]
+#[should_panic]
+fn test_growth_factor_too_small() {
+    use std::collections::growth::Factor;
+
+    Factor::new(2, 2);
+}
+
 #[test]
 fn test_extend() {
     let mut v = Vec::new();
@@ -227,6 +294,48 @@ fn test_clone_from() {
     assert_eq!(v, three)
 }
 
+#[test]
+fn test_push_get() {
+    let mut v = Vec::new();
+    for i in 0..10 {
+        assert_eq!(*v.push_get(i), i);
+    }
+    *v.push_get(10) += 1;
+    assert_eq!(v, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 11]);
+}
+
+#[test]
+fn test_pop_if() {
+    let mut v: Vec<i32> = Vec::new();
+    assert_eq!(v.pop_if(|_| true), None);
+
+    v.extend(1..5);
+    assert_eq!(v.pop_if(|x| *x == 4), Some(4));
+    assert_eq!(v.pop_if(|x| *x == 4), None);
+    assert_eq!(v, [1, 2, 3]);
+
+    // The predicate may modify an element it keeps.
+    assert_eq!(v.pop_if(|x| { *x *= 10; false }), None);
+    assert_eq!(v, [1, 2, 30]);
+}
+
+#[test]
+fn test_pop_front_swap() {
+    let mut v: Vec<i32> = Vec::new();
+    assert_eq!(v.pop_front_swap(), None);
+
+    v.extend(1..6);
+    assert_eq!(v.pop_front_swap(), Some(1));
+    assert_eq!(v, [5, 2, 3, 4]);
+
+    let mut drained = vec![];
+    while let Some(x) = v.pop_front_swap() {
+        drained.push(x);
+    }
+    assert_eq!(drained, [5, 4, 3, 2]);
+    assert!(v.is_empty());
+}
+
 #[test]
 fn test_retain() {
     let mut vec = vec![1, 2, 3, 4];
@@ -654,6 +763,18 @@ fn test_into_boxed_slice() {
     assert_eq!(&*ys, [1, 2, 3]);
 }
 
+#[test]
+fn test_into_raw_parts() {
+    let mut vec = Vec::with_capacity(10);
+    vec.extend((0..4).map(|i| box i));
+    let ptr = vec.as_ptr();
+
+    let (raw, len, cap) = vec.into_raw_parts();
+    assert_eq!((raw as *const _, len, cap), (ptr, 4, 10));
+    let vec = unsafe { Vec::from_raw_parts(raw, len, cap) };
+    assert_eq!(vec, [box 0, box 1, box 2, box 3]);
+}
+
 #[test]
 fn test_append() {
     let mut vec = vec![1, 2, 3];
@@ -663,6 +784,40 @@ fn test_append() {
     assert_eq!(vec2, []);
 }
 
+#[test]
+fn test_merge_sorted() {
+    let mut vec = vec![box 1, box 3, box 3, box 5];
+    vec.merge_sorted(vec![box 0, box 3, box 4, box 5, box 6, box 6]);
+    assert_eq!(vec, [box 0, box 1, box 3, box 4, box 5, box 6]);
+    assert_eq!(vec.capacity(), 10);
+
+    vec.merge_sorted(Vec::new());
+    assert_eq!(vec, [box 0, box 1, box 3, box 4, box 5, box 6]);
+
+    let mut empty = Vec::new();
+    empty.merge_sorted(vec![String::from("a"), String::from("a"), String::from("b")]);
+    assert_eq!(empty, ["a", "b"]);
+}
+
+#[test]
+fn test_concat_into() {
+    let empty: Vec<Vec<i32>> = Vec::new();
+    assert_eq!(empty.concat_into(), []);
+
+    let vecs = vec![vec![], vec![1], vec![], vec![2, 3]];
+    let flat = vecs.concat_into();
+    assert_eq!(flat, [1, 2, 3]);
+    assert_eq!(flat.capacity(), 3);
+
+    // Elements are moved, not cloned, and each is dropped exactly once.
+    let rc = Rc::new(());
+    let vecs = vec![vec![rc.clone(), rc.clone()], vec![rc.clone()]];
+    let flat = vecs.concat_into();
+    assert_eq!(Rc::strong_count(&rc), 4);
+    drop(flat);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
 #[test]
 fn test_split_off() {
     let mut vec = vec![1, 2, 3, 4, 5, 6];
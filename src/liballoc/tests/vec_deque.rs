@@ -8,6 +8,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use std::cmp;
 use std::collections::VecDeque;
 use std::fmt::Debug;
 use std::collections::vec_deque::{Drain};
@@ -125,6 +126,34 @@ fn test_index_out_of_bounds() {
     deq[3];
 }
 
+#[test]
+fn test_index_wrapped() {
+    // Slide a window of five elements along, so that it wraps around the
+    // end of the buffer over and over.
+    let mut window = VecDeque::with_capacity(7);
+    let cap = window.capacity();
+    for i in 0..100 {
+        window.push_back(i);
+        if window.len() > 5 {
+            window.pop_front();
+        }
+        let first = window[0];
+        for j in 0..window.len() {
+            assert_eq!(window[j], first + j);
+            assert_eq!(window.get(j), Some(&(first + j)));
+        }
+        assert_eq!(window.get(window.len()), None);
+    }
+    assert_eq!(window.capacity(), cap);
+
+    for j in 0..5 {
+        window[j] *= 2;
+        *window.get_mut(j).unwrap() += 1;
+    }
+    assert_eq!(window.get_mut(5), None);
+    assert_eq!(window.into_iter().collect::<Vec<_>>(), [191, 193, 195, 197, 199]);
+}
+
 #[derive(Clone, PartialEq, Debug)]
 enum Taggy {
     One(i32),
@@ -248,6 +277,38 @@ fn test_reserve_exact() {
     assert!(d.capacity() >= 51);
 }
 
+#[test]
+fn test_exact_capacity() {
+    let mut d = VecDeque::with_capacity(1000);
+    assert_eq!(d.capacity(), 1000);
+    d.extend(0..1000);
+    assert_eq!(d.capacity(), 1000);
+    d.truncate(10);
+    d.shrink_to_fit();
+    assert_eq!(d.capacity(), 10);
+    assert_eq!(d, (0..10).collect::<VecDeque<_>>());
+
+    // Grow by small amounts from every position of the ring, so that the
+    // wrapped part of the buffer never fits into the new space in one piece.
+    for cap in 1..10 {
+        for offset in 0..cap + 1 {
+            for len in 0..cap + 1 {
+                for additional in 0..4 {
+                    let mut d: VecDeque<usize> = VecDeque::with_capacity(cap);
+                    for _ in 0..offset {
+                        d.push_back(0);
+                        d.pop_front();
+                    }
+                    d.extend(0..len);
+                    d.reserve_exact(additional);
+                    assert_eq!(d.capacity(), cmp::max(cap, len + additional));
+                    assert!(d.iter().cloned().eq(0..len));
+                }
+            }
+        }
+    }
+}
+
 #[test]
 fn test_reserve() {
     let mut d = VecDeque::new();
@@ -578,6 +639,27 @@ fn test_partial_eq_array() {
     assert!(d == ['a', 'b']);
 }
 
+#[test]
+fn test_partial_eq_vec() {
+    let mut d = VecDeque::with_capacity(7);
+    let empty: Vec<i32> = Vec::new();
+    assert!(d == empty);
+    assert!(empty == d);
+
+    // Wrap around the end of the buffer, so the deque is two slices.
+    d.extend(&[0; 6]);
+    for _ in 0..6 {
+        d.pop_front();
+    }
+    d.extend(&[1, 2, 3, 4]);
+    assert!(d.as_slices().1.len() > 0);
+    assert!(d == vec![1, 2, 3, 4]);
+    assert!(vec![1, 2, 3, 4] == d);
+    assert!(vec![1, 2, 3, 5] != d);
+    assert!(vec![1, 2, 3] != d);
+    assert_eq!(vec![1, 2, 3, 4], d);
+}
+
 #[test]
 fn test_hash() {
     let mut x = VecDeque::new();
@@ -690,6 +772,20 @@ fn test_drop() {
     assert_eq!(unsafe { DROPS }, 4);
 }
 
+#[test]
+fn test_pop_back_if() {
+    let mut d = VecDeque::new();
+    assert_eq!(d.pop_back_if(|_| true), None);
+
+    // Wrap the contents around the end of the buffer.
+    d.push_back(3);
+    d.push_front(2);
+    d.push_front(1);
+    assert_eq!(d.pop_back_if(|x| *x == 3), Some(3));
+    assert_eq!(d.pop_back_if(|x| *x == 3), None);
+    assert_eq!(d, [1, 2]);
+}
+
 #[test]
 fn test_drop_with_pop() {
     static mut DROPS: i32 = 0;
@@ -907,6 +1003,196 @@ fn test_as_mut_slices() {
     assert_eq!(ring.capacity() as i32, cap);
 }
 
+#[test]
+fn test_as_contiguous() {
+    let mut ring: VecDeque<i32> = VecDeque::with_capacity(7);
+    assert_eq!(ring.as_contiguous(), Some(&[][..]));
+    ring.extend(0..4);
+    assert_eq!(ring.as_contiguous(), Some(&[0, 1, 2, 3][..]));
+    ring.as_mut_contiguous().unwrap()[0] = 10;
+    assert_eq!(ring.as_contiguous(), Some(&[10, 1, 2, 3][..]));
+
+    ring.push_front(-1);
+    assert_eq!(ring.as_contiguous(), None);
+    assert_eq!(ring.as_mut_contiguous(), None);
+
+    ring.pop_front();
+    ring.pop_front();
+    ring.extend(4..8);
+    assert_eq!(ring.as_contiguous(), Some(&[1, 2, 3, 4, 5, 6, 7][..]));
+    for _ in 0..3 {
+        ring.pop_front();
+    }
+    assert_eq!(ring.as_contiguous(), Some(&[4, 5, 6, 7][..]));
+}
+
+#[test]
+fn test_make_contiguous() {
+    // Start the contents at every offset of the buffer, so that every split
+    // between the front and back parts is covered.
+    for offset in 0..8 {
+        for len in 0..8 {
+            let mut ring: VecDeque<i32> = VecDeque::with_capacity(7);
+            for _ in 0..offset {
+                ring.push_back(0);
+                ring.pop_front();
+            }
+            ring.extend(0..len);
+            let expected: Vec<_> = (0..len).collect();
+            assert_eq!(ring.make_contiguous(), &expected[..]);
+            assert_eq!(ring.as_contiguous(), Some(&expected[..]));
+            ring.push_back(len);
+            ring.push_front(-1);
+            assert_eq!(ring.len(), len as usize + 2);
+        }
+    }
+}
+
+#[test]
+fn test_rotate_left_right() {
+    for offset in 0..16 {
+        for len in 0..16 {
+            for n in 0..len + 1 {
+                let mut expected: Vec<_> = (0..len).collect();
+                let mut ring: VecDeque<usize> = VecDeque::with_capacity(15);
+                for _ in 0..offset {
+                    ring.push_back(0);
+                    ring.pop_front();
+                }
+                ring.extend(0..len);
+
+                ring.rotate_left(n);
+                expected.rotate_left(n);
+                assert_eq!(ring, expected);
+
+                ring.rotate_right(n);
+                expected.rotate_right(n);
+                assert_eq!(ring, expected);
+                assert_eq!(ring.capacity(), 15);
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_rotate_right_out_of_bounds() {
+    let mut ring: VecDeque<_> = (0..3).collect();
+    ring.rotate_right(4);
+}
+
+#[test]
+fn test_into_vec() {
+    for offset in 0..8 {
+        for len in 0..8 {
+            let mut ring: VecDeque<usize> = VecDeque::with_capacity(7);
+            for _ in 0..offset {
+                ring.push_back(0);
+                ring.pop_front();
+            }
+            ring.extend(0..len);
+
+            // The ring buffer is reused as it is, so the capacity of the
+            // vector is that of the whole buffer.
+            let vec = ring.into_vec();
+            assert_eq!(vec, (0..len).collect::<Vec<_>>());
+            assert_eq!(vec.capacity(), 8);
+        }
+    }
+}
+
+#[test]
+fn test_raw_parts() {
+    for offset in 0..8 {
+        for len in 0..8 {
+            let mut ring = VecDeque::with_capacity(7);
+            for _ in 0..offset {
+                ring.push_back(box 0);
+                ring.pop_front();
+            }
+            ring.extend((0..len).map(|i| box i));
+
+            let (ptr, start, raw_len, buf_len) = ring.into_raw_parts();
+            assert_eq!((start, raw_len, buf_len), (offset, len, 8));
+            let mut ring = unsafe { VecDeque::from_raw_parts(ptr, start, raw_len, buf_len) };
+            assert_eq!(ring.len(), len);
+            assert!(ring.iter().map(|x| **x).eq(0..len));
+            ring.push_back(box len);
+            assert_eq!(ring.len(), len + 1);
+        }
+    }
+
+    let zsts: VecDeque<()> = vec![(); 5].into();
+    let (ptr, start, len, buf_len) = zsts.into_raw_parts();
+    let zsts = unsafe { VecDeque::from_raw_parts(ptr, start, len, buf_len) };
+    assert_eq!(zsts.len(), 5);
+}
+
+#[test]
+fn test_iter_range() {
+    for offset in 0..8 {
+        for len in 0..8 {
+            let mut ring: VecDeque<usize> = VecDeque::with_capacity(7);
+            for _ in 0..offset {
+                ring.push_back(0);
+                ring.pop_front();
+            }
+            ring.extend(0..len);
+
+            for start in 0..len + 1 {
+                for end in start..len + 1 {
+                    let mut iter = ring.iter_range(start..end);
+                    assert_eq!(iter.len(), end - start);
+                    assert!(iter.by_ref().cloned().eq(start..end));
+                    assert!(ring.iter_range(start..end).rev().cloned().eq((start..end).rev()));
+                    assert_eq!(iter.next(), None);
+                }
+            }
+            assert!(ring.iter_range(..).eq(ring.iter()));
+
+            for x in ring.iter_range_mut(len / 2..) {
+                *x += 100;
+            }
+            let expected = (0..len).map(|i| if i < len / 2 { i } else { i + 100 });
+            assert!(ring.iter().cloned().eq(expected));
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_iter_range_out_of_bounds() {
+    let ring: VecDeque<_> = (0..3).collect();
+    let _ = ring.iter_range(1..4);
+}
+
+#[test]
+fn test_binary_search() {
+    // Wrap the deque around the end of its buffer at every offset, so the
+    // searched values fall in either half.
+    for offset in 0..16 {
+        for len in 0..16 {
+            let mut ring: VecDeque<usize> = VecDeque::with_capacity(15);
+            for _ in 0..offset {
+                ring.push_back(0);
+                ring.pop_front();
+            }
+            ring.extend((0..len).map(|i| i * 2));
+
+            for x in 0..len * 2 + 1 {
+                let expected = if x % 2 == 0 && x < len * 2 {
+                    Ok(x / 2)
+                } else {
+                    Err((x + 1) / 2)
+                };
+                assert_eq!(ring.binary_search(&x), expected);
+                assert_eq!(ring.binary_search_by(|e| e.cmp(&x)), expected);
+                assert_eq!(ring.binary_search_by_key(&(x * 3), |e| e * 3), expected);
+            }
+        }
+    }
+}
+
 #[test]
 fn test_append() {
     let mut a: VecDeque<_> = vec![1, 2, 3].into_iter().collect();
@@ -928,6 +1214,41 @@ fn test_append() {
     assert_eq!(a.iter().cloned().collect::<Vec<_>>(), []);
 }
 
+#[test]
+fn test_append_wrapped() {
+    // Wrap both deques around the ends of their buffers at every offset,
+    // with and without `self` having to grow.
+    for &(self_cap, other_cap) in &[(7, 7), (15, 7), (3, 15)] {
+        for self_offset in 0..self_cap + 1 {
+            for other_offset in 0..other_cap + 1 {
+                for self_len in 0..self_cap + 1 {
+                    for other_len in 0..other_cap + 1 {
+                        let mut a = VecDeque::with_capacity(self_cap);
+                        let mut b = VecDeque::with_capacity(other_cap);
+                        for _ in 0..self_offset {
+                            a.push_back(box 0);
+                            a.pop_front();
+                        }
+                        for _ in 0..other_offset {
+                            b.push_back(box 0);
+                            b.pop_front();
+                        }
+                        a.extend((0..self_len).map(|i| box i));
+                        b.extend((self_len..self_len + other_len).map(|i| box i));
+
+                        a.append(&mut b);
+                        assert!(b.is_empty());
+                        assert_eq!(a.into_iter().map(|x| *x).collect::<Vec<_>>(),
+                                   (0..self_len + other_len).collect::<Vec<_>>());
+                        b.push_back(box 0);
+                        assert_eq!(b.len(), 1);
+                    }
+                }
+            }
+        }
+    }
+}
+
 #[test]
 fn test_retain() {
     let mut buf = VecDeque::new();
@@ -1024,7 +1345,7 @@ fn test_reserve_exact_2() {
     v.push_back(16);
 
     v.reserve_exact(16);
-    assert!(v.capacity() >= 48)
+    assert!(v.capacity() >= 33)
 }
 
 #[test]
@@ -1070,9 +1391,9 @@ fn test_try_reserve() {
             } else { panic!("usize::MAX should trigger an overflow!") }
         } else {
             // Check isize::MAX is an OOM
-            // VecDeque starts with capacity 7, always adds 1 to the capacity
-            // and also rounds the number to next power of 2 so this is the
-            // furthest we can go without triggering CapacityOverflow
+            // VecDeque starts with capacity 7 and always adds 1 to the
+            // capacity, so this is the furthest we can go without triggering
+            // CapacityOverflow
             if let Err(AllocErr(_)) = empty_bytes.try_reserve(MAX_CAP) {
             } else { panic!("isize::MAX + 1 should trigger an OOM!") }
         }
@@ -1157,9 +1478,9 @@ fn test_try_reserve_exact() {
             } else { panic!("usize::MAX should trigger an overflow!") }
         } else {
             // Check isize::MAX is an OOM
-            // VecDeque starts with capacity 7, always adds 1 to the capacity
-            // and also rounds the number to next power of 2 so this is the
-            // furthest we can go without triggering CapacityOverflow
+            // VecDeque starts with capacity 7 and always adds 1 to the
+            // capacity, so this is the furthest we can go without triggering
+            // CapacityOverflow
             if let Err(AllocErr(_)) = empty_bytes.try_reserve_exact(MAX_CAP) {
             } else { panic!("isize::MAX + 1 should trigger an OOM!") }
         }
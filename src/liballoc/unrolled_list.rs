@@ -0,0 +1,857 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A doubly-linked list that stores several elements per node.
+//!
+//! A [`LinkedList`] allocates a node for every element and follows a
+//! pointer to reach the next one, which makes walking it slow and puts a
+//! lot of pressure on the allocator. An [`UnrolledList`] links together
+//! chunks of up to 32 elements instead. Walking it mostly reads contiguous
+//! memory, and it allocates once per chunk rather than once per element.
+//! In exchange, inserting or removing an element shifts the other elements
+//! of its chunk.
+//!
+//! [`LinkedList`]: ../linked_list/struct.LinkedList.html
+//! [`UnrolledList`]: struct.UnrolledList.html
+
+#![unstable(feature = "unrolled_list", issue = "0")]
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::{FromIterator, FusedIterator};
+use core::mem;
+use core::ops::{Index, IndexMut};
+use core::slice;
+
+use linked_list::{self, LinkedList};
+use vec::{self, Vec};
+
+/// The maximum number of elements in a chunk.
+const CHUNK_LEN: usize = 32;
+
+/// A doubly-linked list of chunks holding up to 32 elements each.
+///
+/// Pushing and popping at either end take `O(1)` time. Finding an element
+/// by index walks the chunks from the nearer end of the list, so [`get`],
+/// [`insert`] and [`remove`] take `O(n / 32)` time, plus the time needed
+/// to shift the other elements of the affected chunk.
+///
+/// A full chunk is split in two to make room for an inserted element. When
+/// [`remove`] leaves a chunk less than half full, the chunk is merged with a
+/// neighbour, or takes elements over from it if the two do not fit in one
+/// chunk. Popping from either end only unlinks a chunk once it is empty.
+/// [`chunks`] gives access to the chunks as slices.
+///
+/// [`append`] takes `O(1)` time, and [`split_off`] only splits the chunk
+/// that contains the split point, if any, rather than moving elements
+/// around.
+///
+/// [`get`]: #method.get
+/// [`insert`]: #method.insert
+/// [`remove`]: #method.remove
+/// [`chunks`]: #method.chunks
+/// [`append`]: #method.append
+/// [`split_off`]: #method.split_off
+///
+/// # Examples
+///
+/// ```
+/// #![feature(unrolled_list)]
+/// use std::collections::unrolled_list::UnrolledList;
+///
+/// let mut list: UnrolledList<_> = (0..100).collect();
+/// assert_eq!(list.chunks().count(), 4);
+///
+/// list.insert(50, -1);
+/// assert_eq!(list[50], -1);
+/// assert_eq!(list.remove(51), Some(50));
+///
+/// let back = list.split_off(64);
+/// assert_eq!(list.len(), 64);
+/// assert_eq!(back.front(), Some(&64));
+/// ```
+pub struct UnrolledList<T> {
+    // No chunk is empty, and each was allocated with room for `CHUNK_LEN`
+    // elements.
+    chunks: LinkedList<Vec<T>>,
+    len: usize,
+}
+
+impl<T> UnrolledList<T> {
+    /// Creates an empty `UnrolledList`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(unrolled_list)]
+    /// use std::collections::unrolled_list::UnrolledList;
+    ///
+    /// let list: UnrolledList<u32> = UnrolledList::new();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn new() -> UnrolledList<T> {
+        UnrolledList {
+            chunks: LinkedList::new(),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the list contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Removes all elements from the list.
+    pub fn clear(&mut self) {
+        self.chunks.clear();
+        self.len = 0;
+    }
+
+    /// Returns a reference to the first element, or `None` if the list is
+    /// empty.
+    pub fn front(&self) -> Option<&T> {
+        self.chunks.front().and_then(|chunk| chunk.first())
+    }
+
+    /// Returns a mutable reference to the first element, or `None` if the
+    /// list is empty.
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.chunks.front_mut().and_then(|chunk| chunk.first_mut())
+    }
+
+    /// Returns a reference to the last element, or `None` if the list is
+    /// empty.
+    pub fn back(&self) -> Option<&T> {
+        self.chunks.back().and_then(|chunk| chunk.last())
+    }
+
+    /// Returns a mutable reference to the last element, or `None` if the
+    /// list is empty.
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.chunks.back_mut().and_then(|chunk| chunk.last_mut())
+    }
+
+    /// Adds an element to the front of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(unrolled_list)]
+    /// use std::collections::unrolled_list::UnrolledList;
+    ///
+    /// let mut list = UnrolledList::new();
+    /// list.push_front(2);
+    /// list.push_front(1);
+    /// assert_eq!(list.front(), Some(&1));
+    /// ```
+    pub fn push_front(&mut self, value: T) {
+        if self.chunks.front().map_or(true, |chunk| chunk.len() == CHUNK_LEN) {
+            self.chunks.push_front(Vec::with_capacity(CHUNK_LEN));
+        }
+        self.chunks.front_mut().unwrap().insert(0, value);
+        self.len += 1;
+    }
+
+    /// Adds an element to the back of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(unrolled_list)]
+    /// use std::collections::unrolled_list::UnrolledList;
+    ///
+    /// let mut list = UnrolledList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// assert_eq!(list.back(), Some(&2));
+    /// ```
+    pub fn push_back(&mut self, value: T) {
+        if self.chunks.back().map_or(true, |chunk| chunk.len() == CHUNK_LEN) {
+            self.chunks.push_back(Vec::with_capacity(CHUNK_LEN));
+        }
+        self.chunks.back_mut().unwrap().push(value);
+        self.len += 1;
+    }
+
+    /// Removes the first element and returns it, or `None` if the list is
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(unrolled_list)]
+    /// use std::collections::unrolled_list::UnrolledList;
+    ///
+    /// let mut list: UnrolledList<_> = vec![1, 2].into_iter().collect();
+    /// assert_eq!(list.pop_front(), Some(1));
+    /// assert_eq!(list.pop_front(), Some(2));
+    /// assert_eq!(list.pop_front(), None);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        let (value, emptied) = match self.chunks.front_mut() {
+            Some(chunk) => (chunk.remove(0), chunk.is_empty()),
+            None => return None,
+        };
+        if emptied {
+            self.chunks.pop_front();
+        }
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// Removes the last element and returns it, or `None` if the list is
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(unrolled_list)]
+    /// use std::collections::unrolled_list::UnrolledList;
+    ///
+    /// let mut list: UnrolledList<_> = vec![1, 2].into_iter().collect();
+    /// assert_eq!(list.pop_back(), Some(2));
+    /// assert_eq!(list.pop_back(), Some(1));
+    /// assert_eq!(list.pop_back(), None);
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        let (value, emptied) = match self.chunks.back_mut() {
+            Some(chunk) => (chunk.pop().unwrap(), chunk.is_empty()),
+            None => return None,
+        };
+        if emptied {
+            self.chunks.pop_back();
+        }
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// Returns a reference to the element at the given index, or `None` if
+    /// the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(unrolled_list)]
+    /// use std::collections::unrolled_list::UnrolledList;
+    ///
+    /// let list: UnrolledList<_> = (0..100).collect();
+    /// assert_eq!(list.get(70), Some(&70));
+    /// assert_eq!(list.get(100), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+        if index < self.len / 2 {
+            let mut index = index;
+            for chunk in self.chunks.iter() {
+                if index < chunk.len() {
+                    return Some(&chunk[index]);
+                }
+                index -= chunk.len();
+            }
+        } else {
+            let mut from_back = self.len - index;
+            for chunk in self.chunks.iter().rev() {
+                if from_back <= chunk.len() {
+                    return Some(&chunk[chunk.len() - from_back]);
+                }
+                from_back -= chunk.len();
+            }
+        }
+        unreachable!()
+    }
+
+    /// Returns a mutable reference to the element at the given index, or
+    /// `None` if the index is out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+        if index < self.len / 2 {
+            let mut index = index;
+            for chunk in self.chunks.iter_mut() {
+                if index < chunk.len() {
+                    return Some(&mut chunk[index]);
+                }
+                index -= chunk.len();
+            }
+        } else {
+            let mut from_back = self.len - index;
+            for chunk in self.chunks.iter_mut().rev() {
+                if from_back <= chunk.len() {
+                    let len = chunk.len();
+                    return Some(&mut chunk[len - from_back]);
+                }
+                from_back -= chunk.len();
+            }
+        }
+        unreachable!()
+    }
+
+    /// Inserts an element at position `index`, shifting all elements after
+    /// it towards the back.
+    ///
+    /// Only the elements in the same chunk are actually moved. If that
+    /// chunk is full, it is split in two first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the list's length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(unrolled_list)]
+    /// use std::collections::unrolled_list::UnrolledList;
+    ///
+    /// let mut list: UnrolledList<_> = vec![1, 3].into_iter().collect();
+    /// list.insert(1, 2);
+    /// list.insert(3, 4);
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), [&1, &2, &3, &4]);
+    /// ```
+    pub fn insert(&mut self, index: usize, value: T) {
+        assert!(index <= self.len, "index out of bounds");
+        if index == 0 {
+            return self.push_front(value);
+        }
+        if index == self.len {
+            return self.push_back(value);
+        }
+
+        let mut index = index;
+        let mut chunks = self.chunks.iter_mut();
+        loop {
+            let chunk = chunks.next().unwrap();
+            if index < chunk.len() || (index == chunk.len() && chunk.len() < CHUNK_LEN) {
+                if chunk.len() == CHUNK_LEN {
+                    let half = CHUNK_LEN / 2;
+                    let mut back = Vec::with_capacity(CHUNK_LEN);
+                    back.extend(chunk.drain(half..));
+                    if index <= half {
+                        chunk.insert(index, value);
+                    } else {
+                        back.insert(index - half, value);
+                    }
+                    chunks.insert_next(back);
+                } else {
+                    chunk.insert(index, value);
+                }
+                break;
+            }
+            index -= chunk.len();
+        }
+        self.len += 1;
+    }
+
+    /// Removes the element at position `index` and returns it, or `None` if
+    /// the index is out of bounds.
+    ///
+    /// Only the elements in the same chunk are actually moved, unless that
+    /// chunk drops below half full. It is then merged with a neighbouring
+    /// chunk, or takes elements over from it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(unrolled_list)]
+    /// use std::collections::unrolled_list::UnrolledList;
+    ///
+    /// let mut list: UnrolledList<_> = vec![1, 2, 3].into_iter().collect();
+    /// assert_eq!(list.remove(1), Some(2));
+    /// assert_eq!(list.remove(2), None);
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), [&1, &3]);
+    /// ```
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len {
+            return None;
+        }
+        if index == 0 {
+            return self.pop_front();
+        }
+        if index == self.len - 1 {
+            return self.pop_back();
+        }
+
+        let mut index = index;
+        let mut chunks = self.chunks.cursor_front_mut();
+        while index >= chunks.current().unwrap().len() {
+            index -= chunks.current().unwrap().len();
+            chunks.move_next();
+        }
+        let value = chunks.current().unwrap().remove(index);
+        self.len -= 1;
+
+        if chunks.current().unwrap().len() < CHUNK_LEN / 2 {
+            if chunks.peek_next().is_some() {
+                let mut next = mem::replace(chunks.peek_next().unwrap(), Vec::new());
+                rebalance(chunks.current().unwrap(), &mut next);
+                if next.is_empty() {
+                    chunks.move_next();
+                    chunks.remove_current();
+                } else {
+                    *chunks.peek_next().unwrap() = next;
+                }
+            } else if chunks.peek_prev().is_some() {
+                let mut chunk = mem::replace(chunks.current().unwrap(), Vec::new());
+                rebalance(chunks.peek_prev().unwrap(), &mut chunk);
+                if chunk.is_empty() {
+                    chunks.remove_current();
+                } else {
+                    *chunks.current().unwrap() = chunk;
+                }
+            }
+        }
+        Some(value)
+    }
+
+    /// Moves all elements of `other` to the back of `self`, leaving `other`
+    /// empty.
+    ///
+    /// This links the chunks of `other` after those of `self`, so it takes
+    /// `O(1)` time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(unrolled_list)]
+    /// use std::collections::unrolled_list::UnrolledList;
+    ///
+    /// let mut list: UnrolledList<_> = (0..10).collect();
+    /// let mut other: UnrolledList<_> = (10..20).collect();
+    /// list.append(&mut other);
+    /// assert_eq!(list.len(), 20);
+    /// assert!(other.is_empty());
+    /// ```
+    pub fn append(&mut self, other: &mut UnrolledList<T>) {
+        self.chunks.append(&mut other.chunks);
+        self.len += mem::replace(&mut other.len, 0);
+    }
+
+    /// Splits the list in two at the given index, and returns everything
+    /// from `at` onwards.
+    ///
+    /// Finding the chunk that holds `at` takes `O(n / 32)` time. If `at` is
+    /// the start of a chunk, the chunks are simply unlinked there; otherwise
+    /// the elements of that chunk from `at` onwards are moved into a new
+    /// chunk first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is greater than the list's length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(unrolled_list)]
+    /// use std::collections::unrolled_list::UnrolledList;
+    ///
+    /// let mut list: UnrolledList<_> = (0..100).collect();
+    /// let back = list.split_off(40);
+    /// assert_eq!(list.back(), Some(&39));
+    /// assert_eq!(back.len(), 60);
+    /// assert_eq!(back.front(), Some(&40));
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> UnrolledList<T> {
+        assert!(at <= self.len, "index out of bounds");
+        if at == 0 {
+            return mem::replace(self, UnrolledList::new());
+        }
+        if at == self.len {
+            return UnrolledList::new();
+        }
+
+        let chunks = {
+            let mut index = at;
+            let mut chunks = self.chunks.iter_mut();
+            loop {
+                let len = chunks.peek_next().unwrap().len();
+                if index < len {
+                    break;
+                }
+                index -= len;
+                chunks.next();
+            }
+            if index == 0 {
+                chunks.split_off_next()
+            } else {
+                let chunk = chunks.next().unwrap();
+                let mut back = Vec::with_capacity(CHUNK_LEN);
+                back.extend(chunk.drain(index..));
+                let mut rest = chunks.split_off_next();
+                rest.push_front(back);
+                rest
+            }
+        };
+        let len = self.len - at;
+        self.len = at;
+        UnrolledList { chunks, len }
+    }
+
+    /// Returns an iterator over the chunks of the list, as slices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(unrolled_list)]
+    /// use std::collections::unrolled_list::UnrolledList;
+    ///
+    /// let list: UnrolledList<_> = (0..40).collect();
+    /// let lens: Vec<_> = list.chunks().map(|chunk| chunk.len()).collect();
+    /// assert_eq!(lens, [32, 8]);
+    /// ```
+    pub fn chunks(&self) -> Chunks<T> {
+        Chunks { iter: self.chunks.iter() }
+    }
+
+    /// Returns an iterator over the elements of the list, from front to
+    /// back.
+    pub fn iter(&self) -> Iter<T> {
+        Iter {
+            chunks: self.chunks.iter(),
+            front: [].iter(),
+            back: [].iter(),
+            len: self.len,
+        }
+    }
+
+    /// Returns an iterator that allows modifying each element of the list,
+    /// from front to back.
+    pub fn iter_mut(&mut self) -> IterMut<T> {
+        IterMut {
+            chunks: self.chunks.iter_mut(),
+            front: [].iter_mut(),
+            back: [].iter_mut(),
+            len: self.len,
+        }
+    }
+}
+
+/// Moves all elements of `back` to the end of `front` if they fit in one
+/// chunk. Otherwise moves elements from the longer chunk to the shorter one
+/// until both are at least half full.
+fn rebalance<T>(front: &mut Vec<T>, back: &mut Vec<T>) {
+    let total = front.len() + back.len();
+    let half = total / 2;
+    if total <= CHUNK_LEN {
+        front.append(back);
+    } else if front.len() < half {
+        let take = half - front.len();
+        front.extend(back.drain(..take));
+    } else if front.len() > half {
+        back.splice(..0, front.drain(half..));
+    }
+}
+
+impl<T> Index<usize> for UnrolledList<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        self.get(index).expect("UnrolledList index out of bounds")
+    }
+}
+
+impl<T> IndexMut<usize> for UnrolledList<T> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        self.get_mut(index).expect("UnrolledList index out of bounds")
+    }
+}
+
+impl<T: Clone> Clone for UnrolledList<T> {
+    fn clone(&self) -> UnrolledList<T> {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T> Default for UnrolledList<T> {
+    /// Creates an empty `UnrolledList<T>`.
+    fn default() -> UnrolledList<T> {
+        UnrolledList::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for UnrolledList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for UnrolledList<T> {
+    fn eq(&self, other: &UnrolledList<T>) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for UnrolledList<T> {}
+
+impl<T: Hash> Hash for UnrolledList<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        for value in self.iter() {
+            value.hash(state);
+        }
+    }
+}
+
+impl<T> Extend<T> for UnrolledList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+impl<'a, T: 'a + Copy> Extend<&'a T> for UnrolledList<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().cloned());
+    }
+}
+
+impl<T> FromIterator<T> for UnrolledList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> UnrolledList<T> {
+        let mut list = UnrolledList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<'a, T> IntoIterator for &'a UnrolledList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut UnrolledList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T> IntoIterator for UnrolledList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// Creates a consuming iterator, that is, one that moves each value out
+    /// of the `UnrolledList`, from front to back.
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter {
+            chunks: self.chunks.into_iter(),
+            front: Vec::new().into_iter(),
+            back: Vec::new().into_iter(),
+            len: self.len,
+        }
+    }
+}
+
+/// An iterator over the chunks of an `UnrolledList`, as slices.
+///
+/// This `struct` is created by the [`chunks`] method on [`UnrolledList`].
+/// See its documentation for more.
+///
+/// [`chunks`]: struct.UnrolledList.html#method.chunks
+/// [`UnrolledList`]: struct.UnrolledList.html
+pub struct Chunks<'a, T: 'a> {
+    iter: linked_list::Iter<'a, Vec<T>>,
+}
+
+// FIXME(#26925) Remove in favor of `#[derive(Clone)]`
+impl<'a, T> Clone for Chunks<'a, T> {
+    fn clone(&self) -> Chunks<'a, T> {
+        Chunks { iter: self.iter.clone() }
+    }
+}
+
+impl<'a, T: fmt::Debug> fmt::Debug for Chunks<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<'a, T> Iterator for Chunks<'a, T> {
+    type Item = &'a [T];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [T]> {
+        self.iter.next().map(|chunk| &chunk[..])
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Chunks<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a [T]> {
+        self.iter.next_back().map(|chunk| &chunk[..])
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Chunks<'a, T> {}
+
+impl<'a, T> FusedIterator for Chunks<'a, T> {}
+
+/// An iterator over the elements of an `UnrolledList`.
+///
+/// This `struct` is created by the [`iter`] method on [`UnrolledList`]. See
+/// its documentation for more.
+///
+/// [`iter`]: struct.UnrolledList.html#method.iter
+/// [`UnrolledList`]: struct.UnrolledList.html
+pub struct Iter<'a, T: 'a> {
+    chunks: linked_list::Iter<'a, Vec<T>>,
+    front: slice::Iter<'a, T>,
+    back: slice::Iter<'a, T>,
+    len: usize,
+}
+
+// FIXME(#26925) Remove in favor of `#[derive(Clone)]`
+impl<'a, T> Clone for Iter<'a, T> {
+    fn clone(&self) -> Iter<'a, T> {
+        Iter {
+            chunks: self.chunks.clone(),
+            front: self.front.clone(),
+            back: self.back.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<'a, T: fmt::Debug> fmt::Debug for Iter<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+/// Implements `Iterator` and `DoubleEndedIterator` for an iterator with
+/// `chunks`, `front`, `back` and `len` fields, where `front` and `back` are
+/// the partly consumed chunks at either end.
+macro_rules! chunked_iterator {
+    (impl[$($params:tt)*] $ty:ty => $item:ty, $into_iter:ident) => {
+        impl<$($params)*> Iterator for $ty {
+            type Item = $item;
+
+            #[inline]
+            fn next(&mut self) -> Option<$item> {
+                loop {
+                    if let Some(value) = self.front.next() {
+                        self.len -= 1;
+                        return Some(value);
+                    }
+                    match self.chunks.next() {
+                        Some(chunk) => self.front = chunk.$into_iter(),
+                        None => {
+                            let value = self.back.next();
+                            if value.is_some() {
+                                self.len -= 1;
+                            }
+                            return value;
+                        }
+                    }
+                }
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.len, Some(self.len))
+            }
+        }
+
+        impl<$($params)*> DoubleEndedIterator for $ty {
+            #[inline]
+            fn next_back(&mut self) -> Option<$item> {
+                loop {
+                    if let Some(value) = self.back.next_back() {
+                        self.len -= 1;
+                        return Some(value);
+                    }
+                    match self.chunks.next_back() {
+                        Some(chunk) => self.back = chunk.$into_iter(),
+                        None => {
+                            let value = self.front.next_back();
+                            if value.is_some() {
+                                self.len -= 1;
+                            }
+                            return value;
+                        }
+                    }
+                }
+            }
+        }
+
+        impl<$($params)*> ExactSizeIterator for $ty {}
+
+        impl<$($params)*> FusedIterator for $ty {}
+    }
+}
+
+chunked_iterator!(impl['a, T] Iter<'a, T> => &'a T, iter);
+
+/// A mutable iterator over the elements of an `UnrolledList`.
+///
+/// This `struct` is created by the [`iter_mut`] method on [`UnrolledList`].
+/// See its documentation for more.
+///
+/// [`iter_mut`]: struct.UnrolledList.html#method.iter_mut
+/// [`UnrolledList`]: struct.UnrolledList.html
+pub struct IterMut<'a, T: 'a> {
+    chunks: linked_list::IterMut<'a, Vec<T>>,
+    front: slice::IterMut<'a, T>,
+    back: slice::IterMut<'a, T>,
+    len: usize,
+}
+
+impl<'a, T: fmt::Debug> fmt::Debug for IterMut<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("IterMut")
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+chunked_iterator!(impl['a, T] IterMut<'a, T> => &'a mut T, iter_mut);
+
+/// An owning iterator over the elements of an `UnrolledList`.
+///
+/// This `struct` is created by the [`into_iter`] method on [`UnrolledList`]
+/// (provided by the `IntoIterator` trait). See its documentation for more.
+///
+/// [`into_iter`]: struct.UnrolledList.html#method.into_iter
+/// [`UnrolledList`]: struct.UnrolledList.html
+pub struct IntoIter<T> {
+    chunks: linked_list::IntoIter<Vec<T>>,
+    front: vec::IntoIter<T>,
+    back: vec::IntoIter<T>,
+    len: usize,
+}
+
+impl<T: fmt::Debug> fmt::Debug for IntoIter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("IntoIter")
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+chunked_iterator!(impl[T] IntoIter<T> => T, into_iter);
@@ -85,6 +85,7 @@ use core::slice;
 use borrow::ToOwned;
 use borrow::Cow;
 use boxed::Box;
+use growth::GrowthPolicy;
 use raw_vec::RawVec;
 use super::allocator::CollectionAllocErr;
 
@@ -428,6 +429,40 @@ impl<T> Vec<T> {
         }
     }
 
+    /// Decomposes a `Vec<T>` into its raw components: the pointer to its
+    /// buffer, its length and its capacity.
+    ///
+    /// After calling this function the caller is responsible for the memory
+    /// previously managed by the `Vec`. The only way to release it is to
+    /// turn the parts back into a `Vec` with [`from_raw_parts`], which lets
+    /// the destructor clean up.
+    ///
+    /// [`from_raw_parts`]: #method.from_raw_parts
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vec_into_raw_parts)]
+    ///
+    /// let v: Vec<i32> = vec![-1, 0, 1];
+    ///
+    /// let (ptr, len, cap) = v.into_raw_parts();
+    ///
+    /// let rebuilt = unsafe {
+    ///     // We can now make changes to the components, such as
+    ///     // transmuting the raw pointer to a compatible type.
+    ///     let ptr = ptr as *mut u32;
+    ///
+    ///     Vec::from_raw_parts(ptr, len, cap)
+    /// };
+    /// assert_eq!(rebuilt, [4294967295, 0, 1]);
+    /// ```
+    #[unstable(feature = "vec_into_raw_parts", issue = "0")]
+    pub fn into_raw_parts(self) -> (*mut T, usize, usize) {
+        let mut me = mem::ManuallyDrop::new(self);
+        (me.as_mut_ptr(), me.len(), me.capacity())
+    }
+
     /// Returns the number of elements the vector can hold without
     /// reallocating.
     ///
@@ -443,6 +478,41 @@ impl<T> Vec<T> {
         self.buf.cap()
     }
 
+    /// Returns the number of elements that the vector's allocation could
+    /// hold.
+    ///
+    /// Allocators often round the size of a request up, so the block backing
+    /// the vector may have room for more elements than [`capacity`]. The
+    /// vector claims that room itself whenever it grows on its own, as in
+    /// [`push`] or [`reserve`], but not after [`with_capacity`],
+    /// [`reserve_exact`] or [`shrink_to_fit`], which set the capacity to
+    /// exactly what was asked for.
+    ///
+    /// [`capacity`]: #method.capacity
+    /// [`push`]: #method.push
+    /// [`reserve`]: #method.reserve
+    /// [`with_capacity`]: #method.with_capacity
+    /// [`reserve_exact`]: #method.reserve_exact
+    /// [`shrink_to_fit`]: #method.shrink_to_fit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(allocated_capacity)]
+    ///
+    /// let mut vec: Vec<u8> = Vec::with_capacity(10);
+    /// assert_eq!(vec.capacity(), 10);
+    /// assert!(vec.allocated_capacity() >= 10);
+    ///
+    /// vec.extend(0..11);
+    /// assert_eq!(vec.capacity(), vec.allocated_capacity());
+    /// ```
+    #[inline]
+    #[unstable(feature = "allocated_capacity", issue = "0")]
+    pub fn allocated_capacity(&self) -> usize {
+        self.buf.usable_cap()
+    }
+
     /// Reserves capacity for at least `additional` more elements to be inserted
     /// in the given `Vec<T>`. The collection may reserve more space to avoid
     /// frequent reallocations. After calling `reserve`, capacity will be
@@ -490,6 +560,37 @@ impl<T> Vec<T> {
         self.buf.reserve_exact(self.len, additional);
     }
 
+    /// Reserves capacity for at least `additional` more elements, letting
+    /// `policy` decide how much to grow by if the vector is full.
+    ///
+    /// [`reserve`] always at least doubles the capacity, which can leave
+    /// much of a large buffer unused. See the [`growth`] module for the
+    /// policies that are available.
+    ///
+    /// [`reserve`]: #method.reserve
+    /// [`growth`]: ../collections/growth/index.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(growth_policy)]
+    ///
+    /// use std::collections::growth::Factor;
+    ///
+    /// let mut vec = Vec::with_capacity(100);
+    /// vec.extend(0..100);
+    /// vec.reserve_with(1, &Factor::new(5, 4));
+    /// assert_eq!(vec.capacity(), 125);
+    /// ```
+    #[unstable(feature = "growth_policy", issue = "0")]
+    pub fn reserve_with<P: GrowthPolicy>(&mut self, additional: usize, policy: &P) {
+        self.buf.reserve_with(self.len, additional, policy);
+    }
+
     /// Tries to reserve capacity for at least `additional` more elements to be inserted
     /// in the given `Vec<T>`. The collection may reserve more space to avoid
     /// frequent reallocations. After calling `reserve`, capacity will be
@@ -1065,6 +1166,30 @@ impl<T> Vec<T> {
         }
     }
 
+    /// Appends an element to the back of a collection and returns a mutable
+    /// reference to it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of elements in the vector overflows a `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vec_push_get)]
+    ///
+    /// let mut vec = vec![1, 2];
+    /// *vec.push_get(3) += 10;
+    /// assert_eq!(vec, [1, 2, 13]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "vec_push_get", issue = "0")]
+    pub fn push_get(&mut self, value: T) -> &mut T {
+        let len = self.len;
+        self.push(value);
+        unsafe { self.get_unchecked_mut(len) }
+    }
+
     /// Removes the last element from a vector and returns it, or [`None`] if it
     /// is empty.
     ///
@@ -1090,6 +1215,78 @@ impl<T> Vec<T> {
         }
     }
 
+    /// Removes the last element from a vector and returns it if `predicate`
+    /// returns `true` for it, or [`None`] if the predicate returns `false`
+    /// or the vector is empty.
+    ///
+    /// The predicate is given a mutable reference to the element, so it may
+    /// also update an element that it decides to keep.
+    ///
+    /// [`None`]: ../../std/option/enum.Option.html#variant.None
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(pop_if)]
+    ///
+    /// let mut vec = vec![1, 2, 3, 4];
+    /// let pred = |x: &mut i32| *x % 2 == 0;
+    ///
+    /// assert_eq!(vec.pop_if(pred), Some(4));
+    /// assert_eq!(vec, [1, 2, 3]);
+    /// assert_eq!(vec.pop_if(pred), None);
+    /// assert_eq!(vec, [1, 2, 3]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "pop_if", issue = "0")]
+    pub fn pop_if<F>(&mut self, predicate: F) -> Option<T>
+        where F: FnOnce(&mut T) -> bool
+    {
+        let pop = match self.last_mut() {
+            Some(last) => predicate(last),
+            None => false,
+        };
+        if pop { self.pop() } else { None }
+    }
+
+    /// Removes the first element from a vector and returns it, or [`None`]
+    /// if it is empty.
+    ///
+    /// The removed element is replaced by the last element of the vector.
+    ///
+    /// This does not preserve ordering, but is O(1), whereas
+    /// `vec.remove(0)` shifts every remaining element down by one. It
+    /// suits a vector used as a work queue whose items may be handled in
+    /// any order. If items must come out in the order they were pushed,
+    /// use a [`VecDeque`] instead.
+    ///
+    /// [`None`]: ../../std/option/enum.Option.html#variant.None
+    /// [`VecDeque`]: ../../std/collections/struct.VecDeque.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vec_pop_front_swap)]
+    ///
+    /// let mut vec = vec![1, 2, 3, 4];
+    /// assert_eq!(vec.pop_front_swap(), Some(1));
+    /// assert_eq!(vec, [4, 2, 3]);
+    /// assert_eq!(vec.pop_front_swap(), Some(4));
+    /// assert_eq!(vec, [3, 2]);
+    ///
+    /// let mut empty: Vec<i32> = Vec::new();
+    /// assert_eq!(empty.pop_front_swap(), None);
+    /// ```
+    #[inline]
+    #[unstable(feature = "vec_pop_front_swap", issue = "0")]
+    pub fn pop_front_swap(&mut self) -> Option<T> {
+        if self.len == 0 {
+            None
+        } else {
+            Some(self.swap_remove(0))
+        }
+    }
+
     /// Moves all the elements of `other` into `Self`, leaving `other` empty.
     ///
     /// # Panics
@@ -1114,6 +1311,51 @@ impl<T> Vec<T> {
         }
     }
 
+    /// Merges the sorted vector `other` into this sorted vector, keeping only
+    /// one copy of each distinct element.
+    ///
+    /// Both vectors must be sorted in ascending order. The result is sorted
+    /// as well, with the duplicates from within and across the two vectors
+    /// removed, as if they had been appended, sorted and deduplicated. This
+    /// is done in a single pass into a buffer allocated once with room for
+    /// every element, and the elements are moved rather than cloned.
+    ///
+    /// If either vector is not sorted the order of the result is
+    /// unspecified, but every element is still kept or dropped exactly once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(merge_dedup)]
+    ///
+    /// let mut vec = vec![1, 3, 5, 7];
+    /// vec.merge_sorted(vec![2, 3, 4, 7, 7, 8]);
+    /// assert_eq!(vec, [1, 2, 3, 4, 5, 7, 8]);
+    /// ```
+    #[unstable(feature = "merge_dedup", issue = "0")]
+    pub fn merge_sorted(&mut self, other: Vec<T>)
+        where T: Ord
+    {
+        let mut merged = Vec::with_capacity(self.len() + other.len());
+        {
+            let mut a = self.drain(..).peekable();
+            let mut b = other.into_iter().peekable();
+            loop {
+                let take_a = match (a.peek(), b.peek()) {
+                    (Some(x), Some(y)) => x <= y,
+                    (Some(_), None) => true,
+                    (None, Some(_)) => false,
+                    (None, None) => break,
+                };
+                let next = if take_a { a.next().unwrap() } else { b.next().unwrap() };
+                if merged.last() != Some(&next) {
+                    merged.push(next);
+                }
+            }
+        }
+        *self = merged;
+    }
+
     /// Appends elements to `Self` from other buffer.
     #[inline]
     unsafe fn append_elements(&mut self, other: *const [T]) {
@@ -1503,6 +1745,44 @@ impl<T: PartialEq> Vec<T> {
     }
 }
 
+impl<T> Vec<Vec<T>> {
+    /// Flattens a vector of vectors into a single vector, consuming it.
+    ///
+    /// Unlike [`concat`], this moves the elements out of the inner vectors
+    /// instead of cloning them, so `T` need not implement `Clone`. The
+    /// total length is computed up front so that the result is allocated
+    /// exactly once, and each inner vector is moved over with a single
+    /// `memcpy`.
+    ///
+    /// [`concat`]: ../../std/slice/trait.SliceConcatExt.html#tymethod.concat
+    ///
+    /// # Panics
+    ///
+    /// Panics if the total number of elements overflows `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vec_concat_into)]
+    ///
+    /// let vecs = vec![vec![1, 2], vec![], vec![3, 4, 5]];
+    /// let flat = vecs.concat_into();
+    /// assert_eq!(flat, [1, 2, 3, 4, 5]);
+    /// assert_eq!(flat.capacity(), 5);
+    /// ```
+    #[unstable(feature = "vec_concat_into", issue = "0")]
+    pub fn concat_into(self) -> Vec<T> {
+        let len = self.iter().fold(0usize, |acc, v| {
+            acc.checked_add(v.len()).expect("capacity overflow")
+        });
+        let mut result = Vec::with_capacity(len);
+        for mut v in self {
+            result.append(&mut v);
+        }
+        result
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Internal methods and functions
 ////////////////////////////////////////////////////////////////////////////////
@@ -160,7 +160,7 @@ impl<T> VecDeque<T> {
     /// index - subtrahend.
     #[inline]
     fn wrap_sub(&self, idx: usize, subtrahend: usize) -> usize {
-        wrap_index(idx.wrapping_sub(subtrahend), self.cap())
+        wrap_index(idx.wrapping_sub(subtrahend).wrapping_add(self.cap()), self.cap())
     }
 
     /// Copies a contiguous block of memory len long from src to dst
@@ -323,13 +323,37 @@ impl<T> VecDeque<T> {
         }
     }
 
+    /// Copies all values from `src` into the buffer, starting at `dst` and
+    /// wrapping around the end of the buffer if necessary. The caller must
+    /// make sure there is room for them past the head.
+    unsafe fn copy_slice(&mut self, dst: usize, src: &[T]) {
+        debug_assert!(self.len() + src.len() < self.cap(),
+                      "cps dst={} len={} cap={}",
+                      dst,
+                      src.len(),
+                      self.cap());
+        let dst_pre_wrap_len = self.cap() - dst;
+        if src.len() <= dst_pre_wrap_len {
+            ptr::copy_nonoverlapping(src.as_ptr(),
+                                     self.ptr().offset(dst as isize),
+                                     src.len());
+        } else {
+            let (pre_wrap, post_wrap) = src.split_at(dst_pre_wrap_len);
+            ptr::copy_nonoverlapping(pre_wrap.as_ptr(),
+                                     self.ptr().offset(dst as isize),
+                                     pre_wrap.len());
+            ptr::copy_nonoverlapping(post_wrap.as_ptr(), self.ptr(), post_wrap.len());
+        }
+    }
+
     /// Frobs the head and tail sections around to handle the fact that we
     /// just reallocated. Unsafe because it trusts old_cap.
     #[inline]
     unsafe fn handle_cap_increase(&mut self, old_cap: usize) {
         let new_cap = self.cap();
 
-        // Move the shortest contiguous section of the ring buffer
+        // Move the shortest contiguous section of the ring buffer, as long as
+        // it fits into the newly added space
         //    T             H
         //   [o o o o o o o . ]
         //    T             H
@@ -346,21 +370,22 @@ impl<T> VecDeque<T> {
         if self.tail <= self.head {
             // A
             // Nop
-        } else if self.head < old_cap - self.tail {
+        } else if self.head < old_cap - self.tail && self.head < new_cap - old_cap {
             // B
             self.copy_nonoverlapping(old_cap, 0, self.head);
             self.head += old_cap;
             debug_assert!(self.head > self.tail);
         } else {
             // C
+            // The capacity may have grown by less than the length of the tail
+            // section, so the source and destination can overlap.
             let new_tail = new_cap - (old_cap - self.tail);
-            self.copy_nonoverlapping(new_tail, self.tail, old_cap - self.tail);
+            self.copy(new_tail, self.tail, old_cap - self.tail);
             self.tail = new_tail;
             debug_assert!(self.head < self.tail);
         }
         debug_assert!(self.head < self.cap());
         debug_assert!(self.tail < self.cap());
-        debug_assert!(self.cap().count_ones() == 1);
     }
 }
 
@@ -381,17 +406,21 @@ impl<T> VecDeque<T> {
 
     /// Creates an empty `VecDeque` with space for at least `n` elements.
     ///
+    /// The requested capacity is not rounded up, so the buffer only has room
+    /// for `n` elements and the one slot the ring buffer always leaves empty.
+    ///
     /// # Examples
     ///
     /// ```
     /// use std::collections::VecDeque;
     ///
     /// let vector: VecDeque<u32> = VecDeque::with_capacity(10);
+    /// assert_eq!(vector.capacity(), 10);
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn with_capacity(n: usize) -> VecDeque<T> {
         // +1 since the ringbuffer always leaves one space empty
-        let cap = cmp::max(n + 1, MINIMUM_CAPACITY + 1).next_power_of_two();
+        let cap = cmp::max(n + 1, MINIMUM_CAPACITY + 1);
         assert!(cap > n, "capacity overflow");
 
         VecDeque {
@@ -401,6 +430,96 @@ impl<T> VecDeque<T> {
         }
     }
 
+    /// Creates a `VecDeque<T>` directly from the raw components of another
+    /// one, as returned by [`into_raw_parts`].
+    ///
+    /// The `len` elements of the deque are read from `ptr` starting at index
+    /// `offset` and wrapping around at the end of the buffer, which holds
+    /// `buf_len` elements.
+    ///
+    /// # Safety
+    ///
+    /// This is highly unsafe, due to the number of invariants that aren't
+    /// checked:
+    ///
+    /// * `ptr` needs to have been previously allocated by the same allocator
+    ///   the standard library uses, for `buf_len` elements of `T`.
+    /// * `offset` and `len` need to be less than `buf_len`.
+    /// * The `len` elements starting at `offset` need to be initialized.
+    ///
+    /// The simplest way to meet these is to only pass the parts returned by
+    /// [`into_raw_parts`]. The ownership of `ptr` is transferred to the
+    /// `VecDeque`, so nothing else may use the pointer afterwards.
+    ///
+    /// [`into_raw_parts`]: #method.into_raw_parts
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vecdeque_raw_parts)]
+    ///
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut buf: VecDeque<_> = vec![2, 3].into();
+    /// buf.push_front(1);
+    ///
+    /// let (ptr, offset, len, buf_len) = buf.into_raw_parts();
+    /// let rebuilt = unsafe { VecDeque::from_raw_parts(ptr, offset, len, buf_len) };
+    /// assert_eq!(rebuilt, [1, 2, 3]);
+    /// ```
+    #[unstable(feature = "vecdeque_raw_parts", issue = "0")]
+    pub unsafe fn from_raw_parts(ptr: *mut T,
+                                 offset: usize,
+                                 len: usize,
+                                 buf_len: usize)
+                                 -> VecDeque<T> {
+        debug_assert!(offset < buf_len && len < buf_len);
+        VecDeque {
+            tail: offset,
+            head: wrap_index(offset + len, buf_len),
+            buf: RawVec::from_raw_parts(ptr, buf_len),
+        }
+    }
+
+    /// Decomposes a `VecDeque<T>` into its raw components: the pointer to
+    /// its buffer, the index in the buffer of its first element, its length
+    /// and the length of the buffer.
+    ///
+    /// The elements are not moved, so they may wrap around the end of the
+    /// buffer. The buffer holds one more element than [`capacity`] reports.
+    ///
+    /// After calling this function the caller is responsible for the memory
+    /// previously managed by the `VecDeque`. The only way to release it is
+    /// to turn the parts back into a `VecDeque` with [`from_raw_parts`].
+    ///
+    /// [`capacity`]: #method.capacity
+    /// [`from_raw_parts`]: #method.from_raw_parts
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vecdeque_raw_parts)]
+    ///
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut buf = VecDeque::with_capacity(7);
+    /// buf.push_back(2);
+    /// buf.push_front(1);
+    ///
+    /// let (ptr, offset, len, buf_len) = buf.into_raw_parts();
+    /// assert_eq!((offset, len, buf_len), (7, 2, 8));
+    /// unsafe {
+    ///     assert_eq!(*ptr.offset(7), 1);
+    ///     assert_eq!(*ptr, 2);
+    ///     drop(VecDeque::from_raw_parts(ptr, offset, len, buf_len));
+    /// }
+    /// ```
+    #[unstable(feature = "vecdeque_raw_parts", issue = "0")]
+    pub fn into_raw_parts(self) -> (*mut T, usize, usize, usize) {
+        let me = mem::ManuallyDrop::new(self);
+        (me.ptr(), me.tail, me.len(), me.cap())
+    }
+
     /// Retrieves an element in the `VecDeque` by index.
     ///
     /// Element at index 0 is the front of the queue.
@@ -531,7 +650,16 @@ impl<T> VecDeque<T> {
     /// [`reserve`]: #method.reserve
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn reserve_exact(&mut self, additional: usize) {
-        self.reserve(additional);
+        let old_cap = self.cap();
+        let used_cap = self.len() + 1;
+        let new_cap = used_cap.checked_add(additional).expect("capacity overflow");
+
+        if new_cap > old_cap {
+            self.buf.reserve_exact(used_cap, new_cap - used_cap);
+            unsafe {
+                self.handle_cap_increase(old_cap);
+            }
+        }
     }
 
     /// Reserves capacity for at least `additional` more elements to be inserted in the given
@@ -554,12 +682,10 @@ impl<T> VecDeque<T> {
     pub fn reserve(&mut self, additional: usize) {
         let old_cap = self.cap();
         let used_cap = self.len() + 1;
-        let new_cap = used_cap.checked_add(additional)
-            .and_then(|needed_cap| needed_cap.checked_next_power_of_two())
-            .expect("capacity overflow");
+        let new_cap = used_cap.checked_add(additional).expect("capacity overflow");
 
         if new_cap > old_cap {
-            self.buf.reserve_exact(used_cap, new_cap - used_cap);
+            self.buf.reserve(used_cap, new_cap - used_cap);
             unsafe {
                 self.handle_cap_increase(old_cap);
             }
@@ -604,7 +730,18 @@ impl<T> VecDeque<T> {
     /// ```
     #[unstable(feature = "try_reserve", reason = "new API", issue="48043")]
     pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), CollectionAllocErr>  {
-        self.try_reserve(additional)
+        let old_cap = self.cap();
+        let used_cap = self.len() + 1;
+        let new_cap = used_cap.checked_add(additional)
+            .ok_or(CollectionAllocErr::CapacityOverflow)?;
+
+        if new_cap > old_cap {
+            self.buf.try_reserve_exact(used_cap, new_cap - used_cap)?;
+            unsafe {
+                self.handle_cap_increase(old_cap);
+            }
+        }
+        Ok(())
     }
 
     /// Tries to reserve capacity for at least `additional` more elements to be inserted
@@ -645,11 +782,10 @@ impl<T> VecDeque<T> {
         let old_cap = self.cap();
         let used_cap = self.len() + 1;
         let new_cap = used_cap.checked_add(additional)
-            .and_then(|needed_cap| needed_cap.checked_next_power_of_two())
             .ok_or(CollectionAllocErr::CapacityOverflow)?;
 
         if new_cap > old_cap {
-            self.buf.try_reserve_exact(used_cap, new_cap - used_cap)?;
+            self.buf.try_reserve(used_cap, new_cap - used_cap)?;
             unsafe {
                 self.handle_cap_increase(old_cap);
             }
@@ -671,7 +807,7 @@ impl<T> VecDeque<T> {
     /// buf.extend(0..4);
     /// assert_eq!(buf.capacity(), 15);
     /// buf.shrink_to_fit();
-    /// assert!(buf.capacity() >= 4);
+    /// assert_eq!(buf.capacity(), 4);
     /// ```
     #[stable(feature = "deque_extras_15", since = "1.5.0")]
     pub fn shrink_to_fit(&mut self) {
@@ -709,7 +845,7 @@ impl<T> VecDeque<T> {
         let target_cap = cmp::max(
             cmp::max(min_capacity, self.len()) + 1,
             MINIMUM_CAPACITY + 1
-        ).next_power_of_two();
+        );
 
         if target_cap < self.cap() {
             // There are three cases of interest:
@@ -762,7 +898,6 @@ impl<T> VecDeque<T> {
 
             debug_assert!(self.head < self.cap());
             debug_assert!(self.tail < self.cap());
-            debug_assert!(self.cap().count_ones() == 1);
         }
     }
 
@@ -841,6 +976,96 @@ impl<T> VecDeque<T> {
         }
     }
 
+    /// Returns a front-to-back iterator over the elements in the given range
+    /// of indices.
+    ///
+    /// The iterator starts at the first element of the range directly, so
+    /// creating it takes `O(1)` time however far into the deque the range
+    /// starts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if
+    /// the end point is greater than the length of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(deque_range)]
+    ///
+    /// use std::collections::VecDeque;
+    ///
+    /// let buf: VecDeque<_> = (0..10).collect();
+    /// let window: Vec<_> = buf.iter_range(3..6).cloned().collect();
+    /// assert_eq!(window, [3, 4, 5]);
+    /// assert_eq!(buf.iter_range(7..).rev().next(), Some(&9));
+    /// ```
+    #[unstable(feature = "deque_range", issue = "0")]
+    pub fn iter_range<R>(&self, range: R) -> Iter<T>
+        where R: RangeBounds<usize>
+    {
+        let (tail, head) = self.range_tail_head(range);
+        Iter {
+            tail,
+            head,
+            ring: unsafe { self.buffer_as_slice() },
+        }
+    }
+
+    /// Returns a front-to-back iterator that returns mutable references to
+    /// the elements in the given range of indices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if
+    /// the end point is greater than the length of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(deque_range)]
+    ///
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut buf: VecDeque<_> = (0..6).collect();
+    /// for x in buf.iter_range_mut(2..4) {
+    ///     *x *= 10;
+    /// }
+    /// assert_eq!(buf, [0, 1, 20, 30, 4, 5]);
+    /// ```
+    #[unstable(feature = "deque_range", issue = "0")]
+    pub fn iter_range_mut<R>(&mut self, range: R) -> IterMut<T>
+        where R: RangeBounds<usize>
+    {
+        let (tail, head) = self.range_tail_head(range);
+        IterMut {
+            tail,
+            head,
+            ring: unsafe { self.buffer_as_mut_slice() },
+        }
+    }
+
+    /// Returns the buffer indices at which the given range of elements starts
+    /// and ends.
+    fn range_tail_head<R>(&self, range: R) -> (usize, usize)
+        where R: RangeBounds<usize>
+    {
+        let len = self.len();
+        let start = match range.start() {
+            Included(&n) => n,
+            Excluded(&n) => n + 1,
+            Unbounded    => 0,
+        };
+        let end = match range.end() {
+            Included(&n) => n + 1,
+            Excluded(&n) => n,
+            Unbounded    => len,
+        };
+        assert!(start <= end, "range lower bound was too large");
+        assert!(end <= len, "range upper bound was too large");
+        (self.wrap_add(self.tail, start), self.wrap_add(self.tail, end))
+    }
+
     /// Returns a pair of slices which contain, in order, the contents of the
     /// `VecDeque`.
     ///
@@ -902,6 +1127,229 @@ impl<T> VecDeque<T> {
         }
     }
 
+    /// Returns the contents of the `VecDeque` as a single slice, if they are
+    /// stored contiguously.
+    ///
+    /// Returns `None` if the contents wrap around the end of the ring buffer,
+    /// in which case [`as_slices`] returns both parts.
+    ///
+    /// [`as_slices`]: #method.as_slices
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(deque_contiguous)]
+    ///
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut vector = VecDeque::with_capacity(3);
+    ///
+    /// vector.push_back(0);
+    /// vector.push_back(1);
+    /// assert_eq!(vector.as_contiguous(), Some(&[0, 1][..]));
+    ///
+    /// vector.push_front(10);
+    /// assert_eq!(vector.as_contiguous(), None);
+    /// ```
+    #[inline]
+    #[unstable(feature = "deque_contiguous", issue = "0")]
+    pub fn as_contiguous(&self) -> Option<&[T]> {
+        let (front, back) = self.as_slices();
+        if back.is_empty() {
+            Some(front)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the contents of the `VecDeque` as a single mutable slice, if
+    /// they are stored contiguously.
+    ///
+    /// Returns `None` if the contents wrap around the end of the ring buffer,
+    /// in which case [`as_mut_slices`] returns both parts.
+    ///
+    /// [`as_mut_slices`]: #method.as_mut_slices
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(deque_contiguous)]
+    ///
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut vector: VecDeque<_> = vec![1, 2, 3].into_iter().collect();
+    ///
+    /// if let Some(slice) = vector.as_mut_contiguous() {
+    ///     slice.reverse();
+    /// }
+    /// assert_eq!(vector.as_contiguous(), Some(&[3, 2, 1][..]));
+    /// ```
+    #[inline]
+    #[unstable(feature = "deque_contiguous", issue = "0")]
+    pub fn as_mut_contiguous(&mut self) -> Option<&mut [T]> {
+        let (front, back) = self.as_mut_slices();
+        if back.is_empty() {
+            Some(front)
+        } else {
+            None
+        }
+    }
+
+    /// Rearranges the buffer so that the contents of the `VecDeque` are
+    /// stored contiguously, and returns them as a single mutable slice.
+    ///
+    /// This moves every element if the contents wrap around the end of the
+    /// ring buffer, and does nothing otherwise. The order of the elements
+    /// does not change.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(deque_contiguous)]
+    ///
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut buf = VecDeque::with_capacity(4);
+    /// buf.push_back(2);
+    /// buf.push_back(3);
+    /// buf.push_front(1);
+    /// assert_eq!(buf.as_contiguous(), None);
+    ///
+    /// buf.make_contiguous().sort_by(|a, b| b.cmp(a));
+    /// assert_eq!(buf.as_contiguous(), Some(&[3, 2, 1][..]));
+    /// ```
+    #[unstable(feature = "deque_contiguous", issue = "0")]
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        if self.head < self.tail {
+            let (tail, head, len) = (self.tail, self.head, self.len());
+            unsafe {
+                // Move the front part down against the back part, which
+                // leaves the elements rotated at the start of the buffer.
+                self.copy(head, tail, self.cap() - tail);
+                slice::from_raw_parts_mut(self.ptr(), len).rotate_left(head);
+            }
+            self.tail = 0;
+            self.head = len;
+        }
+        self.as_mut_slices().0
+    }
+
+    /// Rotates the `VecDeque` `mid` places to the left.
+    ///
+    /// Equivalently, this pops the first `mid` elements and pushes them onto
+    /// the back, so that the element at index `mid` becomes the first
+    /// element.
+    ///
+    /// This is how to advance the "current" position of a round-robin queue:
+    /// `rotate_left(index)` moves the element at `index` to the front while
+    /// keeping the cyclic order of the elements, without reallocating.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid` is greater than the length of the `VecDeque`.
+    ///
+    /// # Complexity
+    ///
+    /// Takes `O(min(mid, len - mid))` time and no extra space. Only the
+    /// elements that move are copied, across the gap between the head and
+    /// the tail, in at most three `memmove`s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vecdeque_rotate)]
+    ///
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut buf: VecDeque<_> = (0..10).collect();
+    ///
+    /// buf.rotate_left(3);
+    /// assert_eq!(buf, [3, 4, 5, 6, 7, 8, 9, 0, 1, 2]);
+    ///
+    /// for i in 1..10 {
+    ///     assert_eq!(i * 3 % 10, buf[0]);
+    ///     buf.rotate_left(3);
+    /// }
+    /// assert_eq!(buf, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// ```
+    #[unstable(feature = "vecdeque_rotate", issue = "0")]
+    pub fn rotate_left(&mut self, mid: usize) {
+        assert!(mid <= self.len());
+        let k = self.len() - mid;
+        if mid <= k {
+            unsafe { self.rotate_left_inner(mid) }
+        } else {
+            unsafe { self.rotate_right_inner(k) }
+        }
+    }
+
+    /// Rotates the `VecDeque` `k` places to the right.
+    ///
+    /// Equivalently, this pops the last `k` elements and pushes them onto
+    /// the front, so that the first element moves to index `k`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is greater than the length of the `VecDeque`.
+    ///
+    /// # Complexity
+    ///
+    /// Takes `O(min(k, len - k))` time and no extra space, as
+    /// [`rotate_left`] does.
+    ///
+    /// [`rotate_left`]: #method.rotate_left
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vecdeque_rotate)]
+    ///
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut buf: VecDeque<_> = (0..10).collect();
+    ///
+    /// buf.rotate_right(3);
+    /// assert_eq!(buf, [7, 8, 9, 0, 1, 2, 3, 4, 5, 6]);
+    ///
+    /// for i in 1..10 {
+    ///     assert_eq!(0, buf[i * 3 % 10]);
+    ///     buf.rotate_right(3);
+    /// }
+    /// assert_eq!(buf, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// ```
+    #[unstable(feature = "vecdeque_rotate", issue = "0")]
+    pub fn rotate_right(&mut self, k: usize) {
+        assert!(k <= self.len());
+        let mid = self.len() - k;
+        if k <= mid {
+            unsafe { self.rotate_right_inner(k) }
+        } else {
+            unsafe { self.rotate_left_inner(mid) }
+        }
+    }
+
+    // Safety: the following two methods require that the rotation amount
+    // be at most half the length of the deque.
+    //
+    // `wrap_copy` requires that `min(x, cap() - x) + copy_len <= cap()`,
+    // and that `min` is never more than half the capacity, regardless of x,
+    // so it's sound to call here because we're copying at most half the
+    // length, which is never above half the capacity.
+
+    unsafe fn rotate_left_inner(&mut self, mid: usize) {
+        debug_assert!(mid * 2 <= self.len());
+        self.wrap_copy(self.head, self.tail, mid);
+        self.head = self.wrap_add(self.head, mid);
+        self.tail = self.wrap_add(self.tail, mid);
+    }
+
+    unsafe fn rotate_right_inner(&mut self, k: usize) {
+        debug_assert!(k * 2 <= self.len());
+        self.head = self.wrap_sub(self.head, k);
+        self.tail = self.wrap_sub(self.tail, k);
+        self.wrap_copy(self.tail, self.head, k);
+    }
+
     /// Returns the number of elements in the `VecDeque`.
     ///
     /// # Examples
@@ -1072,6 +1520,107 @@ impl<T> VecDeque<T> {
         a.contains(x) || b.contains(x)
     }
 
+    /// Binary searches this sorted `VecDeque` for a given element.
+    ///
+    /// If the value is found then `Ok` is returned, containing the index of
+    /// the matching element; if the value is not found then `Err` is
+    /// returned, containing the index where a matching element could be
+    /// inserted while maintaining sorted order. If there are multiple
+    /// matches, any one of them may be returned.
+    ///
+    /// The deque does not have to be contiguous: the search only looks at
+    /// the half of the ring buffer that can contain the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vecdeque_binary_search)]
+    ///
+    /// use std::collections::VecDeque;
+    ///
+    /// let deque: VecDeque<_> = vec![0, 1, 1, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55].into();
+    ///
+    /// assert_eq!(deque.binary_search(&13), Ok(9));
+    /// assert_eq!(deque.binary_search(&4), Err(7));
+    /// assert_eq!(deque.binary_search(&100), Err(13));
+    /// let r = deque.binary_search(&1);
+    /// assert!(match r { Ok(1...4) => true, _ => false, });
+    /// ```
+    #[unstable(feature = "vecdeque_binary_search", issue = "0")]
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+        where T: Ord
+    {
+        self.binary_search_by(|e| e.cmp(x))
+    }
+
+    /// Binary searches this sorted `VecDeque` with a comparator function.
+    ///
+    /// The comparator function should implement an order consistent with
+    /// the sort order of the deque, returning an order code that indicates
+    /// whether its argument is `Less`, `Equal` or `Greater` than the desired
+    /// target. The results are the same as for [`binary_search`].
+    ///
+    /// [`binary_search`]: #method.binary_search
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vecdeque_binary_search)]
+    ///
+    /// use std::collections::VecDeque;
+    ///
+    /// let deque: VecDeque<_> = vec![0, 1, 1, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55].into();
+    ///
+    /// assert_eq!(deque.binary_search_by(|x| x.cmp(&13)), Ok(9));
+    /// assert_eq!(deque.binary_search_by(|x| x.cmp(&4)), Err(7));
+    /// ```
+    #[unstable(feature = "vecdeque_binary_search", issue = "0")]
+    pub fn binary_search_by<'a, F>(&'a self, mut f: F) -> Result<usize, usize>
+        where F: FnMut(&'a T) -> Ordering
+    {
+        let (front, back) = self.as_slices();
+        let in_back = match back.first() {
+            Some(first) => f(first) != Ordering::Greater,
+            None => false,
+        };
+        if in_back {
+            let offset = front.len();
+            back.binary_search_by(f).map(|i| i + offset).map_err(|i| i + offset)
+        } else {
+            front.binary_search_by(f)
+        }
+    }
+
+    /// Binary searches this sorted `VecDeque` with a key extraction
+    /// function.
+    ///
+    /// Assumes that the deque is sorted by the key, for instance with
+    /// [`make_contiguous().sort_by_key()`] using the same key extraction
+    /// function. The results are the same as for [`binary_search`].
+    ///
+    /// [`make_contiguous().sort_by_key()`]: #method.make_contiguous
+    /// [`binary_search`]: #method.binary_search
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vecdeque_binary_search)]
+    ///
+    /// use std::collections::VecDeque;
+    ///
+    /// let deque: VecDeque<_> = vec![(0, 0), (2, 1), (4, 1), (5, 1), (3, 1), (1, 2)].into();
+    ///
+    /// assert_eq!(deque.binary_search_by_key(&2, |&(_, b)| b), Ok(5));
+    /// assert_eq!(deque.binary_search_by_key(&3, |&(_, b)| b), Err(6));
+    /// ```
+    #[unstable(feature = "vecdeque_binary_search", issue = "0")]
+    pub fn binary_search_by_key<'a, B, F>(&'a self, b: &B, mut f: F) -> Result<usize, usize>
+        where F: FnMut(&'a T) -> B,
+              B: Ord
+    {
+        self.binary_search_by(|k| f(k).cmp(b))
+    }
+
     /// Provides a reference to the front element, or `None` if the `VecDeque` is
     /// empty.
     ///
@@ -1273,6 +1822,32 @@ impl<T> VecDeque<T> {
         }
     }
 
+    /// Removes the last element from the `VecDeque` and returns it if
+    /// `predicate` returns `true` for it, or `None` if the predicate returns
+    /// `false` or the `VecDeque` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(pop_if)]
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut buf: VecDeque<_> = vec![1, 2, 3].into_iter().collect();
+    /// assert_eq!(buf.pop_back_if(|x| *x > 2), Some(3));
+    /// assert_eq!(buf.pop_back_if(|x| *x > 2), None);
+    /// assert_eq!(buf, [1, 2]);
+    /// ```
+    #[unstable(feature = "pop_if", issue = "0")]
+    pub fn pop_back_if<F>(&mut self, predicate: F) -> Option<T>
+        where F: FnOnce(&mut T) -> bool
+    {
+        let pop = match self.back_mut() {
+            Some(back) => predicate(back),
+            None => false,
+        };
+        if pop { self.pop_back() } else { None }
+    }
+
     #[inline]
     fn is_contiguous(&self) -> bool {
         self.tail <= self.head
@@ -1835,8 +2410,48 @@ impl<T> VecDeque<T> {
     #[inline]
     #[stable(feature = "append", since = "1.4.0")]
     pub fn append(&mut self, other: &mut Self) {
-        // naive impl
-        self.extend(other.drain(..));
+        // Guarantees there is room in `self` for everything in `other`.
+        self.reserve(other.len());
+        unsafe {
+            let (front, back) = other.as_slices();
+            let head = self.head;
+            self.copy_slice(head, front);
+            self.head = self.wrap_add(head, front.len());
+            let head = self.head;
+            self.copy_slice(head, back);
+            self.head = self.wrap_add(head, back.len());
+        }
+        // The values now belong to `self`, so forget them in `other`.
+        other.tail = other.head;
+    }
+
+    /// Consumes the `VecDeque` and returns its elements as a `Vec`, in
+    /// front-to-back order.
+    ///
+    /// The buffer is reused rather than copied into a new allocation. If the
+    /// contents wrap around the end of the ring buffer they are moved into
+    /// place first, which takes `O(len)` time.
+    ///
+    /// This is the same as `Vec::from(deque)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vecdeque_into_vec)]
+    ///
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut buf: VecDeque<_> = vec![2, 3].into();
+    /// buf.push_front(1);
+    /// let ptr = buf.as_slices().1.as_ptr();
+    ///
+    /// let vec = buf.into_vec();
+    /// assert_eq!(vec, [1, 2, 3]);
+    /// assert_eq!(vec.as_ptr(), ptr);
+    /// ```
+    #[unstable(feature = "vecdeque_into_vec", issue = "0")]
+    pub fn into_vec(self) -> Vec<T> {
+        self.into()
     }
 
     /// Retains only the elements specified by the predicate.
@@ -1924,9 +2539,13 @@ impl<T: Clone> VecDeque<T> {
 /// Returns the index in the underlying buffer for a given logical element index.
 #[inline]
 fn wrap_index(index: usize, size: usize) -> usize {
-    // size is always a power of 2
-    debug_assert!(size.is_power_of_two());
-    index & (size - 1)
+    // index is always less than twice the size, which need not be a power of 2
+    if index >= size {
+        debug_assert!(index - size < size);
+        index - size
+    } else {
+        index
+    }
 }
 
 /// Returns the two slices that cover the `VecDeque`'s valid range
@@ -1968,8 +2587,11 @@ impl<'a, T> RingSlices for &'a mut [T] {
 /// Calculate the number of elements left to be read in the buffer
 #[inline]
 fn count(tail: usize, head: usize, size: usize) -> usize {
-    // size is always a power of 2
-    (head.wrapping_sub(tail)) & (size - 1)
+    if tail <= head {
+        head - tail
+    } else {
+        size - tail + head
+    }
 }
 
 /// An iterator over the elements of a `VecDeque`.
@@ -2045,7 +2667,8 @@ impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
         if self.tail == self.head {
             return None;
         }
-        self.head = wrap_index(self.head.wrapping_sub(1), self.ring.len());
+        self.head = wrap_index(self.head.wrapping_sub(1).wrapping_add(self.ring.len()),
+                               self.ring.len());
         unsafe { Some(self.ring.get_unchecked(self.head)) }
     }
 
@@ -2134,7 +2757,8 @@ impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
         if self.tail == self.head {
             return None;
         }
-        self.head = wrap_index(self.head.wrapping_sub(1), self.ring.len());
+        self.head = wrap_index(self.head.wrapping_sub(1).wrapping_add(self.ring.len()),
+                               self.ring.len());
 
         unsafe {
             let elem = self.ring.get_unchecked_mut(self.head);
@@ -2389,6 +3013,18 @@ __impl_slice_eq1! { VecDeque<A>, Vec<B> }
 __impl_slice_eq1! { VecDeque<A>, &'b [B] }
 __impl_slice_eq1! { VecDeque<A>, &'b mut [B] }
 
+#[stable(feature = "vec_partial_eq_vec_deque", since = "1.27.0")]
+impl<A, B> PartialEq<VecDeque<B>> for Vec<A> where A: PartialEq<B> {
+    fn eq(&self, other: &VecDeque<B>) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+        let (oa, ob) = other.as_slices();
+        let (sa, sb) = self.split_at(oa.len());
+        sa == oa && sb == ob
+    }
+}
+
 macro_rules! array_impls {
     ($($N: expr)+) => {
         $(
@@ -2524,11 +3160,10 @@ impl<T> From<Vec<T>> for VecDeque<T> {
             let len = other.len();
             mem::forget(other);
 
-            // We need to extend the buf if it's not a power of two, too small
-            // or doesn't have at least one free space
-            if !buf.cap().is_power_of_two() || (buf.cap() < (MINIMUM_CAPACITY + 1)) ||
-               (buf.cap() == len) {
-                let cap = cmp::max(buf.cap() + 1, MINIMUM_CAPACITY + 1).next_power_of_two();
+            // We need to extend the buf if it's too small or doesn't have at
+            // least one free space
+            if (buf.cap() < (MINIMUM_CAPACITY + 1)) || (buf.cap() == len) {
+                let cap = cmp::max(len + 1, MINIMUM_CAPACITY + 1);
                 buf.reserve_exact(len, cap - len);
             }
 
@@ -2898,10 +3533,16 @@ mod tests {
                 let mut vec = Vec::with_capacity(cap);
                 vec.extend(0..len);
 
-                let vd = VecDeque::from(vec.clone());
-                assert!(vd.cap().is_power_of_two());
-                assert_eq!(vd.len(), vec.len());
-                assert!(vd.into_iter().eq(vec));
+                let vec_cap = vec.capacity();
+                let vd = VecDeque::from(vec);
+                if vec_cap > len && vec_cap >= 2 {
+                    // The buffer of the vector is reused as it is.
+                    assert_eq!(vd.cap(), vec_cap);
+                } else {
+                    assert!(vd.cap() > len);
+                }
+                assert_eq!(vd.len(), len);
+                assert!(vd.into_iter().eq(0..len));
             }
         }
     }
@@ -36,6 +36,7 @@ extern crate alloc;
 
 use std::cell::{Cell, RefCell};
 use std::cmp;
+use std::collections::HashSet;
 use std::intrinsics;
 use std::marker::{PhantomData, Send};
 use std::mem;
@@ -411,11 +412,69 @@ impl DroplessArena {
     }
 }
 
+/// A set of byte strings whose contents are stored in an arena.
+///
+/// Inserting a byte string copies it into the arena the first time it is
+/// seen, and returns a reference to the stored copy, which lives as long as
+/// the set. Inserting equal contents again returns the same reference, so
+/// interned byte strings can be compared by address.
+pub struct ByteSetArena {
+    arena: DroplessArena,
+
+    /// The byte strings stored so far. The `'static` lifetime is a lie: the
+    /// references point into `arena`, and never escape with a lifetime
+    /// longer than that of `self`.
+    set: RefCell<HashSet<&'static [u8]>>,
+}
+
+impl ByteSetArena {
+    pub fn new() -> ByteSetArena {
+        ByteSetArena {
+            arena: DroplessArena::new(),
+            set: RefCell::new(HashSet::new()),
+        }
+    }
+
+    /// Returns the stored copy of `bytes`, copying it into the arena first
+    /// if it isn't already there.
+    pub fn insert(&self, bytes: &[u8]) -> &[u8] {
+        if let Some(&interned) = self.set.borrow().get(bytes) {
+            return interned;
+        }
+
+        let interned: &'static [u8] = if bytes.is_empty() {
+            &[]
+        } else {
+            unsafe { mem::transmute::<&[u8], &'static [u8]>(self.arena.alloc_slice(bytes)) }
+        };
+        self.set.borrow_mut().insert(interned);
+        interned
+    }
+
+    /// Returns the stored copy of `bytes`, if there is one.
+    pub fn get(&self, bytes: &[u8]) -> Option<&[u8]> {
+        self.set.borrow().get(bytes).map(|&interned| interned)
+    }
+
+    pub fn contains(&self, bytes: &[u8]) -> bool {
+        self.set.borrow().contains(bytes)
+    }
+
+    /// The number of distinct byte strings in the set.
+    pub fn len(&self) -> usize {
+        self.set.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.set.borrow().is_empty()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     extern crate test;
     use self::test::Bencher;
-    use super::TypedArena;
+    use super::{ByteSetArena, TypedArena};
     use std::cell::Cell;
 
     #[allow(dead_code)]
@@ -532,6 +591,46 @@ mod tests {
         }
     }
 
+    #[test]
+    pub fn test_byte_set_arena_dedup() {
+        let set = ByteSetArena::new();
+        assert!(set.is_empty());
+
+        let mut buf = b"hello".to_vec();
+        let hello = set.insert(&buf);
+        assert_eq!(hello, b"hello");
+        assert!(hello.as_ptr() != buf.as_ptr());
+
+        // The stored copy doesn't change with the buffer it came from.
+        buf[0] = b'j';
+        let jello = set.insert(&buf);
+        assert_eq!(hello, b"hello");
+        assert_eq!(jello, b"jello");
+
+        let again = set.insert(b"hello");
+        assert_eq!(again.as_ptr(), hello.as_ptr());
+        assert_eq!(set.len(), 2);
+
+        assert!(set.contains(b"jello"));
+        assert!(!set.contains(b"yellow"));
+        assert_eq!(set.get(b"jello").map(|s| s.as_ptr()), Some(jello.as_ptr()));
+        assert_eq!(set.get(b"yellow"), None);
+    }
+
+    #[test]
+    pub fn test_byte_set_arena_many() {
+        let set = ByteSetArena::new();
+        assert_eq!(set.insert(b""), b"");
+        let interned: Vec<_> = (0..10000u32)
+            .map(|i| set.insert(i.to_string().as_bytes()))
+            .collect();
+        for (i, &bytes) in interned.iter().enumerate() {
+            assert_eq!(bytes, i.to_string().as_bytes());
+            assert_eq!(set.insert(bytes).as_ptr(), bytes.as_ptr());
+        }
+        assert_eq!(set.len(), 10001);
+    }
+
     // Drop tests
 
     struct DropCounter<'a> {
@@ -18,6 +18,11 @@
 //! escaped version of the character given.
 //!
 //! [`escape_default`]: fn.escape_default.html
+//!
+//! A [`CharClass`] is a set of bytes, such as the ASCII digits, that
+//! scanning loops can test bytes against with a single table lookup.
+//!
+//! [`CharClass`]: struct.CharClass.html
 
 #![stable(feature = "core_ascii", since = "1.26.0")]
 
@@ -145,3 +150,212 @@ impl fmt::Debug for EscapeDefault {
         f.pad("EscapeDefault { .. }")
     }
 }
+
+/// A set of bytes, stored as a table with one bit for each of the 256
+/// possible values.
+///
+/// Testing whether a byte is in the class is a single table lookup, no
+/// matter how many bytes or ranges of bytes the class is made of. This
+/// makes it cheaper to scan text with a `CharClass` than with a closure
+/// that chains comparisons, such as
+/// `|b| b == b' ' || b == b'\t' || b == b','`.
+///
+/// The classes matching the `is_ascii_*` methods of `u8` are provided as
+/// associated constants, and others can be built from a list of bytes or a
+/// predicate. Classes are used by the `span_of`, `find_not_of` and
+/// `trim_matches_class` methods of `str` and `[u8]`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(ascii_char_class)]
+/// use std::ascii::CharClass;
+///
+/// let ident = CharClass::ALPHANUMERIC.union(&CharClass::from_bytes(b"_"));
+/// assert!(ident.contains(b'_'));
+/// assert!(!ident.contains(b'-'));
+///
+/// let line = "count_1 = 42";
+/// assert_eq!(&line[..line.span_of(&ident)], "count_1");
+/// ```
+#[unstable(feature = "ascii_char_class", issue = "0")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CharClass {
+    /// Bit `b % 64` of word `b / 64` is set if `b` is in the class.
+    bits: [u64; 4],
+}
+
+#[unstable(feature = "ascii_char_class", issue = "0")]
+impl CharClass {
+    /// The class containing no bytes.
+    pub const EMPTY: CharClass = CharClass { bits: [0, 0, 0, 0] };
+
+    /// The ASCII characters, U+0000 NUL ..= U+007F DELETE.
+    pub const ASCII: CharClass = CharClass { bits: [!0, !0, 0, 0] };
+
+    /// The ASCII whitespace characters, as matched by
+    /// [`u8::is_ascii_whitespace`]: space, tab, line feed, form feed and
+    /// carriage return.
+    ///
+    /// [`u8::is_ascii_whitespace`]: ../primitive.u8.html#method.is_ascii_whitespace
+    pub const WHITESPACE: CharClass = CharClass { bits: [0x0000_0001_0000_3600, 0, 0, 0] };
+
+    /// The ASCII decimal digits, `0` ..= `9`.
+    pub const DIGIT: CharClass = CharClass { bits: [0x03ff_0000_0000_0000, 0, 0, 0] };
+
+    /// The ASCII hexadecimal digits, `0` ..= `9`, `A` ..= `F` and
+    /// `a` ..= `f`.
+    pub const HEX_DIGIT: CharClass = CharClass {
+        bits: [0x03ff_0000_0000_0000, 0x0000_007e_0000_007e, 0, 0],
+    };
+
+    /// The ASCII uppercase letters, `A` ..= `Z`.
+    pub const UPPERCASE: CharClass = CharClass { bits: [0, 0x0000_0000_07ff_fffe, 0, 0] };
+
+    /// The ASCII lowercase letters, `a` ..= `z`.
+    pub const LOWERCASE: CharClass = CharClass { bits: [0, 0x07ff_fffe_0000_0000, 0, 0] };
+
+    /// The ASCII letters, `A` ..= `Z` and `a` ..= `z`.
+    pub const ALPHABETIC: CharClass = CharClass { bits: [0, 0x07ff_fffe_07ff_fffe, 0, 0] };
+
+    /// The ASCII letters and digits.
+    pub const ALPHANUMERIC: CharClass = CharClass {
+        bits: [0x03ff_0000_0000_0000, 0x07ff_fffe_07ff_fffe, 0, 0],
+    };
+
+    /// The ASCII punctuation characters, as matched by
+    /// [`u8::is_ascii_punctuation`].
+    ///
+    /// [`u8::is_ascii_punctuation`]: ../primitive.u8.html#method.is_ascii_punctuation
+    pub const PUNCTUATION: CharClass = CharClass {
+        bits: [0xfc00_fffe_0000_0000, 0x7800_0001_f800_0001, 0, 0],
+    };
+
+    /// The visible ASCII characters, U+0021 '!' ..= U+007E '~'.
+    pub const GRAPHIC: CharClass = CharClass {
+        bits: [0xffff_fffe_0000_0000, 0x7fff_ffff_ffff_ffff, 0, 0],
+    };
+
+    /// The ASCII control characters, U+0000 NUL ..= U+001F UNIT SEPARATOR
+    /// and U+007F DELETE.
+    pub const CONTROL: CharClass = CharClass {
+        bits: [0x0000_0000_ffff_ffff, 0x8000_0000_0000_0000, 0, 0],
+    };
+
+    /// Creates a class containing the given bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_char_class)]
+    /// use std::ascii::CharClass;
+    ///
+    /// let separators = CharClass::from_bytes(b",;");
+    /// assert!(separators.contains(b';'));
+    /// assert!(!separators.contains(b' '));
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> CharClass {
+        let mut class = CharClass::EMPTY;
+        for &b in bytes {
+            class.insert(b);
+        }
+        class
+    }
+
+    /// Creates a class containing the bytes for which `f` returns `true`.
+    ///
+    /// `f` is called once for every byte, in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_char_class)]
+    /// use std::ascii::CharClass;
+    ///
+    /// let octal = CharClass::from_fn(|b| b'0' <= b && b <= b'7');
+    /// assert!(octal.contains(b'7'));
+    /// assert!(!octal.contains(b'8'));
+    /// ```
+    pub fn from_fn<F: FnMut(u8) -> bool>(mut f: F) -> CharClass {
+        let mut class = CharClass::EMPTY;
+        for b in 0..256 {
+            if f(b as u8) {
+                class.insert(b as u8);
+            }
+        }
+        class
+    }
+
+    /// Returns `true` if `byte` is in the class.
+    #[inline]
+    pub fn contains(&self, byte: u8) -> bool {
+        self.bits[(byte >> 6) as usize] & (1 << (byte & 63)) != 0
+    }
+
+    /// Adds `byte` to the class.
+    #[inline]
+    pub fn insert(&mut self, byte: u8) {
+        self.bits[(byte >> 6) as usize] |= 1 << (byte & 63);
+    }
+
+    /// Removes `byte` from the class.
+    #[inline]
+    pub fn remove(&mut self, byte: u8) {
+        self.bits[(byte >> 6) as usize] &= !(1 << (byte & 63));
+    }
+
+    /// Returns the class of the bytes that are in `self`, in `other`, or in
+    /// both.
+    pub fn union(&self, other: &CharClass) -> CharClass {
+        let mut bits = self.bits;
+        for (a, b) in bits.iter_mut().zip(&other.bits) {
+            *a |= *b;
+        }
+        CharClass { bits }
+    }
+
+    /// Returns the class of the bytes that are in both `self` and `other`.
+    pub fn intersection(&self, other: &CharClass) -> CharClass {
+        let mut bits = self.bits;
+        for (a, b) in bits.iter_mut().zip(&other.bits) {
+            *a &= *b;
+        }
+        CharClass { bits }
+    }
+
+    /// Returns the class of the bytes that are not in `self`.
+    ///
+    /// Note that this includes the non-ASCII bytes `0x80` ..= `0xFF` if
+    /// `self` doesn't; use [`intersection`] with [`ASCII`] to leave them
+    /// out.
+    ///
+    /// [`intersection`]: #method.intersection
+    /// [`ASCII`]: #associatedconstant.ASCII
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_char_class)]
+    /// use std::ascii::CharClass;
+    ///
+    /// let not_digit = CharClass::DIGIT.complement();
+    /// assert!(not_digit.contains(b'x'));
+    /// assert!(not_digit.contains(0xff));
+    /// assert!(!not_digit.intersection(&CharClass::ASCII).contains(0xff));
+    /// ```
+    pub fn complement(&self) -> CharClass {
+        let mut bits = self.bits;
+        for a in bits.iter_mut() {
+            *a = !*a;
+        }
+        CharClass { bits }
+    }
+}
+
+#[unstable(feature = "ascii_char_class", issue = "0")]
+impl Default for CharClass {
+    /// Returns the empty class.
+    fn default() -> CharClass {
+        CharClass::EMPTY
+    }
+}
@@ -343,6 +343,23 @@ impl Ordering {
 /// v.sort_by_key(|&num| (num > 3, Reverse(num)));
 /// assert_eq!(v, vec![3, 2, 1, 6, 5, 4]);
 /// ```
+///
+/// Since `Reverse<T>` is `Ord` whenever `T` is, it can also be used as the
+/// element of a `BinaryHeap` to get a min-heap, or as the key of a
+/// `BTreeMap` or `BTreeSet` to iterate in descending order, without writing
+/// a comparator:
+///
+/// ```
+/// use std::cmp::Reverse;
+/// use std::collections::{BinaryHeap, BTreeSet};
+///
+/// let mut heap: BinaryHeap<_> = vec![3, 1, 2].into_iter().map(Reverse).collect();
+/// assert_eq!(heap.pop(), Some(Reverse(1)));
+///
+/// let set: BTreeSet<_> = vec![3, 1, 2].into_iter().map(Reverse).collect();
+/// let descending: Vec<i32> = set.into_iter().map(|Reverse(x)| x).collect();
+/// assert_eq!(descending, [3, 2, 1]);
+/// ```
 #[derive(PartialEq, Eq, Debug, Copy, Clone, Default, Hash)]
 #[stable(feature = "reverse_cmp_key", since = "1.19.0")]
 pub struct Reverse<T>(#[stable(feature = "reverse_cmp_key", since = "1.19.0")] pub T);
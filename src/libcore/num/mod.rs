@@ -188,6 +188,10 @@ impl<T: fmt::UpperHex> fmt::UpperHex for Wrapping<T> {
 }
 
 mod wrapping;
+mod ordered_float;
+
+#[unstable(feature = "ordered_float", issue = "0")]
+pub use self::ordered_float::{OrderedFloat, NotNan, FloatIsNan};
 
 // All these modules are technically private and only exposed for coretests:
 pub mod flt2dec;
@@ -0,0 +1,273 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Totally ordered wrappers around `f32` and `f64`.
+
+use cmp::Ordering::{self, Equal, Greater, Less};
+use fmt;
+use hash::{Hash, Hasher};
+use num::Float;
+
+/// A floating point number with a total order, usable as a key in sorted
+/// collections and heaps.
+///
+/// `f32` and `f64` only implement `PartialOrd` and `PartialEq`, because
+/// NaN is not equal to anything, not even itself. `OrderedFloat` settles
+/// this by treating every NaN as equal to every other NaN and greater than
+/// every other value, including infinity. `-0.0` and `+0.0` are equal, as
+/// they are for the bare float types.
+///
+/// `Hash` is consistent with this equality: all NaNs hash alike, as do both
+/// zeroes.
+///
+/// If NaN can't occur, or should be rejected up front, use [`NotNan`].
+///
+/// [`NotNan`]: struct.NotNan.html
+///
+/// # Examples
+///
+/// ```
+/// #![feature(ordered_float)]
+/// use std::collections::BTreeSet;
+/// use std::f64::NAN;
+/// use std::num::OrderedFloat;
+///
+/// let mut set = BTreeSet::new();
+/// for &x in &[2.5, NAN, -1.0, 0.0, -0.0, NAN] {
+///     set.insert(OrderedFloat(x));
+/// }
+/// let sorted: Vec<f64> = set.iter().map(|x| x.0).collect();
+/// assert_eq!(&sorted[..3], [-1.0, 0.0, 2.5]);
+/// assert!(sorted[3].is_nan());
+/// assert_eq!(sorted.len(), 4);
+/// ```
+#[unstable(feature = "ordered_float", issue = "0")]
+#[derive(Clone, Copy, Default, Debug)]
+pub struct OrderedFloat<T>(#[unstable(feature = "ordered_float", issue = "0")] pub T);
+
+/// A floating point number that is known not to be NaN, and so has a total
+/// order.
+///
+/// A `NotNan` is created with [`new`], which rejects NaN. The order is the
+/// usual order of floating point numbers, with `-0.0` equal to `+0.0`.
+///
+/// [`new`]: #method.new
+///
+/// # Examples
+///
+/// ```
+/// #![feature(ordered_float)]
+/// use std::collections::BinaryHeap;
+/// use std::num::NotNan;
+///
+/// let mut heap = BinaryHeap::new();
+/// for &x in &[0.5, 3.25, -7.0] {
+///     heap.push(NotNan::new(x).unwrap());
+/// }
+/// assert_eq!(heap.pop().map(NotNan::into_inner), Some(3.25));
+///
+/// assert!(NotNan::new(0.0f32 / 0.0).is_err());
+/// ```
+#[unstable(feature = "ordered_float", issue = "0")]
+#[derive(Clone, Copy, Default, Debug)]
+pub struct NotNan<T>(T);
+
+/// The error returned when trying to create a [`NotNan`] from NaN.
+///
+/// [`NotNan`]: struct.NotNan.html
+#[unstable(feature = "ordered_float", issue = "0")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FloatIsNan(());
+
+impl FloatIsNan {
+    #[unstable(feature = "int_error_internals",
+               reason = "available through Error trait and this method should \
+                         not be exposed publicly",
+               issue = "0")]
+    #[doc(hidden)]
+    pub fn __description(&self) -> &str {
+        "floating point value is NaN"
+    }
+}
+
+#[unstable(feature = "ordered_float", issue = "0")]
+impl fmt::Display for FloatIsNan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.__description().fmt(f)
+    }
+}
+
+#[unstable(feature = "ordered_float", issue = "0")]
+impl<T: fmt::Display> fmt::Display for OrderedFloat<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[unstable(feature = "ordered_float", issue = "0")]
+impl<T: fmt::Display> fmt::Display for NotNan<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: Float + Copy> NotNan<T> {
+    /// Wraps `value`, or returns an error if it is NaN.
+    #[unstable(feature = "ordered_float", issue = "0")]
+    #[inline]
+    pub fn new(value: T) -> Result<NotNan<T>, FloatIsNan> {
+        if value.is_nan() {
+            Err(FloatIsNan(()))
+        } else {
+            Ok(NotNan(value))
+        }
+    }
+}
+
+impl<T> NotNan<T> {
+    /// Wraps `value` without checking that it is not NaN.
+    ///
+    /// # Safety
+    ///
+    /// `value` must not be NaN. Unsafe code is allowed to rely on the order
+    /// of `NotNan` values being total.
+    #[unstable(feature = "ordered_float", issue = "0")]
+    #[inline]
+    pub unsafe fn new_unchecked(value: T) -> NotNan<T> {
+        NotNan(value)
+    }
+
+    /// Returns the wrapped value.
+    #[unstable(feature = "ordered_float", issue = "0")]
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+macro_rules! ordered_float_impls {
+    ($($t:ident)*) => ($(
+        impl OrderedFloat<$t> {
+            // The bits of the value, with all NaNs and both zeroes collapsed
+            // into one representation each, so that values that compare
+            // equal hash equally.
+            #[inline]
+            fn canonical_bits(self) -> <$t as Float>::Bits {
+                if self.0.is_nan() {
+                    ::$t::NAN.to_bits()
+                } else if self.0 == 0.0 {
+                    0
+                } else {
+                    self.0.to_bits()
+                }
+            }
+        }
+
+        #[unstable(feature = "ordered_float", issue = "0")]
+        impl Ord for OrderedFloat<$t> {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                match self.0.partial_cmp(&other.0) {
+                    Some(ordering) => ordering,
+                    None => match (self.0.is_nan(), other.0.is_nan()) {
+                        (true, true) => Equal,
+                        (true, false) => Greater,
+                        (false, _) => Less,
+                    },
+                }
+            }
+        }
+
+        #[unstable(feature = "ordered_float", issue = "0")]
+        impl PartialOrd for OrderedFloat<$t> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        #[unstable(feature = "ordered_float", issue = "0")]
+        impl PartialEq for OrderedFloat<$t> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.cmp(other) == Equal
+            }
+        }
+
+        #[unstable(feature = "ordered_float", issue = "0")]
+        impl Eq for OrderedFloat<$t> {}
+
+        #[unstable(feature = "ordered_float", issue = "0")]
+        impl Hash for OrderedFloat<$t> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.canonical_bits().hash(state)
+            }
+        }
+
+        #[unstable(feature = "ordered_float", issue = "0")]
+        impl From<$t> for OrderedFloat<$t> {
+            #[inline]
+            fn from(value: $t) -> OrderedFloat<$t> {
+                OrderedFloat(value)
+            }
+        }
+
+        #[unstable(feature = "ordered_float", issue = "0")]
+        impl Ord for NotNan<$t> {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                // Neither value is NaN, so exactly one of these holds.
+                if self.0 < other.0 {
+                    Less
+                } else if self.0 > other.0 {
+                    Greater
+                } else {
+                    Equal
+                }
+            }
+        }
+
+        #[unstable(feature = "ordered_float", issue = "0")]
+        impl PartialOrd for NotNan<$t> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        #[unstable(feature = "ordered_float", issue = "0")]
+        impl PartialEq for NotNan<$t> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        #[unstable(feature = "ordered_float", issue = "0")]
+        impl Eq for NotNan<$t> {}
+
+        #[unstable(feature = "ordered_float", issue = "0")]
+        impl Hash for NotNan<$t> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                OrderedFloat(self.0).hash(state)
+            }
+        }
+
+        #[unstable(feature = "ordered_float", issue = "0")]
+        impl From<NotNan<$t>> for $t {
+            #[inline]
+            fn from(value: NotNan<$t>) -> $t {
+                value.0
+            }
+        }
+    )*)
+}
+
+ordered_float_impls! { f32 f64 }
@@ -57,7 +57,30 @@ fn repeat_byte(b: u8) -> usize {
 }
 
 /// Return the first index matching the byte `x` in `text`.
+///
+/// The search reads two machine words at a time once `text` is aligned, or
+/// 16 bytes at a time with SSE2 where it is available, so it is much faster
+/// than `text.iter().position(|&b| b == x)`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(slice_memchr)]
+///
+/// use std::slice::memchr;
+///
+/// assert_eq!(memchr(b'k', b"abcdefghijklmnopqrstuvwxyz"), Some(10));
+/// assert_eq!(memchr(b'!', b"abc"), None);
+/// ```
+#[unstable(feature = "slice_memchr", issue = "0")]
 pub fn memchr(x: u8, text: &[u8]) -> Option<usize> {
+    #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+    {
+        if text.len() >= sse2::VECTOR_SIZE {
+            return unsafe { sse2::memchr(x, text) };
+        }
+    }
+
     // Scan for a single byte value by reading two `usize` words at a time.
     //
     // Split `text` in three parts
@@ -102,7 +125,30 @@ pub fn memchr(x: u8, text: &[u8]) -> Option<usize> {
 }
 
 /// Return the last index matching the byte `x` in `text`.
+///
+/// This is the reverse of [`memchr`], scanning from the end of `text`.
+///
+/// [`memchr`]: fn.memchr.html
+///
+/// # Examples
+///
+/// ```
+/// #![feature(slice_memchr)]
+///
+/// use std::slice::memrchr;
+///
+/// assert_eq!(memrchr(b'a', b"banana"), Some(5));
+/// assert_eq!(memrchr(b'!', b"banana"), None);
+/// ```
+#[unstable(feature = "slice_memchr", issue = "0")]
 pub fn memrchr(x: u8, text: &[u8]) -> Option<usize> {
+    #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+    {
+        if text.len() >= sse2::VECTOR_SIZE {
+            return unsafe { sse2::memrchr(x, text) };
+        }
+    }
+
     // Scan for a single byte value by reading two `usize` words at a time.
     //
     // Split `text` in three parts
@@ -147,6 +193,139 @@ pub fn memrchr(x: u8, text: &[u8]) -> Option<usize> {
     text[..offset].iter().rposition(|elt| *elt == x)
 }
 
+// SSE2 is part of the x86_64 baseline, so this is used on every x86_64
+// target unless it has been explicitly disabled.
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+mod sse2 {
+    use arch::x86_64::{__m128i, _mm_cmpeq_epi8, _mm_loadu_si128, _mm_movemask_epi8};
+    use arch::x86_64::_mm_set1_epi8;
+
+    pub const VECTOR_SIZE: usize = 16;
+
+    /// Returns a mask with bit `i` set if byte `i` of the 16 bytes at
+    /// `ptr` equals the corresponding byte of `needle`.
+    #[inline(always)]
+    unsafe fn matches(ptr: *const u8, needle: __m128i) -> u32 {
+        let chunk = _mm_loadu_si128(ptr as *const __m128i);
+        _mm_movemask_epi8(_mm_cmpeq_epi8(chunk, needle)) as u32
+    }
+
+    /// `text` must be at least `VECTOR_SIZE` bytes long.
+    #[inline]
+    pub unsafe fn memchr(x: u8, text: &[u8]) -> Option<usize> {
+        debug_assert!(text.len() >= VECTOR_SIZE);
+        let len = text.len();
+        let ptr = text.as_ptr();
+        let needle = _mm_set1_epi8(x as i8);
+
+        let mut offset = 0;
+        while offset <= len - VECTOR_SIZE {
+            let mask = matches(ptr.offset(offset as isize), needle);
+            if mask != 0 {
+                return Some(offset + mask.trailing_zeros() as usize);
+            }
+            offset += VECTOR_SIZE;
+        }
+
+        // Search the last 16 bytes, overlapping the bytes already searched,
+        // which are known not to match.
+        if offset < len {
+            let last = len - VECTOR_SIZE;
+            let mask = matches(ptr.offset(last as isize), needle);
+            if mask != 0 {
+                return Some(last + mask.trailing_zeros() as usize);
+            }
+        }
+        None
+    }
+
+    /// `text` must be at least `VECTOR_SIZE` bytes long.
+    #[inline]
+    pub unsafe fn memrchr(x: u8, text: &[u8]) -> Option<usize> {
+        debug_assert!(text.len() >= VECTOR_SIZE);
+        let ptr = text.as_ptr();
+        let needle = _mm_set1_epi8(x as i8);
+
+        let mut offset = text.len();
+        while offset >= VECTOR_SIZE {
+            offset -= VECTOR_SIZE;
+            let mask = matches(ptr.offset(offset as isize), needle);
+            if mask != 0 {
+                return Some(offset + 31 - mask.leading_zeros() as usize);
+            }
+        }
+
+        // Search the first 16 bytes, overlapping the bytes already searched,
+        // which are known not to match.
+        if offset > 0 {
+            let mask = matches(ptr, needle);
+            if mask != 0 {
+                return Some(31 - mask.leading_zeros() as usize);
+            }
+        }
+        None
+    }
+}
+
+/// Return the first index matching either of the bytes `x1` or `x2` in
+/// `text`.
+///
+/// Like [`memchr`], this reads `text` a word at a time, testing each word
+/// against both bytes.
+///
+/// [`memchr`]: fn.memchr.html
+///
+/// # Examples
+///
+/// ```
+/// #![feature(slice_memchr)]
+///
+/// use std::slice::memchr2;
+///
+/// // Find the end of an unquoted CSV field.
+/// assert_eq!(memchr2(b',', b'\n', b"field\nnext,row"), Some(5));
+/// assert_eq!(memchr2(b',', b'\n', b"field"), None);
+/// ```
+#[unstable(feature = "slice_memchr", issue = "0")]
+pub fn memchr2(x1: u8, x2: u8, text: &[u8]) -> Option<usize> {
+    // Same approach as `memchr`, but one word at a time, since each word
+    // is already checked twice.
+    let len = text.len();
+    let ptr = text.as_ptr();
+    let usize_bytes = mem::size_of::<usize>();
+    let matches = |elt: &u8| *elt == x1 || *elt == x2;
+
+    // search up to an aligned boundary
+    let mut offset = ptr.align_offset(usize_bytes);
+    if offset > 0 {
+        offset = cmp::min(offset, len);
+        if let Some(index) = text[..offset].iter().position(&matches) {
+            return Some(index);
+        }
+    }
+
+    // search the body of the text
+    let repeated_x1 = repeat_byte(x1);
+    let repeated_x2 = repeat_byte(x2);
+
+    if len >= usize_bytes {
+        while offset <= len - usize_bytes {
+            unsafe {
+                let u = *(ptr.offset(offset as isize) as *const usize);
+
+                // break if there is a matching byte
+                if contains_zero_byte(u ^ repeated_x1) || contains_zero_byte(u ^ repeated_x2) {
+                    break;
+                }
+            }
+            offset += usize_bytes;
+        }
+    }
+
+    // find the byte after the point the body loop stopped
+    text[offset..].iter().position(&matches).map(|i| offset + i)
+}
+
 // test fallback implementations on all platforms
 #[test]
 fn matches_one() {
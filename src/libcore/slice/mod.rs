@@ -35,6 +35,7 @@
 // * The `raw` and `bytes` submodules.
 // * Boilerplate trait implementations.
 
+use array::FixedSizeArray;
 use cmp::Ordering::{self, Less, Equal, Greater};
 use cmp;
 use fmt;
@@ -55,6 +56,9 @@ use iter_private::TrustedRandomAccess;
 /// Pure rust memchr implementation, taken from rust-memchr
 pub mod memchr;
 
+#[unstable(feature = "slice_memchr", issue = "0")]
+pub use self::memchr::{memchr, memchr2, memrchr};
+
 mod rotate;
 mod sort;
 
@@ -90,6 +94,10 @@ pub trait SliceExt {
     fn rsplit<P>(&self, pred: P) -> RSplit<Self::Item, P>
         where P: FnMut(&Self::Item) -> bool;
 
+    #[unstable(feature = "slice_group_by", issue = "0")]
+    fn group_by<P>(&self, pred: P) -> GroupBy<Self::Item, P>
+        where P: FnMut(&Self::Item, &Self::Item) -> bool;
+
     #[stable(feature = "core", since = "1.6.0")]
     fn splitn<P>(&self, n: usize, pred: P) -> SplitN<Self::Item, P>
         where P: FnMut(&Self::Item) -> bool;
@@ -141,6 +149,61 @@ pub trait SliceExt {
         where F: FnMut(&'a Self::Item) -> B,
               B: Ord;
 
+    #[unstable(feature = "slice_partition_point", issue = "0")]
+    fn partition_point<P>(&self, pred: P) -> usize
+        where P: FnMut(&Self::Item) -> bool;
+
+    #[unstable(feature = "is_sorted", issue = "0")]
+    fn is_sorted(&self) -> bool
+        where Self::Item: PartialOrd;
+
+    #[unstable(feature = "slice_min_max", issue = "0")]
+    fn min_max(&self) -> Option<(&Self::Item, &Self::Item)>
+        where Self::Item: Ord;
+
+    #[unstable(feature = "slice_permutations", issue = "0")]
+    fn next_permutation(&mut self) -> bool
+        where Self::Item: Ord;
+
+    #[unstable(feature = "slice_permutations", issue = "0")]
+    fn prev_permutation(&mut self) -> bool
+        where Self::Item: Ord;
+
+    #[unstable(feature = "slice_align_to", issue = "0")]
+    unsafe fn align_to<U>(&self) -> (&[Self::Item], &[U], &[Self::Item]);
+
+    #[unstable(feature = "slice_align_to", issue = "0")]
+    unsafe fn align_to_mut<U>(&mut self) -> (&mut [Self::Item], &mut [U], &mut [Self::Item]);
+
+    #[unstable(feature = "slice_as_chunks", issue = "0")]
+    fn as_chunks<A>(&self) -> (&[A], &[Self::Item])
+        where A: FixedSizeArray<Self::Item>;
+
+    #[unstable(feature = "slice_as_chunks", issue = "0")]
+    fn as_chunks_mut<A>(&mut self) -> (&mut [A], &mut [Self::Item])
+        where A: FixedSizeArray<Self::Item>;
+
+    #[unstable(feature = "slice_as_array", issue = "0")]
+    fn as_array<A>(&self) -> Option<&A>
+        where A: FixedSizeArray<Self::Item>;
+
+    #[unstable(feature = "slice_as_array", issue = "0")]
+    fn to_array<A>(&self) -> Option<A>
+        where A: FixedSizeArray<Self::Item> + Copy;
+
+    #[unstable(feature = "get_many_mut", issue = "0")]
+    fn get_many_mut<'a, I>(&'a mut self, indices: I) -> Option<I::Output>
+        where I: DisjointIndices<'a, Self::Item>;
+
+    #[unstable(feature = "is_sorted", issue = "0")]
+    fn is_sorted_by<F>(&self, compare: F) -> bool
+        where F: FnMut(&Self::Item, &Self::Item) -> Option<Ordering>;
+
+    #[unstable(feature = "is_sorted", issue = "0")]
+    fn is_sorted_by_key<K, F>(&self, f: F) -> bool
+        where F: FnMut(&Self::Item) -> K,
+              K: PartialOrd;
+
     #[stable(feature = "core", since = "1.6.0")]
     fn len(&self) -> usize;
 
@@ -173,6 +236,10 @@ pub trait SliceExt {
     fn rsplit_mut<P>(&mut self, pred: P) -> RSplitMut<Self::Item, P>
         where P: FnMut(&Self::Item) -> bool;
 
+    #[unstable(feature = "slice_group_by", issue = "0")]
+    fn group_by_mut<P>(&mut self, pred: P) -> GroupByMut<Self::Item, P>
+        where P: FnMut(&Self::Item, &Self::Item) -> bool;
+
     #[stable(feature = "core", since = "1.6.0")]
     fn splitn_mut<P>(&mut self, n: usize, pred: P) -> SplitNMut<Self::Item, P>
         where P: FnMut(&Self::Item) -> bool;
@@ -238,6 +305,22 @@ pub trait SliceExt {
     fn sort_unstable_by_key<B, F>(&mut self, f: F)
         where F: FnMut(&Self::Item) -> B,
               B: Ord;
+
+    #[unstable(feature = "slice_select_nth_unstable", issue = "0")]
+    fn select_nth_unstable(&mut self, index: usize)
+        -> (&mut [Self::Item], &mut Self::Item, &mut [Self::Item])
+        where Self::Item: Ord;
+
+    #[unstable(feature = "slice_select_nth_unstable", issue = "0")]
+    fn select_nth_unstable_by<F>(&mut self, index: usize, compare: F)
+        -> (&mut [Self::Item], &mut Self::Item, &mut [Self::Item])
+        where F: FnMut(&Self::Item, &Self::Item) -> Ordering;
+
+    #[unstable(feature = "slice_select_nth_unstable", issue = "0")]
+    fn select_nth_unstable_by_key<B, F>(&mut self, index: usize, f: F)
+        -> (&mut [Self::Item], &mut Self::Item, &mut [Self::Item])
+        where F: FnMut(&Self::Item) -> B,
+              B: Ord;
 }
 
 // Use macros to be generic over const/mut
@@ -326,6 +409,13 @@ impl<T> SliceExt for [T] {
         RSplit { inner: self.split(pred) }
     }
 
+    #[inline]
+    fn group_by<P>(&self, pred: P) -> GroupBy<T, P>
+        where P: FnMut(&T, &T) -> bool
+    {
+        GroupBy { v: self, pred }
+    }
+
     #[inline]
     fn splitn<P>(&self, n: usize, pred: P) -> SplitN<T, P>
         where P: FnMut(&T) -> bool
@@ -523,6 +613,13 @@ impl<T> SliceExt for [T] {
         RSplitMut { inner: self.split_mut(pred) }
     }
 
+    #[inline]
+    fn group_by_mut<P>(&mut self, pred: P) -> GroupByMut<T, P>
+        where P: FnMut(&T, &T) -> bool
+    {
+        GroupByMut { v: self, pred }
+    }
+
     #[inline]
     fn splitn_mut<P>(&mut self, n: usize, pred: P) -> SplitNMut<T, P>
         where P: FnMut(&T) -> bool
@@ -732,6 +829,180 @@ impl<T> SliceExt for [T] {
         self.binary_search_by(|k| f(k).cmp(b))
     }
 
+    #[inline]
+    fn partition_point<P>(&self, mut pred: P) -> usize
+        where P: FnMut(&T) -> bool
+    {
+        // The comparator never returns `Equal`, so the search always ends
+        // with `Err` holding the index of the first element failing `pred`.
+        self.binary_search_by(|x| if pred(x) { Ordering::Less } else { Ordering::Greater })
+            .unwrap_or_else(|i| i)
+    }
+
+    #[inline]
+    fn is_sorted(&self) -> bool
+        where T: PartialOrd
+    {
+        self.is_sorted_by(|a, b| a.partial_cmp(b))
+    }
+
+    fn min_max(&self) -> Option<(&T, &T)>
+        where T: Ord
+    {
+        let (first, rest) = match self.split_first() {
+            Some(split) => split,
+            None => return None,
+        };
+        let mut min = first;
+        let mut max = first;
+        // Ordering each pair first means that only the smaller element has
+        // to be compared with `min` and only the larger one with `max`: three
+        // comparisons for every two elements. On ties the earlier element
+        // wins for `min` and the later one for `max`, like `Iterator::min`
+        // and `Iterator::max`.
+        for pair in rest.chunks(2) {
+            let (small, large) = if pair.len() == 2 && pair[1] < pair[0] {
+                (&pair[1], &pair[0])
+            } else {
+                (&pair[0], &pair[pair.len() - 1])
+            };
+            if small < min {
+                min = small;
+            }
+            if large >= max {
+                max = large;
+            }
+        }
+        Some((min, max))
+    }
+
+    fn next_permutation(&mut self) -> bool
+        where T: Ord
+    {
+        // Find the longest weakly decreasing suffix. If that is the whole
+        // slice, this is already the last permutation.
+        let mut i = self.len();
+        while i > 1 && self[i - 2] >= self[i - 1] {
+            i -= 1;
+        }
+        if i <= 1 {
+            return false;
+        }
+
+        // Swap the element before the suffix with the rightmost element of
+        // the suffix that is greater than it, then sort the suffix, which is
+        // still weakly decreasing, by reversing it.
+        let pivot = i - 2;
+        let mut j = self.len() - 1;
+        while self[j] <= self[pivot] {
+            j -= 1;
+        }
+        self.swap(pivot, j);
+        self[pivot + 1..].reverse();
+        true
+    }
+
+    fn prev_permutation(&mut self) -> bool
+        where T: Ord
+    {
+        // The mirror image of `next_permutation`, with the comparisons
+        // reversed.
+        let mut i = self.len();
+        while i > 1 && self[i - 2] <= self[i - 1] {
+            i -= 1;
+        }
+        if i <= 1 {
+            return false;
+        }
+
+        let pivot = i - 2;
+        let mut j = self.len() - 1;
+        while self[j] >= self[pivot] {
+            j -= 1;
+        }
+        self.swap(pivot, j);
+        self[pivot + 1..].reverse();
+        true
+    }
+
+    unsafe fn align_to<U>(&self) -> (&[T], &[U], &[T]) {
+        let (prefix, middle, us) = align_to_offsets::<T, U>(self.as_ptr(), self.len());
+        let (left, rest) = self.split_at(prefix);
+        let (mid, right) = rest.split_at(middle);
+        (left, from_raw_parts(mid.as_ptr() as *const U, us), right)
+    }
+
+    unsafe fn align_to_mut<U>(&mut self) -> (&mut [T], &mut [U], &mut [T]) {
+        let (prefix, middle, us) = align_to_offsets::<T, U>(self.as_ptr(), self.len());
+        let (left, rest) = self.split_at_mut(prefix);
+        let (mid, right) = rest.split_at_mut(middle);
+        (left, from_raw_parts_mut(mid.as_mut_ptr() as *mut U, us), right)
+    }
+
+    fn as_chunks<A>(&self) -> (&[A], &[T])
+        where A: FixedSizeArray<T>
+    {
+        let chunk_size = array_len::<T, A>();
+        assert!(chunk_size != 0);
+        let chunks = self.len() / chunk_size;
+        let (body, rest) = self.split_at(chunks * chunk_size);
+        // `A` is `[T; chunk_size]`, which has the layout of `chunk_size`
+        // consecutive `T`s.
+        (unsafe { from_raw_parts(body.as_ptr() as *const A, chunks) }, rest)
+    }
+
+    fn as_chunks_mut<A>(&mut self) -> (&mut [A], &mut [T])
+        where A: FixedSizeArray<T>
+    {
+        let chunk_size = array_len::<T, A>();
+        assert!(chunk_size != 0);
+        let chunks = self.len() / chunk_size;
+        let (body, rest) = self.split_at_mut(chunks * chunk_size);
+        (unsafe { from_raw_parts_mut(body.as_mut_ptr() as *mut A, chunks) }, rest)
+    }
+
+    #[inline]
+    fn as_array<A>(&self) -> Option<&A>
+        where A: FixedSizeArray<T>
+    {
+        if self.len() == array_len::<T, A>() {
+            Some(unsafe { &*(self.as_ptr() as *const A) })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn to_array<A>(&self) -> Option<A>
+        where A: FixedSizeArray<T> + Copy
+    {
+        self.as_array().map(|array: &A| *array)
+    }
+
+    #[inline]
+    fn get_many_mut<'a, I>(&'a mut self, indices: I) -> Option<I::Output>
+        where I: DisjointIndices<'a, T>
+    {
+        indices.get_many_mut(self)
+    }
+
+    fn is_sorted_by<F>(&self, mut compare: F) -> bool
+        where F: FnMut(&T, &T) -> Option<Ordering>
+    {
+        self.windows(2).all(|w| match compare(&w[0], &w[1]) {
+            Some(Less) | Some(Equal) => true,
+            Some(Greater) | None => false,
+        })
+    }
+
+    #[inline]
+    fn is_sorted_by_key<K, F>(&self, mut f: F) -> bool
+        where F: FnMut(&T) -> K,
+              K: PartialOrd
+    {
+        self.is_sorted_by(|a, b| f(a).partial_cmp(&f(b)))
+    }
+
     #[inline]
     fn sort_unstable(&mut self)
         where Self::Item: Ord
@@ -753,6 +1024,30 @@ impl<T> SliceExt for [T] {
     {
         sort::quicksort(self, |a, b| f(a).lt(&f(b)));
     }
+
+    #[inline]
+    fn select_nth_unstable(&mut self, index: usize) -> (&mut [T], &mut T, &mut [T])
+        where T: Ord
+    {
+        sort::select_nth(self, index, |a, b| a.lt(b))
+    }
+
+    #[inline]
+    fn select_nth_unstable_by<F>(&mut self, index: usize, mut compare: F)
+        -> (&mut [T], &mut T, &mut [T])
+        where F: FnMut(&T, &T) -> Ordering
+    {
+        sort::select_nth(self, index, |a, b| compare(a, b) == Ordering::Less)
+    }
+
+    #[inline]
+    fn select_nth_unstable_by_key<B, F>(&mut self, index: usize, mut f: F)
+        -> (&mut [T], &mut T, &mut [T])
+        where F: FnMut(&T) -> B,
+              B: Ord
+    {
+        sort::select_nth(self, index, |a, b| f(a).lt(&f(b)))
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -779,6 +1074,48 @@ impl<T, I> ops::IndexMut<I> for [T]
     }
 }
 
+/// Splits a slice of `len` elements of `T` starting at `ptr` for
+/// `align_to`, returning the length of the prefix and of the middle part
+/// in elements of `T`, and the length of the middle part in elements of
+/// `U`.
+///
+/// The middle part starts at an address aligned for `U` and covers a
+/// whole number of both `T`s and `U`s.
+fn align_to_offsets<T, U>(ptr: *const T, len: usize) -> (usize, usize, usize) {
+    fn gcd(a: usize, b: usize) -> usize {
+        if b == 0 { a } else { gcd(b, a % b) }
+    }
+
+    let t_size = mem::size_of::<T>();
+    let u_size = mem::size_of::<U>();
+    if t_size == 0 || u_size == 0 {
+        return (len, 0, 0);
+    }
+
+    let offset = ptr.align_offset(mem::align_of::<U>());
+    if offset == usize::max_value() || offset % t_size != 0 || offset / t_size > len {
+        return (len, 0, 0);
+    }
+    let prefix = offset / t_size;
+
+    // The middle part must be a multiple of the least common multiple of
+    // both sizes, so that it consists of whole `T`s and whole `U`s.
+    let lcm = t_size / gcd(t_size, u_size) * u_size;
+    let middle_bytes = (len - prefix) * t_size / lcm * lcm;
+    (prefix, middle_bytes / t_size, middle_bytes / u_size)
+}
+
+/// Returns the number of elements of `T` in the array type `A`.
+fn array_len<T, A: FixedSizeArray<T>>() -> usize {
+    if mem::size_of::<T>() == 0 {
+        // `A` is zero-sized as well, so a dangling pointer to it is as good
+        // as any, and the length can be read off that.
+        unsafe { (&*ptr::NonNull::<A>::dangling().as_ptr()).as_slice().len() }
+    } else {
+        mem::size_of::<A>() / mem::size_of::<T>()
+    }
+}
+
 #[inline(never)]
 #[cold]
 fn slice_index_len_fail(index: usize, len: usize) -> ! {
@@ -823,6 +1160,56 @@ pub trait SliceIndex<T: ?Sized> {
     fn index_mut(self, slice: &mut T) -> &mut Self::Output;
 }
 
+/// A helper trait for borrowing several elements of a slice mutably at once.
+///
+/// This is implemented for arrays of up to eight `usize` indices.
+#[unstable(feature = "get_many_mut", issue = "0")]
+pub trait DisjointIndices<'a, T: 'a> {
+    /// The borrowed elements, in the same order as the indices.
+    type Output;
+
+    /// Returns mutable references to the elements at these indices, or
+    /// `None` if any index is out of bounds or appears more than once.
+    fn get_many_mut(self, slice: &'a mut [T]) -> Option<Self::Output>;
+}
+
+macro_rules! disjoint_indices_impls {
+    ($($N:expr => ($($i:expr)+))+) => {
+        $(
+            #[unstable(feature = "get_many_mut", issue = "0")]
+            impl<'a, T: 'a> DisjointIndices<'a, T> for [usize; $N] {
+                type Output = [&'a mut T; $N];
+
+                #[inline]
+                fn get_many_mut(self, slice: &'a mut [T]) -> Option<[&'a mut T; $N]> {
+                    for (i, index) in self.iter().enumerate() {
+                        if *index >= slice.len() || self[..i].contains(index) {
+                            return None;
+                        }
+                    }
+                    // The indices are in bounds and pairwise distinct, so the
+                    // references never alias.
+                    let ptr = slice.as_mut_ptr();
+                    unsafe {
+                        Some([$(&mut *ptr.offset(self[$i] as isize)),+])
+                    }
+                }
+            }
+        )+
+    }
+}
+
+disjoint_indices_impls! {
+    1 => (0)
+    2 => (0 1)
+    3 => (0 1 2)
+    4 => (0 1 2 3)
+    5 => (0 1 2 3 4)
+    6 => (0 1 2 3 4 5)
+    7 => (0 1 2 3 4 5 6)
+    8 => (0 1 2 3 4 5 6 7)
+}
+
 #[stable(feature = "slice-get-slice-impls", since = "1.15.0")]
 impl<T> SliceIndex<[T]> for usize {
     type Output = T;
@@ -2640,6 +3027,169 @@ unsafe impl<'a, T> TrustedRandomAccess for ExactChunksMut<'a, T> {
     fn may_have_side_effect() -> bool { false }
 }
 
+/// An iterator over a slice in (non-overlapping) runs of elements, where
+/// `pred` holds for every pair of consecutive elements within a run.
+///
+/// This struct is created by the [`group_by`] method on [slices].
+///
+/// [`group_by`]: ../../std/primitive.slice.html#method.group_by
+/// [slices]: ../../std/primitive.slice.html
+#[unstable(feature = "slice_group_by", issue = "0")]
+pub struct GroupBy<'a, T: 'a, P> where P: FnMut(&T, &T) -> bool {
+    v: &'a [T],
+    pred: P,
+}
+
+#[unstable(feature = "slice_group_by", issue = "0")]
+impl<'a, T: 'a + fmt::Debug, P> fmt::Debug for GroupBy<'a, T, P>
+    where P: FnMut(&T, &T) -> bool
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GroupBy")
+            .field("v", &self.v)
+            .finish()
+    }
+}
+
+// FIXME(#26925) Remove in favor of `#[derive(Clone)]`
+#[unstable(feature = "slice_group_by", issue = "0")]
+impl<'a, T, P> Clone for GroupBy<'a, T, P> where P: Clone + FnMut(&T, &T) -> bool {
+    fn clone(&self) -> GroupBy<'a, T, P> {
+        GroupBy {
+            v: self.v,
+            pred: self.pred.clone(),
+        }
+    }
+}
+
+#[unstable(feature = "slice_group_by", issue = "0")]
+impl<'a, T, P> Iterator for GroupBy<'a, T, P> where P: FnMut(&T, &T) -> bool {
+    type Item = &'a [T];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [T]> {
+        if self.v.is_empty() {
+            None
+        } else {
+            let mut len = 1;
+            while len < self.v.len() && (self.pred)(&self.v[len - 1], &self.v[len]) {
+                len += 1;
+            }
+            let (head, tail) = self.v.split_at(len);
+            self.v = tail;
+            Some(head)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.v.is_empty() { (0, Some(0)) } else { (1, Some(self.v.len())) }
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<&'a [T]> {
+        self.next_back()
+    }
+}
+
+#[unstable(feature = "slice_group_by", issue = "0")]
+impl<'a, T, P> DoubleEndedIterator for GroupBy<'a, T, P> where P: FnMut(&T, &T) -> bool {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a [T]> {
+        if self.v.is_empty() {
+            None
+        } else {
+            let mut start = self.v.len() - 1;
+            while start > 0 && (self.pred)(&self.v[start - 1], &self.v[start]) {
+                start -= 1;
+            }
+            let (head, tail) = self.v.split_at(start);
+            self.v = head;
+            Some(tail)
+        }
+    }
+}
+
+#[unstable(feature = "slice_group_by", issue = "0")]
+impl<'a, T, P> FusedIterator for GroupBy<'a, T, P> where P: FnMut(&T, &T) -> bool {}
+
+/// An iterator over a slice in (non-overlapping) mutable runs of elements,
+/// where `pred` holds for every pair of consecutive elements within a run.
+///
+/// This struct is created by the [`group_by_mut`] method on [slices].
+///
+/// [`group_by_mut`]: ../../std/primitive.slice.html#method.group_by_mut
+/// [slices]: ../../std/primitive.slice.html
+#[unstable(feature = "slice_group_by", issue = "0")]
+pub struct GroupByMut<'a, T: 'a, P> where P: FnMut(&T, &T) -> bool {
+    v: &'a mut [T],
+    pred: P,
+}
+
+#[unstable(feature = "slice_group_by", issue = "0")]
+impl<'a, T: 'a + fmt::Debug, P> fmt::Debug for GroupByMut<'a, T, P>
+    where P: FnMut(&T, &T) -> bool
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GroupByMut")
+            .field("v", &self.v)
+            .finish()
+    }
+}
+
+#[unstable(feature = "slice_group_by", issue = "0")]
+impl<'a, T, P> Iterator for GroupByMut<'a, T, P> where P: FnMut(&T, &T) -> bool {
+    type Item = &'a mut [T];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut [T]> {
+        if self.v.is_empty() {
+            None
+        } else {
+            let mut len = 1;
+            while len < self.v.len() && (self.pred)(&self.v[len - 1], &self.v[len]) {
+                len += 1;
+            }
+            let tmp = mem::replace(&mut self.v, &mut []);
+            let (head, tail) = tmp.split_at_mut(len);
+            self.v = tail;
+            Some(head)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.v.is_empty() { (0, Some(0)) } else { (1, Some(self.v.len())) }
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<&'a mut [T]> {
+        self.next_back()
+    }
+}
+
+#[unstable(feature = "slice_group_by", issue = "0")]
+impl<'a, T, P> DoubleEndedIterator for GroupByMut<'a, T, P> where P: FnMut(&T, &T) -> bool {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a mut [T]> {
+        if self.v.is_empty() {
+            None
+        } else {
+            let mut start = self.v.len() - 1;
+            while start > 0 && (self.pred)(&self.v[start - 1], &self.v[start]) {
+                start -= 1;
+            }
+            let tmp = mem::replace(&mut self.v, &mut []);
+            let (head, tail) = tmp.split_at_mut(start);
+            self.v = head;
+            Some(tail)
+        }
+    }
+}
+
+#[unstable(feature = "slice_group_by", issue = "0")]
+impl<'a, T, P> FusedIterator for GroupByMut<'a, T, P> where P: FnMut(&T, &T) -> bool {}
+
 //
 // Free functions
 //
@@ -701,3 +701,129 @@ pub fn quicksort<T, F>(v: &mut [T], mut is_less: F)
 
     recurse(v, &mut is_less, None, limit);
 }
+
+/// Reorders `v` so that the element at `index` is in its final sorted position, using
+/// quickselect with the same pivot selection as `recurse`.
+///
+/// `limit` is the number of allowed imbalanced partitions before switching to `heapsort`, which
+/// bounds the worst case at `O(n log n)`. The expected running time is `O(n)`.
+fn select_loop<'a, T, F>(mut v: &'a mut [T], mut index: usize, is_less: &mut F,
+                         mut pred: Option<&'a T>, mut limit: usize)
+    where F: FnMut(&T, &T) -> bool
+{
+    // Slices of up to this length get sorted using insertion sort.
+    const MAX_INSERTION: usize = 10;
+
+    // True if the last partitioning was reasonably balanced.
+    let mut was_balanced = true;
+
+    loop {
+        let len = v.len();
+
+        // Very short slices get sorted using insertion sort.
+        if len <= MAX_INSERTION {
+            insertion_sort(v, is_less);
+            return;
+        }
+
+        // If too many bad pivot choices were made, simply fall back to heapsort in order to
+        // guarantee `O(n log n)` worst-case.
+        if limit == 0 {
+            heapsort(v, is_less);
+            return;
+        }
+
+        // If the last partitioning was imbalanced, try breaking patterns in the slice by shuffling
+        // some elements around. Hopefully we'll choose a better pivot this time.
+        if !was_balanced {
+            break_patterns(v);
+            limit -= 1;
+        }
+
+        let (pivot, _) = choose_pivot(v, is_less);
+
+        // If the chosen pivot is equal to the predecessor, then it's the smallest element in the
+        // slice. Partition the slice into elements equal to and elements greater than the pivot.
+        if let Some(p) = pred {
+            if !is_less(p, &v[pivot]) {
+                let mid = partition_equal(v, pivot, is_less);
+
+                // Every element before `mid` is equal to the pivot, so if `index` falls among
+                // them we're done.
+                if mid > index {
+                    return;
+                }
+
+                // Otherwise, continue with the elements greater than the pivot.
+                v = &mut {v}[mid..];
+                index -= mid;
+                pred = None;
+                continue;
+            }
+        }
+
+        // Partition the slice.
+        let (mid, _) = partition(v, pivot, is_less);
+        was_balanced = cmp::min(mid, len - mid) >= len / 8;
+
+        // Split the slice into `left`, `pivot`, and `right`.
+        let (left, right) = {v}.split_at_mut(mid);
+        let (pivot, right) = right.split_at_mut(1);
+        let pivot = &pivot[0];
+
+        // Only the side containing `index` needs to be looked at any further.
+        if mid < index {
+            v = right;
+            index -= mid + 1;
+            pred = Some(pivot);
+        } else if mid > index {
+            v = left;
+        } else {
+            // The pivot itself landed at `index`.
+            return;
+        }
+    }
+}
+
+/// Reorders `v` such that the element at `index` is at its final sorted position, with every
+/// element before it less than or equal to it and every element after it greater than or equal
+/// to it, and returns the three parts.
+///
+/// # Panics
+///
+/// Panics if `index >= v.len()`.
+pub fn select_nth<T, F>(v: &mut [T], index: usize, mut is_less: F) -> (&mut [T], &mut T, &mut [T])
+    where F: FnMut(&T, &T) -> bool
+{
+    if index >= v.len() {
+        panic!("select_nth_unstable index {} out of range for slice of length {}",
+               index, v.len());
+    }
+
+    // Sorting has no meaningful behavior on zero-sized types.
+    if mem::size_of::<T>() != 0 {
+        if index == 0 || index == v.len() - 1 {
+            // Selecting the minimum or maximum only needs a single scan.
+            let mut best = 0;
+            for i in 1..v.len() {
+                let better = if index == 0 {
+                    is_less(&v[i], &v[best])
+                } else {
+                    !is_less(&v[i], &v[best])
+                };
+                if better {
+                    best = i;
+                }
+            }
+            v.swap(best, index);
+        } else {
+            // Limit the number of imbalanced partitions to `floor(log2(len)) + 1`.
+            let limit = mem::size_of::<usize>() * 8 - v.len().leading_zeros() as usize;
+            select_loop(v, index, &mut is_less, None, limit);
+        }
+    }
+
+    let (left, right) = v.split_at_mut(index);
+    let (nth, right) = right.split_at_mut(1);
+    (left, &mut nth[0], right)
+}
@@ -120,6 +120,20 @@ fn test_eq_ignore_ascii_case() {
     }
 }
 
+#[test]
+fn test_eq_ignore_ascii_case_bytes_and_string() {
+    let header = b"Content-Length: 12";
+    assert!(header[..14].eq_ignore_ascii_case(b"content-length"));
+    assert!(!header[..14].eq_ignore_ascii_case(b"content-type"));
+    assert!(!b"\xc3\x89".eq_ignore_ascii_case(b"\xc3\xa9"));
+
+    let path = String::from("archive.TAR.gz");
+    assert!(path[8..].eq_ignore_ascii_case("tar.GZ"));
+    assert!(path.eq_ignore_ascii_case(&"ARCHIVE.tar.GZ".to_string()));
+    assert_eq!(path.to_ascii_lowercase(), "archive.tar.gz");
+    assert_eq!(header.to_ascii_uppercase(), b"CONTENT-LENGTH: 12".to_vec());
+}
+
 #[test]
 fn inference_works() {
     let x = "a".to_string();
@@ -355,3 +369,46 @@ fn test_is_ascii_control() {
         " ",
     );
 }
+
+#[test]
+fn test_char_class_constants() {
+    use core::ascii::CharClass;
+
+    let classes: [(CharClass, fn(&u8) -> bool); 11] = [
+        (CharClass::ASCII, u8::is_ascii),
+        (CharClass::WHITESPACE, u8::is_ascii_whitespace),
+        (CharClass::DIGIT, u8::is_ascii_digit),
+        (CharClass::HEX_DIGIT, u8::is_ascii_hexdigit),
+        (CharClass::UPPERCASE, u8::is_ascii_uppercase),
+        (CharClass::LOWERCASE, u8::is_ascii_lowercase),
+        (CharClass::ALPHABETIC, u8::is_ascii_alphabetic),
+        (CharClass::ALPHANUMERIC, u8::is_ascii_alphanumeric),
+        (CharClass::PUNCTUATION, u8::is_ascii_punctuation),
+        (CharClass::GRAPHIC, u8::is_ascii_graphic),
+        (CharClass::CONTROL, u8::is_ascii_control),
+    ];
+    for &(ref class, pred) in classes.iter() {
+        assert_eq!(*class, CharClass::from_fn(|b| pred(&b)));
+        for b in 0..=255 {
+            assert_eq!(class.contains(b), pred(&b));
+        }
+    }
+    assert!((0..=255).all(|b| !CharClass::EMPTY.contains(b)));
+}
+
+#[test]
+fn test_char_class_set_operations() {
+    use core::ascii::CharClass;
+
+    let mut class = CharClass::from_bytes(b"abc");
+    assert!(class.contains(b'b'));
+    class.insert(0xff);
+    class.remove(b'b');
+    assert!(class.contains(0xff) && !class.contains(b'b'));
+    assert_eq!(class, CharClass::from_bytes(b"ac\xff"));
+
+    assert_eq!(CharClass::DIGIT.union(&CharClass::ALPHABETIC), CharClass::ALPHANUMERIC);
+    assert_eq!(CharClass::GRAPHIC.intersection(&CharClass::DIGIT), CharClass::DIGIT);
+    assert_eq!(CharClass::ASCII.complement().intersection(&CharClass::ASCII), CharClass::EMPTY);
+    assert_eq!(CharClass::EMPTY.complement().complement(), CharClass::default());
+}
@@ -45,6 +45,26 @@ fn test_ordering_reverse() {
     assert_eq!(Greater.reverse(), Less);
 }
 
+#[test]
+fn test_reverse() {
+    use core::cmp::{self, Reverse};
+
+    assert_eq!(Reverse(1).cmp(&Reverse(2)), Greater);
+    assert_eq!(Reverse(2).cmp(&Reverse(2)), Equal);
+    assert!(Reverse(3) < Reverse(2));
+    assert!(Reverse(2) >= Reverse(2));
+    assert_eq!(cmp::max(Reverse(1), Reverse(2)), Reverse(1));
+
+    // Incomparable values stay incomparable.
+    let nan = 0.0f64 / 0.0;
+    assert_eq!(Reverse(nan).partial_cmp(&Reverse(1.0)), None);
+    assert!(!(Reverse(nan) < Reverse(1.0)) && !(Reverse(nan) >= Reverse(1.0)));
+
+    let mut v = [(1, 'b'), (0, 'a'), (1, 'a'), (0, 'b')];
+    v.sort_unstable_by_key(|&(n, c)| (Reverse(n), c));
+    assert_eq!(v, [(1, 'a'), (1, 'b'), (0, 'a'), (0, 'b')]);
+}
+
 #[test]
 fn test_ordering_order() {
     assert!(Less < Equal);
@@ -1249,6 +1249,25 @@ fn test_cloned() {
     assert_eq!(it.next_back(), None);
 }
 
+#[test]
+fn test_copied() {
+    let xs = [2, 4, 6, 8];
+
+    let mut it = xs.iter().copied();
+    assert_eq!(it.len(), 4);
+    assert_eq!(it.next(), Some(2));
+    assert_eq!(it.len(), 3);
+    assert_eq!(it.next_back(), Some(8));
+    assert_eq!(it.len(), 2);
+    assert_eq!(it.clone().sum::<i32>(), 10);
+    assert_eq!(it.rfold(0, |acc, x| acc * 10 + x), 64);
+
+    let ys = [1u8, 2, 3];
+    let zs = [4u8, 5, 6];
+    let zipped: Vec<_> = ys.iter().copied().zip(zs.iter().copied()).collect();
+    assert_eq!(zipped, [(1, 4), (2, 5), (3, 6)]);
+}
+
 #[test]
 fn test_double_ended_map() {
     let xs = [1, 2, 3, 4, 5, 6];
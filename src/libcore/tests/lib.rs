@@ -10,6 +10,7 @@
 
 #![deny(warnings)]
 
+#![feature(ascii_char_class)]
 #![feature(ascii_ctype)]
 #![feature(box_syntax)]
 #![feature(core_float)]
@@ -21,23 +22,36 @@
 #![feature(fixed_size_array)]
 #![feature(flt2dec)]
 #![feature(fmt_internals)]
+#![feature(get_many_mut)]
 #![feature(hashmap_internals)]
 #![feature(iterator_step_by)]
 #![cfg_attr(stage0, feature(i128_type))]
 #![cfg_attr(stage0, feature(inclusive_range_syntax))]
 #![feature(iterator_try_fold)]
 #![feature(iterator_flatten)]
+#![feature(is_sorted)]
 #![cfg_attr(stage0, feature(conservative_impl_trait))]
+#![feature(iter_copied)]
 #![feature(iter_rfind)]
 #![feature(iter_rfold)]
 #![feature(iterator_repeat_with)]
 #![feature(nonzero)]
+#![feature(ordered_float)]
 #![feature(pattern)]
 #![feature(range_is_empty)]
 #![feature(raw)]
 #![feature(refcell_replace_swap)]
 #![feature(slice_patterns)]
+#![feature(slice_align_to)]
+#![feature(slice_as_array)]
+#![feature(slice_as_chunks)]
+#![feature(slice_group_by)]
+#![feature(slice_memchr)]
+#![feature(slice_min_max)]
+#![feature(slice_partition_point)]
+#![feature(slice_permutations)]
 #![feature(slice_rotate)]
+#![feature(slice_select_nth_unstable)]
 #![feature(sort_internals)]
 #![feature(specialization)]
 #![feature(step_trait)]
@@ -36,6 +36,7 @@ mod u64;
 mod flt2dec;
 mod dec2flt;
 mod bignum;
+mod ordered_float;
 
 /// Groups items that assume the pointer width is either 16/32/64, and has to be altered if
 /// support for larger/smaller pointer widths are added in the future.
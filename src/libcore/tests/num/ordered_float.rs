@@ -0,0 +1,70 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::cmp::Ordering::*;
+use core::num::{NotNan, OrderedFloat};
+use core::{f32, f64};
+use std::collections::HashSet;
+
+#[test]
+fn test_ordered_float_cmp() {
+    let nan = OrderedFloat(f64::NAN);
+    let inf = OrderedFloat(f64::INFINITY);
+    let one = OrderedFloat(1.0);
+    let zero = OrderedFloat(0.0);
+    let neg_zero = OrderedFloat(-0.0);
+
+    assert_eq!(nan.cmp(&nan), Equal);
+    assert_eq!(nan.cmp(&inf), Greater);
+    assert_eq!(inf.cmp(&nan), Less);
+    assert_eq!(one.cmp(&inf), Less);
+    assert_eq!(zero.cmp(&neg_zero), Equal);
+    assert_eq!(nan, OrderedFloat(-f64::NAN));
+    assert!(one < nan);
+
+    let mut v = [nan, one, OrderedFloat(f64::NEG_INFINITY), zero, inf, nan];
+    v.sort();
+    let sorted: Vec<f64> = v.iter().map(|x| x.0).collect();
+    assert_eq!(&sorted[..4], [f64::NEG_INFINITY, 0.0, 1.0, f64::INFINITY]);
+    assert!(sorted[4].is_nan() && sorted[5].is_nan());
+}
+
+#[test]
+fn test_ordered_float_hash() {
+    let mut set = HashSet::new();
+    assert!(set.insert(OrderedFloat(f32::NAN)));
+    assert!(!set.insert(OrderedFloat(-f32::NAN)));
+    assert!(!set.insert(OrderedFloat(f32::NAN * 2.0)));
+    assert!(set.insert(OrderedFloat(0.0f32)));
+    assert!(!set.insert(OrderedFloat(-0.0f32)));
+    assert!(set.insert(OrderedFloat(1.0f32)));
+    assert_eq!(set.len(), 3);
+}
+
+#[test]
+fn test_not_nan() {
+    assert!(NotNan::new(f64::NAN).is_err());
+    assert!(NotNan::new(f32::NAN).is_err());
+
+    let a = NotNan::new(1.5f32).unwrap();
+    let b = NotNan::new(-2.0f32).unwrap();
+    assert_eq!(a.cmp(&b), Greater);
+    assert_eq!(NotNan::new(0.0).unwrap(), NotNan::new(-0.0).unwrap());
+    assert_eq!(a.into_inner(), 1.5);
+    assert_eq!(f32::from(b), -2.0);
+
+    let mut set = HashSet::new();
+    set.insert(NotNan::new(0.0).unwrap());
+    assert!(set.contains(&NotNan::new(-0.0).unwrap()));
+
+    let mut v: Vec<_> = [3.0, -1.0, 2.0].iter().map(|&x| NotNan::new(x).unwrap()).collect();
+    v.sort();
+    assert_eq!(v.iter().map(|x| x.into_inner()).collect::<Vec<f64>>(), [-1.0, 2.0, 3.0]);
+}
@@ -69,6 +69,34 @@ fn test_binary_search() {
     assert_eq!(b.binary_search(&8), Err(5));
 }
 
+#[test]
+fn test_partition_point() {
+    let b: [i32; 0] = [];
+    assert_eq!(b.partition_point(|&x| x < 5), 0);
+
+    let b = [4];
+    assert_eq!(b.partition_point(|&x| x < 3), 0);
+    assert_eq!(b.partition_point(|&x| x < 4), 0);
+    assert_eq!(b.partition_point(|&x| x < 5), 1);
+
+    let b = [1, 2, 4, 6, 8, 9];
+    assert_eq!(b.partition_point(|&x| x < 5), 3);
+    assert_eq!(b.partition_point(|&x| x < 6), 3);
+    assert_eq!(b.partition_point(|&x| x < 7), 4);
+    assert_eq!(b.partition_point(|&x| x < 8), 4);
+    assert_eq!(b.partition_point(|&x| x < 10), 6);
+
+    let b = [1, 3, 3, 3, 7];
+    assert_eq!(b.partition_point(|&x| x < 0), 0);
+    assert_eq!(b.partition_point(|&x| x < 1), 0);
+    assert_eq!(b.partition_point(|&x| x < 3), 1);
+    assert_eq!(b.partition_point(|&x| x <= 3), 4);
+    assert_eq!(b.partition_point(|&x| x < 8), 5);
+
+    let b = [7, 15, 3, 5, 4, 12, 6];
+    assert_eq!(b.partition_point(|&x| x % 2 != 0), 4);
+}
+
 #[test]
 // Test implementation specific behavior when finding equivalent elements.
 // It is ok to break this test but when you do a crater run is highly advisable.
@@ -85,6 +113,232 @@ fn test_binary_search_implementation_details() {
     assert_eq!(b.binary_search(&3), Ok(8));
 }
 
+#[test]
+fn test_is_sorted() {
+    let empty: [i32; 0] = [];
+
+    assert!([1, 2, 2, 9].is_sorted());
+    assert!(![1, 3, 2].is_sorted());
+    assert!([0].is_sorted());
+    assert!(empty.is_sorted());
+    assert!(![0.0, 1.0, ::std::f32::NAN].is_sorted());
+    assert!([-2, -1, 0, 3].is_sorted());
+    assert!(![-2i32, -1, 0, 3].is_sorted_by_key(|n| n.abs()));
+    assert!(!["c", "bb", "aaa"].is_sorted());
+    assert!(["c", "bb", "aaa"].is_sorted_by_key(|s| s.len()));
+    assert!([4, 2, 1].is_sorted_by(|a, b| Some(b.cmp(a))));
+    assert!(![1, 2].is_sorted_by(|_, _| None));
+}
+
+#[test]
+fn test_min_max() {
+    let empty: [i32; 0] = [];
+    assert_eq!(empty.min_max(), None);
+    assert_eq!([7].min_max(), Some((&7, &7)));
+    assert_eq!([2, 1].min_max(), Some((&1, &2)));
+    assert_eq!([3, 1, 4, 1, 5, 9, 2, 6].min_max(), Some((&1, &9)));
+    assert_eq!([3, 1, 4, 1, 5, 9, 2].min_max(), Some((&1, &9)));
+    assert_eq!([5, 4, 3, 2, 1, 0].min_max(), Some((&0, &5)));
+
+    // The first minimum and the last maximum are returned.
+    for v in &[[1, 0, 2, 0, 2, 1, 2], [1, 0, 2, 0, 2, 2, 1]] {
+        let (min, max) = v.min_max().unwrap();
+        assert!(::std::ptr::eq(min, &v[1]));
+        assert!(::std::ptr::eq(max, &v[v.iter().rposition(|&x| x == 2).unwrap()]));
+    }
+}
+
+#[test]
+fn test_next_permutation() {
+    let mut empty: [i32; 0] = [];
+    assert!(!empty.next_permutation());
+    let mut one = [1];
+    assert!(!one.next_permutation());
+
+    let mut v = [1, 2, 3, 4];
+    let mut count = 1;
+    let mut prev = v;
+    while v.next_permutation() {
+        assert!(prev < v);
+        prev = v;
+        count += 1;
+    }
+    assert_eq!(count, 24);
+    assert_eq!(v, [4, 3, 2, 1]);
+
+    // Repeated elements: 5! / (2! * 3!) distinct permutations.
+    let mut v = [0, 0, 1, 1, 1];
+    let mut count = 1;
+    while v.next_permutation() {
+        count += 1;
+    }
+    assert_eq!(count, 10);
+    assert_eq!(v, [1, 1, 1, 0, 0]);
+}
+
+#[test]
+fn test_prev_permutation() {
+    let mut empty: [i32; 0] = [];
+    assert!(!empty.prev_permutation());
+
+    let mut v = [4, 3, 2, 1];
+    let mut count = 1;
+    let mut prev = v;
+    while v.prev_permutation() {
+        assert!(prev > v);
+        prev = v;
+        count += 1;
+    }
+    assert_eq!(count, 24);
+    assert_eq!(v, [1, 2, 3, 4]);
+
+    // Each undoes the other.
+    let mut v = [2, 5, 1, 5, 3];
+    let orig = v;
+    assert!(v.next_permutation());
+    assert!(v.prev_permutation());
+    assert_eq!(v, orig);
+}
+
+#[test]
+fn test_align_to() {
+    let bytes = [1u8; 67];
+    for start in 0..8 {
+        let v = &bytes[start..];
+        let (prefix, words, suffix) = unsafe { v.align_to::<u32>() };
+        assert!(prefix.len() < 4);
+        assert!(suffix.len() < 4);
+        assert_eq!(prefix.len() + words.len() * 4 + suffix.len(), v.len());
+        assert_eq!(words.as_ptr() as usize % ::std::mem::align_of::<u32>(), 0);
+        assert!(words.iter().all(|&w| w == 0x01010101));
+    }
+
+    // Sizes that don't divide each other: the middle holds whole elements
+    // of both types.
+    let v = [[0u8; 3]; 20];
+    let (prefix, middle, suffix) = unsafe { v.align_to::<u16>() };
+    assert_eq!(prefix.len() * 3 + middle.len() * 2 + suffix.len() * 3, 60);
+    assert_eq!(middle.len() % 3, 0);
+
+    // Zero-sized types are never split.
+    let units = [(); 5];
+    let (prefix, middle, suffix) = unsafe { units.align_to::<u32>() };
+    assert_eq!((prefix.len(), middle.len(), suffix.len()), (5, 0, 0));
+    let (prefix, middle, suffix) = unsafe { bytes.align_to::<()>() };
+    assert_eq!((prefix.len(), middle.len(), suffix.len()), (67, 0, 0));
+}
+
+#[test]
+fn test_align_to_mut() {
+    let mut bytes = [0u8; 37];
+    {
+        let (prefix, words, suffix) = unsafe { bytes.align_to_mut::<u64>() };
+        for b in prefix.iter_mut().chain(suffix) {
+            *b = 1;
+        }
+        for w in words {
+            *w = 0x0101010101010101;
+        }
+    }
+    assert!(bytes.iter().all(|&b| b == 1));
+}
+
+#[test]
+fn test_as_chunks() {
+    let v = [0, 1, 2, 3, 4, 5, 6, 7];
+    let (chunks, rest) = v.as_chunks::<[i32; 3]>();
+    assert_eq!(chunks, [[0, 1, 2], [3, 4, 5]]);
+    assert_eq!(rest, [6, 7]);
+
+    let (chunks, rest) = v.as_chunks::<[i32; 4]>();
+    assert_eq!(chunks, [[0, 1, 2, 3], [4, 5, 6, 7]]);
+    assert!(rest.is_empty());
+
+    let (chunks, rest) = v.as_chunks::<[i32; 9]>();
+    assert!(chunks.is_empty());
+    assert_eq!(rest, v);
+
+    let (chunks, rest) = v[..0].as_chunks::<[i32; 2]>();
+    assert!(chunks.is_empty() && rest.is_empty());
+
+    let units = [(); 7];
+    let (chunks, rest) = units.as_chunks::<[(); 2]>();
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(rest.len(), 1);
+}
+
+#[test]
+fn test_as_chunks_mut() {
+    let mut v = [0, 1, 2, 3, 4, 5, 6];
+    {
+        let (chunks, rest) = v.as_chunks_mut::<[i32; 2]>();
+        for chunk in chunks {
+            chunk.reverse();
+        }
+        rest[0] = -1;
+    }
+    assert_eq!(v, [1, 0, 3, 2, 5, 4, -1]);
+}
+
+#[test]
+#[should_panic]
+fn test_as_chunks_zero() {
+    let v = [1, 2, 3];
+    v.as_chunks::<[i32; 0]>();
+}
+
+#[test]
+fn test_as_array() {
+    let v = [0, 1, 2, 3, 4];
+    assert_eq!(v[1..4].as_array::<[i32; 3]>(), Some(&[1, 2, 3]));
+    assert_eq!(v.as_array::<[i32; 4]>(), None);
+    assert_eq!(v.as_array::<[i32; 6]>(), None);
+    assert_eq!(v[..0].as_array::<[i32; 0]>(), Some(&[]));
+
+    let array = v.as_array::<[i32; 5]>().unwrap();
+    assert_eq!(array as *const [i32; 5] as *const i32, v.as_ptr());
+
+    assert!([(); 3].as_array::<[(); 3]>().is_some());
+    assert!([(); 3].as_array::<[(); 2]>().is_none());
+}
+
+#[test]
+fn test_to_array() {
+    let bytes = [0xde, 0xad, 0xbe, 0xef, 0x01];
+    let word: [u8; 4] = bytes[..4].to_array().unwrap();
+    assert_eq!(word, [0xde, 0xad, 0xbe, 0xef]);
+    assert_eq!(bytes.to_array::<[u8; 4]>(), None);
+    assert_eq!(bytes[4..].to_array(), Some([1u8]));
+}
+
+#[test]
+fn test_get_many_mut() {
+    let mut v = [0, 1, 2, 3, 4, 5, 6, 7, 8];
+    {
+        let [a, b, c] = v.get_many_mut([8, 0, 4]).unwrap();
+        *a += 10;
+        *b += 20;
+        ::std::mem::swap(b, c);
+    }
+    assert_eq!(v, [4, 1, 2, 3, 20, 5, 6, 7, 18]);
+
+    {
+        let mut all = v.get_many_mut([0, 1, 2, 3, 4, 5, 6, 7]).unwrap();
+        for r in all.iter_mut() {
+            **r = 0;
+        }
+    }
+    assert_eq!(v, [0, 0, 0, 0, 0, 0, 0, 0, 18]);
+
+    assert!(v.get_many_mut([9]).is_none());
+    assert!(v.get_many_mut([2, 5, 2]).is_none());
+    assert!(v[..0].get_many_mut([0]).is_none());
+
+    let mut units = [(), ()];
+    assert!(units.get_many_mut([1, 0]).is_some());
+    assert!(units.get_many_mut([1, 1]).is_none());
+}
+
 #[test]
 fn test_iterator_nth() {
     let v: &[_] = &[0, 1, 2, 3, 4];
@@ -324,6 +578,36 @@ fn test_exact_chunks_mut_zip() {
     assert_eq!(v1, [13, 14, 19, 20, 4]);
 }
 
+#[test]
+fn test_group_by() {
+    let v: &[i32] = &[1, 1, 1, 3, 3, 2, 2, 2, 1, 0];
+
+    let mut iter = v.group_by(|a, b| a == b);
+    assert_eq!(iter.next(), Some(&[1, 1, 1][..]));
+    assert_eq!(iter.next(), Some(&[3, 3][..]));
+    assert_eq!(iter.next_back(), Some(&[0][..]));
+    assert_eq!(iter.next_back(), Some(&[1][..]));
+    assert_eq!(iter.next(), Some(&[2, 2, 2][..]));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    assert_eq!(v.group_by(|a, b| a >= b).count(), 2);
+    assert_eq!(v.group_by(|a, b| a >= b).last(), Some(&[3, 3, 2, 2, 2, 1, 0][..]));
+
+    let v: &[i32] = &[];
+    assert_eq!(v.group_by(|_, _| true).next(), None);
+}
+
+#[test]
+fn test_group_by_mut() {
+    let v: &mut [i32] = &mut [1, 1, 2, 3, 2, 3, 2, 3, 4];
+
+    for run in v.group_by_mut(|a, b| a <= b).rev() {
+        run.reverse();
+    }
+    assert_eq!(v, [3, 2, 1, 1, 3, 2, 4, 3, 2]);
+}
+
 #[test]
 fn test_windows_count() {
     let v: &[i32] = &[0, 1, 2, 3, 4, 5];
@@ -550,3 +834,165 @@ fn sort_unstable() {
     v.sort_unstable();
     assert!(v == [0xDEADBEEF]);
 }
+
+#[test]
+#[cfg(not(target_arch = "wasm32"))]
+fn select_nth_unstable() {
+    use core::cmp::Ordering::{Equal, Greater, Less};
+    use rand::{Rng, XorShiftRng};
+
+    let mut rng = XorShiftRng::new_unseeded();
+
+    for len in (2..21).chain(500..501) {
+        let mut orig = vec![0; len];
+
+        for &modulus in &[5, 10, 1000] {
+            for _ in 0..10 {
+                for i in 0..len {
+                    orig[i] = rng.gen::<i32>() % modulus;
+                }
+
+                let v_sorted = {
+                    let mut v = orig.clone();
+                    v.sort();
+                    v
+                };
+
+                // Sort in default order.
+                for pivot in 0..len {
+                    let mut v = orig.clone();
+                    v.select_nth_unstable(pivot);
+
+                    assert_eq!(v_sorted[pivot], v[pivot]);
+                    for i in 0..pivot {
+                        for j in pivot..len {
+                            assert!(v[i] <= v[j]);
+                        }
+                    }
+                }
+
+                // Sort in ascending order.
+                for pivot in 0..len {
+                    let mut v = orig.clone();
+                    let (left, pivot, right) = v.select_nth_unstable_by(pivot, |a, b| a.cmp(b));
+
+                    assert_eq!(left.len() + right.len(), len - 1);
+
+                    for l in left {
+                        assert!(l <= pivot);
+                        for r in right.iter_mut() {
+                            assert!(l <= r);
+                            assert!(pivot <= r);
+                        }
+                    }
+                }
+
+                // Sort in descending order.
+                let sort_descending_comparator = |a: &i32, b: &i32| b.cmp(a);
+                let v_sorted_descending = {
+                    let mut v = orig.clone();
+                    v.sort_by(sort_descending_comparator);
+                    v
+                };
+
+                for pivot in 0..len {
+                    let mut v = orig.clone();
+                    v.select_nth_unstable_by(pivot, sort_descending_comparator);
+
+                    assert_eq!(v_sorted_descending[pivot], v[pivot]);
+                    for i in 0..pivot {
+                        for j in pivot..len {
+                            assert!(v[j] <= v[i]);
+                        }
+                    }
+                }
+
+                // Select by key.
+                for pivot in 0..len {
+                    let mut v = orig.clone();
+                    let (_, nth, _) = v.select_nth_unstable_by_key(pivot, |&x| x);
+                    assert_eq!(*nth, v_sorted[pivot]);
+                }
+            }
+        }
+    }
+
+    // Sort at index using a completely random comparison function.
+    // This will reorder the elements *somehow*, but won't panic.
+    let mut v = [0; 500];
+    for i in 0..v.len() {
+        v[i] = i as i32;
+    }
+
+    for pivot in 0..v.len() {
+        v.select_nth_unstable_by(pivot, |_, _| *rng.choose(&[Less, Equal, Greater]).unwrap());
+        v.sort();
+        for i in 0..v.len() {
+            assert_eq!(v[i], i as i32);
+        }
+    }
+
+    // Should not panic.
+    [(); 10].select_nth_unstable(0);
+    [(); 10].select_nth_unstable(5);
+    [(); 10].select_nth_unstable(9);
+    [(); 100].select_nth_unstable(0);
+    [(); 100].select_nth_unstable(50);
+    [(); 100].select_nth_unstable(99);
+
+    let mut v = [0xDEADBEEFu64];
+    v.select_nth_unstable(0);
+    assert!(v == [0xDEADBEEF]);
+}
+
+#[test]
+#[should_panic(expected = "index 0 out of range for slice of length 0")]
+fn select_nth_unstable_zero_length() {
+    [0i32; 0].select_nth_unstable(0);
+}
+
+#[test]
+#[should_panic(expected = "index 20 out of range for slice of length 10")]
+fn select_nth_unstable_past_length() {
+    [0i32; 10].select_nth_unstable(20);
+}
+
+#[test]
+fn test_memchr2() {
+    use core::slice::memchr2;
+    assert_eq!(memchr2(b'a', b'b', b"abab"), Some(0));
+    assert_eq!(memchr2(b'a', b'b', b"baba"), Some(0));
+    assert_eq!(memchr2(b'y', b'z', b"aaaaz"), Some(4));
+    assert_eq!(memchr2(b'a', b'b', b""), None);
+    assert_eq!(memchr2(b'a', b'b', b"xyz"), None);
+}
+
+#[test]
+fn test_memchr2_each_alignment() {
+    use core::slice::memchr2;
+    let mut data = [1u8; 64];
+    data[40] = 3;
+    data[50] = 2;
+    for start in 0..16 {
+        assert_eq!(memchr2(2, 3, &data[start..]), Some(40 - start));
+        assert_eq!(memchr2(2, 4, &data[start..]), Some(50 - start));
+    }
+}
+
+#[test]
+fn test_memchr_each_length() {
+    use core::slice::{memchr, memrchr};
+    // Lengths on both sides of the 16-byte vector searches, with the match
+    // in every position, including the overlapping final vector.
+    let mut data = [0u8; 40];
+    for len in 0..data.len() {
+        assert_eq!(memchr(1, &data[..len]), None);
+        assert_eq!(memrchr(1, &data[..len]), None);
+        for pos in 0..len {
+            data[pos] = 1;
+            assert_eq!(memchr(1, &data[..len]), Some(pos));
+            assert_eq!(memrchr(1, &data[..len]), Some(pos));
+            data[pos] = 0;
+        }
+    }
+}
@@ -102,6 +102,40 @@ impl BitVector {
             idx: 0,
         }
     }
+
+    /// The underlying words; bit `i` is bit `i % WORD_BITS` of word
+    /// `i / WORD_BITS`.
+    #[inline]
+    pub fn as_words(&self) -> &[Word] {
+        &self.data
+    }
+
+    #[inline]
+    pub fn as_mut_words(&mut self) -> &mut [Word] {
+        &mut self.data
+    }
+
+    /// Builds a vector for `num_bits` bits out of words laid out as in
+    /// `as_words`. Returns `None` unless there are exactly as many words as
+    /// `BitVector::new(num_bits)` would allocate and no bit at or past
+    /// `num_bits` is set.
+    pub fn from_words(words: &[Word], num_bits: usize) -> Option<BitVector> {
+        if words.len() != self::words(num_bits) {
+            return None;
+        }
+        let (last_word, mask) = word_mask(num_bits);
+        if last_word < words.len() && words[last_word] & !(mask - 1) != 0 {
+            return None;
+        }
+        Some(BitVector {
+            data: words.to_vec(),
+        })
+    }
+
+    #[inline]
+    pub fn into_words(self) -> Vec<Word> {
+        self.data
+    }
 }
 
 pub struct BitVectorIter<'a> {
@@ -163,11 +197,99 @@ impl FromIterator<bool> for BitVector {
     }
 }
 
+/// Hands out `usize` ids, always picking the smallest one that is not
+/// currently in use, so that freed ids get recycled.
+///
+/// The allocated ids are tracked in a `BitVector`, together with a cursor
+/// below which no id is free, so allocating does not have to rescan the
+/// densely used prefix each time.
+#[derive(Clone, Debug)]
+pub struct IdAllocator {
+    bits: BitVector,
+    /// Every id below `hint` is allocated.
+    hint: usize,
+    len: usize,
+}
+
+impl IdAllocator {
+    pub fn new() -> IdAllocator {
+        IdAllocator {
+            bits: BitVector::new(0),
+            hint: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the smallest id that is not allocated, and marks it as allocated.
+    pub fn allocate(&mut self) -> usize {
+        let data = &mut self.bits.data;
+        let mut word = self.hint / WORD_BITS;
+        while word < data.len() && data[word] == !0 {
+            word += 1;
+        }
+        if word == data.len() {
+            data.push(0);
+        }
+        let bit = (!data[word]).trailing_zeros() as usize;
+        data[word] |= 1 << bit;
+
+        let id = word * WORD_BITS + bit;
+        self.hint = id + 1;
+        self.len += 1;
+        id
+    }
+
+    /// Makes `id` available again. Returns false if it was not allocated.
+    pub fn free(&mut self, id: usize) -> bool {
+        if !self.is_allocated(id) {
+            return false;
+        }
+        self.bits.remove(id);
+        if id < self.hint {
+            self.hint = id;
+        }
+        self.len -= 1;
+        true
+    }
+
+    pub fn is_allocated(&self, id: usize) -> bool {
+        id < self.bits.data.len() * WORD_BITS && self.bits.contains(id)
+    }
+
+    /// Number of ids currently allocated.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Frees all ids.
+    pub fn clear(&mut self) {
+        self.bits.clear();
+        self.hint = 0;
+        self.len = 0;
+    }
+
+    /// Iterates over the allocated ids in ascending order.
+    pub fn iter<'a>(&'a self) -> BitVectorIter<'a> {
+        self.bits.iter()
+    }
+}
+
+impl Default for IdAllocator {
+    fn default() -> IdAllocator {
+        IdAllocator::new()
+    }
+}
+
 /// A "bit matrix" is basically a matrix of booleans represented as
 /// one gigantic bitvector. In other words, it is as if you have
 /// `rows` bitvectors, each of length `columns`.
 #[derive(Clone, Debug)]
 pub struct BitMatrix {
+    rows: usize,
     columns: usize,
     vector: Vec<Word>,
 }
@@ -179,13 +301,25 @@ impl BitMatrix {
         // element. Round up to an even number of words.
         let words_per_row = words(columns);
         BitMatrix {
+            rows,
             columns,
             vector: vec![0; rows * words_per_row],
         }
     }
 
+    /// The number of rows in the matrix.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// The number of columns in the matrix.
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
     /// The range of bits for a given row.
     fn range(&self, row: usize) -> (usize, usize) {
+        assert!(row < self.rows);
         let words_per_row = words(self.columns);
         let start = row * words_per_row;
         (start, start + words_per_row)
@@ -196,6 +330,7 @@ impl BitMatrix {
     ///
     /// Returns true if this changed the matrix, and false otherwise.
     pub fn add(&mut self, row: usize, column: usize) -> bool {
+        assert!(column < self.columns);
         let (start, _) = self.range(row);
         let (word, mask) = word_mask(column);
         let vector = &mut self.vector[..];
@@ -205,11 +340,27 @@ impl BitMatrix {
         v1 != v2
     }
 
+    /// Sets the cell at `(row, column)` to false. Put another way,
+    /// remove `column` from the bitset for `row`.
+    ///
+    /// Returns true if this changed the matrix, and false otherwise.
+    pub fn remove(&mut self, row: usize, column: usize) -> bool {
+        assert!(column < self.columns);
+        let (start, _) = self.range(row);
+        let (word, mask) = word_mask(column);
+        let vector = &mut self.vector[..];
+        let v1 = vector[start + word];
+        let v2 = v1 & !mask;
+        vector[start + word] = v2;
+        v1 != v2
+    }
+
     /// Do the bits from `row` contain `column`? Put another way, is
     /// the matrix cell at `(row, column)` true?  Put yet another way,
     /// if the matrix represents (transitive) reachability, can
     /// `row` reach `column`?
     pub fn contains(&self, row: usize, column: usize) -> bool {
+        assert!(column < self.columns);
         let (start, _) = self.range(row);
         let (word, mask) = word_mask(column);
         (self.vector[start + word] & mask) != 0
@@ -259,6 +410,40 @@ impl BitMatrix {
         changed
     }
 
+    /// Extends the matrix, which must be square, to its transitive
+    /// closure: afterwards, if `a` can reach `b` and `b` can reach `c`,
+    /// then `a` can reach `c`.
+    ///
+    /// This is Warshall's algorithm, done a row at a time, so it takes
+    /// O(n^3 / WORD_BITS) time for an `n x n` matrix regardless of how
+    /// many edges there are.
+    pub fn transitive_closure(&mut self) {
+        assert_eq!(self.rows, self.columns);
+        for via in 0..self.rows {
+            for row in 0..self.rows {
+                // Anything that reaches `via` reaches everything
+                // `via` does.
+                if row != via && self.contains(row, via) {
+                    self.merge(via, row);
+                }
+            }
+        }
+    }
+
+    /// Returns the transpose of this matrix: a `columns x rows` matrix
+    /// in which `(column, row)` is set wherever `(row, column)` is set
+    /// here. If the matrix represents reachability, this reverses every
+    /// edge.
+    pub fn transpose(&self) -> BitMatrix {
+        let mut result = BitMatrix::new(self.columns, self.rows);
+        for row in 0..self.rows {
+            for column in self.iter(row) {
+                result.add(column, row);
+            }
+        }
+        result
+    }
+
     /// Iterates through all the columns set to true in a given row of
     /// the matrix.
     pub fn iter<'a>(&'a self, row: usize) -> BitVectorIter<'a> {
@@ -626,3 +811,123 @@ fn matrix_iter() {
     }
     assert!(iter.next().is_none());
 }
+
+#[test]
+fn matrix_remove() {
+    let mut matrix = BitMatrix::new(3, 200);
+    assert_eq!(matrix.rows(), 3);
+    assert_eq!(matrix.columns(), 200);
+    assert!(matrix.add(1, 150));
+    assert!(matrix.add(2, 150));
+    assert!(matrix.remove(1, 150));
+    assert!(!matrix.remove(1, 150));
+    assert!(!matrix.contains(1, 150));
+    assert!(matrix.contains(2, 150));
+}
+
+#[test]
+#[should_panic]
+fn matrix_column_out_of_bounds() {
+    let mut matrix = BitMatrix::new(3, 10);
+    matrix.add(0, 10);
+}
+
+#[test]
+fn matrix_transpose() {
+    let mut matrix = BitMatrix::new(2, 130);
+    matrix.add(0, 0);
+    matrix.add(0, 129);
+    matrix.add(1, 64);
+    matrix.add(1, 129);
+
+    let transposed = matrix.transpose();
+    assert_eq!(transposed.rows(), 130);
+    assert_eq!(transposed.columns(), 2);
+    assert_eq!(transposed.iter(0).collect::<Vec<_>>(), [0]);
+    assert_eq!(transposed.iter(64).collect::<Vec<_>>(), [1]);
+    assert_eq!(transposed.iter(129).collect::<Vec<_>>(), [0, 1]);
+    assert_eq!(transposed.iter(1).count(), 0);
+
+    let back = transposed.transpose();
+    for row in 0..2 {
+        assert_eq!(back.iter(row).collect::<Vec<_>>(),
+                   matrix.iter(row).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn matrix_transitive_closure() {
+    // 0 -> 1 -> 2 -> 3, 3 -> 1, and 4 on its own; 150 -> 0.
+    let mut matrix = BitMatrix::new(200, 200);
+    matrix.add(0, 1);
+    matrix.add(1, 2);
+    matrix.add(2, 3);
+    matrix.add(3, 1);
+    matrix.add(150, 0);
+    matrix.transitive_closure();
+
+    assert_eq!(matrix.iter(0).collect::<Vec<_>>(), [1, 2, 3]);
+    assert_eq!(matrix.iter(1).collect::<Vec<_>>(), [1, 2, 3]);
+    assert_eq!(matrix.iter(3).collect::<Vec<_>>(), [1, 2, 3]);
+    assert_eq!(matrix.iter(4).count(), 0);
+    assert_eq!(matrix.iter(150).collect::<Vec<_>>(), [0, 1, 2, 3]);
+    assert!(!matrix.contains(0, 0));
+}
+
+#[test]
+fn id_allocator_reuses_smallest() {
+    let mut ids = IdAllocator::new();
+    for i in 0..300 {
+        assert_eq!(ids.allocate(), i);
+    }
+    assert_eq!(ids.len(), 300);
+
+    assert!(ids.free(200));
+    assert!(ids.free(5));
+    assert!(ids.free(130));
+    assert!(!ids.free(130));
+    assert!(!ids.free(1000));
+    assert!(!ids.is_allocated(5));
+    assert!(ids.is_allocated(6));
+    assert!(!ids.is_allocated(1000));
+    assert_eq!(ids.len(), 297);
+
+    assert_eq!(ids.allocate(), 5);
+    assert_eq!(ids.allocate(), 130);
+    assert_eq!(ids.allocate(), 200);
+    assert_eq!(ids.allocate(), 300);
+    assert_eq!(ids.iter().count(), 301);
+
+    ids.clear();
+    assert!(ids.is_empty());
+    assert_eq!(ids.allocate(), 0);
+    assert_eq!(ids.iter().collect::<Vec<_>>(), [0]);
+}
+
+#[test]
+fn bitvec_words() {
+    let mut vec = BitVector::new(200);
+    vec.insert(1);
+    vec.insert(130);
+    assert_eq!(vec.as_words(), [2, 4]);
+
+    vec.as_mut_words()[0] = 0b101;
+    assert_eq!(vec.iter().collect::<Vec<_>>(), [0, 2, 130]);
+}
+
+#[test]
+fn bitvec_from_words() {
+    let vec = BitVector::from_words(&[0b101, 1 << 71], 200).unwrap();
+    assert_eq!(vec.iter().collect::<Vec<_>>(), [0, 2, 199]);
+    assert_eq!(vec.clone().into_words(), [0b101, 1 << 71]);
+    assert_eq!(BitVector::from_words(vec.as_words(), 200), Some(vec));
+
+    // The wrong number of words.
+    assert_eq!(BitVector::from_words(&[0], 200), None);
+    assert_eq!(BitVector::from_words(&[0, 0, 0], 200), None);
+    // A bit past the end.
+    assert_eq!(BitVector::from_words(&[0, 1 << 72], 200), None);
+    // Whole words need no mask.
+    assert_eq!(BitVector::from_words(&[!0, !0], 256).unwrap().count(), 256);
+    assert_eq!(BitVector::from_words(&[], 0), Some(BitVector::new(0)));
+}
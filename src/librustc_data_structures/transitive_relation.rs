@@ -369,17 +369,11 @@ impl<T: Clone + Debug + Eq + Hash> TransitiveRelation<T> {
     fn compute_closure(&self) -> BitMatrix {
         let mut matrix = BitMatrix::new(self.elements.len(),
                                         self.elements.len());
-        let mut changed = true;
-        while changed {
-            changed = false;
-            for edge in self.edges.iter() {
-                // add an edge from S -> T
-                changed |= matrix.add(edge.source.0, edge.target.0);
-
-                // add all outgoing edges from T into S
-                changed |= matrix.merge(edge.target.0, edge.source.0);
-            }
+        for edge in self.edges.iter() {
+            // add an edge from S -> T
+            matrix.add(edge.source.0, edge.target.0);
         }
+        matrix.transitive_closure();
         matrix
     }
 }
@@ -28,6 +28,8 @@
 
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::ascii::{EscapeDefault, escape_default};
+#[unstable(feature = "ascii_char_class", issue = "0")]
+pub use core::ascii::CharClass;
 
 /// Extension methods for ASCII-subset only operations.
 ///
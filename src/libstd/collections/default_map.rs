@@ -0,0 +1,396 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A map wrapper that supplies a default value for missing keys.
+//!
+//! [`DefaultMap`] removes the entry API ceremony from counters, adjacency
+//! lists and other maps that are built up one key at a time: looking up a
+//! missing key mutably inserts `V::default()` first. It works over any map
+//! implementing [`MapStorage`], which includes both [`HashMap`] and
+//! [`BTreeMap`].
+//!
+//! [`DefaultMap`]: struct.DefaultMap.html
+//! [`MapStorage`]: trait.MapStorage.html
+//! [`HashMap`]: ../hash_map/struct.HashMap.html
+//! [`BTreeMap`]: ../btree_map/struct.BTreeMap.html
+
+#![unstable(feature = "default_map", issue = "0")]
+
+use fmt;
+use hash::{BuildHasher, Hash};
+use marker::PhantomData;
+use ops::{Index, IndexMut};
+
+use super::{BTreeMap, HashMap};
+
+/// The operations a map must provide to back a [`DefaultMap`].
+///
+/// [`DefaultMap`]: struct.DefaultMap.html
+pub trait MapStorage<K, V> {
+    /// Returns a reference to the value for `key`, if it is present.
+    fn get(&self, key: &K) -> Option<&V>;
+
+    /// Returns a mutable reference to the value for `key`, first inserting
+    /// the result of `default` if it is not present.
+    fn get_or_insert_with<F: FnOnce() -> V>(&mut self, key: K, default: F) -> &mut V;
+
+    /// Removes `key` from the map, returning its value if it was present.
+    fn remove(&mut self, key: &K) -> Option<V>;
+
+    /// Returns the number of entries in the map.
+    fn len(&self) -> usize;
+}
+
+impl<K, V, S> MapStorage<K, V> for HashMap<K, V, S>
+    where K: Eq + Hash,
+          S: BuildHasher
+{
+    fn get(&self, key: &K) -> Option<&V> {
+        HashMap::get(self, key)
+    }
+
+    fn get_or_insert_with<F: FnOnce() -> V>(&mut self, key: K, default: F) -> &mut V {
+        self.entry(key).or_insert_with(default)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        HashMap::remove(self, key)
+    }
+
+    fn len(&self) -> usize {
+        HashMap::len(self)
+    }
+}
+
+impl<K: Ord, V> MapStorage<K, V> for BTreeMap<K, V> {
+    fn get(&self, key: &K) -> Option<&V> {
+        BTreeMap::get(self, key)
+    }
+
+    fn get_or_insert_with<F: FnOnce() -> V>(&mut self, key: K, default: F) -> &mut V {
+        self.entry(key).or_insert_with(default)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        BTreeMap::remove(self, key)
+    }
+
+    fn len(&self) -> usize {
+        BTreeMap::len(self)
+    }
+}
+
+/// A map in which every key is implicitly present with the value
+/// `V::default()`.
+///
+/// Mutable access to a missing key, through [`get_mut`] or indexing,
+/// inserts `V::default()` for it and returns a reference to the new value.
+/// Shared access to a missing key returns a reference to a default value
+/// kept by the map, without inserting anything.
+///
+/// The entries are kept in a `HashMap` unless another [`MapStorage`] is
+/// given to [`with_map`]; use a `BTreeMap` to iterate in key order.
+///
+/// [`get_mut`]: #method.get_mut
+/// [`with_map`]: #method.with_map
+/// [`MapStorage`]: trait.MapStorage.html
+///
+/// # Examples
+///
+/// Counting words:
+///
+/// ```
+/// #![feature(default_map)]
+/// use std::collections::default_map::DefaultMap;
+///
+/// let mut counts: DefaultMap<_, u32> = DefaultMap::new();
+/// for word in "the cat saw the other cat and the dog".split(' ') {
+///     counts[word] += 1;
+/// }
+/// assert_eq!(counts["the"], 3);
+/// assert_eq!(counts["cat"], 2);
+/// assert_eq!(counts["bird"], 0);
+/// assert_eq!(counts.len(), 6);
+/// ```
+///
+/// Building an adjacency list in key order:
+///
+/// ```
+/// #![feature(default_map)]
+/// use std::collections::BTreeMap;
+/// use std::collections::default_map::DefaultMap;
+///
+/// let mut graph: DefaultMap<_, Vec<_>, _> = DefaultMap::with_map(BTreeMap::new());
+/// for &(from, to) in &[(1, 2), (1, 3), (2, 3)] {
+///     graph.get_mut(from).push(to);
+/// }
+/// assert_eq!(graph[1], [2, 3]);
+/// assert!(graph[3].is_empty());
+///
+/// let graph = graph.into_inner();
+/// assert_eq!(graph.keys().collect::<Vec<_>>(), [&1, &2]);
+/// ```
+pub struct DefaultMap<K, V, M = HashMap<K, V>> {
+    map: M,
+    default: V,
+    marker: PhantomData<K>,
+}
+
+impl<K: Eq + Hash, V: Default> DefaultMap<K, V> {
+    /// Creates an empty `DefaultMap` backed by a `HashMap`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(default_map)]
+    /// use std::collections::default_map::DefaultMap;
+    ///
+    /// let map: DefaultMap<&str, i32> = DefaultMap::new();
+    /// assert!(map.is_empty());
+    /// ```
+    pub fn new() -> DefaultMap<K, V> {
+        DefaultMap::with_map(HashMap::new())
+    }
+}
+
+impl<K, V: Default, M: MapStorage<K, V>> DefaultMap<K, V, M> {
+    /// Wraps `map`, keeping its existing entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(default_map)]
+    /// use std::collections::HashMap;
+    /// use std::collections::default_map::DefaultMap;
+    ///
+    /// let mut scores = HashMap::new();
+    /// scores.insert("alice", 10);
+    ///
+    /// let mut scores = DefaultMap::with_map(scores);
+    /// scores["alice"] += 5;
+    /// scores["bob"] += 7;
+    /// assert_eq!(scores["alice"], 15);
+    /// assert_eq!(scores["bob"], 7);
+    /// ```
+    pub fn with_map(map: M) -> DefaultMap<K, V, M> {
+        DefaultMap {
+            map,
+            default: V::default(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the value for `key`, or to a default value if
+    /// `key` is not present.
+    ///
+    /// This never inserts into the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(default_map)]
+    /// use std::collections::default_map::DefaultMap;
+    ///
+    /// let mut map = DefaultMap::new();
+    /// *map.get_mut(1) = "one".to_string();
+    /// assert_eq!(map.get(&1), "one");
+    /// assert_eq!(map.get(&2), "");
+    /// assert_eq!(map.len(), 1);
+    /// ```
+    pub fn get(&self, key: &K) -> &V {
+        self.map.get(key).unwrap_or(&self.default)
+    }
+
+    /// Returns a mutable reference to the value for `key`, first inserting
+    /// `V::default()` if `key` is not present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(default_map)]
+    /// use std::collections::default_map::DefaultMap;
+    ///
+    /// let mut map: DefaultMap<_, Vec<_>> = DefaultMap::new();
+    /// map.get_mut("a").push(1);
+    /// map.get_mut("a").push(2);
+    /// assert_eq!(map["a"], [1, 2]);
+    /// ```
+    pub fn get_mut(&mut self, key: K) -> &mut V {
+        self.map.get_or_insert_with(key, V::default)
+    }
+
+    /// Returns `true` if a value has been inserted for `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(default_map)]
+    /// use std::collections::default_map::DefaultMap;
+    ///
+    /// let mut map: DefaultMap<_, i32> = DefaultMap::new();
+    /// assert_eq!(map[1], 0);
+    /// assert!(!map.contains_key(&1));
+    /// map[1] += 0;
+    /// assert!(map.contains_key(&1));
+    /// ```
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.map.get(key).is_some()
+    }
+
+    /// Removes `key` from the map, returning its value if one had been
+    /// inserted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(default_map)]
+    /// use std::collections::default_map::DefaultMap;
+    ///
+    /// let mut map: DefaultMap<_, i32> = DefaultMap::new();
+    /// map[1] += 4;
+    /// assert_eq!(map.remove(&1), Some(4));
+    /// assert_eq!(map.remove(&1), None);
+    /// ```
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        self.map.remove(key)
+    }
+
+    /// Returns the number of keys that have been inserted.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if no keys have been inserted.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a reference to the underlying map.
+    pub fn get_ref(&self) -> &M {
+        &self.map
+    }
+
+    /// Unwraps this `DefaultMap`, returning the underlying map.
+    pub fn into_inner(self) -> M {
+        self.map
+    }
+}
+
+impl<K, V, M> fmt::Debug for DefaultMap<K, V, M>
+    where M: fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.map.fmt(f)
+    }
+}
+
+impl<K, V, M> Clone for DefaultMap<K, V, M>
+    where V: Clone,
+          M: Clone
+{
+    fn clone(&self) -> Self {
+        DefaultMap {
+            map: self.map.clone(),
+            default: self.default.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<K, V, M> Default for DefaultMap<K, V, M>
+    where V: Default,
+          M: MapStorage<K, V> + Default
+{
+    fn default() -> Self {
+        DefaultMap::with_map(M::default())
+    }
+}
+
+impl<K, V, M> Index<K> for DefaultMap<K, V, M>
+    where V: Default,
+          M: MapStorage<K, V>
+{
+    type Output = V;
+
+    /// Returns a reference to the value for `key`, or to a default value if
+    /// `key` is not present.
+    #[inline]
+    fn index(&self, key: K) -> &V {
+        self.get(&key)
+    }
+}
+
+impl<K, V, M> IndexMut<K> for DefaultMap<K, V, M>
+    where V: Default,
+          M: MapStorage<K, V>
+{
+    /// Returns a mutable reference to the value for `key`, first inserting
+    /// `V::default()` if `key` is not present.
+    #[inline]
+    fn index_mut(&mut self, key: K) -> &mut V {
+        self.get_mut(key)
+    }
+}
+
+#[cfg(test)]
+mod test_default_map {
+    use super::DefaultMap;
+    use collections::{BTreeMap, HashMap};
+
+    #[test]
+    fn test_counter() {
+        let mut counts: DefaultMap<_, u32> = DefaultMap::new();
+        for &c in &[3, 1, 3, 3, 2, 1] {
+            counts[c] += 1;
+        }
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts[3], 3);
+        assert_eq!(counts[1], 2);
+        assert_eq!(counts[2], 1);
+        assert_eq!(counts[4], 0);
+        assert!(!counts.contains_key(&4));
+        assert_eq!(counts.len(), 3);
+    }
+
+    #[test]
+    fn test_get_does_not_insert() {
+        let mut map: DefaultMap<u32, Vec<u32>> = DefaultMap::new();
+        assert!(map.get(&7).is_empty());
+        assert!(map.is_empty());
+        map.get_mut(7).push(1);
+        assert_eq!(map.get(&7), &[1]);
+        assert_eq!(map.remove(&7), Some(vec![1]));
+        assert!(map.get(&7).is_empty());
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn test_btree_backed() {
+        let mut graph: DefaultMap<_, Vec<_>, _> = DefaultMap::with_map(BTreeMap::new());
+        for &(from, to) in &[(3, 1), (1, 2), (3, 2), (1, 3)] {
+            graph[from].push(to);
+        }
+        let graph = graph.into_inner();
+        let edges: Vec<_> = graph.iter().map(|(k, v)| (*k, v.clone())).collect();
+        assert_eq!(edges, [(1, vec![2, 3]), (3, vec![1, 2])]);
+    }
+
+    #[test]
+    fn test_with_existing_map() {
+        let mut map = HashMap::new();
+        map.insert("a", 1);
+        let mut map = DefaultMap::with_map(map);
+        map["a"] += 1;
+        map["b"] += 1;
+        assert_eq!(map.get_ref().len(), 2);
+        assert_eq!(map["a"], 2);
+        assert_eq!(map["b"], 1);
+    }
+}
@@ -0,0 +1,632 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A map that forgets its oldest entries.
+//!
+//! The map is a [`HashMap`] from each key to its value and the sequence
+//! number of the insertion that stored it, together with a queue of
+//! `(key, sequence number)` pairs in insertion order. Expired entries are
+//! always at the front of the queue, so expiry pops from the front until it
+//! reaches an entry that is still live. Queue entries whose key has since
+//! been removed or reinserted are stale; they are skipped when they reach
+//! the front, and the queue is compacted whenever stale entries make up
+//! more than half of it.
+//!
+//! [`HashMap`]: ../hash_map/struct.HashMap.html
+
+use borrow::Borrow;
+use collections::vec_deque::{self, VecDeque};
+use fmt;
+use hash::{Hash, BuildHasher};
+use iter::FusedIterator;
+
+use super::map::{HashMap, RandomState};
+
+/// A hash map bounded both in size and in the age of its entries, where age
+/// is counted in insertions.
+///
+/// An entry lives until either of these happens:
+///
+/// * `max_age` insertions, counting its own, have been made since it was
+///   last inserted, or
+/// * the map holds more than `max_len` entries and it is the oldest one.
+///
+/// In other words, the map holds at most the `max_len` most recently
+/// inserted keys, out of those inserted by the last `max_age` calls to
+/// [`insert`]. Inserting a key that is already present refreshes its age.
+/// Looking a key up does not.
+///
+/// Expiry happens during [`insert`], in amortized `O(1)` time.
+///
+/// The map keeps a second copy of every key in its insertion queue, so keys
+/// must be `Clone`. Cheap keys such as integers or handles work best.
+///
+/// It is a logic error for a key to be modified in such a way that its hash
+/// or equality changes while it is in the map.
+///
+/// [`insert`]: #method.insert
+///
+/// # Examples
+///
+/// Suppressing duplicates among the last few messages:
+///
+/// ```
+/// #![feature(expiring_map)]
+/// use std::collections::expiring_map::ExpiringMap;
+///
+/// let mut recent = ExpiringMap::new(100, 3);
+/// let mut shown = Vec::new();
+/// for &msg in &["a", "b", "a", "c", "d", "e", "a"] {
+///     if recent.insert(msg, ()).is_none() {
+///         shown.push(msg);
+///     }
+/// }
+/// // The second "a" was a repeat, but by the third, "a" had dropped out of
+/// // the last three insertions.
+/// assert_eq!(shown, ["a", "b", "c", "d", "e", "a"]);
+/// ```
+#[derive(Clone)]
+#[unstable(feature = "expiring_map", issue = "0")]
+pub struct ExpiringMap<K, V, S = RandomState> {
+    map: HashMap<K, (V, u64), S>,
+    order: VecDeque<(K, u64)>,
+    inserted: u64,
+    max_len: usize,
+    max_age: usize,
+}
+
+impl<K: Hash + Eq + Clone, V> ExpiringMap<K, V, RandomState> {
+    /// Creates an empty `ExpiringMap` holding at most `max_len` entries,
+    /// each for at most `max_age` insertions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(expiring_map)]
+    /// use std::collections::expiring_map::ExpiringMap;
+    ///
+    /// let mut map = ExpiringMap::new(10, 100);
+    /// map.insert('a', 4);
+    /// ```
+    #[unstable(feature = "expiring_map", issue = "0")]
+    pub fn new(max_len: usize, max_age: usize) -> ExpiringMap<K, V, RandomState> {
+        ExpiringMap::with_hasher(max_len, max_age, RandomState::new())
+    }
+}
+
+impl<K, V, S> ExpiringMap<K, V, S>
+    where K: Hash + Eq + Clone,
+          S: BuildHasher
+{
+    /// Creates an empty `ExpiringMap` holding at most `max_len` entries,
+    /// each for at most `max_age` insertions, which will use the given hash
+    /// builder to hash keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(expiring_map)]
+    /// use std::collections::hash_map::RandomState;
+    /// use std::collections::expiring_map::ExpiringMap;
+    ///
+    /// let mut map = ExpiringMap::with_hasher(10, 100, RandomState::new());
+    /// map.insert('a', 4);
+    /// ```
+    #[unstable(feature = "expiring_map", issue = "0")]
+    pub fn with_hasher(max_len: usize, max_age: usize, hash_builder: S)
+                       -> ExpiringMap<K, V, S> {
+        ExpiringMap {
+            map: HashMap::with_hasher(hash_builder),
+            order: VecDeque::new(),
+            inserted: 0,
+            max_len,
+            max_age,
+        }
+    }
+
+    /// Inserts a key-value pair into the map, then expires the entries that
+    /// have become too old or no longer fit.
+    ///
+    /// If the map already had this key, its value is replaced, the old value
+    /// is returned and the entry's age starts over. Otherwise `None` is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(expiring_map)]
+    /// use std::collections::expiring_map::ExpiringMap;
+    ///
+    /// let mut map = ExpiringMap::new(2, 100);
+    /// assert_eq!(map.insert("a", 1), None);
+    /// assert_eq!(map.insert("b", 2), None);
+    /// assert_eq!(map.insert("a", 3), Some(1));
+    ///
+    /// // "b" is now the oldest entry, so it makes room for "c".
+    /// map.insert("c", 4);
+    /// assert!(!map.contains_key("b"));
+    /// assert_eq!(map.len(), 2);
+    /// ```
+    #[unstable(feature = "expiring_map", issue = "0")]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let seq = self.inserted;
+        self.inserted += 1;
+        self.order.push_back((key.clone(), seq));
+        let old = self.map.insert(key, (value, seq)).map(|(v, _)| v);
+        self.expire();
+        old
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    ///
+    /// The key may be any borrowed form of the map's key type, but
+    /// [`Hash`] and [`Eq`] on the borrowed form *must* match those for
+    /// the key type.
+    ///
+    /// [`Eq`]: ../../std/cmp/trait.Eq.html
+    /// [`Hash`]: ../../std/hash/trait.Hash.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(expiring_map)]
+    /// use std::collections::expiring_map::ExpiringMap;
+    ///
+    /// let mut map = ExpiringMap::new(10, 100);
+    /// map.insert("a", 1);
+    /// assert_eq!(map.get("a"), Some(&1));
+    /// assert_eq!(map.get("b"), None);
+    /// ```
+    #[unstable(feature = "expiring_map", issue = "0")]
+    pub fn get<Q: ?Sized>(&self, key: &Q) -> Option<&V>
+        where K: Borrow<Q>, Q: Hash + Eq
+    {
+        self.map.get(key).map(|&(ref v, _)| v)
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key.
+    ///
+    /// Changing the value does not refresh the entry's age.
+    ///
+    /// The key may be any borrowed form of the map's key type, but
+    /// [`Hash`] and [`Eq`] on the borrowed form *must* match those for
+    /// the key type.
+    ///
+    /// [`Eq`]: ../../std/cmp/trait.Eq.html
+    /// [`Hash`]: ../../std/hash/trait.Hash.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(expiring_map)]
+    /// use std::collections::expiring_map::ExpiringMap;
+    ///
+    /// let mut map = ExpiringMap::new(10, 100);
+    /// map.insert("a", 1);
+    /// if let Some(x) = map.get_mut("a") {
+    ///     *x += 10;
+    /// }
+    /// assert_eq!(map.get("a"), Some(&11));
+    /// ```
+    #[unstable(feature = "expiring_map", issue = "0")]
+    pub fn get_mut<Q: ?Sized>(&mut self, key: &Q) -> Option<&mut V>
+        where K: Borrow<Q>, Q: Hash + Eq
+    {
+        self.map.get_mut(key).map(|&mut (ref mut v, _)| v)
+    }
+
+    /// Returns `true` if the map contains a value for the specified key.
+    ///
+    /// The key may be any borrowed form of the map's key type, but
+    /// [`Hash`] and [`Eq`] on the borrowed form *must* match those for
+    /// the key type.
+    ///
+    /// [`Eq`]: ../../std/cmp/trait.Eq.html
+    /// [`Hash`]: ../../std/hash/trait.Hash.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(expiring_map)]
+    /// use std::collections::expiring_map::ExpiringMap;
+    ///
+    /// let mut map = ExpiringMap::new(10, 100);
+    /// map.insert("a", 1);
+    /// assert!(map.contains_key("a"));
+    /// assert!(!map.contains_key("b"));
+    /// ```
+    #[unstable(feature = "expiring_map", issue = "0")]
+    pub fn contains_key<Q: ?Sized>(&self, key: &Q) -> bool
+        where K: Borrow<Q>, Q: Hash + Eq
+    {
+        self.map.contains_key(key)
+    }
+
+    /// Removes a key from the map, returning its value if the key was in
+    /// the map.
+    ///
+    /// The key may be any borrowed form of the map's key type, but
+    /// [`Hash`] and [`Eq`] on the borrowed form *must* match those for
+    /// the key type.
+    ///
+    /// [`Eq`]: ../../std/cmp/trait.Eq.html
+    /// [`Hash`]: ../../std/hash/trait.Hash.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(expiring_map)]
+    /// use std::collections::expiring_map::ExpiringMap;
+    ///
+    /// let mut map = ExpiringMap::new(10, 100);
+    /// map.insert("a", 1);
+    /// assert_eq!(map.remove("a"), Some(1));
+    /// assert_eq!(map.remove("a"), None);
+    /// ```
+    #[unstable(feature = "expiring_map", issue = "0")]
+    pub fn remove<Q: ?Sized>(&mut self, key: &Q) -> Option<V>
+        where K: Borrow<Q>, Q: Hash + Eq
+    {
+        let value = self.map.remove(key).map(|(v, _)| v);
+        if value.is_some() {
+            self.expire();
+        }
+        value
+    }
+
+    /// Returns `true` if the queue entry `(key, seq)` still describes a
+    /// live entry of the map.
+    fn is_live(&self, key: &K, seq: u64) -> bool {
+        match self.map.get(key) {
+            Some(&(_, s)) => s == seq,
+            None => false,
+        }
+    }
+
+    /// Drops stale queue entries and expired map entries from the front of
+    /// the queue, then compacts the queue if it is mostly stale.
+    fn expire(&mut self) {
+        loop {
+            let (live, too_old) = match self.order.front() {
+                Some(&(ref key, seq)) => {
+                    (self.is_live(key, seq), self.inserted - seq > self.max_age as u64)
+                }
+                None => break,
+            };
+            if !live {
+                self.order.pop_front();
+            } else if too_old || self.map.len() > self.max_len {
+                let (key, _) = self.order.pop_front().unwrap();
+                self.map.remove(&key);
+            } else {
+                break;
+            }
+        }
+
+        if self.order.len() / 2 > self.map.len() {
+            let map = &self.map;
+            self.order.retain(|&(ref key, seq)| match map.get(key) {
+                Some(&(_, s)) => s == seq,
+                None => false,
+            });
+        }
+    }
+}
+
+impl<K, V, S> ExpiringMap<K, V, S> {
+    /// Returns the greatest number of entries the map will hold.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(expiring_map)]
+    /// use std::collections::expiring_map::ExpiringMap;
+    ///
+    /// let map: ExpiringMap<i32, i32> = ExpiringMap::new(10, 100);
+    /// assert_eq!(map.max_len(), 10);
+    /// ```
+    #[unstable(feature = "expiring_map", issue = "0")]
+    pub fn max_len(&self) -> usize {
+        self.max_len
+    }
+
+    /// Returns the number of insertions an entry is kept for.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(expiring_map)]
+    /// use std::collections::expiring_map::ExpiringMap;
+    ///
+    /// let map: ExpiringMap<i32, i32> = ExpiringMap::new(10, 100);
+    /// assert_eq!(map.max_age(), 100);
+    /// ```
+    #[unstable(feature = "expiring_map", issue = "0")]
+    pub fn max_age(&self) -> usize {
+        self.max_age
+    }
+
+    /// Returns the number of entries in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(expiring_map)]
+    /// use std::collections::expiring_map::ExpiringMap;
+    ///
+    /// let mut map = ExpiringMap::new(10, 100);
+    /// map.insert("a", 1);
+    /// map.insert("a", 2);
+    /// assert_eq!(map.len(), 1);
+    /// ```
+    #[unstable(feature = "expiring_map", issue = "0")]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the map contains no entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(expiring_map)]
+    /// use std::collections::expiring_map::ExpiringMap;
+    ///
+    /// let mut map = ExpiringMap::new(10, 100);
+    /// assert!(map.is_empty());
+    /// map.insert("a", 1);
+    /// assert!(!map.is_empty());
+    /// ```
+    #[unstable(feature = "expiring_map", issue = "0")]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Removes all entries from the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(expiring_map)]
+    /// use std::collections::expiring_map::ExpiringMap;
+    ///
+    /// let mut map = ExpiringMap::new(10, 100);
+    /// map.insert("a", 1);
+    /// map.clear();
+    /// assert!(map.is_empty());
+    /// assert!(!map.contains_key("a"));
+    /// ```
+    #[unstable(feature = "expiring_map", issue = "0")]
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.order.clear();
+    }
+
+    /// Returns an iterator visiting all entries from the oldest to the most
+    /// recently inserted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(expiring_map)]
+    /// use std::collections::expiring_map::ExpiringMap;
+    ///
+    /// let mut map = ExpiringMap::new(10, 100);
+    /// map.insert("a", 1);
+    /// map.insert("b", 2);
+    /// map.insert("a", 3);
+    ///
+    /// let entries: Vec<_> = map.iter().collect();
+    /// assert_eq!(entries, [(&"b", &2), (&"a", &3)]);
+    /// ```
+    #[unstable(feature = "expiring_map", issue = "0")]
+    pub fn iter(&self) -> Iter<K, V, S> {
+        Iter {
+            order: self.order.iter(),
+            map: &self.map,
+            remaining: self.map.len(),
+        }
+    }
+}
+
+#[unstable(feature = "expiring_map", issue = "0")]
+impl<K, V, S> fmt::Debug for ExpiringMap<K, V, S>
+    where K: Hash + Eq + fmt::Debug,
+          V: fmt::Debug,
+          S: BuildHasher
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[unstable(feature = "expiring_map", issue = "0")]
+impl<K, V, S> Extend<(K, V)> for ExpiringMap<K, V, S>
+    where K: Hash + Eq + Clone,
+          S: BuildHasher
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+#[unstable(feature = "expiring_map", issue = "0")]
+impl<'a, K, V, S> IntoIterator for &'a ExpiringMap<K, V, S>
+    where K: Hash + Eq,
+          S: BuildHasher
+{
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V, S>;
+
+    fn into_iter(self) -> Iter<'a, K, V, S> {
+        self.iter()
+    }
+}
+
+/// An iterator over the entries of an `ExpiringMap`, oldest first.
+///
+/// This `struct` is created by the [`iter`] method on [`ExpiringMap`].
+/// See its documentation for more.
+///
+/// [`iter`]: struct.ExpiringMap.html#method.iter
+/// [`ExpiringMap`]: struct.ExpiringMap.html
+#[unstable(feature = "expiring_map", issue = "0")]
+pub struct Iter<'a, K: 'a, V: 'a, S: 'a> {
+    order: vec_deque::Iter<'a, (K, u64)>,
+    map: &'a HashMap<K, (V, u64), S>,
+    remaining: usize,
+}
+
+// FIXME(#26925) Remove in favor of `#[derive(Clone)]`
+#[unstable(feature = "expiring_map", issue = "0")]
+impl<'a, K, V, S> Clone for Iter<'a, K, V, S> {
+    fn clone(&self) -> Iter<'a, K, V, S> {
+        Iter {
+            order: self.order.clone(),
+            map: self.map,
+            remaining: self.remaining,
+        }
+    }
+}
+
+#[unstable(feature = "expiring_map", issue = "0")]
+impl<'a, K, V, S> fmt::Debug for Iter<'a, K, V, S>
+    where K: Hash + Eq + fmt::Debug,
+          V: fmt::Debug,
+          S: BuildHasher
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+#[unstable(feature = "expiring_map", issue = "0")]
+impl<'a, K, V, S> Iterator for Iter<'a, K, V, S>
+    where K: Hash + Eq,
+          S: BuildHasher
+{
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        let map = self.map;
+        for &(ref key, seq) in &mut self.order {
+            match map.get(key) {
+                Some(&(ref value, s)) if s == seq => {
+                    self.remaining -= 1;
+                    return Some((key, value));
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+#[unstable(feature = "expiring_map", issue = "0")]
+impl<'a, K, V, S> ExactSizeIterator for Iter<'a, K, V, S>
+    where K: Hash + Eq,
+          S: BuildHasher
+{
+    fn len(&self) -> usize {
+        self.remaining
+    }
+}
+
+#[unstable(feature = "expiring_map", issue = "0")]
+impl<'a, K, V, S> FusedIterator for Iter<'a, K, V, S>
+    where K: Hash + Eq,
+          S: BuildHasher
+{}
+
+#[cfg(test)]
+mod test_expiring_map {
+    use super::ExpiringMap;
+
+    #[test]
+    fn test_max_len() {
+        let mut map = ExpiringMap::new(3, 100);
+        for i in 0..10 {
+            map.insert(i, i * 10);
+            assert!(map.len() <= 3);
+        }
+        let entries: Vec<_> = map.iter().map(|(&k, &v)| (k, v)).collect();
+        assert_eq!(entries, [(7, 70), (8, 80), (9, 90)]);
+    }
+
+    #[test]
+    fn test_max_age() {
+        let mut map = ExpiringMap::new(100, 4);
+        for i in 0..4 {
+            map.insert(i, ());
+        }
+        assert_eq!(map.len(), 4);
+        map.insert(4, ());
+        assert!(!map.contains_key(&0));
+        assert_eq!(map.len(), 4);
+
+        // Reinserting refreshes the age of a key.
+        map.insert(1, ());
+        map.insert(5, ());
+        assert!(map.contains_key(&1));
+        assert!(!map.contains_key(&2));
+        let keys: Vec<_> = map.iter().map(|(&k, _)| k).collect();
+        assert_eq!(keys, [3, 4, 1, 5]);
+    }
+
+    #[test]
+    fn test_remove_and_reinsert() {
+        let mut map = ExpiringMap::new(2, 100);
+        map.insert("a", 1);
+        map.insert("b", 2);
+        assert_eq!(map.remove("a"), Some(1));
+        map.insert("c", 3);
+        // Removing "a" made room, so "b" is still here.
+        assert_eq!(map.get("b"), Some(&2));
+        map.insert("a", 4);
+        assert!(!map.contains_key("b"));
+        assert_eq!(map.iter().len(), 2);
+    }
+
+    #[test]
+    fn test_stale_entries_are_compacted() {
+        let mut map = ExpiringMap::new(usize::max_value(), usize::max_value());
+        map.insert(0, ());
+        for i in 1..1000 {
+            map.insert(i, ());
+            map.remove(&i);
+        }
+        assert_eq!(map.len(), 1);
+        assert!(map.order.len() <= 3);
+
+        for _ in 0..1000 {
+            map.insert(1, ());
+        }
+        assert_eq!(map.len(), 2);
+        assert!(map.order.len() <= 5);
+    }
+
+    #[test]
+    fn test_zero_limits() {
+        let mut map = ExpiringMap::new(0, 100);
+        assert_eq!(map.insert(1, 1), None);
+        assert!(map.is_empty());
+
+        let mut map = ExpiringMap::new(100, 0);
+        assert_eq!(map.insert(1, 1), None);
+        assert!(map.is_empty());
+    }
+}
@@ -0,0 +1,1056 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A map that evicts its least recently used entry when it is full.
+//!
+//! The cache is a [`HashMap`] from each key to its value and the sequence
+//! number of its last use, together with a queue of `(key, sequence
+//! number)` pairs in order of use. Every use pushes a new pair onto the
+//! back of the queue, leaving the key's older pairs behind as stale. The
+//! least recently used entry is then the first live pair in the queue.
+//! Stale pairs are skipped when they reach the front, and the queue is
+//! compacted whenever they make up more than half of it.
+//!
+//! This is the same scheme as [`ExpiringMap`], which counts insertions
+//! rather than uses.
+//!
+//! [`HashMap`]: ../hash_map/struct.HashMap.html
+//! [`ExpiringMap`]: ../expiring_map/struct.ExpiringMap.html
+
+use borrow::Borrow;
+use collections::vec_deque::{self, VecDeque};
+use fmt::{self, Debug};
+use hash::{Hash, BuildHasher};
+use iter::FusedIterator;
+use mem;
+
+use super::map::{self, HashMap, RandomState};
+
+/// A hash map holding at most a fixed number of entries, which evicts the
+/// least recently used entry to make room for a new one.
+///
+/// Inserting a key, or looking it up with [`get`], [`get_mut`] or
+/// [`entry`], counts as a use. [`peek`] and [`contains_key`] don't change
+/// the order of eviction.
+///
+/// [`get_or_insert_with`] and [`entry`] do the usual
+/// "check, insert if missing, then get" sequence in one call. They still
+/// hash the key twice: once to find out whether it is cached, and once more
+/// to reach its entry or to insert it.
+///
+/// All operations take amortized `O(1)` time. The cache records the order
+/// of use in a queue of keys, so keys must be `Clone`, and every use clones
+/// the key once. Cheap keys such as integers, handles or `Rc<str>` work
+/// best.
+///
+/// It is a logic error for a key to be modified in such a way that its hash
+/// or equality changes while it is in the cache.
+///
+/// [`get`]: #method.get
+/// [`get_mut`]: #method.get_mut
+/// [`entry`]: #method.entry
+/// [`peek`]: #method.peek
+/// [`contains_key`]: #method.contains_key
+/// [`get_or_insert_with`]: #method.get_or_insert_with
+///
+/// # Examples
+///
+/// ```
+/// #![feature(lru_cache)]
+/// use std::collections::lru_cache::LruCache;
+///
+/// let mut lengths = LruCache::new(2);
+/// let mut computed = 0;
+/// for &word in &["apple", "fig", "apple", "kiwi", "fig"] {
+///     let len = *lengths.get_or_insert_with(word, || {
+///         computed += 1;
+///         word.len()
+///     });
+///     assert_eq!(len, word.len());
+/// }
+/// // "fig" was evicted by "kiwi", since "apple" had been used more
+/// // recently, so it had to be computed again.
+/// assert_eq!(computed, 4);
+/// ```
+#[derive(Clone)]
+#[unstable(feature = "lru_cache", issue = "0")]
+pub struct LruCache<K, V, S = RandomState> {
+    map: HashMap<K, (V, u64), S>,
+    order: VecDeque<(K, u64)>,
+    used: u64,
+    capacity: usize,
+}
+
+impl<K: Hash + Eq + Clone, V> LruCache<K, V, RandomState> {
+    /// Creates an empty `LruCache` holding at most `capacity` entries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(lru_cache)]
+    /// use std::collections::lru_cache::LruCache;
+    ///
+    /// let mut cache = LruCache::new(10);
+    /// cache.insert('a', 4);
+    /// ```
+    #[unstable(feature = "lru_cache", issue = "0")]
+    pub fn new(capacity: usize) -> LruCache<K, V, RandomState> {
+        LruCache::with_hasher(capacity, RandomState::new())
+    }
+}
+
+impl<K, V, S> LruCache<K, V, S>
+    where K: Hash + Eq + Clone,
+          S: BuildHasher
+{
+    /// Creates an empty `LruCache` holding at most `capacity` entries, which
+    /// will use the given hash builder to hash keys.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(lru_cache)]
+    /// use std::collections::hash_map::RandomState;
+    /// use std::collections::lru_cache::LruCache;
+    ///
+    /// let mut cache = LruCache::with_hasher(10, RandomState::new());
+    /// cache.insert('a', 4);
+    /// ```
+    #[unstable(feature = "lru_cache", issue = "0")]
+    pub fn with_hasher(capacity: usize, hash_builder: S) -> LruCache<K, V, S> {
+        assert!(capacity != 0, "LruCache capacity must not be zero");
+        LruCache {
+            map: HashMap::with_hasher(hash_builder),
+            order: VecDeque::new(),
+            used: 0,
+            capacity,
+        }
+    }
+
+    /// Inserts a key-value pair into the cache, and marks it as the most
+    /// recently used entry.
+    ///
+    /// If the cache already had this key, its value is replaced and the old
+    /// value is returned. Otherwise `None` is returned, and if the cache was
+    /// full, the least recently used entry is evicted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(lru_cache)]
+    /// use std::collections::lru_cache::LruCache;
+    ///
+    /// let mut cache = LruCache::new(2);
+    /// assert_eq!(cache.insert("a", 1), None);
+    /// assert_eq!(cache.insert("b", 2), None);
+    /// assert_eq!(cache.insert("a", 3), Some(1));
+    ///
+    /// // "b" is now the least recently used entry, so it makes room for "c".
+    /// cache.insert("c", 4);
+    /// assert!(!cache.contains_key("b"));
+    /// assert_eq!(cache.len(), 2);
+    /// ```
+    #[unstable(feature = "lru_cache", issue = "0")]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.entry(key) {
+            Entry::Occupied(mut entry) => Some(entry.insert(value)),
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+                None
+            }
+        }
+    }
+
+    /// Returns a reference to the value corresponding to the key, and marks
+    /// it as the most recently used entry.
+    ///
+    /// The key may be any borrowed form of the cache's key type, but
+    /// [`Hash`] and [`Eq`] on the borrowed form *must* match those for
+    /// the key type.
+    ///
+    /// [`Eq`]: ../../std/cmp/trait.Eq.html
+    /// [`Hash`]: ../../std/hash/trait.Hash.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(lru_cache)]
+    /// use std::collections::lru_cache::LruCache;
+    ///
+    /// let mut cache = LruCache::new(2);
+    /// cache.insert("a", 1);
+    /// cache.insert("b", 2);
+    /// assert_eq!(cache.get("a"), Some(&1));
+    ///
+    /// // Using "a" saved it from eviction.
+    /// cache.insert("c", 3);
+    /// assert_eq!(cache.get("a"), Some(&1));
+    /// assert_eq!(cache.get("b"), None);
+    /// ```
+    #[unstable(feature = "lru_cache", issue = "0")]
+    pub fn get<Q: ?Sized>(&mut self, key: &Q) -> Option<&V>
+        where K: Borrow<Q>, Q: Hash + Eq
+    {
+        self.get_mut(key).map(|value| &*value)
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key,
+    /// and marks it as the most recently used entry.
+    ///
+    /// If the key is present, it is cloned to record the use.
+    ///
+    /// The key may be any borrowed form of the cache's key type, but
+    /// [`Hash`] and [`Eq`] on the borrowed form *must* match those for
+    /// the key type.
+    ///
+    /// [`Eq`]: ../../std/cmp/trait.Eq.html
+    /// [`Hash`]: ../../std/hash/trait.Hash.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(lru_cache)]
+    /// use std::collections::lru_cache::LruCache;
+    ///
+    /// let mut cache = LruCache::new(2);
+    /// cache.insert("a", 1);
+    /// if let Some(x) = cache.get_mut("a") {
+    ///     *x += 10;
+    /// }
+    /// assert_eq!(cache.get("a"), Some(&11));
+    /// ```
+    #[unstable(feature = "lru_cache", issue = "0")]
+    pub fn get_mut<Q: ?Sized>(&mut self, key: &Q) -> Option<&mut V>
+        where K: Borrow<Q>, Q: Hash + Eq
+    {
+        self.compact();
+        let seq = self.next_seq();
+        match self.map.get_key_value_mut(key) {
+            Some((key, &mut (ref mut value, ref mut used))) => {
+                *used = seq;
+                self.order.push_back((key.clone(), seq));
+                Some(value)
+            }
+            None => None,
+        }
+    }
+
+    /// Returns a reference to the value corresponding to the key, without
+    /// marking it as used.
+    ///
+    /// The key may be any borrowed form of the cache's key type, but
+    /// [`Hash`] and [`Eq`] on the borrowed form *must* match those for
+    /// the key type.
+    ///
+    /// [`Eq`]: ../../std/cmp/trait.Eq.html
+    /// [`Hash`]: ../../std/hash/trait.Hash.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(lru_cache)]
+    /// use std::collections::lru_cache::LruCache;
+    ///
+    /// let mut cache = LruCache::new(2);
+    /// cache.insert("a", 1);
+    /// cache.insert("b", 2);
+    /// assert_eq!(cache.peek("a"), Some(&1));
+    ///
+    /// // Peeking at "a" didn't save it from eviction.
+    /// cache.insert("c", 3);
+    /// assert_eq!(cache.peek("a"), None);
+    /// ```
+    #[unstable(feature = "lru_cache", issue = "0")]
+    pub fn peek<Q: ?Sized>(&self, key: &Q) -> Option<&V>
+        where K: Borrow<Q>, Q: Hash + Eq
+    {
+        self.map.get(key).map(|&(ref value, _)| value)
+    }
+
+    /// Returns `true` if the cache contains a value for the specified key.
+    ///
+    /// This does not mark the entry as used.
+    ///
+    /// The key may be any borrowed form of the cache's key type, but
+    /// [`Hash`] and [`Eq`] on the borrowed form *must* match those for
+    /// the key type.
+    ///
+    /// [`Eq`]: ../../std/cmp/trait.Eq.html
+    /// [`Hash`]: ../../std/hash/trait.Hash.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(lru_cache)]
+    /// use std::collections::lru_cache::LruCache;
+    ///
+    /// let mut cache = LruCache::new(2);
+    /// cache.insert("a", 1);
+    /// assert!(cache.contains_key("a"));
+    /// assert!(!cache.contains_key("b"));
+    /// ```
+    #[unstable(feature = "lru_cache", issue = "0")]
+    pub fn contains_key<Q: ?Sized>(&self, key: &Q) -> bool
+        where K: Borrow<Q>, Q: Hash + Eq
+    {
+        self.map.contains_key(key)
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key,
+    /// inserting the result of `default` first if the key is not present.
+    /// Either way, the entry becomes the most recently used one.
+    ///
+    /// Inserting into a full cache evicts the least recently used entry.
+    /// Like [`entry`], this hashes the key twice and clones it once.
+    ///
+    /// [`entry`]: #method.entry
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(lru_cache)]
+    /// use std::collections::lru_cache::LruCache;
+    ///
+    /// let mut cache = LruCache::new(10);
+    /// *cache.get_or_insert_with("a", || 0) += 1;
+    /// *cache.get_or_insert_with("a", || 0) += 1;
+    /// assert_eq!(cache.peek("a"), Some(&2));
+    /// ```
+    #[unstable(feature = "lru_cache", issue = "0")]
+    pub fn get_or_insert_with<F: FnOnce() -> V>(&mut self, key: K, default: F) -> &mut V {
+        self.entry(key).or_insert_with(default)
+    }
+
+    /// Gets the given key's corresponding entry in the cache for in-place
+    /// manipulation, and marks it as the most recently used entry if it is
+    /// present.
+    ///
+    /// This looks the key up to find out whether it is present, then again
+    /// to reach the occupied entry or, through [`VacantEntry::insert`], to
+    /// insert it. Either way the key is cloned once to record the use.
+    ///
+    /// [`VacantEntry::insert`]: struct.VacantEntry.html#method.insert
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(lru_cache)]
+    /// use std::collections::lru_cache::{Entry, LruCache};
+    ///
+    /// let mut cache = LruCache::new(10);
+    /// for word in "the cat and the hat".split(' ') {
+    ///     match cache.entry(word) {
+    ///         Entry::Occupied(mut e) => *e.get_mut() += 1,
+    ///         Entry::Vacant(e) => {
+    ///             e.insert(1);
+    ///         }
+    ///     }
+    /// }
+    /// assert_eq!(cache.peek("the"), Some(&2));
+    /// assert_eq!(cache.peek("cat"), Some(&1));
+    /// ```
+    #[unstable(feature = "lru_cache", issue = "0")]
+    pub fn entry(&mut self, key: K) -> Entry<K, V, S> {
+        self.compact();
+        // A vacant entry borrows the whole cache, since inserting may have
+        // to evict another entry, so it can't hold on to the map's entry.
+        if !self.map.contains_key(&key) {
+            return Entry::Vacant(VacantEntry { key, cache: self });
+        }
+        let seq = self.next_seq();
+        match self.map.entry(key) {
+            map::Entry::Occupied(mut inner) => {
+                inner.get_mut().1 = seq;
+                self.order.push_back((inner.key().clone(), seq));
+                Entry::Occupied(OccupiedEntry { inner })
+            }
+            map::Entry::Vacant(_) => unreachable!(),
+        }
+    }
+
+    /// Removes a key from the cache, returning its value if the key was in
+    /// the cache.
+    ///
+    /// The key may be any borrowed form of the cache's key type, but
+    /// [`Hash`] and [`Eq`] on the borrowed form *must* match those for
+    /// the key type.
+    ///
+    /// [`Eq`]: ../../std/cmp/trait.Eq.html
+    /// [`Hash`]: ../../std/hash/trait.Hash.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(lru_cache)]
+    /// use std::collections::lru_cache::LruCache;
+    ///
+    /// let mut cache = LruCache::new(10);
+    /// cache.insert("a", 1);
+    /// assert_eq!(cache.remove("a"), Some(1));
+    /// assert_eq!(cache.remove("a"), None);
+    /// ```
+    #[unstable(feature = "lru_cache", issue = "0")]
+    pub fn remove<Q: ?Sized>(&mut self, key: &Q) -> Option<V>
+        where K: Borrow<Q>, Q: Hash + Eq
+    {
+        let value = self.map.remove(key).map(|(value, _)| value);
+        self.compact();
+        value
+    }
+
+    /// Removes a key from the cache, returning the stored key and value if
+    /// the key was in the cache.
+    ///
+    /// The key may be any borrowed form of the cache's key type, but
+    /// [`Hash`] and [`Eq`] on the borrowed form *must* match those for
+    /// the key type.
+    ///
+    /// [`Eq`]: ../../std/cmp/trait.Eq.html
+    /// [`Hash`]: ../../std/hash/trait.Hash.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(lru_cache)]
+    /// use std::collections::lru_cache::LruCache;
+    ///
+    /// let mut cache = LruCache::new(10);
+    /// cache.insert("a", 1);
+    /// assert_eq!(cache.remove_entry("a"), Some(("a", 1)));
+    /// assert_eq!(cache.remove_entry("a"), None);
+    /// ```
+    #[unstable(feature = "lru_cache", issue = "0")]
+    pub fn remove_entry<Q: ?Sized>(&mut self, key: &Q) -> Option<(K, V)>
+        where K: Borrow<Q>, Q: Hash + Eq
+    {
+        let entry = self.map.remove_entry(key).map(|(key, (value, _))| (key, value));
+        self.compact();
+        entry
+    }
+
+    /// Removes the least recently used entry and returns it, or `None` if
+    /// the cache is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(lru_cache)]
+    /// use std::collections::lru_cache::LruCache;
+    ///
+    /// let mut cache = LruCache::new(10);
+    /// cache.insert("a", 1);
+    /// cache.insert("b", 2);
+    /// cache.get("a");
+    /// assert_eq!(cache.pop_lru(), Some(("b", 2)));
+    /// assert_eq!(cache.pop_lru(), Some(("a", 1)));
+    /// assert_eq!(cache.pop_lru(), None);
+    /// ```
+    #[unstable(feature = "lru_cache", issue = "0")]
+    pub fn pop_lru(&mut self) -> Option<(K, V)> {
+        while let Some((key, seq)) = self.order.pop_front() {
+            if self.is_live(&key, seq) {
+                let (value, _) = self.map.remove(&key).unwrap();
+                return Some((key, value));
+            }
+        }
+        None
+    }
+
+    fn next_seq(&mut self) -> u64 {
+        let seq = self.used;
+        self.used += 1;
+        seq
+    }
+
+    /// Returns `true` if the queue entry `(key, seq)` records the last use
+    /// of a live entry of the cache.
+    fn is_live(&self, key: &K, seq: u64) -> bool {
+        match self.map.get(key) {
+            Some(&(_, s)) => s == seq,
+            None => false,
+        }
+    }
+
+    /// Drops the stale entries from the queue if they make up more than
+    /// half of it.
+    fn compact(&mut self) {
+        if self.order.len() / 2 > self.map.len() {
+            let map = &self.map;
+            self.order.retain(|&(ref key, seq)| match map.get(key) {
+                Some(&(_, s)) => s == seq,
+                None => false,
+            });
+        }
+    }
+}
+
+impl<K, V, S> LruCache<K, V, S> {
+    /// Returns the greatest number of entries the cache will hold.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(lru_cache)]
+    /// use std::collections::lru_cache::LruCache;
+    ///
+    /// let cache: LruCache<i32, i32> = LruCache::new(10);
+    /// assert_eq!(cache.capacity(), 10);
+    /// ```
+    #[unstable(feature = "lru_cache", issue = "0")]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of entries in the cache.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(lru_cache)]
+    /// use std::collections::lru_cache::LruCache;
+    ///
+    /// let mut cache = LruCache::new(10);
+    /// cache.insert("a", 1);
+    /// cache.insert("a", 2);
+    /// assert_eq!(cache.len(), 1);
+    /// ```
+    #[unstable(feature = "lru_cache", issue = "0")]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the cache contains no entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(lru_cache)]
+    /// use std::collections::lru_cache::LruCache;
+    ///
+    /// let mut cache = LruCache::new(10);
+    /// assert!(cache.is_empty());
+    /// cache.insert("a", 1);
+    /// assert!(!cache.is_empty());
+    /// ```
+    #[unstable(feature = "lru_cache", issue = "0")]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Removes all entries from the cache.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(lru_cache)]
+    /// use std::collections::lru_cache::LruCache;
+    ///
+    /// let mut cache = LruCache::new(10);
+    /// cache.insert("a", 1);
+    /// cache.clear();
+    /// assert!(cache.is_empty());
+    /// ```
+    #[unstable(feature = "lru_cache", issue = "0")]
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.order.clear();
+    }
+
+    /// Returns an iterator visiting all entries from the least to the most
+    /// recently used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(lru_cache)]
+    /// use std::collections::lru_cache::LruCache;
+    ///
+    /// let mut cache = LruCache::new(10);
+    /// cache.insert("a", 1);
+    /// cache.insert("b", 2);
+    /// cache.get("a");
+    ///
+    /// let entries: Vec<_> = cache.iter().collect();
+    /// assert_eq!(entries, [(&"b", &2), (&"a", &1)]);
+    /// ```
+    #[unstable(feature = "lru_cache", issue = "0")]
+    pub fn iter(&self) -> Iter<K, V, S> {
+        Iter {
+            order: self.order.iter(),
+            map: &self.map,
+            remaining: self.map.len(),
+        }
+    }
+}
+
+#[unstable(feature = "lru_cache", issue = "0")]
+impl<K, V, S> Debug for LruCache<K, V, S>
+    where K: Hash + Eq + Debug,
+          V: Debug,
+          S: BuildHasher
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[unstable(feature = "lru_cache", issue = "0")]
+impl<K, V, S> Extend<(K, V)> for LruCache<K, V, S>
+    where K: Hash + Eq + Clone,
+          S: BuildHasher
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+#[unstable(feature = "lru_cache", issue = "0")]
+impl<'a, K, V, S> IntoIterator for &'a LruCache<K, V, S>
+    where K: Hash + Eq,
+          S: BuildHasher
+{
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V, S>;
+
+    fn into_iter(self) -> Iter<'a, K, V, S> {
+        self.iter()
+    }
+}
+
+/// A view into a single entry in an `LruCache`, which may either be vacant
+/// or occupied.
+///
+/// This `enum` is constructed from the [`entry`] method on [`LruCache`].
+///
+/// [`entry`]: struct.LruCache.html#method.entry
+/// [`LruCache`]: struct.LruCache.html
+#[unstable(feature = "lru_cache", issue = "0")]
+pub enum Entry<'a, K: 'a, V: 'a, S: 'a> {
+    /// An occupied entry.
+    #[unstable(feature = "lru_cache", issue = "0")]
+    Occupied(#[unstable(feature = "lru_cache", issue = "0")] OccupiedEntry<'a, K, V>),
+
+    /// A vacant entry.
+    #[unstable(feature = "lru_cache", issue = "0")]
+    Vacant(#[unstable(feature = "lru_cache", issue = "0")] VacantEntry<'a, K, V, S>),
+}
+
+#[unstable(feature = "lru_cache", issue = "0")]
+impl<'a, K: 'a + Debug, V: 'a + Debug, S: 'a> Debug for Entry<'a, K, V, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Entry::Vacant(ref v) => f.debug_tuple("Entry").field(v).finish(),
+            Entry::Occupied(ref o) => f.debug_tuple("Entry").field(o).finish(),
+        }
+    }
+}
+
+/// A view into an occupied entry in an `LruCache`, which has already been
+/// marked as the most recently used one. It is part of the [`Entry`] enum.
+///
+/// [`Entry`]: enum.Entry.html
+#[unstable(feature = "lru_cache", issue = "0")]
+pub struct OccupiedEntry<'a, K: 'a, V: 'a> {
+    inner: map::OccupiedEntry<'a, K, (V, u64)>,
+}
+
+#[unstable(feature = "lru_cache", issue = "0")]
+impl<'a, K: 'a + Debug, V: 'a + Debug> Debug for OccupiedEntry<'a, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OccupiedEntry")
+            .field("key", self.key())
+            .field("value", self.get())
+            .finish()
+    }
+}
+
+/// A view into a vacant entry in an `LruCache`. It is part of the [`Entry`]
+/// enum.
+///
+/// [`Entry`]: enum.Entry.html
+#[unstable(feature = "lru_cache", issue = "0")]
+pub struct VacantEntry<'a, K: 'a, V: 'a, S: 'a> {
+    key: K,
+    cache: &'a mut LruCache<K, V, S>,
+}
+
+#[unstable(feature = "lru_cache", issue = "0")]
+impl<'a, K: 'a + Debug, V: 'a, S: 'a> Debug for VacantEntry<'a, K, V, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("VacantEntry")
+            .field(self.key())
+            .finish()
+    }
+}
+
+impl<'a, K, V, S> Entry<'a, K, V, S>
+    where K: Hash + Eq + Clone,
+          S: BuildHasher
+{
+    /// Ensures a value is in the entry by inserting the default if empty,
+    /// and returns a mutable reference to the value in the entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(lru_cache)]
+    /// use std::collections::lru_cache::LruCache;
+    ///
+    /// let mut cache = LruCache::new(10);
+    /// *cache.entry("a").or_insert(3) *= 2;
+    /// assert_eq!(cache.peek("a"), Some(&6));
+    /// ```
+    #[unstable(feature = "lru_cache", issue = "0")]
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting the result of the
+    /// default function if empty, and returns a mutable reference to the
+    /// value in the entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(lru_cache)]
+    /// use std::collections::lru_cache::LruCache;
+    ///
+    /// let mut cache = LruCache::new(10);
+    /// let s = "hoho".to_string();
+    /// cache.entry("a").or_insert_with(|| s);
+    /// assert_eq!(cache.peek("a").map(|s| &s[..]), Some("hoho"));
+    /// ```
+    #[unstable(feature = "lru_cache", issue = "0")]
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Returns a reference to this entry's key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(lru_cache)]
+    /// use std::collections::lru_cache::LruCache;
+    ///
+    /// let mut cache: LruCache<&str, u32> = LruCache::new(10);
+    /// assert_eq!(cache.entry("a").key(), &"a");
+    /// ```
+    #[unstable(feature = "lru_cache", issue = "0")]
+    pub fn key(&self) -> &K {
+        match *self {
+            Entry::Occupied(ref entry) => entry.key(),
+            Entry::Vacant(ref entry) => entry.key(),
+        }
+    }
+}
+
+impl<'a, K: 'a, V: 'a> OccupiedEntry<'a, K, V> {
+    /// Gets a reference to the key in the entry.
+    #[unstable(feature = "lru_cache", issue = "0")]
+    pub fn key(&self) -> &K {
+        self.inner.key()
+    }
+
+    /// Gets a reference to the value in the entry.
+    #[unstable(feature = "lru_cache", issue = "0")]
+    pub fn get(&self) -> &V {
+        &self.inner.get().0
+    }
+
+    /// Gets a mutable reference to the value in the entry.
+    #[unstable(feature = "lru_cache", issue = "0")]
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.inner.get_mut().0
+    }
+
+    /// Converts the entry into a mutable reference to its value, which
+    /// lives as long as the borrow of the cache.
+    #[unstable(feature = "lru_cache", issue = "0")]
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.inner.into_mut().0
+    }
+
+    /// Sets the value of the entry, and returns the entry's old value.
+    #[unstable(feature = "lru_cache", issue = "0")]
+    pub fn insert(&mut self, value: V) -> V {
+        mem::replace(self.get_mut(), value)
+    }
+
+    /// Takes the key and value of the entry out of the cache, and returns
+    /// them.
+    #[unstable(feature = "lru_cache", issue = "0")]
+    pub fn remove_entry(self) -> (K, V) {
+        let (key, (value, _)) = self.inner.remove_entry();
+        (key, value)
+    }
+
+    /// Takes the value of the entry out of the cache, and returns it.
+    #[unstable(feature = "lru_cache", issue = "0")]
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+}
+
+impl<'a, K: 'a, V: 'a, S: 'a> VacantEntry<'a, K, V, S> {
+    /// Gets a reference to the key that would be used when inserting a
+    /// value through the `VacantEntry`.
+    #[unstable(feature = "lru_cache", issue = "0")]
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Takes ownership of the key.
+    #[unstable(feature = "lru_cache", issue = "0")]
+    pub fn into_key(self) -> K {
+        self.key
+    }
+}
+
+impl<'a, K, V, S> VacantEntry<'a, K, V, S>
+    where K: Hash + Eq + Clone,
+          S: BuildHasher
+{
+    /// Sets the value of the entry as the most recently used one, and
+    /// returns a mutable reference to it. If the cache is full, the least
+    /// recently used entry is evicted first.
+    #[unstable(feature = "lru_cache", issue = "0")]
+    pub fn insert(self, value: V) -> &'a mut V {
+        let cache = self.cache;
+        if cache.map.len() >= cache.capacity {
+            cache.pop_lru();
+        }
+        let seq = cache.next_seq();
+        cache.order.push_back((self.key.clone(), seq));
+        match cache.map.entry(self.key) {
+            map::Entry::Vacant(inner) => &mut inner.insert((value, seq)).0,
+            map::Entry::Occupied(_) => unreachable!(),
+        }
+    }
+}
+
+/// An iterator over the entries of an `LruCache`, least recently used
+/// first.
+///
+/// This `struct` is created by the [`iter`] method on [`LruCache`].
+/// See its documentation for more.
+///
+/// [`iter`]: struct.LruCache.html#method.iter
+/// [`LruCache`]: struct.LruCache.html
+#[unstable(feature = "lru_cache", issue = "0")]
+pub struct Iter<'a, K: 'a, V: 'a, S: 'a> {
+    order: vec_deque::Iter<'a, (K, u64)>,
+    map: &'a HashMap<K, (V, u64), S>,
+    remaining: usize,
+}
+
+// FIXME(#26925) Remove in favor of `#[derive(Clone)]`
+#[unstable(feature = "lru_cache", issue = "0")]
+impl<'a, K, V, S> Clone for Iter<'a, K, V, S> {
+    fn clone(&self) -> Iter<'a, K, V, S> {
+        Iter {
+            order: self.order.clone(),
+            map: self.map,
+            remaining: self.remaining,
+        }
+    }
+}
+
+#[unstable(feature = "lru_cache", issue = "0")]
+impl<'a, K, V, S> Debug for Iter<'a, K, V, S>
+    where K: Hash + Eq + Debug,
+          V: Debug,
+          S: BuildHasher
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+#[unstable(feature = "lru_cache", issue = "0")]
+impl<'a, K, V, S> Iterator for Iter<'a, K, V, S>
+    where K: Hash + Eq,
+          S: BuildHasher
+{
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        let map = self.map;
+        for &(ref key, seq) in &mut self.order {
+            match map.get(key) {
+                Some(&(ref value, s)) if s == seq => {
+                    self.remaining -= 1;
+                    return Some((key, value));
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+#[unstable(feature = "lru_cache", issue = "0")]
+impl<'a, K, V, S> ExactSizeIterator for Iter<'a, K, V, S>
+    where K: Hash + Eq,
+          S: BuildHasher
+{
+    fn len(&self) -> usize {
+        self.remaining
+    }
+}
+
+#[unstable(feature = "lru_cache", issue = "0")]
+impl<'a, K, V, S> FusedIterator for Iter<'a, K, V, S>
+    where K: Hash + Eq,
+          S: BuildHasher
+{}
+
+#[cfg(test)]
+mod test_lru_cache {
+    use super::{Entry, LruCache};
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let mut cache = LruCache::new(3);
+        for i in 0..3 {
+            cache.insert(i, i * 10);
+        }
+        assert_eq!(cache.get(&0), Some(&0));
+        cache.insert(3, 30);
+        assert!(!cache.contains_key(&1));
+
+        assert_eq!(cache.peek(&2), Some(&20));
+        cache.insert(4, 40);
+        assert!(!cache.contains_key(&2));
+
+        let keys: Vec<_> = cache.iter().map(|(&k, _)| k).collect();
+        assert_eq!(keys, [0, 3, 4]);
+        assert_eq!(cache.iter().len(), 3);
+    }
+
+    #[test]
+    fn test_remove_entry() {
+        let mut cache = LruCache::new(3);
+        for key in &["a", "b", "c"] {
+            cache.insert(key.to_string(), key.len());
+        }
+        assert_eq!(cache.remove_entry("b"), Some(("b".to_string(), 1)));
+        assert_eq!(cache.remove_entry("b"), None);
+        assert_eq!(cache.len(), 2);
+
+        assert_eq!(cache.pop_lru(), Some(("a".to_string(), 1)));
+        assert_eq!(cache.pop_lru(), Some(("c".to_string(), 1)));
+        assert_eq!(cache.pop_lru(), None);
+    }
+
+    #[test]
+    fn test_get_or_insert_with() {
+        let mut cache = LruCache::new(2);
+        let mut calls = 0;
+        for &key in &[1, 2, 1, 3, 1, 2] {
+            cache.get_or_insert_with(key, || {
+                calls += 1;
+                key * 100
+            });
+        }
+        // 1 stays cached throughout; 2 is evicted by 3, and 3 by 2.
+        assert_eq!(calls, 4);
+        assert_eq!(cache.peek(&1), Some(&100));
+        assert_eq!(cache.peek(&2), Some(&200));
+        assert!(!cache.contains_key(&3));
+    }
+
+    #[test]
+    fn test_entry() {
+        let mut cache = LruCache::new(2);
+        cache.insert("a", 1);
+        cache.insert("b", 2);
+
+        match cache.entry("a") {
+            Entry::Occupied(mut e) => {
+                assert_eq!(e.insert(10), 1);
+                assert_eq!(e.get(), &10);
+            }
+            Entry::Vacant(_) => panic!("expected an occupied entry"),
+        }
+        // Taking the entry marked "a" as used.
+        match cache.entry("c") {
+            Entry::Occupied(_) => panic!("expected a vacant entry"),
+            Entry::Vacant(e) => {
+                assert_eq!(e.key(), &"c");
+                assert_eq!(*e.insert(3), 3);
+            }
+        }
+        assert!(!cache.contains_key("b"));
+
+        match cache.entry("a") {
+            Entry::Occupied(e) => assert_eq!(e.remove_entry(), ("a", 10)),
+            Entry::Vacant(_) => panic!("expected an occupied entry"),
+        }
+        assert_eq!(cache.len(), 1);
+
+        // A vacant entry that isn't filled in changes nothing.
+        match cache.entry("d") {
+            Entry::Vacant(e) => assert_eq!(e.into_key(), "d"),
+            Entry::Occupied(_) => panic!("expected a vacant entry"),
+        }
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.pop_lru(), Some(("c", 3)));
+        assert_eq!(cache.pop_lru(), None);
+    }
+
+    #[test]
+    fn test_stale_entries_are_compacted() {
+        let mut cache = LruCache::new(10);
+        cache.insert(0, ());
+        cache.insert(1, ());
+        for _ in 0..1000 {
+            cache.get(&1);
+            cache.get(&0);
+        }
+        assert!(cache.order.len() <= 6);
+        let keys: Vec<_> = cache.iter().map(|(&k, _)| k).collect();
+        assert_eq!(keys, [1, 0]);
+
+        for i in 2..1000 {
+            cache.insert(i, ());
+            cache.remove(&i);
+        }
+        assert_eq!(cache.len(), 2);
+        assert!(cache.order.len() <= 6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_capacity() {
+        let _cache: LruCache<i32, i32> = LruCache::new(0);
+    }
+}
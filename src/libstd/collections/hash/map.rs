@@ -224,6 +224,12 @@ const DISPLACEMENT_THRESHOLD: usize = 128;
 // 1. Alfredo Viola (2005). Distributional analysis of Robin Hood linear probing
 //    hashing with buckets.
 
+/// The number of random buckets `sample_entry` probes for an entry before
+/// falling back to picking one by position. A table that has grown to fit
+/// its entries is at least 45% full, so this rarely runs out unless the map
+/// is small or entries have been removed.
+const SAMPLE_PROBES: usize = 32;
+
 /// A hash map implemented with linear probing and Robin Hood bucket stealing.
 ///
 /// By default, `HashMap` uses a hashing algorithm selected to provide
@@ -554,6 +560,20 @@ fn robin_hood<'a, K: 'a, V: 'a>(bucket: FullBucketMut<'a, K, V>,
     }
 }
 
+/// Returns a number below `n`, which must not be 0, drawn uniformly from
+/// the random numbers produced by `rng`.
+fn random_below<R: FnMut() -> u64>(rng: &mut R, n: u64) -> u64 {
+    // Reject the lowest `2^64 % n` values, so that the rest cover every
+    // remainder equally often.
+    let reject = 0u64.wrapping_sub(n) % n;
+    loop {
+        let x = rng();
+        if x >= reject {
+            return x % n;
+        }
+    }
+}
+
 impl<K, V, S> HashMap<K, V, S>
     where K: Eq + Hash,
           S: BuildHasher
@@ -765,6 +785,77 @@ impl<K, V, S> HashMap<K, V, S>
         self.table.capacity()
     }
 
+    /// Returns a histogram of the probe lengths of the entries in the map.
+    ///
+    /// The probe length of an entry is the distance between the bucket its
+    /// hash would ideally place it in and the bucket it actually occupies,
+    /// which is the number of extra buckets a lookup for its key has to
+    /// inspect. The element at index `i` of the returned vector is the number
+    /// of entries with a probe length of `i`; the vector is empty for an
+    /// empty map and otherwise ends with a nonzero count.
+    ///
+    /// A good hash function keeps almost every entry within a few buckets of
+    /// its ideal position. Long tails in this histogram point to a `Hash`
+    /// implementation or `Hasher` that distributes keys poorly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hash_map_diagnostics)]
+    /// use std::collections::HashMap;
+    ///
+    /// let map: HashMap<u32, u32> = (0..100).map(|i| (i, i)).collect();
+    /// let histogram = map.probe_histogram();
+    /// assert_eq!(histogram.iter().sum::<usize>(), map.len());
+    /// assert_eq!(histogram.len(), map.max_probe_length() + 1);
+    /// ```
+    #[unstable(feature = "hash_map_diagnostics", issue = "0")]
+    pub fn probe_histogram(&self) -> Vec<usize> {
+        let mut histogram = Vec::new();
+        if self.table.capacity() == 0 {
+            return histogram;
+        }
+        let mut bucket = Bucket::first(&self.table);
+        for _ in 0..self.raw_capacity() {
+            bucket = match bucket.peek() {
+                Full(full) => {
+                    let displacement = full.displacement();
+                    if histogram.len() <= displacement {
+                        histogram.resize(displacement + 1, 0);
+                    }
+                    histogram[displacement] += 1;
+                    full.into_bucket()
+                }
+                Empty(empty) => empty.into_bucket(),
+            };
+            bucket.next();
+        }
+        histogram
+    }
+
+    /// Returns the longest probe length of any entry in the map, or `0` if
+    /// the map is empty.
+    ///
+    /// See [`probe_histogram`] for what the probe length of an entry is.
+    ///
+    /// [`probe_histogram`]: #method.probe_histogram
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hash_map_diagnostics)]
+    /// use std::collections::HashMap;
+    ///
+    /// let mut map = HashMap::new();
+    /// assert_eq!(map.max_probe_length(), 0);
+    /// map.insert(1, "a");
+    /// assert_eq!(map.max_probe_length(), 0);
+    /// ```
+    #[unstable(feature = "hash_map_diagnostics", issue = "0")]
+    pub fn max_probe_length(&self) -> usize {
+        self.probe_histogram().len().saturating_sub(1)
+    }
+
     /// Reserves capacity for at least `additional` more elements to be inserted
     /// in the `HashMap`. The collection may reserve more space to avoid
     /// frequent reallocations.
@@ -1061,6 +1152,47 @@ impl<K, V, S> HashMap<K, V, S>
         Iter { inner: self.table.iter() }
     }
 
+    /// Resumes an iteration over the map from a [`Checkpoint`] taken from an
+    /// earlier [`iter`], visiting the entries that it had not reached yet.
+    ///
+    /// This lets a very large map be processed a piece at a time, such as
+    /// across turns of an event loop, without holding a borrow of it in
+    /// between or starting over from the beginning each time.
+    ///
+    /// Returns `None` if entries have been inserted into or removed from the
+    /// map since the checkpoint was taken, if the map has been resized, or
+    /// if the checkpoint was taken from a different map. Changing the values
+    /// of existing entries does not affect the checkpoint.
+    ///
+    /// Resuming takes constant time.
+    ///
+    /// [`Checkpoint`]: struct.Checkpoint.html
+    /// [`iter`]: #method.iter
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hash_map_checkpoint)]
+    /// use std::collections::HashMap;
+    ///
+    /// let map: HashMap<u32, u32> = (0..100).map(|i| (i, i * i)).collect();
+    ///
+    /// let mut sum = 0;
+    /// let mut checkpoint = map.iter().checkpoint();
+    /// while checkpoint.remaining() > 0 {
+    ///     // Visit at most ten entries at a time.
+    ///     let mut iter = map.iter_from(checkpoint).unwrap();
+    ///     sum += iter.by_ref().take(10).map(|(_, v)| v).sum::<u32>();
+    ///     checkpoint = iter.checkpoint();
+    /// }
+    /// assert_eq!(sum, map.values().sum());
+    /// ```
+    #[unstable(feature = "hash_map_checkpoint", issue = "0")]
+    pub fn iter_from(&self, checkpoint: Checkpoint) -> Option<Iter<K, V>> {
+        self.table.iter_from(checkpoint.bucket, checkpoint.remaining, checkpoint.version)
+            .map(|inner| Iter { inner })
+    }
+
     /// An iterator visiting all key-value pairs in arbitrary order,
     /// with mutable references to the values.
     /// The iterator element type is `(&'a K, &'a mut V)`.
@@ -1089,6 +1221,60 @@ impl<K, V, S> HashMap<K, V, S>
         IterMut { inner: self.table.iter_mut() }
     }
 
+    /// An iterator visiting all key-value pairs in arbitrary order, yielding
+    /// clones of the keys and values.
+    /// The iterator element type is `(K, V)`.
+    ///
+    /// This is equivalent to `map.iter().map(|(k, v)| (k.clone(), v.clone()))`,
+    /// but the pairs are cloned straight out of the table's buckets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(map_iter_cloned)]
+    /// use std::collections::HashMap;
+    ///
+    /// let mut map = HashMap::new();
+    /// map.insert("a", 1);
+    /// map.insert("b", 2);
+    /// map.insert("c", 3);
+    ///
+    /// let mut pairs: Vec<(&str, i32)> = map.iter_cloned().collect();
+    /// pairs.sort();
+    /// assert_eq!(pairs, [("a", 1), ("b", 2), ("c", 3)]);
+    /// ```
+    #[unstable(feature = "map_iter_cloned", issue = "0")]
+    pub fn iter_cloned(&self) -> IterCloned<K, V>
+        where K: Clone, V: Clone
+    {
+        IterCloned { inner: self.table.iter() }
+    }
+
+    /// Returns an entry of the map chosen uniformly at random, using `rng`
+    /// as a source of random numbers, or `None` if the map is empty.
+    ///
+    /// Probing random buckets until one is full is unbiased, since every
+    /// entry occupies exactly one bucket. After removals the table may be
+    /// sparse, so after a few misses an entry is picked by its position in
+    /// iteration order instead, which is slower but just as fair.
+    pub(super) fn sample_entry<R>(&self, rng: &mut R) -> Option<(&K, &V)>
+        where R: FnMut() -> u64
+    {
+        let len = self.table.size();
+        if len == 0 {
+            return None;
+        }
+        let buckets = self.table.capacity() as u64;
+        for _ in 0..SAMPLE_PROBES {
+            let index = random_below(rng, buckets) as usize;
+            if let Full(bucket) = Bucket::at_index(&self.table, index).peek() {
+                return Some(bucket.into_refs());
+            }
+        }
+        let index = random_below(rng, len as u64) as usize;
+        self.iter().nth(index)
+    }
+
     /// Gets the given key's corresponding entry in the map for in-place manipulation.
     ///
     /// # Examples
@@ -1308,6 +1494,18 @@ impl<K, V, S> HashMap<K, V, S>
         self.search_mut(k).map(|bucket| bucket.into_mut_refs().1)
     }
 
+    /// Returns the stored key and a mutable reference to the value
+    /// corresponding to `k`, with a single lookup.
+    pub(super) fn get_key_value_mut<Q: ?Sized>(&mut self, k: &Q) -> Option<(&K, &mut V)>
+        where K: Borrow<Q>,
+              Q: Hash + Eq
+    {
+        self.search_mut(k).map(|bucket| {
+            let (key, value) = bucket.into_mut_refs();
+            (&*key, value)
+        })
+    }
+
     /// Inserts a key-value pair into the map.
     ///
     /// If the map did not have this key present, [`None`] is returned.
@@ -1340,6 +1538,42 @@ impl<K, V, S> HashMap<K, V, S>
         self.insert_hashed_nocheck(hash, k, v)
     }
 
+    /// Inserts a key-value pair into the map and returns a mutable reference
+    /// to the value in the map.
+    ///
+    /// If the map already had this key present, the old value is dropped and
+    /// replaced. Like [`insert`], this does not update the key.
+    ///
+    /// This is equivalent to calling [`insert`] and then [`get_mut`], without
+    /// looking the key up a second time.
+    ///
+    /// [`insert`]: #method.insert
+    /// [`get_mut`]: #method.get_mut
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(map_insert_get)]
+    /// use std::collections::HashMap;
+    ///
+    /// let mut map = HashMap::new();
+    /// *map.insert_get(37, 1) += 1;
+    /// assert_eq!(map[&37], 2);
+    ///
+    /// assert_eq!(*map.insert_get(37, 10), 10);
+    /// assert_eq!(map[&37], 10);
+    /// ```
+    #[unstable(feature = "map_insert_get", issue = "0")]
+    pub fn insert_get(&mut self, k: K, v: V) -> &mut V {
+        match self.entry(k) {
+            Occupied(mut entry) => {
+                entry.insert(v);
+                entry.into_mut()
+            }
+            Vacant(entry) => entry.insert(v),
+        }
+    }
+
     /// Removes a key from the map, returning the value at the key if the key
     /// was previously in the map.
     ///
@@ -1451,6 +1685,124 @@ impl<K, V, S> HashMap<K, V, S>
             debug_assert!(elems_left == 0 || bucket.index() != start_index);
         }
     }
+
+    /// Creates an iterator which uses a closure to determine if a key-value
+    /// pair should be removed.
+    ///
+    /// If the closure returns `true`, the pair is removed from the map and
+    /// yielded by the iterator. If it returns `false`, the pair stays in the
+    /// map and is not yielded. The closure gets a mutable reference to every
+    /// value, whether or not the pair is removed. Pairs are visited in an
+    /// arbitrary order.
+    ///
+    /// Pairs are only removed as the iterator advances, so the rest of the
+    /// map is never rebuilt. If the iterator is dropped before it is
+    /// exhausted, the remaining pairs that match are still removed. If the
+    /// closure panics, the pair it was called on and all the pairs not yet
+    /// visited stay in the map, and the closure is not called again.
+    ///
+    /// # Examples
+    ///
+    /// Splitting a map into even and odd keys:
+    ///
+    /// ```
+    /// #![feature(extract_if)]
+    /// use std::collections::HashMap;
+    ///
+    /// let mut map: HashMap<i32, i32> = (0..8).map(|x| (x, x * 10)).collect();
+    /// let evens: HashMap<_, _> = map.extract_if(|k, _v| k % 2 == 0).collect();
+    /// let odds = map;
+    ///
+    /// let mut evens = evens.keys().cloned().collect::<Vec<_>>();
+    /// let mut odds = odds.keys().cloned().collect::<Vec<_>>();
+    /// evens.sort();
+    /// odds.sort();
+    ///
+    /// assert_eq!(evens, [0, 2, 4, 6]);
+    /// assert_eq!(odds, [1, 3, 5, 7]);
+    /// ```
+    #[unstable(feature = "extract_if", issue = "0")]
+    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<K, V, F>
+        where F: FnMut(&K, &mut V) -> bool
+    {
+        ExtractIf {
+            inner: self.extract_if_inner(),
+            pred,
+        }
+    }
+
+    pub(super) fn extract_if_inner(&mut self) -> ExtractIfInner<K, V> {
+        let elems_left = self.table.size();
+        let bucket = if elems_left == 0 {
+            None
+        } else {
+            let mut bucket = Bucket::head_bucket(&mut self.table);
+            bucket.prev();
+            Some(bucket)
+        };
+        ExtractIfInner {
+            bucket,
+            elems_left,
+        }
+    }
+
+    /// Feeds an order-independent hash of the map into `state`.
+    ///
+    /// `HashMap` does not implement [`Hash`], because the order it iterates
+    /// in depends on its hasher and its history of insertions. This method
+    /// hashes every key-value pair on its own with [`DefaultHasher::new`],
+    /// which uses fixed keys, and adds up the results with wrapping addition.
+    /// It then feeds the number of entries and that sum into `state`, so two
+    /// maps that compare equal always produce the same hash, whatever their
+    /// hashers.
+    ///
+    /// Since `state` can be any [`Hasher`], this can be called from the
+    /// `Hash` implementation of a type that contains a map.
+    ///
+    /// [`Hash`]: ../../std/hash/trait.Hash.html
+    /// [`Hasher`]: ../../std/hash/trait.Hasher.html
+    /// [`DefaultHasher::new`]: struct.DefaultHasher.html#method.new
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hash_unordered)]
+    ///
+    /// use std::collections::HashMap;
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::{Hash, Hasher};
+    ///
+    /// struct Squares(HashMap<u32, u32>);
+    ///
+    /// impl Hash for Squares {
+    ///     fn hash<H: Hasher>(&self, state: &mut H) {
+    ///         self.0.hash_unordered(state);
+    ///     }
+    /// }
+    ///
+    /// fn fingerprint<T: Hash>(value: &T) -> u64 {
+    ///     let mut hasher = DefaultHasher::new();
+    ///     value.hash(&mut hasher);
+    ///     hasher.finish()
+    /// }
+    ///
+    /// let a = Squares((0..100).map(|i| (i, i * i)).collect());
+    /// let b = Squares((0..100).rev().map(|i| (i, i * i)).collect());
+    /// assert_eq!(fingerprint(&a), fingerprint(&b));
+    /// ```
+    #[unstable(feature = "hash_unordered", issue = "0")]
+    pub fn hash_unordered<H: Hasher>(&self, state: &mut H)
+        where V: Hash
+    {
+        let mut sum = 0u64;
+        for entry in self.iter() {
+            let mut hasher = DefaultHasher::new();
+            entry.hash(&mut hasher);
+            sum = sum.wrapping_add(hasher.finish());
+        }
+        state.write_usize(self.len());
+        state.write_u64(sum);
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -1498,6 +1850,15 @@ impl<K, V, S> Default for HashMap<K, V, S>
     }
 }
 
+// The compiler does not check stability attributes on macros, so `hashmap!`
+// creates its map through this function to require the
+// `collection_macros` feature.
+#[doc(hidden)]
+#[unstable(feature = "collection_macros", issue = "0")]
+pub fn __with_capacity_for_macro<K: Hash + Eq, V>(capacity: usize) -> HashMap<K, V, RandomState> {
+    HashMap::with_capacity(capacity)
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<'a, K, Q: ?Sized, V, S> Index<&'a Q> for HashMap<K, V, S>
     where K: Eq + Hash + Borrow<Q>,
@@ -1546,6 +1907,72 @@ impl<'a, K: Debug, V: Debug> fmt::Debug for Iter<'a, K, V> {
     }
 }
 
+impl<'a, K, V> Iter<'a, K, V> {
+    /// Returns a checkpoint that [`HashMap::iter_from`] can resume this
+    /// iteration from later, once the iterator itself is gone.
+    ///
+    /// [`HashMap::iter_from`]: struct.HashMap.html#method.iter_from
+    #[unstable(feature = "hash_map_checkpoint", issue = "0")]
+    pub fn checkpoint(&self) -> Checkpoint {
+        let (bucket, remaining, version) = self.inner.position();
+        Checkpoint { bucket, remaining, version }
+    }
+}
+
+/// A saved position in the iteration over a `HashMap` or `HashSet`.
+///
+/// This `struct` is created by the `checkpoint` method on the iterators
+/// returned by [`HashMap::iter`] and [`HashSet::iter`]. It does not borrow
+/// the map, and is only meaningful for the map it was taken from.
+///
+/// [`HashMap::iter`]: struct.HashMap.html#method.iter
+/// [`HashSet::iter`]: ../hash_set/struct.HashSet.html#method.iter
+#[unstable(feature = "hash_map_checkpoint", issue = "0")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Checkpoint {
+    bucket: usize,
+    remaining: usize,
+    version: table::Version,
+}
+
+impl Checkpoint {
+    /// Returns the number of entries that iteration from this checkpoint
+    /// has left to visit.
+    #[unstable(feature = "hash_map_checkpoint", issue = "0")]
+    pub fn remaining(&self) -> usize {
+        self.remaining
+    }
+}
+
+/// An iterator over clones of the entries of a `HashMap`.
+///
+/// This `struct` is created by the [`iter_cloned`] method on [`HashMap`]. See
+/// its documentation for more.
+///
+/// [`iter_cloned`]: struct.HashMap.html#method.iter_cloned
+/// [`HashMap`]: struct.HashMap.html
+#[unstable(feature = "map_iter_cloned", issue = "0")]
+pub struct IterCloned<'a, K: 'a, V: 'a> {
+    inner: table::Iter<'a, K, V>,
+}
+
+// FIXME(#26925) Remove in favor of `#[derive(Clone)]`
+#[unstable(feature = "map_iter_cloned", issue = "0")]
+impl<'a, K, V> Clone for IterCloned<'a, K, V> {
+    fn clone(&self) -> IterCloned<'a, K, V> {
+        IterCloned { inner: self.inner.clone() }
+    }
+}
+
+#[unstable(feature = "map_iter_cloned", issue = "0")]
+impl<'a, K: Debug, V: Debug> fmt::Debug for IterCloned<'a, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list()
+            .entries(Iter { inner: self.inner.clone() })
+            .finish()
+    }
+}
+
 /// A mutable iterator over the entries of a `HashMap`.
 ///
 /// This `struct` is created by the [`iter_mut`] method on [`HashMap`]. See its
@@ -1579,7 +2006,7 @@ pub struct IntoIter<K, V> {
 /// [`HashMap`]: struct.HashMap.html
 #[stable(feature = "rust1", since = "1.0.0")]
 pub struct Keys<'a, K: 'a, V: 'a> {
-    inner: Iter<'a, K, V>,
+    pub(super) inner: Iter<'a, K, V>,
 }
 
 // FIXME(#26925) Remove in favor of `#[derive(Clone)]`
@@ -1640,6 +2067,29 @@ pub struct Drain<'a, K: 'a, V: 'a> {
     pub(super) inner: table::Drain<'a, K, V>,
 }
 
+/// An iterator produced by calling `extract_if` on HashMap.
+///
+/// See the [`extract_if`] method on [`HashMap`] for more.
+///
+/// [`extract_if`]: struct.HashMap.html#method.extract_if
+/// [`HashMap`]: struct.HashMap.html
+#[unstable(feature = "extract_if", issue = "0")]
+pub struct ExtractIf<'a, K: 'a, V: 'a, F>
+    where F: FnMut(&K, &mut V) -> bool,
+{
+    inner: ExtractIfInner<'a, K, V>,
+    pred: F,
+}
+
+/// The part of `ExtractIf` that does not depend on the predicate, shared
+/// with `HashSet`.
+pub(super) struct ExtractIfInner<'a, K: 'a, V: 'a> {
+    // The next bucket to look at, or `None` if the table is empty or the
+    // predicate has panicked.
+    bucket: Option<Bucket<K, V, &'a mut RawTable<K, V>>>,
+    elems_left: usize,
+}
+
 /// A mutable iterator over the values of a `HashMap`.
 ///
 /// This `struct` is created by the [`values_mut`] method on [`HashMap`]. See its
@@ -1859,6 +2309,29 @@ impl<'a, K, V> ExactSizeIterator for Iter<'a, K, V> {
 #[stable(feature = "fused", since = "1.26.0")]
 impl<'a, K, V> FusedIterator for Iter<'a, K, V> {}
 
+#[unstable(feature = "map_iter_cloned", issue = "0")]
+impl<'a, K: Clone, V: Clone> Iterator for IterCloned<'a, K, V> {
+    type Item = (K, V);
+
+    #[inline]
+    fn next(&mut self) -> Option<(K, V)> {
+        self.inner.next().map(|(k, v)| (k.clone(), v.clone()))
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+#[unstable(feature = "map_iter_cloned", issue = "0")]
+impl<'a, K: Clone, V: Clone> ExactSizeIterator for IterCloned<'a, K, V> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+#[unstable(feature = "map_iter_cloned", issue = "0")]
+impl<'a, K: Clone, V: Clone> FusedIterator for IterCloned<'a, K, V> {}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<'a, K, V> Iterator for IterMut<'a, K, V> {
     type Item = (&'a K, &'a mut V);
@@ -2042,6 +2515,88 @@ impl<'a, K, V> fmt::Debug for Drain<'a, K, V>
     }
 }
 
+#[unstable(feature = "extract_if", issue = "0")]
+impl<'a, K, V, F> Iterator for ExtractIf<'a, K, V, F>
+    where F: FnMut(&K, &mut V) -> bool,
+{
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<(K, V)> {
+        self.inner.next(&mut self.pred)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[unstable(feature = "extract_if", issue = "0")]
+impl<'a, K, V, F> Drop for ExtractIf<'a, K, V, F>
+    where F: FnMut(&K, &mut V) -> bool,
+{
+    fn drop(&mut self) {
+        for _ in self.by_ref() { }
+    }
+}
+
+#[unstable(feature = "extract_if", issue = "0")]
+impl<'a, K, V, F> fmt::Debug for ExtractIf<'a, K, V, F>
+    where F: FnMut(&K, &mut V) -> bool,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad("ExtractIf { .. }")
+    }
+}
+
+impl<'a, K, V> ExtractIfInner<'a, K, V> {
+    pub(super) fn next<F>(&mut self, pred: &mut F) -> Option<(K, V)>
+        where F: FnMut(&K, &mut V) -> bool
+    {
+        while self.elems_left != 0 {
+            // Taking the bucket out first means that a panicking `pred`
+            // leaves it `None`, which ends the iteration.
+            let mut bucket = self.bucket.take()?;
+            let removed = match bucket.peek() {
+                Full(mut full) => {
+                    self.elems_left -= 1;
+                    let should_remove = {
+                        let (k, v) = full.read_mut();
+                        pred(k, v)
+                    };
+                    if should_remove {
+                        let prev_raw = full.raw();
+                        let (k, v, t) = pop_internal(full);
+                        bucket = Bucket::new_from(prev_raw, t);
+                        Some((k, v))
+                    } else {
+                        bucket = full.into_bucket();
+                        None
+                    }
+                },
+                Empty(b) => {
+                    bucket = b.into_bucket();
+                    None
+                }
+            };
+            // Walk backwards, as `retain` does, so that the elements shifted
+            // back by a removal have all been looked at already.
+            bucket.prev();
+            self.bucket = Some(bucket);
+            if removed.is_some() {
+                return removed;
+            }
+        }
+        None
+    }
+
+    pub(super) fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.bucket {
+            Some(_) => (0, Some(self.elems_left)),
+            None => (0, Some(0)),
+        }
+    }
+}
+
 impl<'a, K, V> Entry<'a, K, V> {
     #[stable(feature = "rust1", since = "1.0.0")]
     /// Ensures a value is in the entry by inserting the default if empty, and returns
@@ -2786,6 +3341,18 @@ mod test_map {
         assert_eq!(*m.get(&2).unwrap(), 4);
     }
 
+    #[test]
+    fn test_insert_get() {
+        let mut m = HashMap::new();
+        *m.insert_get(1, 2) += 1;
+        assert_eq!(m.len(), 1);
+        assert_eq!(m[&1], 3);
+
+        assert_eq!(*m.insert_get(1, 5), 5);
+        assert_eq!(m.len(), 1);
+        assert_eq!(m[&1], 5);
+    }
+
     #[test]
     fn test_clone() {
         let mut m = HashMap::new();
@@ -3142,6 +3709,21 @@ mod test_map {
         assert_eq!(observed, 0xFFFF_FFFF);
     }
 
+    #[test]
+    fn test_iter_cloned() {
+        let m: HashMap<_, _> = (0..32).map(|i| (i, i.to_string())).collect();
+        let mut iter = m.iter_cloned();
+        assert_eq!(iter.len(), 32);
+        let first = iter.next().unwrap();
+        assert_eq!(iter.len(), 31);
+
+        let mut pairs: Vec<_> = iter.collect();
+        pairs.push(first);
+        pairs.sort();
+        let expected: Vec<_> = (0..32).map(|i| (i, i.to_string())).collect();
+        assert_eq!(pairs, expected);
+    }
+
     #[test]
     fn test_keys() {
         let vec = vec![(1, 'a'), (2, 'b'), (3, 'c')];
@@ -3521,6 +4103,46 @@ mod test_map {
         assert_eq!(a[&3], "three");
     }
 
+    #[test]
+    fn test_probe_histogram() {
+        let mut m = HashMap::new();
+        assert!(m.probe_histogram().is_empty());
+        assert_eq!(m.max_probe_length(), 0);
+
+        m.insert(1, 1);
+        assert_eq!(m.probe_histogram(), [1]);
+        assert_eq!(m.max_probe_length(), 0);
+
+        for i in 2..1000 {
+            m.insert(i, i);
+        }
+        let histogram = m.probe_histogram();
+        assert_eq!(histogram.iter().sum::<usize>(), 1000);
+        assert!(*histogram.last().unwrap() > 0);
+        assert_eq!(m.max_probe_length(), histogram.len() - 1);
+    }
+
+    #[test]
+    fn test_probe_histogram_bad_hasher() {
+        use hash::{BuildHasherDefault, Hasher};
+
+        // Sends every key to the same bucket, so the n-th key inserted
+        // ends up n buckets away from it.
+        #[derive(Default)]
+        struct Constant;
+        impl Hasher for Constant {
+            fn finish(&self) -> u64 { 0 }
+            fn write(&mut self, _: &[u8]) {}
+        }
+
+        let mut m: HashMap<u32, (), BuildHasherDefault<Constant>> = HashMap::default();
+        for i in 0..10 {
+            m.insert(i, ());
+        }
+        assert_eq!(m.probe_histogram(), [1; 10]);
+        assert_eq!(m.max_probe_length(), 9);
+    }
+
     #[test]
     fn test_capacity_not_less_than_len() {
         let mut a = HashMap::new();
@@ -3593,6 +4215,50 @@ mod test_map {
         assert_eq!(map[&6], 60);
     }
 
+    #[test]
+    fn test_extract_if() {
+        let mut map: HashMap<i32, i32> = (0..100).map(|x|(x, x*10)).collect();
+
+        let mut evens: Vec<_> = map.extract_if(|&k, v| { *v += 1; k % 2 == 0 }).collect();
+        evens.sort();
+        assert_eq!(evens, (0..50).map(|x| (x * 2, x * 20 + 1)).collect::<Vec<_>>());
+        assert_eq!(map.len(), 50);
+        assert_eq!(map[&1], 11);
+        assert_eq!(map[&3], 31);
+        assert!(!map.contains_key(&2));
+
+        // Dropping the iterator removes the rest of the matching pairs.
+        map.extract_if(|&k, _| k < 51).next();
+        assert_eq!(map.len(), 25);
+        assert!(map.keys().all(|&k| k > 50));
+    }
+
+    #[test]
+    fn test_extract_if_pred_panic() {
+        use panic;
+
+        let mut map: HashMap<i32, i32> = (0..100).map(|x|(x, x*10)).collect();
+        let mut calls = 0;
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            for _ in map.extract_if(|_, _| {
+                calls += 1;
+                if calls == 30 {
+                    panic!("panic in predicate");
+                }
+                true
+            }) {}
+        }));
+        assert!(result.is_err());
+
+        // The 29 pairs that were yielded are gone, the rest are untouched,
+        // and the predicate was not called again while dropping.
+        assert_eq!(calls, 30);
+        assert_eq!(map.len(), 71);
+        for (&k, &v) in &map {
+            assert_eq!(v, k * 10);
+        }
+    }
+
     #[test]
     fn test_adaptive() {
         const TEST_LEN: usize = 5000;
@@ -3641,4 +4307,102 @@ mod test_map {
         }
     }
 
+    #[test]
+    fn test_hashmap_macro() {
+        let empty: HashMap<i32, i32> = hashmap!{};
+        assert!(empty.is_empty());
+
+        let map = hashmap! {
+            1 => "a",
+            2 => "b",
+            1 => "c",
+        };
+        assert_eq!(map.len(), 2);
+        assert_eq!(map[&1], "c");
+        assert_eq!(map[&2], "b");
+        assert!(map.capacity() >= 3);
+    }
+
+    #[test]
+    fn test_iter_checkpoint() {
+        let map: HashMap<i32, i32> = (0..1000).map(|i| (i, -i)).collect();
+        let mut seen = Vec::new();
+        let mut checkpoint = map.iter().checkpoint();
+        assert_eq!(checkpoint.remaining(), 1000);
+        loop {
+            let mut iter = map.iter_from(checkpoint).unwrap();
+            let before = seen.len();
+            seen.extend(iter.by_ref().take(7).map(|(&k, &v)| {
+                assert_eq!(k, -v);
+                k
+            }));
+            checkpoint = iter.checkpoint();
+            assert_eq!(checkpoint.remaining(), 1000 - seen.len());
+            if seen.len() == before {
+                break;
+            }
+        }
+        seen.sort();
+        assert_eq!(seen, (0..1000).collect::<Vec<_>>());
+
+        let empty: HashMap<i32, i32> = HashMap::new();
+        assert_eq!(empty.iter_from(empty.iter().checkpoint()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_iter_checkpoint_after_insert() {
+        let mut map: HashMap<i32, i32> = (0..10).map(|i| (i, i)).collect();
+        let checkpoint = map.iter().checkpoint();
+        map.insert(10, 10);
+        assert!(map.iter_from(checkpoint).is_none());
+        assert_eq!(map.iter_from(map.iter().checkpoint()).unwrap().count(), 11);
+
+        // An insert and a remove leave the length the same, but may still
+        // have moved entries around.
+        let checkpoint = map.iter().checkpoint();
+        map.insert(11, 11);
+        map.remove(&11);
+        assert!(map.iter_from(checkpoint).is_none());
+
+        // Changing values doesn't move any entries.
+        let checkpoint = map.iter().checkpoint();
+        *map.get_mut(&0).unwrap() = 100;
+        map.insert(1, 101);
+        assert_eq!(map.iter_from(checkpoint).unwrap().count(), 11);
+
+        // Neither does a checkpoint carry over to another map, even an
+        // identical one.
+        let other = map.clone();
+        assert!(other.iter_from(checkpoint).is_none());
+        map.clear();
+        assert!(map.iter_from(checkpoint).is_none());
+    }
+
+    #[test]
+    fn test_hash_unordered() {
+        use hash::Hasher;
+        use super::DefaultHasher;
+
+        fn fingerprint(map: &HashMap<String, i32>) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            map.hash_unordered(&mut hasher);
+            hasher.finish()
+        }
+
+        let mut a = HashMap::new();
+        let mut b = HashMap::with_capacity(1000);
+        for i in 0..100 {
+            a.insert(i.to_string(), i);
+            b.insert((99 - i).to_string(), 99 - i);
+        }
+        assert_eq!(fingerprint(&a), fingerprint(&b));
+
+        b.insert("5".to_string(), 6);
+        assert!(fingerprint(&a) != fingerprint(&b));
+        b.insert("5".to_string(), 5);
+        b.insert("100".to_string(), 100);
+        assert!(fingerprint(&a) != fingerprint(&b));
+
+        assert!(fingerprint(&HashMap::new()) != fingerprint(&a));
+    }
 }
@@ -12,7 +12,11 @@
 
 mod bench;
 mod table;
+pub mod expiring;
+pub mod lru;
 pub mod map;
+pub mod perfect;
+pub mod priority;
 pub mod set;
 
 trait Recover<Q: ?Sized> {
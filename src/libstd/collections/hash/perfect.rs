@@ -0,0 +1,904 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A read-only map over a key set that is fixed at construction time.
+//!
+//! The map is built with the "hash, displace and compress" (CHD) algorithm:
+//! keys are first hashed into small buckets, and then each bucket is assigned
+//! a pair of displacement values such that every key lands in its own slot
+//! of a table that is exactly as large as the number of keys. Lookups hash
+//! the key once, read one displacement pair and compare a single key.
+
+use borrow::Borrow;
+use fmt;
+use hash::{Hash, Hasher};
+#[allow(deprecated)]
+use hash::SipHasher13;
+use iter::{FromIterator, FusedIterator};
+use ops::Index;
+use slice;
+use vec;
+
+/// Average number of keys per displacement bucket.
+const LAMBDA: usize = 5;
+
+/// Seed for the first construction attempt; later attempts derive new keys
+/// from it so that construction is deterministic for a given input.
+const INITIAL_SEED: u64 = 0x243f_6a88_85a3_08d3;
+
+/// Number of seeds tried before giving up on construction. A seed fails
+/// with a very small probability, so running out of seeds means that the
+/// keys' hashes don't depend on the seed.
+const MAX_SEEDS: usize = 32;
+
+/// The three values derived from a key's hash: the displacement bucket and
+/// the two functions combined with that bucket's displacements.
+#[derive(PartialEq, Eq)]
+struct Hashes {
+    g: u32,
+    f1: u32,
+    f2: u32,
+}
+
+#[allow(deprecated)]
+fn hash<Q: ?Sized + Hash>(seed: u64, key: &Q) -> Hashes {
+    let mut state = SipHasher13::new_with_keys(seed, seed.rotate_left(32) ^ !0);
+    key.hash(&mut state);
+    let h = state.finish();
+    Hashes {
+        g: (h >> 32) as u32,
+        f1: h as u32,
+        f2: (h.wrapping_mul(0x9e37_79b9_7f4a_7c15) >> 32) as u32,
+    }
+}
+
+#[inline]
+fn displace(f1: u32, f2: u32, d1: u32, d2: u32) -> u32 {
+    d2.wrapping_add(f1.wrapping_mul(d1)).wrapping_add(f2)
+}
+
+/// A map with a fixed set of keys and guaranteed collision-free lookups.
+///
+/// A `PerfectHashMap` is built once, either with a [`PerfectHashMapBuilder`]
+/// or by collecting an iterator of key-value pairs, and cannot have keys
+/// added or removed afterwards. In exchange every lookup costs exactly one
+/// hash computation and at most one key comparison, and the map stores
+/// nothing besides the entries themselves and two `u32`s for every five
+/// keys.
+///
+/// This makes it a good fit for keyword tables, opcode maps and other
+/// lookup tables whose contents are known up front.
+///
+/// Construction is deterministic: the same keys inserted in the same order
+/// always produce the same layout. It is expected to take linear time, but
+/// unlike [`HashMap`] the hash seed is not random, so a `PerfectHashMap`
+/// should not be built from keys chosen by an adversary.
+///
+/// [`PerfectHashMapBuilder`]: struct.PerfectHashMapBuilder.html
+/// [`HashMap`]: ../hash_map/struct.HashMap.html
+///
+/// # Examples
+///
+/// ```
+/// #![feature(perfect_hash_map)]
+/// use std::collections::perfect_hash_map::PerfectHashMap;
+///
+/// let keywords: PerfectHashMap<&str, u8> =
+///     vec![("fn", 0), ("let", 1), ("match", 2), ("loop", 3)].into_iter().collect();
+///
+/// assert_eq!(keywords.len(), 4);
+/// assert_eq!(keywords.get("match"), Some(&2));
+/// assert_eq!(keywords.get("while"), None);
+/// assert_eq!(keywords["fn"], 0);
+/// ```
+#[derive(Clone)]
+#[unstable(feature = "perfect_hash_map", issue = "0")]
+pub struct PerfectHashMap<K, V> {
+    seed: u64,
+    disps: Box<[(u32, u32)]>,
+    entries: Box<[(K, V)]>,
+}
+
+/// A builder for [`PerfectHashMap`].
+///
+/// Entries are collected with [`insert`] and the perfect hash is computed
+/// once by [`build`].
+///
+/// [`PerfectHashMap`]: struct.PerfectHashMap.html
+/// [`insert`]: #method.insert
+/// [`build`]: #method.build
+///
+/// # Examples
+///
+/// ```
+/// #![feature(perfect_hash_map)]
+/// use std::collections::perfect_hash_map::PerfectHashMapBuilder;
+///
+/// let mut builder = PerfectHashMapBuilder::new();
+/// builder.insert("add", 0x01).insert("sub", 0x02).insert("mul", 0x03);
+/// let opcodes = builder.build();
+///
+/// assert_eq!(opcodes.get("sub"), Some(&0x02));
+/// ```
+#[derive(Clone, Debug)]
+#[unstable(feature = "perfect_hash_map", issue = "0")]
+pub struct PerfectHashMapBuilder<K, V> {
+    entries: Vec<(K, V)>,
+}
+
+impl<K: Hash + Eq, V> PerfectHashMapBuilder<K, V> {
+    /// Creates an empty `PerfectHashMapBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(perfect_hash_map)]
+    /// use std::collections::perfect_hash_map::PerfectHashMapBuilder;
+    ///
+    /// let builder: PerfectHashMapBuilder<&str, i32> = PerfectHashMapBuilder::new();
+    /// assert!(builder.build().is_empty());
+    /// ```
+    #[unstable(feature = "perfect_hash_map", issue = "0")]
+    pub fn new() -> PerfectHashMapBuilder<K, V> {
+        PerfectHashMapBuilder { entries: Vec::new() }
+    }
+
+    /// Creates an empty `PerfectHashMapBuilder` with space for at least
+    /// `capacity` entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(perfect_hash_map)]
+    /// use std::collections::perfect_hash_map::PerfectHashMapBuilder;
+    ///
+    /// let mut builder = PerfectHashMapBuilder::with_capacity(2);
+    /// builder.insert(1, "a").insert(2, "b");
+    /// assert_eq!(builder.build().len(), 2);
+    /// ```
+    #[unstable(feature = "perfect_hash_map", issue = "0")]
+    pub fn with_capacity(capacity: usize) -> PerfectHashMapBuilder<K, V> {
+        PerfectHashMapBuilder { entries: Vec::with_capacity(capacity) }
+    }
+
+    /// Adds an entry to the map being built.
+    ///
+    /// If the same key is inserted more than once, the value inserted last
+    /// is the one kept in the finished map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(perfect_hash_map)]
+    /// use std::collections::perfect_hash_map::PerfectHashMapBuilder;
+    ///
+    /// let mut builder = PerfectHashMapBuilder::new();
+    /// builder.insert("a", 1).insert("a", 2);
+    /// let map = builder.build();
+    /// assert_eq!(map.len(), 1);
+    /// assert_eq!(map["a"], 2);
+    /// ```
+    #[unstable(feature = "perfect_hash_map", issue = "0")]
+    pub fn insert(&mut self, key: K, value: V) -> &mut PerfectHashMapBuilder<K, V> {
+        self.entries.push((key, value));
+        self
+    }
+
+    /// Computes the perfect hash for the inserted keys and returns the
+    /// finished map.
+    ///
+    /// # Panics
+    ///
+    /// Panics if two keys that are not equal hash identically, which happens
+    /// when their `Hash` implementation feeds the same data to the hasher
+    /// for both. No seed can tell such keys apart.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(perfect_hash_map)]
+    /// use std::collections::perfect_hash_map::PerfectHashMapBuilder;
+    ///
+    /// let mut builder = PerfectHashMapBuilder::new();
+    /// for i in 0..100 {
+    ///     builder.insert(i, i * i);
+    /// }
+    /// let squares = builder.build();
+    /// assert_eq!(squares[&9], 81);
+    /// ```
+    #[unstable(feature = "perfect_hash_map", issue = "0")]
+    pub fn build(self) -> PerfectHashMap<K, V> {
+        let mut seed = INITIAL_SEED;
+        let mut entries = self.entries;
+        let mut seeds_tried = 0;
+        loop {
+            let hashes: Vec<Hashes> = entries.iter().map(|&(ref k, _)| hash(seed, k)).collect();
+            let buckets = group_into_buckets(&entries, &hashes);
+
+            // Drop all but the last insertion of every duplicated key, and
+            // look for unequal keys with identical hashes, which no choice of
+            // displacements can separate. Keys with the same hashes always
+            // share a bucket, so this only compares bucket mates.
+            let mut keep = vec![true; entries.len()];
+            let mut any_duplicates = false;
+            let mut any_collisions = false;
+            for bucket in &buckets {
+                for (i, &a) in bucket.iter().enumerate() {
+                    for &b in &bucket[i + 1..] {
+                        if entries[a].0 == entries[b].0 {
+                            keep[a] = false;
+                            any_duplicates = true;
+                        } else if hashes[a] == hashes[b] {
+                            any_collisions = true;
+                        }
+                    }
+                }
+            }
+            if any_duplicates {
+                let mut keep = keep.into_iter();
+                entries.retain(|_| keep.next().unwrap());
+                continue;
+            }
+
+            if !any_collisions {
+                if let Some(disps) = try_displace(&hashes, buckets) {
+                    return PerfectHashMap::from_parts(seed, disps, entries, &hashes);
+                }
+            }
+
+            seeds_tried += 1;
+            if seeds_tried == MAX_SEEDS {
+                if any_collisions {
+                    panic!("PerfectHashMapBuilder::build: keys that are not equal have \
+                            identical hashes under every seed; their `Hash` implementation \
+                            must feed different data to the hasher for unequal keys");
+                }
+                panic!("PerfectHashMapBuilder::build: no perfect hash found after {} seeds",
+                       MAX_SEEDS);
+            }
+            seed = seed.wrapping_mul(0x5851_f42d_4c95_7f2d).wrapping_add(0x1405_7b7e_f767_814f);
+        }
+    }
+}
+
+/// Groups the indices of `entries` by displacement bucket, with the largest
+/// buckets first so that they are placed while the table is still empty.
+fn group_into_buckets<K, V>(entries: &[(K, V)], hashes: &[Hashes]) -> Vec<Vec<usize>> {
+    let num_buckets = (entries.len() + LAMBDA - 1) / LAMBDA;
+    let mut buckets = vec![Vec::new(); num_buckets];
+    for (i, h) in hashes.iter().enumerate() {
+        buckets[h.g as usize % num_buckets].push(i);
+    }
+    buckets
+}
+
+/// Tries to find displacements that send every key to a distinct slot,
+/// returning `None` if some bucket cannot be placed with the current seed.
+fn try_displace(hashes: &[Hashes], buckets: Vec<Vec<usize>>) -> Option<Box<[(u32, u32)]>> {
+    let table_len = hashes.len();
+    let mut disps = vec![(0, 0); buckets.len()];
+
+    let mut order: Vec<usize> = (0..buckets.len()).collect();
+    order.sort_by(|&a, &b| buckets[b].len().cmp(&buckets[a].len()));
+
+    // `generation` marks which slots were claimed by the attempt currently
+    // in progress, so a failed attempt doesn't have to be undone.
+    let mut slots: Vec<Option<usize>> = vec![None; table_len];
+    let mut try_map = vec![0u64; table_len];
+    let mut generation = 0u64;
+    let mut claimed = Vec::new();
+
+    'buckets: for &b in &order {
+        let bucket = &buckets[b];
+        if bucket.is_empty() {
+            continue;
+        }
+        for d1 in 0..table_len as u32 {
+            'disps: for d2 in 0..table_len as u32 {
+                generation += 1;
+                claimed.clear();
+                for &i in bucket {
+                    let h = &hashes[i];
+                    let idx = displace(h.f1, h.f2, d1, d2) as usize % table_len;
+                    if slots[idx].is_some() || try_map[idx] == generation {
+                        continue 'disps;
+                    }
+                    try_map[idx] = generation;
+                    claimed.push((idx, i));
+                }
+                for &(idx, i) in &claimed {
+                    slots[idx] = Some(i);
+                }
+                disps[b] = (d1, d2);
+                continue 'buckets;
+            }
+        }
+        return None;
+    }
+
+    Some(disps.into_boxed_slice())
+}
+
+impl<K: Hash + Eq, V> PerfectHashMap<K, V> {
+    fn from_parts(seed: u64,
+                  disps: Box<[(u32, u32)]>,
+                  entries: Vec<(K, V)>,
+                  hashes: &[Hashes])
+                  -> PerfectHashMap<K, V> {
+        let len = entries.len();
+        let mut slots: Vec<Option<(K, V)>> = entries.into_iter().map(Some).collect();
+        let mut order = vec![0; len];
+        for (i, h) in hashes.iter().enumerate() {
+            let (d1, d2) = disps[h.g as usize % disps.len()];
+            order[displace(h.f1, h.f2, d1, d2) as usize % len] = i;
+        }
+        let entries = order.into_iter()
+                           .map(|i| slots[i].take().unwrap())
+                           .collect::<Vec<_>>()
+                           .into_boxed_slice();
+        PerfectHashMap { seed, disps, entries }
+    }
+
+    /// Returns the slot that `key` would occupy, if the map is not empty.
+    fn index_of<Q: ?Sized>(&self, key: &Q) -> Option<usize>
+        where K: Borrow<Q>,
+              Q: Hash + Eq
+    {
+        if self.entries.is_empty() {
+            return None;
+        }
+        let h = hash(self.seed, key);
+        let (d1, d2) = self.disps[h.g as usize % self.disps.len()];
+        let idx = displace(h.f1, h.f2, d1, d2) as usize % self.entries.len();
+        if self.entries[idx].0.borrow() == key {
+            Some(idx)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    ///
+    /// The key may be any borrowed form of the map's key type, but
+    /// [`Hash`] and [`Eq`] on the borrowed form *must* match those for
+    /// the key type.
+    ///
+    /// [`Eq`]: ../../std/cmp/trait.Eq.html
+    /// [`Hash`]: ../../std/hash/trait.Hash.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(perfect_hash_map)]
+    /// use std::collections::perfect_hash_map::PerfectHashMap;
+    ///
+    /// let map: PerfectHashMap<_, _> = vec![(1, "a")].into_iter().collect();
+    /// assert_eq!(map.get(&1), Some(&"a"));
+    /// assert_eq!(map.get(&2), None);
+    /// ```
+    #[unstable(feature = "perfect_hash_map", issue = "0")]
+    #[inline]
+    pub fn get<Q: ?Sized>(&self, key: &Q) -> Option<&V>
+        where K: Borrow<Q>,
+              Q: Hash + Eq
+    {
+        self.index_of(key).map(|idx| &self.entries[idx].1)
+    }
+
+    /// Returns `true` if the map contains a value for the specified key.
+    ///
+    /// The key may be any borrowed form of the map's key type, but
+    /// [`Hash`] and [`Eq`] on the borrowed form *must* match those for
+    /// the key type.
+    ///
+    /// [`Eq`]: ../../std/cmp/trait.Eq.html
+    /// [`Hash`]: ../../std/hash/trait.Hash.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(perfect_hash_map)]
+    /// use std::collections::perfect_hash_map::PerfectHashMap;
+    ///
+    /// let map: PerfectHashMap<_, _> = vec![(1, "a")].into_iter().collect();
+    /// assert_eq!(map.contains_key(&1), true);
+    /// assert_eq!(map.contains_key(&2), false);
+    /// ```
+    #[unstable(feature = "perfect_hash_map", issue = "0")]
+    #[inline]
+    pub fn contains_key<Q: ?Sized>(&self, key: &Q) -> bool
+        where K: Borrow<Q>,
+              Q: Hash + Eq
+    {
+        self.index_of(key).is_some()
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key.
+    ///
+    /// The set of keys is fixed, but the values may still be modified in
+    /// place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(perfect_hash_map)]
+    /// use std::collections::perfect_hash_map::PerfectHashMap;
+    ///
+    /// let mut map: PerfectHashMap<_, _> = vec![(1, "a")].into_iter().collect();
+    /// if let Some(x) = map.get_mut(&1) {
+    ///     *x = "b";
+    /// }
+    /// assert_eq!(map[&1], "b");
+    /// ```
+    #[unstable(feature = "perfect_hash_map", issue = "0")]
+    #[inline]
+    pub fn get_mut<Q: ?Sized>(&mut self, key: &Q) -> Option<&mut V>
+        where K: Borrow<Q>,
+              Q: Hash + Eq
+    {
+        match self.index_of(key) {
+            Some(idx) => Some(&mut self.entries[idx].1),
+            None => None,
+        }
+    }
+}
+
+impl<K, V> PerfectHashMap<K, V> {
+    /// Returns the number of elements in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(perfect_hash_map)]
+    /// use std::collections::perfect_hash_map::PerfectHashMap;
+    ///
+    /// let map: PerfectHashMap<_, _> = vec![(1, "a"), (2, "b")].into_iter().collect();
+    /// assert_eq!(map.len(), 2);
+    /// ```
+    #[unstable(feature = "perfect_hash_map", issue = "0")]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the map contains no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(perfect_hash_map)]
+    /// use std::collections::perfect_hash_map::PerfectHashMap;
+    ///
+    /// let map: PerfectHashMap<i32, i32> = Vec::new().into_iter().collect();
+    /// assert!(map.is_empty());
+    /// ```
+    #[unstable(feature = "perfect_hash_map", issue = "0")]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// An iterator visiting all key-value pairs in arbitrary order.
+    /// The iterator element type is `(&'a K, &'a V)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(perfect_hash_map)]
+    /// use std::collections::perfect_hash_map::PerfectHashMap;
+    ///
+    /// let map: PerfectHashMap<_, _> = vec![("a", 1), ("b", 2)].into_iter().collect();
+    /// let mut sum = 0;
+    /// for (_, val) in map.iter() {
+    ///     sum += *val;
+    /// }
+    /// assert_eq!(sum, 3);
+    /// ```
+    #[unstable(feature = "perfect_hash_map", issue = "0")]
+    pub fn iter(&self) -> Iter<K, V> {
+        Iter { inner: self.entries.iter() }
+    }
+
+    /// An iterator visiting all keys in arbitrary order.
+    /// The iterator element type is `&'a K`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(perfect_hash_map)]
+    /// use std::collections::perfect_hash_map::PerfectHashMap;
+    ///
+    /// let map: PerfectHashMap<_, _> = vec![("a", 1)].into_iter().collect();
+    /// assert_eq!(map.keys().collect::<Vec<_>>(), [&"a"]);
+    /// ```
+    #[unstable(feature = "perfect_hash_map", issue = "0")]
+    pub fn keys(&self) -> Keys<K, V> {
+        Keys { inner: self.iter() }
+    }
+
+    /// An iterator visiting all values in arbitrary order.
+    /// The iterator element type is `&'a V`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(perfect_hash_map)]
+    /// use std::collections::perfect_hash_map::PerfectHashMap;
+    ///
+    /// let map: PerfectHashMap<_, _> = vec![("a", 1)].into_iter().collect();
+    /// assert_eq!(map.values().collect::<Vec<_>>(), [&1]);
+    /// ```
+    #[unstable(feature = "perfect_hash_map", issue = "0")]
+    pub fn values(&self) -> Values<K, V> {
+        Values { inner: self.iter() }
+    }
+}
+
+#[unstable(feature = "perfect_hash_map", issue = "0")]
+impl<K: Hash + Eq, V> FromIterator<(K, V)> for PerfectHashMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> PerfectHashMap<K, V> {
+        PerfectHashMapBuilder { entries: iter.into_iter().collect() }.build()
+    }
+}
+
+#[unstable(feature = "perfect_hash_map", issue = "0")]
+impl<'a, K, Q: ?Sized, V> Index<&'a Q> for PerfectHashMap<K, V>
+    where K: Eq + Hash + Borrow<Q>,
+          Q: Eq + Hash
+{
+    type Output = V;
+
+    /// Returns a reference to the value corresponding to the supplied key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is not present in the `PerfectHashMap`.
+    #[inline]
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("no entry found for key")
+    }
+}
+
+#[unstable(feature = "perfect_hash_map", issue = "0")]
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for PerfectHashMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[unstable(feature = "perfect_hash_map", issue = "0")]
+impl<'a, K, V> IntoIterator for &'a PerfectHashMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+#[unstable(feature = "perfect_hash_map", issue = "0")]
+impl<K, V> IntoIterator for PerfectHashMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    fn into_iter(self) -> IntoIter<K, V> {
+        IntoIter { inner: self.entries.into_vec().into_iter() }
+    }
+}
+
+/// An iterator over the entries of a `PerfectHashMap`.
+///
+/// This `struct` is created by the [`iter`] method on [`PerfectHashMap`]. See its
+/// documentation for more.
+///
+/// [`iter`]: struct.PerfectHashMap.html#method.iter
+/// [`PerfectHashMap`]: struct.PerfectHashMap.html
+#[unstable(feature = "perfect_hash_map", issue = "0")]
+pub struct Iter<'a, K: 'a, V: 'a> {
+    inner: slice::Iter<'a, (K, V)>,
+}
+
+// FIXME(#26925) Remove in favor of `#[derive(Clone)]`
+#[unstable(feature = "perfect_hash_map", issue = "0")]
+impl<'a, K, V> Clone for Iter<'a, K, V> {
+    fn clone(&self) -> Iter<'a, K, V> {
+        Iter { inner: self.inner.clone() }
+    }
+}
+
+#[unstable(feature = "perfect_hash_map", issue = "0")]
+impl<'a, K: fmt::Debug, V: fmt::Debug> fmt::Debug for Iter<'a, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+#[unstable(feature = "perfect_hash_map", issue = "0")]
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        self.inner.next().map(|&(ref k, ref v)| (k, v))
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[unstable(feature = "perfect_hash_map", issue = "0")]
+impl<'a, K, V> ExactSizeIterator for Iter<'a, K, V> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+#[unstable(feature = "perfect_hash_map", issue = "0")]
+impl<'a, K, V> FusedIterator for Iter<'a, K, V> {}
+
+/// An iterator over the keys of a `PerfectHashMap`.
+///
+/// This `struct` is created by the [`keys`] method on [`PerfectHashMap`]. See its
+/// documentation for more.
+///
+/// [`keys`]: struct.PerfectHashMap.html#method.keys
+/// [`PerfectHashMap`]: struct.PerfectHashMap.html
+#[unstable(feature = "perfect_hash_map", issue = "0")]
+pub struct Keys<'a, K: 'a, V: 'a> {
+    inner: Iter<'a, K, V>,
+}
+
+// FIXME(#26925) Remove in favor of `#[derive(Clone)]`
+#[unstable(feature = "perfect_hash_map", issue = "0")]
+impl<'a, K, V> Clone for Keys<'a, K, V> {
+    fn clone(&self) -> Keys<'a, K, V> {
+        Keys { inner: self.inner.clone() }
+    }
+}
+
+#[unstable(feature = "perfect_hash_map", issue = "0")]
+impl<'a, K: fmt::Debug, V> fmt::Debug for Keys<'a, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+#[unstable(feature = "perfect_hash_map", issue = "0")]
+impl<'a, K, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a K> {
+        self.inner.next().map(|(k, _)| k)
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[unstable(feature = "perfect_hash_map", issue = "0")]
+impl<'a, K, V> ExactSizeIterator for Keys<'a, K, V> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+#[unstable(feature = "perfect_hash_map", issue = "0")]
+impl<'a, K, V> FusedIterator for Keys<'a, K, V> {}
+
+/// An iterator over the values of a `PerfectHashMap`.
+///
+/// This `struct` is created by the [`values`] method on [`PerfectHashMap`]. See its
+/// documentation for more.
+///
+/// [`values`]: struct.PerfectHashMap.html#method.values
+/// [`PerfectHashMap`]: struct.PerfectHashMap.html
+#[unstable(feature = "perfect_hash_map", issue = "0")]
+pub struct Values<'a, K: 'a, V: 'a> {
+    inner: Iter<'a, K, V>,
+}
+
+// FIXME(#26925) Remove in favor of `#[derive(Clone)]`
+#[unstable(feature = "perfect_hash_map", issue = "0")]
+impl<'a, K, V> Clone for Values<'a, K, V> {
+    fn clone(&self) -> Values<'a, K, V> {
+        Values { inner: self.inner.clone() }
+    }
+}
+
+#[unstable(feature = "perfect_hash_map", issue = "0")]
+impl<'a, K, V: fmt::Debug> fmt::Debug for Values<'a, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+#[unstable(feature = "perfect_hash_map", issue = "0")]
+impl<'a, K, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a V> {
+        self.inner.next().map(|(_, v)| v)
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[unstable(feature = "perfect_hash_map", issue = "0")]
+impl<'a, K, V> ExactSizeIterator for Values<'a, K, V> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+#[unstable(feature = "perfect_hash_map", issue = "0")]
+impl<'a, K, V> FusedIterator for Values<'a, K, V> {}
+
+/// An owning iterator over the entries of a `PerfectHashMap`.
+///
+/// This `struct` is created by the [`into_iter`] method on [`PerfectHashMap`][`PerfectHashMap`]
+/// (provided by the `IntoIterator` trait). See its documentation for more.
+///
+/// [`into_iter`]: struct.PerfectHashMap.html#method.into_iter
+/// [`PerfectHashMap`]: struct.PerfectHashMap.html
+#[unstable(feature = "perfect_hash_map", issue = "0")]
+#[derive(Debug)]
+pub struct IntoIter<K, V> {
+    inner: vec::IntoIter<(K, V)>,
+}
+
+#[unstable(feature = "perfect_hash_map", issue = "0")]
+impl<K, V> Iterator for IntoIter<K, V> {
+    type Item = (K, V);
+
+    #[inline]
+    fn next(&mut self) -> Option<(K, V)> {
+        self.inner.next()
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[unstable(feature = "perfect_hash_map", issue = "0")]
+impl<K, V> ExactSizeIterator for IntoIter<K, V> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+#[unstable(feature = "perfect_hash_map", issue = "0")]
+impl<K, V> FusedIterator for IntoIter<K, V> {}
+
+#[cfg(test)]
+mod test_perfect {
+    use super::{PerfectHashMap, PerfectHashMapBuilder};
+
+    #[test]
+    fn test_empty() {
+        let map: PerfectHashMap<u32, u32> = PerfectHashMapBuilder::new().build();
+        assert!(map.is_empty());
+        assert_eq!(map.get(&0), None);
+        assert_eq!(map.iter().next(), None);
+    }
+
+    #[test]
+    fn test_single() {
+        let map: PerfectHashMap<_, _> = vec![("only", 1)].into_iter().collect();
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.get("only"), Some(&1));
+        assert_eq!(map.get("other"), None);
+    }
+
+    #[test]
+    fn test_lookup_all() {
+        let n = 5000;
+        let map: PerfectHashMap<_, _> = (0..n).map(|i| (i, i * 2)).collect();
+        assert_eq!(map.len(), n);
+        for i in 0..n {
+            assert_eq!(map.get(&i), Some(&(i * 2)));
+        }
+        for i in n..2 * n {
+            assert_eq!(map.get(&i), None);
+        }
+    }
+
+    #[test]
+    fn test_minimal() {
+        // Every slot of the table is used exactly once.
+        let map: PerfectHashMap<_, _> = (0..1000).map(|i| (i.to_string(), i)).collect();
+        let mut seen = vec![false; 1000];
+        for (k, &v) in &map {
+            assert_eq!(*k, v.to_string());
+            assert!(!seen[v]);
+            seen[v] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+    }
+
+    #[test]
+    fn test_duplicates_last_wins() {
+        let mut builder = PerfectHashMapBuilder::new();
+        for i in 0..100 {
+            builder.insert(i % 10, i);
+        }
+        let map = builder.build();
+        assert_eq!(map.len(), 10);
+        for i in 0..10 {
+            assert_eq!(map[&i], 90 + i);
+        }
+    }
+
+    #[test]
+    fn test_deterministic() {
+        let a: PerfectHashMap<_, _> = (0..200).map(|i| (i, ())).collect();
+        let b: PerfectHashMap<_, _> = (0..200).map(|i| (i, ())).collect();
+        assert!(a.keys().eq(b.keys()));
+    }
+
+    #[test]
+    fn test_get_mut() {
+        let mut map: PerfectHashMap<_, _> = (0..10).map(|i| (i, i)).collect();
+        for i in 0..10 {
+            *map.get_mut(&i).unwrap() += 1;
+        }
+        assert_eq!(map.get_mut(&10), None);
+        for i in 0..10 {
+            assert_eq!(map[&i], i + 1);
+        }
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let map: PerfectHashMap<_, _> = (0..50).map(|i| (i, i)).collect();
+        let mut v: Vec<_> = map.into_iter().collect();
+        v.sort();
+        assert_eq!(v, (0..50).map(|i| (i, i)).collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[should_panic(expected = "identical hashes")]
+    fn test_unequal_keys_with_equal_hashes() {
+        use hash::{Hash, Hasher};
+
+        // Equality looks at both fields, but hashing only at the first.
+        #[derive(PartialEq, Eq)]
+        struct Key(u32, u32);
+        impl Hash for Key {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.0.hash(state);
+            }
+        }
+
+        let mut builder = PerfectHashMapBuilder::new();
+        for i in 0..20 {
+            builder.insert(Key(i, 0), ());
+        }
+        builder.insert(Key(7, 1), ());
+        builder.build();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_nonexistent() {
+        let map: PerfectHashMap<_, _> = vec![(1, 1)].into_iter().collect();
+        map[&2];
+    }
+}
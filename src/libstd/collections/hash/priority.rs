@@ -0,0 +1,694 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A priority queue whose entries are addressed by key.
+//!
+//! The queue is a binary max-heap of `(key, priority)` pairs together with a
+//! hash index from each key to its current position in the heap. The index
+//! is kept up to date as entries move, so that an entry can be found, have
+//! its priority changed or be removed in `O(log n)` time without scanning
+//! the heap.
+
+use borrow::Borrow;
+use fmt;
+use hash::{Hash, BuildHasher};
+use iter::{FromIterator, FusedIterator};
+use mem;
+use slice;
+
+use super::map::{HashMap, RandomState};
+
+/// A priority queue of keys, where the priority of any key can be changed
+/// after it has been pushed.
+///
+/// Like [`BinaryHeap`], this is a max-heap: [`pop`] returns the entry with
+/// the greatest priority. Wrap priorities in [`Reverse`] to pop the smallest
+/// first. Unlike `BinaryHeap`, every key is present at most once, and the
+/// queue maintains a [`HashMap`] from keys to heap positions so that
+/// [`change_priority`] and [`remove`] can find an entry directly.
+///
+/// The index holds a second copy of every key, so keys must be `Clone`.
+/// Cheap keys such as integers or handles work best.
+///
+/// It is a logic error for a key to be modified in such a way that its hash
+/// or equality changes while it is in the queue.
+///
+/// [`BinaryHeap`]: ../binary_heap/struct.BinaryHeap.html
+/// [`HashMap`]: ../hash_map/struct.HashMap.html
+/// [`Reverse`]: ../../cmp/struct.Reverse.html
+/// [`pop`]: #method.pop
+/// [`change_priority`]: #method.change_priority
+/// [`remove`]: #method.remove
+///
+/// # Examples
+///
+/// ```
+/// #![feature(keyed_priority_queue)]
+/// use std::cmp::Reverse;
+/// use std::collections::keyed_priority_queue::KeyedPriorityQueue;
+///
+/// // Tasks ordered by deadline, earliest first.
+/// let mut tasks = KeyedPriorityQueue::new();
+/// tasks.push("backup", Reverse(30));
+/// tasks.push("email", Reverse(10));
+/// tasks.push("report", Reverse(20));
+///
+/// // The backup became urgent.
+/// tasks.change_priority("backup", Reverse(5));
+/// // The report was cancelled.
+/// tasks.remove("report");
+///
+/// assert_eq!(tasks.pop(), Some(("backup", Reverse(5))));
+/// assert_eq!(tasks.pop(), Some(("email", Reverse(10))));
+/// assert_eq!(tasks.pop(), None);
+/// ```
+#[derive(Clone)]
+#[unstable(feature = "keyed_priority_queue", issue = "0")]
+pub struct KeyedPriorityQueue<K, P, S = RandomState> {
+    heap: Vec<(K, P)>,
+    index: HashMap<K, usize, S>,
+}
+
+impl<K: Hash + Eq + Clone, P: Ord> KeyedPriorityQueue<K, P, RandomState> {
+    /// Creates an empty `KeyedPriorityQueue`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(keyed_priority_queue)]
+    /// use std::collections::keyed_priority_queue::KeyedPriorityQueue;
+    ///
+    /// let mut queue = KeyedPriorityQueue::new();
+    /// queue.push('a', 4);
+    /// ```
+    #[unstable(feature = "keyed_priority_queue", issue = "0")]
+    pub fn new() -> KeyedPriorityQueue<K, P, RandomState> {
+        KeyedPriorityQueue::with_hasher(RandomState::new())
+    }
+
+    /// Creates an empty `KeyedPriorityQueue` with space for at least
+    /// `capacity` entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(keyed_priority_queue)]
+    /// use std::collections::keyed_priority_queue::KeyedPriorityQueue;
+    ///
+    /// let mut queue = KeyedPriorityQueue::with_capacity(10);
+    /// queue.push('a', 4);
+    /// ```
+    #[unstable(feature = "keyed_priority_queue", issue = "0")]
+    pub fn with_capacity(capacity: usize) -> KeyedPriorityQueue<K, P, RandomState> {
+        KeyedPriorityQueue::with_capacity_and_hasher(capacity, RandomState::new())
+    }
+}
+
+impl<K, P, S> KeyedPriorityQueue<K, P, S>
+    where K: Hash + Eq + Clone,
+          P: Ord,
+          S: BuildHasher
+{
+    /// Creates an empty `KeyedPriorityQueue` which will use the given hash
+    /// builder to hash keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(keyed_priority_queue)]
+    /// use std::collections::hash_map::RandomState;
+    /// use std::collections::keyed_priority_queue::KeyedPriorityQueue;
+    ///
+    /// let mut queue = KeyedPriorityQueue::with_hasher(RandomState::new());
+    /// queue.push('a', 4);
+    /// ```
+    #[unstable(feature = "keyed_priority_queue", issue = "0")]
+    pub fn with_hasher(hash_builder: S) -> KeyedPriorityQueue<K, P, S> {
+        KeyedPriorityQueue {
+            heap: Vec::new(),
+            index: HashMap::with_hasher(hash_builder),
+        }
+    }
+
+    /// Creates an empty `KeyedPriorityQueue` with space for at least
+    /// `capacity` entries, which will use the given hash builder to hash
+    /// keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(keyed_priority_queue)]
+    /// use std::collections::hash_map::RandomState;
+    /// use std::collections::keyed_priority_queue::KeyedPriorityQueue;
+    ///
+    /// let mut queue = KeyedPriorityQueue::with_capacity_and_hasher(10, RandomState::new());
+    /// queue.push('a', 4);
+    /// ```
+    #[unstable(feature = "keyed_priority_queue", issue = "0")]
+    pub fn with_capacity_and_hasher(capacity: usize, hash_builder: S)
+                                    -> KeyedPriorityQueue<K, P, S> {
+        KeyedPriorityQueue {
+            heap: Vec::with_capacity(capacity),
+            index: HashMap::with_capacity_and_hasher(capacity, hash_builder),
+        }
+    }
+
+    /// Pushes `key` onto the queue with the given priority.
+    ///
+    /// If the key was already in the queue, its priority is replaced and the
+    /// old priority is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(keyed_priority_queue)]
+    /// use std::collections::keyed_priority_queue::KeyedPriorityQueue;
+    ///
+    /// let mut queue = KeyedPriorityQueue::new();
+    /// assert_eq!(queue.push("a", 1), None);
+    /// assert_eq!(queue.push("b", 5), None);
+    /// assert_eq!(queue.push("a", 9), Some(1));
+    ///
+    /// assert_eq!(queue.len(), 2);
+    /// assert_eq!(queue.peek(), Some((&"a", &9)));
+    /// ```
+    #[unstable(feature = "keyed_priority_queue", issue = "0")]
+    pub fn push(&mut self, key: K, priority: P) -> Option<P> {
+        if let Some(&pos) = self.index.get(&key) {
+            let old = mem::replace(&mut self.heap[pos].1, priority);
+            self.sift(pos);
+            return Some(old);
+        }
+        let pos = self.heap.len();
+        self.index.insert(key.clone(), pos);
+        self.heap.push((key, priority));
+        self.sift_up(pos);
+        None
+    }
+
+    /// Removes the entry with the greatest priority from the queue and
+    /// returns it, or `None` if the queue is empty.
+    ///
+    /// If several entries share the greatest priority, which one is
+    /// returned is unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(keyed_priority_queue)]
+    /// use std::collections::keyed_priority_queue::KeyedPriorityQueue;
+    ///
+    /// let mut queue = KeyedPriorityQueue::new();
+    /// queue.push("a", 1);
+    /// queue.push("b", 3);
+    ///
+    /// assert_eq!(queue.pop(), Some(("b", 3)));
+    /// assert_eq!(queue.pop(), Some(("a", 1)));
+    /// assert_eq!(queue.pop(), None);
+    /// ```
+    #[unstable(feature = "keyed_priority_queue", issue = "0")]
+    pub fn pop(&mut self) -> Option<(K, P)> {
+        if self.heap.is_empty() {
+            None
+        } else {
+            Some(self.remove_at(0))
+        }
+    }
+
+    /// Returns the entry with the greatest priority, or `None` if the queue
+    /// is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(keyed_priority_queue)]
+    /// use std::collections::keyed_priority_queue::KeyedPriorityQueue;
+    ///
+    /// let mut queue = KeyedPriorityQueue::new();
+    /// assert_eq!(queue.peek(), None);
+    ///
+    /// queue.push("a", 1);
+    /// queue.push("b", 3);
+    /// assert_eq!(queue.peek(), Some((&"b", &3)));
+    /// ```
+    #[unstable(feature = "keyed_priority_queue", issue = "0")]
+    pub fn peek(&self) -> Option<(&K, &P)> {
+        self.heap.first().map(|&(ref k, ref p)| (k, p))
+    }
+
+    /// Returns the priority of `key`, or `None` if it is not in the queue.
+    ///
+    /// The key may be any borrowed form of the queue's key type, but
+    /// [`Hash`] and [`Eq`] on the borrowed form *must* match those for
+    /// the key type.
+    ///
+    /// [`Eq`]: ../../std/cmp/trait.Eq.html
+    /// [`Hash`]: ../../std/hash/trait.Hash.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(keyed_priority_queue)]
+    /// use std::collections::keyed_priority_queue::KeyedPriorityQueue;
+    ///
+    /// let mut queue = KeyedPriorityQueue::new();
+    /// queue.push("a", 1);
+    /// assert_eq!(queue.get_priority("a"), Some(&1));
+    /// assert_eq!(queue.get_priority("b"), None);
+    /// ```
+    #[unstable(feature = "keyed_priority_queue", issue = "0")]
+    pub fn get_priority<Q: ?Sized>(&self, key: &Q) -> Option<&P>
+        where K: Borrow<Q>, Q: Hash + Eq
+    {
+        self.index.get(key).map(|&pos| &self.heap[pos].1)
+    }
+
+    /// Returns `true` if the queue contains `key`.
+    ///
+    /// The key may be any borrowed form of the queue's key type, but
+    /// [`Hash`] and [`Eq`] on the borrowed form *must* match those for
+    /// the key type.
+    ///
+    /// [`Eq`]: ../../std/cmp/trait.Eq.html
+    /// [`Hash`]: ../../std/hash/trait.Hash.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(keyed_priority_queue)]
+    /// use std::collections::keyed_priority_queue::KeyedPriorityQueue;
+    ///
+    /// let mut queue = KeyedPriorityQueue::new();
+    /// queue.push("a", 1);
+    /// assert!(queue.contains_key("a"));
+    /// assert!(!queue.contains_key("b"));
+    /// ```
+    #[unstable(feature = "keyed_priority_queue", issue = "0")]
+    pub fn contains_key<Q: ?Sized>(&self, key: &Q) -> bool
+        where K: Borrow<Q>, Q: Hash + Eq
+    {
+        self.index.contains_key(key)
+    }
+
+    /// Changes the priority of `key`, returning its old priority.
+    ///
+    /// If the key is not in the queue, nothing happens and `None` is
+    /// returned; use [`push`] to insert or update.
+    ///
+    /// The key may be any borrowed form of the queue's key type, but
+    /// [`Hash`] and [`Eq`] on the borrowed form *must* match those for
+    /// the key type.
+    ///
+    /// [`push`]: #method.push
+    /// [`Eq`]: ../../std/cmp/trait.Eq.html
+    /// [`Hash`]: ../../std/hash/trait.Hash.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(keyed_priority_queue)]
+    /// use std::collections::keyed_priority_queue::KeyedPriorityQueue;
+    ///
+    /// let mut queue = KeyedPriorityQueue::new();
+    /// queue.push("a", 1);
+    /// queue.push("b", 3);
+    ///
+    /// assert_eq!(queue.change_priority("a", 5), Some(1));
+    /// assert_eq!(queue.change_priority("c", 5), None);
+    /// assert_eq!(queue.pop(), Some(("a", 5)));
+    /// ```
+    #[unstable(feature = "keyed_priority_queue", issue = "0")]
+    pub fn change_priority<Q: ?Sized>(&mut self, key: &Q, priority: P) -> Option<P>
+        where K: Borrow<Q>, Q: Hash + Eq
+    {
+        let pos = match self.index.get(key) {
+            Some(&pos) => pos,
+            None => return None,
+        };
+        let old = mem::replace(&mut self.heap[pos].1, priority);
+        self.sift(pos);
+        Some(old)
+    }
+
+    /// Removes `key` from the queue, returning the stored key and its
+    /// priority, or `None` if it was not in the queue.
+    ///
+    /// The key may be any borrowed form of the queue's key type, but
+    /// [`Hash`] and [`Eq`] on the borrowed form *must* match those for
+    /// the key type.
+    ///
+    /// [`Eq`]: ../../std/cmp/trait.Eq.html
+    /// [`Hash`]: ../../std/hash/trait.Hash.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(keyed_priority_queue)]
+    /// use std::collections::keyed_priority_queue::KeyedPriorityQueue;
+    ///
+    /// let mut queue = KeyedPriorityQueue::new();
+    /// queue.push("a", 1);
+    /// queue.push("b", 3);
+    ///
+    /// assert_eq!(queue.remove("b"), Some(("b", 3)));
+    /// assert_eq!(queue.remove("b"), None);
+    /// assert_eq!(queue.len(), 1);
+    /// ```
+    #[unstable(feature = "keyed_priority_queue", issue = "0")]
+    pub fn remove<Q: ?Sized>(&mut self, key: &Q) -> Option<(K, P)>
+        where K: Borrow<Q>, Q: Hash + Eq
+    {
+        let pos = match self.index.get(key) {
+            Some(&pos) => pos,
+            None => return None,
+        };
+        Some(self.remove_at(pos))
+    }
+
+    /// Removes the entry at heap position `pos`, filling the hole with the
+    /// last entry and restoring the heap property around it.
+    fn remove_at(&mut self, pos: usize) -> (K, P) {
+        let (key, priority) = self.heap.swap_remove(pos);
+        self.index.remove(&key);
+        if pos < self.heap.len() {
+            self.update_index(pos);
+            self.sift(pos);
+        }
+        (key, priority)
+    }
+
+    /// Records in the index that the entry at `pos` is now stored there.
+    fn update_index(&mut self, pos: usize) {
+        if let Some(slot) = self.index.get_mut(&self.heap[pos].0) {
+            *slot = pos;
+        }
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        self.update_index(a);
+        self.update_index(b);
+    }
+
+    /// Moves the entry at `pos` to where it belongs after its priority has
+    /// changed in either direction.
+    fn sift(&mut self, pos: usize) {
+        let pos = self.sift_up(pos);
+        self.sift_down(pos);
+    }
+
+    fn sift_up(&mut self, mut pos: usize) -> usize {
+        while pos > 0 {
+            let parent = (pos - 1) / 2;
+            if self.heap[pos].1 <= self.heap[parent].1 {
+                break;
+            }
+            self.swap(pos, parent);
+            pos = parent;
+        }
+        pos
+    }
+
+    fn sift_down(&mut self, mut pos: usize) {
+        let len = self.heap.len();
+        loop {
+            let mut child = 2 * pos + 1;
+            if child >= len {
+                break;
+            }
+            if child + 1 < len && self.heap[child].1 < self.heap[child + 1].1 {
+                child += 1;
+            }
+            if self.heap[pos].1 >= self.heap[child].1 {
+                break;
+            }
+            self.swap(pos, child);
+            pos = child;
+        }
+    }
+}
+
+impl<K, P, S> KeyedPriorityQueue<K, P, S> {
+    /// Returns the number of entries in the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(keyed_priority_queue)]
+    /// use std::collections::keyed_priority_queue::KeyedPriorityQueue;
+    ///
+    /// let mut queue = KeyedPriorityQueue::new();
+    /// queue.push("a", 1);
+    /// queue.push("a", 2);
+    /// assert_eq!(queue.len(), 1);
+    /// ```
+    #[unstable(feature = "keyed_priority_queue", issue = "0")]
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Returns `true` if the queue contains no entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(keyed_priority_queue)]
+    /// use std::collections::keyed_priority_queue::KeyedPriorityQueue;
+    ///
+    /// let mut queue = KeyedPriorityQueue::new();
+    /// assert!(queue.is_empty());
+    /// queue.push("a", 1);
+    /// assert!(!queue.is_empty());
+    /// ```
+    #[unstable(feature = "keyed_priority_queue", issue = "0")]
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Removes all entries from the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(keyed_priority_queue)]
+    /// use std::collections::keyed_priority_queue::KeyedPriorityQueue;
+    ///
+    /// let mut queue = KeyedPriorityQueue::new();
+    /// queue.push("a", 1);
+    /// queue.clear();
+    /// assert!(queue.is_empty());
+    /// assert!(!queue.contains_key("a"));
+    /// ```
+    #[unstable(feature = "keyed_priority_queue", issue = "0")]
+    pub fn clear(&mut self) {
+        self.heap.clear();
+        self.index.clear();
+    }
+
+    /// Returns an iterator visiting all entries in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(keyed_priority_queue)]
+    /// use std::collections::keyed_priority_queue::KeyedPriorityQueue;
+    ///
+    /// let mut queue = KeyedPriorityQueue::new();
+    /// queue.push("a", 1);
+    /// queue.push("b", 2);
+    ///
+    /// let mut entries: Vec<_> = queue.iter().collect();
+    /// entries.sort();
+    /// assert_eq!(entries, [(&"a", &1), (&"b", &2)]);
+    /// ```
+    #[unstable(feature = "keyed_priority_queue", issue = "0")]
+    pub fn iter(&self) -> Iter<K, P> {
+        Iter { iter: self.heap.iter() }
+    }
+}
+
+#[unstable(feature = "keyed_priority_queue", issue = "0")]
+impl<K, P, S> fmt::Debug for KeyedPriorityQueue<K, P, S>
+    where K: fmt::Debug,
+          P: fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[unstable(feature = "keyed_priority_queue", issue = "0")]
+impl<K, P, S> Default for KeyedPriorityQueue<K, P, S>
+    where K: Hash + Eq + Clone,
+          P: Ord,
+          S: BuildHasher + Default
+{
+    /// Creates an empty `KeyedPriorityQueue<K, P, S>`, with the `Default`
+    /// value for the hasher.
+    fn default() -> KeyedPriorityQueue<K, P, S> {
+        KeyedPriorityQueue::with_hasher(Default::default())
+    }
+}
+
+#[unstable(feature = "keyed_priority_queue", issue = "0")]
+impl<K, P, S> FromIterator<(K, P)> for KeyedPriorityQueue<K, P, S>
+    where K: Hash + Eq + Clone,
+          P: Ord,
+          S: BuildHasher + Default
+{
+    fn from_iter<I: IntoIterator<Item = (K, P)>>(iter: I) -> KeyedPriorityQueue<K, P, S> {
+        let mut queue = KeyedPriorityQueue::default();
+        queue.extend(iter);
+        queue
+    }
+}
+
+#[unstable(feature = "keyed_priority_queue", issue = "0")]
+impl<K, P, S> Extend<(K, P)> for KeyedPriorityQueue<K, P, S>
+    where K: Hash + Eq + Clone,
+          P: Ord,
+          S: BuildHasher
+{
+    fn extend<I: IntoIterator<Item = (K, P)>>(&mut self, iter: I) {
+        for (key, priority) in iter {
+            self.push(key, priority);
+        }
+    }
+}
+
+#[unstable(feature = "keyed_priority_queue", issue = "0")]
+impl<'a, K, P, S> IntoIterator for &'a KeyedPriorityQueue<K, P, S> {
+    type Item = (&'a K, &'a P);
+    type IntoIter = Iter<'a, K, P>;
+
+    fn into_iter(self) -> Iter<'a, K, P> {
+        self.iter()
+    }
+}
+
+/// An iterator over the entries of a `KeyedPriorityQueue`.
+///
+/// This `struct` is created by the [`iter`] method on [`KeyedPriorityQueue`].
+/// See its documentation for more.
+///
+/// [`iter`]: struct.KeyedPriorityQueue.html#method.iter
+/// [`KeyedPriorityQueue`]: struct.KeyedPriorityQueue.html
+#[unstable(feature = "keyed_priority_queue", issue = "0")]
+pub struct Iter<'a, K: 'a, P: 'a> {
+    iter: slice::Iter<'a, (K, P)>,
+}
+
+// FIXME(#26925) Remove in favor of `#[derive(Clone)]`
+#[unstable(feature = "keyed_priority_queue", issue = "0")]
+impl<'a, K, P> Clone for Iter<'a, K, P> {
+    fn clone(&self) -> Iter<'a, K, P> {
+        Iter { iter: self.iter.clone() }
+    }
+}
+
+#[unstable(feature = "keyed_priority_queue", issue = "0")]
+impl<'a, K: fmt::Debug, P: fmt::Debug> fmt::Debug for Iter<'a, K, P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+#[unstable(feature = "keyed_priority_queue", issue = "0")]
+impl<'a, K, P> Iterator for Iter<'a, K, P> {
+    type Item = (&'a K, &'a P);
+
+    #[inline]
+    fn next(&mut self) -> Option<(&'a K, &'a P)> {
+        self.iter.next().map(|&(ref k, ref p)| (k, p))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[unstable(feature = "keyed_priority_queue", issue = "0")]
+impl<'a, K, P> ExactSizeIterator for Iter<'a, K, P> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+#[unstable(feature = "keyed_priority_queue", issue = "0")]
+impl<'a, K, P> FusedIterator for Iter<'a, K, P> {}
+
+#[cfg(test)]
+mod test_keyed_priority_queue {
+    use super::KeyedPriorityQueue;
+    use cmp::Reverse;
+
+    #[test]
+    fn test_push_pop() {
+        let mut queue = KeyedPriorityQueue::new();
+        for (i, &p) in [5, 1, 8, 3, 9, 2, 7].iter().enumerate() {
+            assert_eq!(queue.push(i, p), None);
+        }
+        assert_eq!(queue.len(), 7);
+
+        let mut popped = Vec::new();
+        while let Some((_, p)) = queue.pop() {
+            popped.push(p);
+        }
+        assert_eq!(popped, [9, 8, 7, 5, 3, 2, 1]);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_change_priority() {
+        let mut queue: KeyedPriorityQueue<_, _> = (0..100).map(|i| (i, Reverse(i))).collect();
+
+        // Reverse the order of the odd keys and push the even keys to the back.
+        for i in 0..100 {
+            let priority = if i % 2 == 1 { Reverse(-i) } else { Reverse(i + 100) };
+            assert_eq!(queue.change_priority(&i, priority), Some(Reverse(i)));
+        }
+        assert_eq!(queue.change_priority(&100, Reverse(0)), None);
+        assert_eq!(queue.get_priority(&3), Some(&Reverse(-3)));
+
+        let keys: Vec<_> = (0..100).map(|_| queue.pop().unwrap().0).collect();
+        let odd = (0..50).rev().map(|i| 2 * i + 1);
+        let even = (0..50).map(|i| 2 * i);
+        assert!(keys.into_iter().eq(odd.chain(even)));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut queue: KeyedPriorityQueue<_, _> = (0..50).map(|i| (i, (i * 37) % 50)).collect();
+        for i in (0..50).filter(|i| i % 3 == 0) {
+            assert_eq!(queue.remove(&i), Some((i, (i * 37) % 50)));
+            assert_eq!(queue.remove(&i), None);
+            assert!(!queue.contains_key(&i));
+        }
+
+        let mut last = None;
+        while let Some((key, priority)) = queue.pop() {
+            assert!(key % 3 != 0);
+            assert!(last.map_or(true, |last| last >= priority));
+            last = Some(priority);
+        }
+    }
+
+    #[test]
+    fn test_push_existing() {
+        let mut queue = KeyedPriorityQueue::new();
+        queue.push("a", 1);
+        queue.push("b", 2);
+        assert_eq!(queue.push("a", 3), Some(1));
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue.pop(), Some(("a", 3)));
+        assert_eq!(queue.pop(), Some(("b", 2)));
+    }
+}
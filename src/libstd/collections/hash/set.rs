@@ -10,12 +10,12 @@
 
 use borrow::Borrow;
 use fmt;
-use hash::{Hash, BuildHasher};
+use hash::{Hash, Hasher, BuildHasher};
 use iter::{Chain, FromIterator, FusedIterator};
 use ops::{BitOr, BitAnd, BitXor, Sub};
 
 use super::Recover;
-use super::map::{self, HashMap, Keys, RandomState};
+use super::map::{self, Checkpoint, HashMap, Keys, RandomState};
 
 // Future Optimization (FIXME!)
 // =============================
@@ -341,6 +341,75 @@ impl<T, S> HashSet<T, S>
         Iter { iter: self.map.keys() }
     }
 
+    /// Resumes an iteration over the set from a [`Checkpoint`] taken from an
+    /// earlier [`iter`], visiting the elements that it had not reached yet.
+    ///
+    /// Returns `None` if values have been inserted into or removed from the
+    /// set since the checkpoint was taken, or if the checkpoint was taken
+    /// from a different set. See [`HashMap::iter_from`] for details.
+    ///
+    /// [`Checkpoint`]: ../hash_map/struct.Checkpoint.html
+    /// [`iter`]: #method.iter
+    /// [`HashMap::iter_from`]: ../hash_map/struct.HashMap.html#method.iter_from
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hash_map_checkpoint)]
+    /// use std::collections::HashSet;
+    ///
+    /// let set: HashSet<u32> = (0..10).collect();
+    /// let mut iter = set.iter();
+    /// let first = iter.next().cloned();
+    /// let checkpoint = iter.checkpoint();
+    ///
+    /// let rest: Vec<u32> = set.iter_from(checkpoint).unwrap().cloned().collect();
+    /// assert_eq!(rest.len(), 9);
+    /// assert!(!rest.contains(&first.unwrap()));
+    /// ```
+    #[unstable(feature = "hash_map_checkpoint", issue = "0")]
+    pub fn iter_from(&self, checkpoint: Checkpoint) -> Option<Iter<T>> {
+        self.map.iter_from(checkpoint).map(|inner| Iter { iter: Keys { inner } })
+    }
+
+    /// Returns a value of the set chosen uniformly at random, or `None` if
+    /// the set is empty.
+    ///
+    /// `rng` is called for uniformly distributed random numbers, as many
+    /// times as needed. This takes expected constant time, except in sets
+    /// that are much smaller than their capacity, where it may take time
+    /// proportional to the capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(set_sample)]
+    /// use std::collections::HashSet;
+    ///
+    /// // A small xorshift generator. Use a proper source of randomness
+    /// // where the quality of the choice matters.
+    /// let mut state = 0x2545_f491_4f6c_dd1du64;
+    /// let mut rng = move || {
+    ///     state ^= state << 13;
+    ///     state ^= state >> 7;
+    ///     state ^= state << 17;
+    ///     state
+    /// };
+    ///
+    /// let set: HashSet<_> = (1..10).collect();
+    /// let x = *set.sample(&mut rng).unwrap();
+    /// assert!(1 <= x && x < 10);
+    ///
+    /// let empty: HashSet<i32> = HashSet::new();
+    /// assert_eq!(empty.sample(&mut rng), None);
+    /// ```
+    #[unstable(feature = "set_sample", issue = "0")]
+    pub fn sample<R>(&self, mut rng: R) -> Option<&T>
+        where R: FnMut() -> u64
+    {
+        self.map.sample_entry(&mut rng).map(|(value, _)| value)
+    }
+
     /// Visits the values representing the difference,
     /// i.e. the values that are in `self` but not in `other`.
     ///
@@ -762,6 +831,89 @@ impl<T, S> HashSet<T, S>
     {
         self.map.retain(|k, _| f(k));
     }
+
+    /// Creates an iterator which uses a closure to determine if an element
+    /// should be removed.
+    ///
+    /// If the closure returns `true`, the element is removed from the set
+    /// and yielded by the iterator. If it returns `false`, the element stays
+    /// in the set and is not yielded. Elements are visited in an arbitrary
+    /// order.
+    ///
+    /// Elements are only removed as the iterator advances, so the rest of
+    /// the set is never rebuilt. If the iterator is dropped before it is
+    /// exhausted, the remaining elements that match are still removed. If
+    /// the closure panics, the element it was called on and all the elements
+    /// not yet visited stay in the set, and the closure is not called again.
+    ///
+    /// # Examples
+    ///
+    /// Splitting a set into even and odd numbers:
+    ///
+    /// ```
+    /// #![feature(extract_if)]
+    /// use std::collections::HashSet;
+    ///
+    /// let mut set: HashSet<i32> = (0..8).collect();
+    /// let evens: HashSet<_> = set.extract_if(|v| v % 2 == 0).collect();
+    /// let odds = set;
+    ///
+    /// let mut evens = evens.into_iter().collect::<Vec<_>>();
+    /// let mut odds = odds.into_iter().collect::<Vec<_>>();
+    /// evens.sort();
+    /// odds.sort();
+    ///
+    /// assert_eq!(evens, [0, 2, 4, 6]);
+    /// assert_eq!(odds, [1, 3, 5, 7]);
+    /// ```
+    #[unstable(feature = "extract_if", issue = "0")]
+    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<T, F>
+        where F: FnMut(&T) -> bool
+    {
+        ExtractIf {
+            inner: self.map.extract_if_inner(),
+            pred,
+        }
+    }
+
+    /// Feeds an order-independent hash of the set into `state`.
+    ///
+    /// Every element is hashed on its own with a fixed-key hasher, and the
+    /// number of elements and the wrapping sum of those hashes are fed into
+    /// `state`, so two sets that compare equal always produce the same hash.
+    /// See [`HashMap::hash_unordered`] for details.
+    ///
+    /// [`HashMap::hash_unordered`]: struct.HashMap.html#method.hash_unordered
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hash_unordered)]
+    ///
+    /// use std::collections::HashSet;
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::Hasher;
+    ///
+    /// let a: HashSet<&str> = ["a", "b", "c"].iter().cloned().collect();
+    /// let b: HashSet<&str> = ["c", "b", "a"].iter().cloned().collect();
+    ///
+    /// let mut hash_a = DefaultHasher::new();
+    /// a.hash_unordered(&mut hash_a);
+    /// let mut hash_b = DefaultHasher::new();
+    /// b.hash_unordered(&mut hash_b);
+    /// assert_eq!(hash_a.finish(), hash_b.finish());
+    /// ```
+    #[unstable(feature = "hash_unordered", issue = "0")]
+    pub fn hash_unordered<H: Hasher>(&self, state: &mut H) {
+        let mut sum = 0u64;
+        for elem in self.iter() {
+            let mut hasher = map::DefaultHasher::new();
+            elem.hash(&mut hasher);
+            sum = sum.wrapping_add(hasher.finish());
+        }
+        state.write_usize(self.len());
+        state.write_u64(sum);
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -838,6 +990,15 @@ impl<T, S> Default for HashSet<T, S>
     }
 }
 
+// The compiler does not check stability attributes on macros, so `hashset!`
+// creates its set through this function to require the
+// `collection_macros` feature.
+#[doc(hidden)]
+#[unstable(feature = "collection_macros", issue = "0")]
+pub fn __with_capacity_for_macro<T: Hash + Eq>(capacity: usize) -> HashSet<T, RandomState> {
+    HashSet::with_capacity(capacity)
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<'a, 'b, T, S> BitOr<&'b HashSet<T, S>> for &'a HashSet<T, S>
     where T: Eq + Hash + Clone,
@@ -1002,6 +1163,20 @@ pub struct Drain<'a, K: 'a> {
     iter: map::Drain<'a, K, ()>,
 }
 
+/// An iterator produced by calling `extract_if` on HashSet.
+///
+/// See the [`extract_if`] method on [`HashSet`] for more.
+///
+/// [`HashSet`]: struct.HashSet.html
+/// [`extract_if`]: struct.HashSet.html#method.extract_if
+#[unstable(feature = "extract_if", issue = "0")]
+pub struct ExtractIf<'a, K: 'a, F>
+    where F: FnMut(&K) -> bool,
+{
+    inner: map::ExtractIfInner<'a, K, ()>,
+    pred: F,
+}
+
 /// A lazy iterator producing elements in the intersection of `HashSet`s.
 ///
 /// This `struct` is created by the [`intersection`] method on [`HashSet`].
@@ -1108,6 +1283,18 @@ impl<'a, K> Clone for Iter<'a, K> {
         Iter { iter: self.iter.clone() }
     }
 }
+
+impl<'a, K> Iter<'a, K> {
+    /// Returns a checkpoint that [`HashSet::iter_from`] can resume this
+    /// iteration from later, once the iterator itself is gone.
+    ///
+    /// [`HashSet::iter_from`]: struct.HashSet.html#method.iter_from
+    #[unstable(feature = "hash_map_checkpoint", issue = "0")]
+    pub fn checkpoint(&self) -> Checkpoint {
+        self.iter.inner.checkpoint()
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<'a, K> Iterator for Iter<'a, K> {
     type Item = &'a K;
@@ -1186,6 +1373,39 @@ impl<'a, K> ExactSizeIterator for Drain<'a, K> {
 #[stable(feature = "fused", since = "1.26.0")]
 impl<'a, K> FusedIterator for Drain<'a, K> {}
 
+#[unstable(feature = "extract_if", issue = "0")]
+impl<'a, K, F> Iterator for ExtractIf<'a, K, F>
+    where F: FnMut(&K) -> bool,
+{
+    type Item = K;
+
+    fn next(&mut self) -> Option<K> {
+        let pred = &mut self.pred;
+        self.inner.next(&mut |k, _| pred(k)).map(|(k, ())| k)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[unstable(feature = "extract_if", issue = "0")]
+impl<'a, K, F> Drop for ExtractIf<'a, K, F>
+    where F: FnMut(&K) -> bool,
+{
+    fn drop(&mut self) {
+        for _ in self.by_ref() { }
+    }
+}
+
+#[unstable(feature = "extract_if", issue = "0")]
+impl<'a, K, F> fmt::Debug for ExtractIf<'a, K, F>
+    where F: FnMut(&K) -> bool,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad("ExtractIf { .. }")
+    }
+}
+
 #[stable(feature = "std_debug", since = "1.16.0")]
 impl<'a, K: fmt::Debug> fmt::Debug for Drain<'a, K> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -1404,6 +1624,43 @@ mod test_set {
     use super::HashSet;
     use super::super::map::RandomState;
 
+    struct XorShift(u64);
+
+    impl XorShift {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_sample() {
+        let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+
+        let empty: HashSet<i32> = HashSet::new();
+        assert_eq!(empty.sample(|| rng.next()), None);
+
+        let set: HashSet<_> = (0..16).collect();
+        let mut seen = [0; 16];
+        for _ in 0..1600 {
+            seen[*set.sample(|| rng.next()).unwrap()] += 1;
+        }
+        assert!(seen.iter().all(|&n| n > 50), "{:?}", seen);
+    }
+
+    #[test]
+    fn test_sample_sparse() {
+        let mut rng = XorShift(0x9e37_79b9_7f4a_7c15);
+        let mut set: HashSet<_> = (0..1000).collect();
+        set.retain(|&x| x == 123 || x == 456);
+        for _ in 0..100 {
+            let x = *set.sample(|| rng.next()).unwrap();
+            assert!(x == 123 || x == 456);
+        }
+    }
+
     #[test]
     fn test_zero_capacities() {
         type HS = HashSet<i32>;
@@ -1780,4 +2037,64 @@ mod test_set {
         assert!(set.contains(&4));
         assert!(set.contains(&6));
     }
+
+    #[test]
+    fn test_extract_if() {
+        let xs = [1, 2, 3, 4, 5, 6];
+        let mut set: HashSet<i32> = xs.iter().cloned().collect();
+        let mut evens: Vec<_> = set.extract_if(|&k| k % 2 == 0).collect();
+        evens.sort();
+        assert_eq!(evens, [2, 4, 6]);
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(&1));
+        assert!(set.contains(&3));
+        assert!(set.contains(&5));
+    }
+
+    #[test]
+    fn test_hashset_macro() {
+        let empty: HashSet<i32> = hashset![];
+        assert!(empty.is_empty());
+
+        let set = hashset![1, 2, 3, 2,];
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(&1) && set.contains(&2) && set.contains(&3));
+        assert!(set.capacity() >= 4);
+    }
+
+    #[test]
+    fn test_iter_checkpoint() {
+        let mut set: HashSet<i32> = (0..100).collect();
+        let mut iter = set.iter();
+        let first: Vec<i32> = iter.by_ref().take(40).cloned().collect();
+        let checkpoint = iter.checkpoint();
+        let rest: Vec<i32> = set.iter_from(checkpoint).unwrap().cloned().collect();
+        assert_eq!(rest.len(), 60);
+        assert!(rest.iter().all(|x| !first.contains(x)));
+
+        set.remove(&rest[0]);
+        assert!(set.iter_from(checkpoint).is_none());
+    }
+
+    #[test]
+    fn test_hash_unordered() {
+        use hash::Hasher;
+        use super::super::map::DefaultHasher;
+
+        fn fingerprint(set: &HashSet<u64>) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            set.hash_unordered(&mut hasher);
+            hasher.finish()
+        }
+
+        let a: HashSet<u64> = (0..1000).collect();
+        let mut b = HashSet::with_capacity(5000);
+        b.extend((0..1000).rev());
+        assert_eq!(fingerprint(&a), fingerprint(&b));
+
+        b.remove(&500);
+        assert!(fingerprint(&a) != fingerprint(&b));
+        b.insert(1000);
+        assert!(fingerprint(&a) != fingerprint(&b));
+    }
 }
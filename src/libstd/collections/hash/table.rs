@@ -18,6 +18,7 @@ use mem::{align_of, size_of, needs_drop};
 use mem;
 use ops::{Deref, DerefMut};
 use ptr::{self, Unique, NonNull};
+use sync::atomic::{AtomicUsize, Ordering};
 use alloc::allocator::CollectionAllocErr;
 
 use self::BucketState::*;
@@ -120,6 +121,11 @@ pub struct RawTable<K, V> {
     size: usize,
     hashes: TaggedHashUintPtr,
 
+    // Tells apart the tables an iteration position may have been taken
+    // from. See `Version`.
+    id: usize,
+    modifications: usize,
+
     // Because K/V do not appear directly in any of the types in the struct,
     // inform rustc that in fact instances of K and V are reachable from here.
     marker: marker::PhantomData<(K, V)>,
@@ -135,6 +141,23 @@ pub struct RawBucket<K, V> {
     _marker: marker::PhantomData<(K, V)>,
 }
 
+/// The ids handed out to tables as they are allocated. Tables without an
+/// allocation have id 0.
+static NEXT_TABLE_ID: AtomicUsize = AtomicUsize::new(1);
+
+/// Identifies a table and the positions of its entries.
+///
+/// Every allocation gets a new id, and the modification count goes up
+/// whenever an entry is put into or taken out of the table, so two equal
+/// versions mean the same entries in the same buckets. An iteration position
+/// taken together with a version can be resumed as long as the table still
+/// has that version.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Version {
+    id: usize,
+    modifications: usize,
+}
+
 impl<K, V> Copy for RawBucket<K, V> {}
 impl<K, V> Clone for RawBucket<K, V> {
     fn clone(&self) -> RawBucket<K, V> {
@@ -478,7 +501,9 @@ impl<K, V, M> EmptyBucket<K, V, M>
             *self.raw.hash() = hash.inspect();
             ptr::write(self.raw.pair(), (key, value));
 
-            self.table.borrow_table_mut().size += 1;
+            let table = self.table.borrow_table_mut();
+            table.size += 1;
+            table.modifications = table.modifications.wrapping_add(1);
         }
 
         FullBucket {
@@ -549,6 +574,7 @@ impl<'t, K, V> FullBucket<K, V, &'t mut RawTable<K, V>> {
     /// taken bucket.
     pub fn take(self) -> (EmptyBucket<K, V, &'t mut RawTable<K, V>>, K, V) {
         self.table.size -= 1;
+        self.table.modifications = self.table.modifications.wrapping_add(1);
 
         unsafe {
             *self.raw.hash() = EMPTY_BUCKET;
@@ -724,6 +750,8 @@ impl<K, V> RawTable<K, V> {
                 size: 0,
                 capacity_mask: capacity.wrapping_sub(1),
                 hashes: TaggedHashUintPtr::new(EMPTY as *mut HashUint),
+                id: 0,
+                modifications: 0,
                 marker: marker::PhantomData,
             });
         }
@@ -766,6 +794,8 @@ impl<K, V> RawTable<K, V> {
             capacity_mask: capacity.wrapping_sub(1),
             size: 0,
             hashes: TaggedHashUintPtr::new(hashes),
+            id: NEXT_TABLE_ID.fetch_add(1, Ordering::Relaxed),
+            modifications: 0,
             marker: marker::PhantomData,
         })
     }
@@ -830,6 +860,15 @@ impl<K, V> RawTable<K, V> {
         self.size
     }
 
+    /// The table's current version, which changes whenever the positions of
+    /// its entries do.
+    pub fn version(&self) -> Version {
+        Version {
+            id: self.id,
+            modifications: self.modifications,
+        }
+    }
+
     fn raw_buckets(&self) -> RawBuckets<K, V> {
         RawBuckets {
             raw: self.raw_bucket_at(0),
@@ -841,12 +880,34 @@ impl<K, V> RawTable<K, V> {
     pub fn iter(&self) -> Iter<K, V> {
         Iter {
             iter: self.raw_buckets(),
+            version: self.version(),
+        }
+    }
+
+    /// Returns an iterator over the entries in the buckets from `idx` on,
+    /// resuming from an iterator's `position`. Returns `None` if the table
+    /// no longer has the version the position was taken at.
+    pub fn iter_from(&self, idx: usize, elems_left: usize, version: Version)
+                     -> Option<Iter<K, V>> {
+        // Ids are only reused once the counter wraps around, so the other
+        // checks just keep a position from a long gone table in bounds.
+        if version != self.version() || idx > self.capacity() || elems_left > self.size {
+            return None;
         }
+        Some(Iter {
+            iter: RawBuckets {
+                raw: self.raw_bucket_at(idx),
+                elems_left,
+                marker: marker::PhantomData,
+            },
+            version,
+        })
     }
 
     pub fn iter_mut(&mut self) -> IterMut<K, V> {
         IterMut {
             iter: self.raw_buckets(),
+            version: self.version(),
             _marker: marker::PhantomData,
         }
     }
@@ -967,6 +1028,7 @@ impl<'a, K, V> ExactSizeIterator for RawBuckets<'a, K, V> {
 /// Iterator over shared references to entries in a table.
 pub struct Iter<'a, K: 'a, V: 'a> {
     iter: RawBuckets<'a, K, V>,
+    version: Version,
 }
 
 unsafe impl<'a, K: Sync, V: Sync> Sync for Iter<'a, K, V> {}
@@ -977,13 +1039,24 @@ impl<'a, K, V> Clone for Iter<'a, K, V> {
     fn clone(&self) -> Iter<'a, K, V> {
         Iter {
             iter: self.iter.clone(),
+            version: self.version,
         }
     }
 }
 
+impl<'a, K, V> Iter<'a, K, V> {
+    /// Returns the index of the next bucket to look at, the number of
+    /// entries left to yield and the table's version, which
+    /// `RawTable::iter_from` resumes from.
+    pub fn position(&self) -> (usize, usize, Version) {
+        (self.iter.raw.idx, self.iter.elems_left, self.version)
+    }
+}
+
 /// Iterator over mutable references to entries in a table.
 pub struct IterMut<'a, K: 'a, V: 'a> {
     iter: RawBuckets<'a, K, V>,
+    version: Version,
     // To ensure invariance with respect to V
     _marker: marker::PhantomData<&'a mut V>,
 }
@@ -997,6 +1070,7 @@ impl<'a, K: 'a, V: 'a> IterMut<'a, K, V> {
     pub fn iter(&self) -> Iter<K, V> {
         Iter {
             iter: self.iter.clone(),
+            version: self.version,
         }
     }
 }
@@ -1014,6 +1088,7 @@ impl<K, V> IntoIter<K, V> {
     pub fn iter(&self) -> Iter<K, V> {
         Iter {
             iter: self.iter.clone(),
+            version: self.table.version(),
         }
     }
 }
@@ -1032,6 +1107,7 @@ impl<'a, K, V> Drain<'a, K, V> {
     pub fn iter(&self) -> Iter<K, V> {
         Iter {
             iter: self.iter.clone(),
+            version: unsafe { self.table.as_ref().version() },
         }
     }
 }
@@ -1109,7 +1185,9 @@ impl<'a, K, V> Iterator for Drain<'a, K, V> {
     fn next(&mut self) -> Option<(SafeHash, K, V)> {
         self.iter.next().map(|raw| {
             unsafe {
-                self.table.as_mut().size -= 1;
+                let table = self.table.as_mut();
+                table.size -= 1;
+                table.modifications = table.modifications.wrapping_add(1);
                 let (k, v) = ptr::read(raw.pair());
                 (SafeHash { hash: ptr::replace(&mut *raw.hash(), EMPTY_BUCKET) }, k, v)
             }
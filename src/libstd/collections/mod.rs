@@ -431,6 +431,28 @@ pub use alloc::{LinkedList, VecDeque};
 pub use alloc::{binary_heap, btree_map, btree_set};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use alloc::{linked_list, vec_deque};
+#[unstable(feature = "topo_sort", issue = "0")]
+pub use alloc::algorithms;
+#[unstable(feature = "fast_remove", issue = "0")]
+pub use alloc::fast_remove;
+#[unstable(feature = "growth_policy", issue = "0")]
+pub use alloc::growth;
+#[unstable(feature = "history_buffer", issue = "0")]
+pub use alloc::history_buffer;
+#[unstable(feature = "range_map", issue = "0")]
+pub use alloc::range_map;
+#[unstable(feature = "rope", issue = "0")]
+pub use alloc::rope;
+#[unstable(feature = "seg_vec", issue = "0")]
+pub use alloc::seg_vec;
+#[unstable(feature = "shared_string", issue = "0")]
+pub use alloc::shared_string;
+#[unstable(feature = "static_map", issue = "0")]
+pub use alloc::static_map;
+#[unstable(feature = "string_table", issue = "0")]
+pub use alloc::string_table;
+#[unstable(feature = "unrolled_list", issue = "0")]
+pub use alloc::unrolled_list;
 
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::hash_map::HashMap;
@@ -463,3 +485,36 @@ pub mod hash_set {
     #[stable(feature = "rust1", since = "1.0.0")]
     pub use super::hash::set::*;
 }
+
+#[unstable(feature = "perfect_hash_map", issue = "0")]
+pub mod perfect_hash_map {
+    //! A read-only map over a fixed key set, using a minimal perfect hash.
+    #[unstable(feature = "perfect_hash_map", issue = "0")]
+    pub use super::hash::perfect::*;
+}
+
+#[unstable(feature = "expiring_map", issue = "0")]
+pub mod expiring_map {
+    //! A hash map whose entries expire after a number of insertions, or
+    //! when it grows too large.
+    #[unstable(feature = "expiring_map", issue = "0")]
+    pub use super::hash::expiring::*;
+}
+
+#[unstable(feature = "lru_cache", issue = "0")]
+pub mod lru_cache {
+    //! A hash map that evicts its least recently used entry when it is full.
+    #[unstable(feature = "lru_cache", issue = "0")]
+    pub use super::hash::lru::*;
+}
+
+#[unstable(feature = "keyed_priority_queue", issue = "0")]
+pub mod keyed_priority_queue {
+    //! A priority queue whose entries can be looked up, reprioritized and
+    //! removed by key.
+    #[unstable(feature = "keyed_priority_queue", issue = "0")]
+    pub use super::hash::priority::*;
+}
+
+#[unstable(feature = "default_map", issue = "0")]
+pub mod default_map;
@@ -51,7 +51,9 @@
 // coherence challenge (e.g., specialization, neg impls, etc) we can
 // reconsider what crate these items belong in.
 
+use alloc::algorithms;
 use alloc::allocator;
+use alloc::string_table;
 use any::TypeId;
 use borrow::Cow;
 use cell;
@@ -296,6 +298,13 @@ impl Error for num::ParseFloatError {
     }
 }
 
+#[unstable(feature = "ordered_float", issue = "0")]
+impl Error for num::FloatIsNan {
+    fn description(&self) -> &str {
+        self.__description()
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl Error for string::FromUtf8Error {
     fn description(&self) -> &str {
@@ -317,6 +326,20 @@ impl Error for string::ParseError {
     }
 }
 
+#[unstable(feature = "string_table", issue = "0")]
+impl Error for string_table::InvalidBlob {
+    fn description(&self) -> &str {
+        "invalid string table blob"
+    }
+}
+
+#[unstable(feature = "topo_sort", issue = "0")]
+impl<K: fmt::Debug> Error for algorithms::CycleError<K> {
+    fn description(&self) -> &str {
+        "dependency cycle detected"
+    }
+}
+
 #[stable(feature = "decode_utf16", since = "1.9.0")]
 impl Error for char::DecodeUtf16Error {
     fn description(&self) -> &str {
@@ -301,6 +301,7 @@
 #![feature(slice_bytes)]
 #![feature(slice_concat_ext)]
 #![feature(slice_internals)]
+#![feature(slice_memchr)]
 #![feature(slice_patterns)]
 #![feature(staged_api)]
 #![feature(stmt_expr_attributes)]
@@ -359,7 +360,7 @@ use prelude::v1::*;
 extern crate core as __core;
 
 #[macro_use]
-#[macro_reexport(vec, format)]
+#[macro_reexport(vec, format, static_map, btreemap, btreeset)]
 extern crate alloc;
 extern crate alloc_system;
 extern crate std_unicode;
@@ -266,6 +266,70 @@ macro_rules! select {
     })
 }
 
+/// Creates a [`HashMap`] from a list of `key => value` pairs.
+///
+/// The map is allocated with room for exactly as many entries as are
+/// listed. The pairs are inserted in order, so a later pair replaces an
+/// earlier one with an equal key.
+///
+/// [`HashMap`]: ../std/collections/struct.HashMap.html
+///
+/// # Examples
+///
+/// ```
+/// #![feature(collection_macros)]
+///
+/// let ports = hashmap! {
+///     "http" => 80,
+///     "https" => 443,
+/// };
+/// assert_eq!(ports["https"], 443);
+/// assert_eq!(ports.len(), 2);
+/// ```
+#[macro_export]
+#[unstable(feature = "collection_macros", issue = "0")]
+macro_rules! hashmap {
+    (@unit $x:expr) => (());
+    () => ($crate::collections::hash_map::__with_capacity_for_macro(0));
+    ($($k:expr => $v:expr),+) => ({
+        let len = <[()]>::len(&[$(hashmap!(@unit $k)),+]);
+        let mut map = $crate::collections::hash_map::__with_capacity_for_macro(len);
+        $( map.insert($k, $v); )+
+        map
+    });
+    ($($k:expr => $v:expr,)+) => (hashmap!($($k => $v),+))
+}
+
+/// Creates a [`HashSet`] containing the arguments.
+///
+/// The set is allocated with room for exactly as many elements as are
+/// listed.
+///
+/// [`HashSet`]: ../std/collections/struct.HashSet.html
+///
+/// # Examples
+///
+/// ```
+/// #![feature(collection_macros)]
+///
+/// let vowels = hashset!['a', 'e', 'i', 'o', 'u'];
+/// assert!(vowels.contains(&'o'));
+/// assert!(!vowels.contains(&'y'));
+/// ```
+#[macro_export]
+#[unstable(feature = "collection_macros", issue = "0")]
+macro_rules! hashset {
+    (@unit $x:expr) => (());
+    () => ($crate::collections::hash_set::__with_capacity_for_macro(0));
+    ($($x:expr),+) => ({
+        let len = <[()]>::len(&[$(hashset!(@unit $x)),+]);
+        let mut set = $crate::collections::hash_set::__with_capacity_for_macro(len);
+        $( set.insert($x); )+
+        set
+    });
+    ($($x:expr,)+) => (hashset!($($x),+))
+}
+
 #[cfg(test)]
 macro_rules! assert_approx_eq {
     ($a:expr, $b:expr) => ({
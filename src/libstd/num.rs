@@ -20,6 +20,8 @@
 pub use core::num::{FpCategory, ParseIntError, ParseFloatError, TryFromIntError};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::num::Wrapping;
+#[unstable(feature = "ordered_float", issue = "0")]
+pub use core::num::{OrderedFloat, NotNan, FloatIsNan};
 
 #[unstable(feature = "nonzero", issue = "49137")]
 #[allow(deprecated)]
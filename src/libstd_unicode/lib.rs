@@ -42,6 +42,7 @@
 #![feature(unboxed_closures)]
 
 mod bool_trie;
+mod normalize;
 mod tables;
 mod u_str;
 mod version;
@@ -50,7 +51,7 @@ pub mod lossy;
 
 #[allow(deprecated)]
 pub mod str {
-    pub use u_str::{SplitWhitespace, UnicodeStr};
+    pub use u_str::{Graphemes, SplitWhitespace, UnicodeStr};
     pub use u_str::Utf16Encoder;
 }
 
@@ -59,6 +60,12 @@ pub mod derived_property {
     pub use tables::derived_property::{Case_Ignorable, Cased};
 }
 
+// For use in liballoc, not re-exported in libstd.
+pub mod normalization {
+    pub use normalize::{canonical_combining_class, compose};
+    pub use normalize::{decompose_canonical, decompose_compatible};
+}
+
 // For use in libsyntax
 pub mod property {
     pub use tables::property::Pattern_White_Space;
@@ -0,0 +1,116 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Functions for computing canonical and compatible decompositions and
+//! canonical compositions of Unicode characters.
+
+use core::char;
+use tables::normalization::{canonical_decomposition, compatibility_decomposition, composition};
+
+pub use tables::normalization::canonical_combining_class;
+
+/// Computes the full canonical decomposition of `c`, passing each resulting
+/// character to `emit` in turn.
+pub fn decompose_canonical<F: FnMut(char)>(c: char, mut emit: F) {
+    decompose(c, false, &mut emit);
+}
+
+/// Computes the full compatibility decomposition of `c`, passing each
+/// resulting character to `emit` in turn.
+pub fn decompose_compatible<F: FnMut(char)>(c: char, mut emit: F) {
+    decompose(c, true, &mut emit);
+}
+
+fn decompose<F: FnMut(char)>(c: char, compatible: bool, emit: &mut F) {
+    // 7-bit ASCII never decomposes
+    if c <= '\x7f' {
+        emit(c);
+        return;
+    }
+
+    // Hangul syllables decompose algorithmically
+    if is_hangul_syllable(c) {
+        decompose_hangul(c, emit);
+        return;
+    }
+
+    // Canonical decompositions also apply to compatibility decompositions,
+    // and both may need to be applied recursively.
+    if let Some(chars) = canonical_decomposition(c) {
+        for &d in chars {
+            decompose(d, compatible, emit);
+        }
+        return;
+    }
+    if compatible {
+        if let Some(chars) = compatibility_decomposition(c) {
+            for &d in chars {
+                decompose(d, compatible, emit);
+            }
+            return;
+        }
+    }
+
+    emit(c);
+}
+
+/// Returns the primary composite of the pair `a`, `b`, if there is one.
+pub fn compose(a: char, b: char) -> Option<char> {
+    compose_hangul(a, b).or_else(|| composition(a, b))
+}
+
+// Constants from Unicode 10.0.0 Section 3.12 Conjoining Jamo Behavior
+const S_BASE: u32 = 0xAC00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11A7;
+const L_COUNT: u32 = 19;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = V_COUNT * T_COUNT;
+const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+fn is_hangul_syllable(c: char) -> bool {
+    (c as u32).wrapping_sub(S_BASE) < S_COUNT
+}
+
+fn decompose_hangul<F: FnMut(char)>(s: char, emit: &mut F) {
+    let s_index = s as u32 - S_BASE;
+    let l = L_BASE + s_index / N_COUNT;
+    let v = V_BASE + (s_index % N_COUNT) / T_COUNT;
+    let t = T_BASE + s_index % T_COUNT;
+    unsafe {
+        emit(char::from_u32_unchecked(l));
+        emit(char::from_u32_unchecked(v));
+        if t != T_BASE {
+            emit(char::from_u32_unchecked(t));
+        }
+    }
+}
+
+fn compose_hangul(a: char, b: char) -> Option<char> {
+    let (a, b) = (a as u32, b as u32);
+    let l_index = a.wrapping_sub(L_BASE);
+    let v_index = b.wrapping_sub(V_BASE);
+    if l_index < L_COUNT && v_index < V_COUNT {
+        // L + V => LV
+        let s = S_BASE + (l_index * V_COUNT + v_index) * T_COUNT;
+        return unsafe { Some(char::from_u32_unchecked(s)) };
+    }
+
+    let s_index = a.wrapping_sub(S_BASE);
+    let t_index = b.wrapping_sub(T_BASE);
+    if s_index < S_COUNT && s_index % T_COUNT == 0 && 0 < t_index && t_index < T_COUNT {
+        // LV + T => LVT
+        return unsafe { Some(char::from_u32_unchecked(a + t_index)) };
+    }
+
+    None
+}
@@ -16,6 +16,7 @@
 use core::char;
 use core::iter::{Filter, FusedIterator};
 use core::str::Split;
+use tables::grapheme::{self, GraphemeCat};
 
 /// An iterator over the non-whitespace substrings of a string,
 /// separated by any amount of whitespace.
@@ -31,10 +32,24 @@ pub struct SplitWhitespace<'a> {
     inner: Filter<Split<'a, IsWhitespace>, IsNotEmpty>,
 }
 
+/// An iterator over the extended grapheme clusters of a string.
+///
+/// This struct is created by the [`graphemes`] method on [`str`].
+/// See its documentation for more.
+///
+/// [`graphemes`]: ../../std/primitive.str.html#method.graphemes
+/// [`str`]: ../../std/primitive.str.html
+#[unstable(feature = "str_graphemes", issue = "0")]
+#[derive(Clone, Debug)]
+pub struct Graphemes<'a> {
+    string: &'a str,
+}
+
 /// Methods for Unicode string slices
 #[allow(missing_docs)] // docs in liballoc
 pub trait UnicodeStr {
     fn split_whitespace<'a>(&'a self) -> SplitWhitespace<'a>;
+    fn graphemes<'a>(&'a self) -> Graphemes<'a>;
     fn is_whitespace(&self) -> bool;
     fn is_alphanumeric(&self) -> bool;
     fn trim(&self) -> &str;
@@ -48,6 +63,11 @@ impl UnicodeStr for str {
         SplitWhitespace { inner: self.split(IsWhitespace).filter(IsNotEmpty) }
     }
 
+    #[inline]
+    fn graphemes(&self) -> Graphemes {
+        Graphemes { string: self }
+    }
+
     #[inline]
     fn is_whitespace(&self) -> bool {
         self.chars().all(|c| c.is_whitespace())
@@ -187,3 +207,115 @@ impl<'a> DoubleEndedIterator for SplitWhitespace<'a> {
 
 #[stable(feature = "fused", since = "1.26.0")]
 impl<'a> FusedIterator for SplitWhitespace<'a> {}
+
+impl<'a> Graphemes<'a> {
+    /// Views the part of the string that has not been iterated over yet.
+    #[unstable(feature = "str_graphemes", issue = "0")]
+    #[inline]
+    pub fn as_str(&self) -> &'a str {
+        self.string
+    }
+}
+
+#[unstable(feature = "str_graphemes", issue = "0")]
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let mut chars = self.string.char_indices();
+        let mut after = match chars.next() {
+            Some((_, c)) => grapheme::grapheme_category(c),
+            None => return None,
+        };
+        let mut end = self.string.len();
+        for (idx, c) in chars {
+            let before = after;
+            after = grapheme::grapheme_category(c);
+            if is_boundary(&self.string[..idx], before, after) {
+                end = idx;
+                break;
+            }
+        }
+        let (head, tail) = self.string.split_at(end);
+        self.string = tail;
+        Some(head)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // A single grapheme cluster may span any number of bytes.
+        let len = self.string.len();
+        (if len == 0 { 0 } else { 1 }, Some(len))
+    }
+}
+
+#[unstable(feature = "str_graphemes", issue = "0")]
+impl<'a> DoubleEndedIterator for Graphemes<'a> {
+    fn next_back(&mut self) -> Option<&'a str> {
+        let mut chars = self.string.char_indices().rev();
+        let (mut start, mut before) = match chars.next() {
+            Some((idx, c)) => (idx, grapheme::grapheme_category(c)),
+            None => return None,
+        };
+        for (idx, c) in chars {
+            let after = before;
+            before = grapheme::grapheme_category(c);
+            if is_boundary(&self.string[..start], before, after) {
+                break;
+            }
+            start = idx;
+        }
+        let (head, tail) = self.string.split_at(start);
+        self.string = head;
+        Some(tail)
+    }
+}
+
+#[unstable(feature = "str_graphemes", issue = "0")]
+impl<'a> FusedIterator for Graphemes<'a> {}
+
+/// Whether there is an extended grapheme cluster boundary between a
+/// character of category `before` and one of category `after`, following
+/// the rules of [UAX #29].
+///
+/// `prefix` is the text up to the candidate boundary, starting at an earlier
+/// boundary. It is only inspected for the rules that need more context than
+/// the two characters themselves.
+///
+/// [UAX #29]: http://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundary_Rules
+fn is_boundary(prefix: &str, before: GraphemeCat, after: GraphemeCat) -> bool {
+    use tables::grapheme::*;
+
+    match (before, after) {
+        // GB3
+        (GC_CR, GC_LF) => false,
+        // GB4, GB5
+        (GC_Control, _) | (GC_CR, _) | (GC_LF, _) => true,
+        (_, GC_Control) | (_, GC_CR) | (_, GC_LF) => true,
+        // GB6, GB7, GB8
+        (GC_L, GC_L) | (GC_L, GC_V) | (GC_L, GC_LV) | (GC_L, GC_LVT) => false,
+        (GC_LV, GC_V) | (GC_LV, GC_T) | (GC_V, GC_V) | (GC_V, GC_T) => false,
+        (GC_LVT, GC_T) | (GC_T, GC_T) => false,
+        // GB9, GB9a, GB9b
+        (_, GC_Extend) | (_, GC_ZWJ) | (_, GC_SpacingMark) | (GC_Prepend, _) => false,
+        // GB10: an emoji modifier attaches to a preceding emoji base, even
+        // across `Extend` characters.
+        (_, GC_E_Modifier) => {
+            match prefix.chars().rev().map(grapheme_category).find(|&cat| cat != GC_Extend) {
+                Some(GC_E_Base) | Some(GC_E_Base_GAZ) => false,
+                _ => true,
+            }
+        }
+        // GB11
+        (GC_ZWJ, GC_Glue_After_Zwj) | (GC_ZWJ, GC_E_Base_GAZ) => false,
+        // GB12, GB13: regional indicators pair up into flags.
+        (GC_Regional_Indicator, GC_Regional_Indicator) => {
+            let run = prefix.chars().rev()
+                            .take_while(|&c| grapheme_category(c) == GC_Regional_Indicator)
+                            .count();
+            run % 2 == 0
+        }
+        // GB999
+        _ => true,
+    }
+}
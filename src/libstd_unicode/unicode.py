@@ -458,6 +458,99 @@ def emit_norm_module(f, canon, compat, combine, norm_props):
             canon_comp[decomp[0]].append( (decomp[1], char) )
     canon_comp_keys = sorted(canon_comp.keys())
 
+    f.write("pub mod normalization {\n")
+
+    def mkdata_fun(table):
+        def f(char):
+            return "(%s,&[%s])" % (escape_char(char), ",".join(map(escape_char, table[char])))
+        return f
+
+    def comp_pfun(char):
+        pairs = sorted(canon_comp[char])
+        return "(%s,&[%s])" % (escape_char(char), ",".join(
+            "(%s,%s)" % (escape_char(second), escape_char(composed))
+            for (second, composed) in pairs))
+
+    f.write("""    use core::cmp::Ordering::{Equal, Less, Greater};
+    use core::option::Option;
+    use core::result::Result::{Ok, Err};
+
+    fn bsearch_table<T: Copy>(c: char, r: &[(char, T)]) -> Option<T> {
+        r.binary_search_by(|&(key, _)| key.cmp(&c)).ok().map(|idx| r[idx].1)
+    }
+
+    pub fn canonical_decomposition(c: char) -> Option<&'static [char]> {
+        bsearch_table(c, canonical_table)
+    }
+
+    pub fn compatibility_decomposition(c: char) -> Option<&'static [char]> {
+        bsearch_table(c, compatibility_table)
+    }
+
+    pub fn composition(first: char, second: char) -> Option<char> {
+        bsearch_table(first, composition_table).and_then(|candidates| {
+            bsearch_table(second, candidates)
+        })
+    }
+
+    pub fn canonical_combining_class(c: char) -> u8 {
+        match combining_class_table.binary_search_by(|&(lo, hi, _)| {
+            if lo <= c && c <= hi { Equal }
+            else if hi < c { Less }
+            else { Greater }
+        }) {
+            Ok(idx) => combining_class_table[idx].2,
+            Err(_) => 0,
+        }
+    }
+
+""")
+    emit_table(f, "canonical_table", canon_keys, "&[(char, &'static [char])]",
+        pfun=mkdata_fun(canon), is_pub=False)
+    emit_table(f, "compatibility_table", compat_keys, "&[(char, &'static [char])]",
+        pfun=mkdata_fun(compat), is_pub=False)
+    emit_table(f, "composition_table", canon_comp_keys,
+        "&[(char, &'static [(char, char)])]", pfun=comp_pfun, is_pub=False)
+    emit_table(f, "combining_class_table", combine, "&[(char, char, u8)]", is_pub=False,
+        pfun=lambda x: "(%s,%s,%s)" % (escape_char(x[0]), escape_char(x[1]), x[2]))
+    f.write("}\n\n")
+
+def emit_grapheme_module(f, grapheme_table, grapheme_cats):
+    f.write("""pub mod grapheme {
+    use core::cmp::Ordering::{Equal, Less, Greater};
+    use core::result::Result::{Ok, Err};
+
+    pub use self::GraphemeCat::*;
+
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum GraphemeCat {
+""")
+    for cat in grapheme_cats + ["Any"]:
+        f.write("        GC_" + cat + ",\n")
+    f.write("""    }
+
+    fn bsearch_range_value_table(c: char, r: &[(char, char, GraphemeCat)]) -> GraphemeCat {
+        match r.binary_search_by(|&(lo, hi, _)| {
+            if lo <= c && c <= hi { Equal }
+            else if hi < c { Less }
+            else { Greater }
+        }) {
+            Ok(idx) => r[idx].2,
+            Err(_) => GC_Any,
+        }
+    }
+
+    pub fn grapheme_category(c: char) -> GraphemeCat {
+        bsearch_range_value_table(c, grapheme_cat_table)
+    }
+
+""")
+    emit_table(f, "grapheme_cat_table", grapheme_table, "&[(char, char, GraphemeCat)]",
+        pfun=lambda x: "(%s,%s,GC_%s)" % (escape_char(x[0]), escape_char(x[1]), x[2]),
+        is_pub=False)
+    f.write("}\n\n")
+
 if __name__ == "__main__":
     r = "tables.rs"
     if os.path.exists(r):
@@ -502,3 +595,19 @@ pub const UNICODE_VERSION: UnicodeVersion = UnicodeVersion {
         # normalizations and conversions module
         emit_norm_module(rf, canon_decomp, compat_decomp, combines, norm_props)
         emit_conversions_module(rf, to_upper, to_lower, to_title)
+
+        # grapheme cluster break categories, see
+        # http://www.unicode.org/reports/tr29/#Grapheme_Cluster_Break_Property_Values
+        grapheme_cats = load_properties("auxiliary/GraphemeBreakProperty.txt", [])
+
+        # Control also includes Cs (surrogate codepoints), but these are not valid
+        # `char`s in Rust, so they are removed from the table.
+        grapheme_cats["Control"] = group_cat(list(
+            set(ungroup_cat(grapheme_cats["Control"]))
+            - set(ungroup_cat([surrogate_codepoints]))))
+
+        grapheme_table = []
+        for cat in grapheme_cats:
+            grapheme_table.extend([(lo, hi, cat) for (lo, hi) in grapheme_cats[cat]])
+        grapheme_table.sort(key=lambda w: w[0])
+        emit_grapheme_module(rf, grapheme_table, sorted(grapheme_cats.keys()))